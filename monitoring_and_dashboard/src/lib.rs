@@ -1,14 +1,25 @@
 use std::net::SocketAddr;
 use std::convert::Infallible;
-use hyper::{Body, Request, Response, Server};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
 use hyper::service::{make_service_fn, service_fn};
 use tracing::{info, error};
 
 /// Module de métriques : collecte et exposition des métriques au format Prometheus.
 pub mod metrics {
-    use prometheus::{Encoder, TextEncoder, Counter, Histogram, register_counter, register_histogram, gather};
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+    use prometheus::{
+        Encoder, TextEncoder, Counter, CounterVec, Histogram, Opts, register_counter, register_counter_vec,
+        register_histogram, gather,
+    };
     use lazy_static::lazy_static;
 
+    /// Nombre maximal par défaut de valeurs distinctes d'un label, par métrique.
+    pub const DEFAULT_MAX_LABEL_VALUES: usize = 100;
+
+    /// Valeur de label regroupant les valeurs au-delà de la limite.
+    pub const OVERFLOW_LABEL_VALUE: &str = "other";
+
     lazy_static! {
         /// Compteur pour le nombre total d'événements.
         pub static ref MY_COUNTER: Counter = register_counter!(
@@ -21,6 +32,67 @@ pub mod metrics {
             "nodara_request_duration_seconds",
             "Histogramme des durées de traitement des requêtes"
         ).expect("Échec de la création de l'histogramme");
+
+        /// Compteur des valeurs de label regroupées dans `OVERFLOW_LABEL_VALUE`, par métrique.
+        pub static ref LABEL_OVERFLOW_TOTAL: CounterVec = register_counter_vec!(
+            "metrics_label_overflow_total",
+            "Nombre de valeurs de label regroupées sous \"other\" faute de place",
+            &["metric"]
+        ).expect("Échec de la création du compteur de débordement");
+    }
+
+    /// Borne la cardinalité d'un label : les `limit` premières valeurs distinctes sont conservées,
+    /// les suivantes sont remplacées par `OVERFLOW_LABEL_VALUE` et comptées dans
+    /// `metrics_label_overflow_total`.
+    pub struct LabelLimiter {
+        metric: String,
+        limit: usize,
+        seen: Mutex<HashSet<String>>,
+    }
+
+    impl LabelLimiter {
+        pub fn new(metric: &str, limit: usize) -> Self {
+            Self { metric: metric.to_string(), limit, seen: Mutex::new(HashSet::new()) }
+        }
+
+        /// Retourne la valeur de label à utiliser pour `value`.
+        pub fn admit<'a>(&self, value: &'a str) -> &'a str {
+            let mut seen = self.seen.lock().expect("Limiteur de labels empoisonné");
+            if seen.contains(value) {
+                return value;
+            }
+            if seen.len() < self.limit {
+                seen.insert(value.to_string());
+                return value;
+            }
+            LABEL_OVERFLOW_TOTAL.with_label_values(&[&self.metric]).inc();
+            OVERFLOW_LABEL_VALUE
+        }
+    }
+
+    /// Compteur à un label dont la cardinalité est bornée par un `LabelLimiter`.
+    pub struct BoundedCounterVec {
+        counter: CounterVec,
+        limiter: LabelLimiter,
+    }
+
+    impl BoundedCounterVec {
+        /// Crée et enregistre le compteur `name`, limité à `max_label_values` valeurs de `label`.
+        pub fn register(name: &str, help: &str, label: &str, max_label_values: usize) -> prometheus::Result<Self> {
+            let counter = CounterVec::new(Opts::new(name, help), &[label])?;
+            prometheus::register(Box::new(counter.clone()))?;
+            Ok(Self { counter, limiter: LabelLimiter::new(name, max_label_values) })
+        }
+
+        /// Incrémente la série de `value` (ou `OVERFLOW_LABEL_VALUE` au-delà de la limite).
+        pub fn inc(&self, value: &str) {
+            self.counter.with_label_values(&[self.limiter.admit(value)]).inc();
+        }
+
+        /// Valeur courante de la série de `value`.
+        pub fn get(&self, value: &str) -> f64 {
+            self.counter.with_label_values(&[value]).get()
+        }
     }
 
     /// Récupère toutes les métriques et les encode au format texte (exposition Prometheus).
@@ -35,6 +107,243 @@ pub mod metrics {
     }
 }
 
+/// Module de push : métriques poussées par des processus externes (workers offchain) au format
+/// texte Prometheus, à la manière d'un pushgateway, puis exposées avec les métriques locales.
+pub mod push {
+    use std::collections::BTreeMap;
+    use std::fmt;
+    use std::sync::Mutex;
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        /// Registre global des métriques poussées via `POST /push`.
+        pub static ref PUSHED: Mutex<PushRegistry> = Mutex::new(PushRegistry::default());
+    }
+
+    /// Erreur de parsing d'une entrée au format texte Prometheus, avec son numéro de ligne (base 1).
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct PushError {
+        pub line: usize,
+        pub reason: &'static str,
+    }
+
+    impl fmt::Display for PushError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "line {}: {}", self.line, self.reason)
+        }
+    }
+
+    impl std::error::Error for PushError {}
+
+    /// Famille de métriques : métadonnées `HELP`/`TYPE` et dernière valeur de chaque série.
+    #[derive(Debug, Clone, Default, PartialEq)]
+    struct Family {
+        help: Option<String>,
+        kind: Option<String>,
+        /// Série (`nom{labels}` normalisé) -> valeur telle que reçue.
+        samples: BTreeMap<String, String>,
+    }
+
+    /// Registre des métriques poussées. Une série déjà connue est remplacée par la dernière
+    /// valeur reçue ; les autres séries sont conservées.
+    #[derive(Debug, Clone, Default, PartialEq)]
+    pub struct PushRegistry {
+        families: BTreeMap<String, Family>,
+    }
+
+    const KINDS: [&str; 5] = ["counter", "gauge", "histogram", "summary", "untyped"];
+    const SUFFIXES: [&str; 3] = ["_bucket", "_sum", "_count"];
+
+    impl PushRegistry {
+        /// Parse `input` et fusionne ses séries dans le registre. L'entrée est rejetée en bloc
+        /// à la première ligne invalide, sans modifier le registre.
+        /// Retourne le nombre de séries reçues.
+        pub fn merge(&mut self, input: &str) -> Result<usize, PushError> {
+            let parsed = parse(input)?;
+            let count = parsed.values().map(|family| family.samples.len()).sum();
+            for (name, family) in parsed {
+                let entry = self.families.entry(name).or_default();
+                if family.help.is_some() {
+                    entry.help = family.help;
+                }
+                if family.kind.is_some() {
+                    entry.kind = family.kind;
+                }
+                entry.samples.extend(family.samples);
+            }
+            Ok(count)
+        }
+
+        /// Encode le registre au format texte Prometheus.
+        pub fn render(&self) -> String {
+            let mut out = String::new();
+            for (name, family) in &self.families {
+                if let Some(help) = &family.help {
+                    out.push_str(&format!("# HELP {} {}\n", name, help));
+                }
+                if let Some(kind) = &family.kind {
+                    out.push_str(&format!("# TYPE {} {}\n", name, kind));
+                }
+                for (series, value) in &family.samples {
+                    out.push_str(&format!("{} {}\n", series, value));
+                }
+            }
+            out
+        }
+    }
+
+    /// Fusionne `input` dans le registre global `PUSHED`.
+    pub fn push_metrics(input: &str) -> Result<usize, PushError> {
+        PUSHED.lock().expect("Registre de push empoisonné").merge(input)
+    }
+
+    /// Encode les métriques du registre global `PUSHED`.
+    pub fn gather_pushed() -> String {
+        PUSHED.lock().expect("Registre de push empoisonné").render()
+    }
+
+    fn parse(input: &str) -> Result<BTreeMap<String, Family>, PushError> {
+        let mut families: BTreeMap<String, Family> = BTreeMap::new();
+        for (index, raw) in input.lines().enumerate() {
+            let err = |reason| PushError { line: index + 1, reason };
+            let line = raw.trim();
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(comment) = line.strip_prefix('#') {
+                let mut parts = comment.trim_start().splitn(3, char::is_whitespace);
+                match (parts.next(), parts.next(), parts.next()) {
+                    (Some("HELP"), Some(name), text) => {
+                        if !is_metric_name(name) {
+                            return Err(err("invalid metric name"));
+                        }
+                        families.entry(name.to_string()).or_default().help =
+                            Some(text.unwrap_or("").trim().to_string());
+                    }
+                    (Some("TYPE"), Some(name), Some(kind)) => {
+                        let kind = kind.trim();
+                        if !is_metric_name(name) {
+                            return Err(err("invalid metric name"));
+                        }
+                        if !KINDS.contains(&kind) {
+                            return Err(err("unknown metric type"));
+                        }
+                        families.entry(name.to_string()).or_default().kind = Some(kind.to_string());
+                    }
+                    (Some("TYPE"), _, _) => return Err(err("missing metric type")),
+                    // Autres commentaires : ignorés.
+                    _ => {}
+                }
+                continue;
+            }
+            let (name, labels, rest) = parse_series(line).ok_or_else(|| err("invalid series"))?;
+            let mut fields = rest.split_whitespace();
+            let value = fields.next().ok_or_else(|| err("missing value"))?;
+            if parse_value(value).is_none() {
+                return Err(err("invalid value"));
+            }
+            if let Some(timestamp) = fields.next() {
+                if timestamp.parse::<i64>().is_err() {
+                    return Err(err("invalid timestamp"));
+                }
+            }
+            if fields.next().is_some() {
+                return Err(err("unexpected trailing data"));
+            }
+            let family = family_of(&families, name);
+            let series = if labels.is_empty() {
+                name.to_string()
+            } else {
+                let rendered: Vec<String> = labels.iter().map(|(k, v)| format!("{}=\"{}\"", k, v)).collect();
+                format!("{}{{{}}}", name, rendered.join(","))
+            };
+            families.entry(family).or_default().samples.insert(series, value.to_string());
+        }
+        Ok(families)
+    }
+
+    /// Famille d'un échantillon : celle déclarée par `TYPE`/`HELP` pour son nom ou pour son nom
+    /// privé d'un suffixe d'histogramme/résumé, à défaut son propre nom.
+    fn family_of(families: &BTreeMap<String, Family>, name: &str) -> String {
+        SUFFIXES
+            .iter()
+            .filter_map(|suffix| name.strip_suffix(suffix))
+            .find(|base| families.contains_key(*base))
+            .unwrap_or(name)
+            .to_string()
+    }
+
+    /// Découpe `nom{labels} reste` ; les labels sont triés par nom, valeurs conservées échappées.
+    fn parse_series(line: &str) -> Option<(&str, Vec<(&str, &str)>, &str)> {
+        let name_end = line.find(|c: char| c == '{' || c.is_whitespace()).unwrap_or(line.len());
+        let name = &line[..name_end];
+        if !is_metric_name(name) {
+            return None;
+        }
+        let mut rest = &line[name_end..];
+        let mut labels = Vec::new();
+        if let Some(mut inner) = rest.strip_prefix('{') {
+            loop {
+                inner = inner.trim_start();
+                if let Some(after) = inner.strip_prefix('}') {
+                    rest = after;
+                    break;
+                }
+                let eq = inner.find('=')?;
+                let key = inner[..eq].trim();
+                if !is_label_name(key) || labels.iter().any(|(k, _)| *k == key) {
+                    return None;
+                }
+                let quoted = inner[eq + 1..].trim_start().strip_prefix('"')?;
+                let end = closing_quote(quoted)?;
+                labels.push((key, &quoted[..end]));
+                inner = quoted[end + 1..].trim_start();
+                inner = inner.strip_prefix(',').unwrap_or(inner);
+            }
+            labels.sort();
+        }
+        if !rest.starts_with(char::is_whitespace) {
+            return None;
+        }
+        Some((name, labels, rest))
+    }
+
+    /// Position du guillemet fermant une valeur de label, en tenant compte des échappements.
+    fn closing_quote(s: &str) -> Option<usize> {
+        let mut escaped = false;
+        for (i, c) in s.char_indices() {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => return Some(i),
+                _ => {}
+            }
+        }
+        None
+    }
+
+    fn parse_value(value: &str) -> Option<f64> {
+        match value {
+            "+Inf" => Some(f64::INFINITY),
+            "-Inf" => Some(f64::NEG_INFINITY),
+            "NaN" => Some(f64::NAN),
+            _ => value.parse().ok(),
+        }
+    }
+
+    fn is_metric_name(name: &str) -> bool {
+        let mut chars = name.chars();
+        matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_' || c == ':')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == ':')
+    }
+
+    fn is_label_name(name: &str) -> bool {
+        let mut chars = name.chars();
+        matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+    }
+}
+
 /// Module de dashboard : fonctions de gestion de la configuration des dashboards.
 pub mod dashboard {
     use std::fs;
@@ -62,13 +371,45 @@ pub fn init_monitoring() {
     info!("Monitoring and Dashboard module initialized.");
 }
 
-/// Démarre un serveur HTTP pour exposer les métriques au format Prometheus.
-pub async fn serve_metrics(addr: SocketAddr) {
-    async fn metrics_handler(_req: Request<Body>) -> Result<Response<Body>, Infallible> {
-        let body = metrics::gather_metrics();
-        Ok(Response::new(Body::from(body)))
+/// Traite une requête du serveur de métriques.
+///
+/// - `POST /push` : fusionne des métriques au format texte Prometheus dans le registre de push
+///   (`400` si l'entrée est invalide).
+/// - toute autre requête : métriques locales suivies des métriques poussées.
+pub async fn metrics_handler(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+    if req.uri().path() == "/push" {
+        if req.method() != Method::POST {
+            return Ok(status_response(StatusCode::METHOD_NOT_ALLOWED, "Use POST to push metrics\n".into()));
+        }
+        let bytes = match hyper::body::to_bytes(req.into_body()).await {
+            Ok(bytes) => bytes,
+            Err(e) => return Ok(status_response(StatusCode::BAD_REQUEST, format!("Invalid body: {}\n", e))),
+        };
+        let pushed = std::str::from_utf8(&bytes)
+            .map_err(|_| "body is not UTF-8".to_string())
+            .and_then(|input| push::push_metrics(input).map_err(|e| e.to_string()));
+        return Ok(match pushed {
+            Ok(count) => {
+                info!("Pushed {} metric series.", count);
+                status_response(StatusCode::OK, format!("{} series pushed\n", count))
+            }
+            Err(e) => status_response(StatusCode::BAD_REQUEST, format!("Invalid metrics: {}\n", e)),
+        });
     }
+    let mut body = metrics::gather_metrics();
+    body.push_str(&push::gather_pushed());
+    Ok(Response::new(Body::from(body)))
+}
+
+fn status_response(status: StatusCode, body: String) -> Response<Body> {
+    let mut response = Response::new(Body::from(body));
+    *response.status_mut() = status;
+    response
+}
 
+/// Démarre un serveur HTTP pour exposer les métriques au format Prometheus et recevoir les
+/// métriques poussées sur `POST /push`.
+pub async fn serve_metrics(addr: SocketAddr) {
     let make_svc = make_service_fn(|_conn| async {
         Ok::<_, Infallible>(service_fn(metrics_handler))
     });
@@ -104,3 +445,77 @@ pub async fn serve_dashboard(addr: SocketAddr) {
         error!("Dashboard server error: {}", e);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn send(req: Request<Body>) -> (StatusCode, String) {
+        let response = metrics_handler(req).await.unwrap();
+        let status = response.status();
+        let bytes = hyper::body::to_bytes(response.into_body()).await.unwrap();
+        (status, String::from_utf8(bytes.to_vec()).unwrap())
+    }
+
+    fn post(body: &'static str) -> Request<Body> {
+        Request::post("/push").body(Body::from(body)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn pushed_metric_is_exposed_on_scrape() {
+        let (status, _) = send(post(
+            "# HELP nodara_ocw_jobs_total Jobs run by the worker\n\
+             # TYPE nodara_ocw_jobs_total counter\n\
+             nodara_ocw_jobs_total{worker=\"oracle\",region=\"eu\"} 42\n",
+        ))
+        .await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, body) = send(Request::get("/metrics").body(Body::empty()).unwrap()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.contains("# TYPE nodara_ocw_jobs_total counter"));
+        assert!(body.contains("nodara_ocw_jobs_total{region=\"eu\",worker=\"oracle\"} 42"));
+    }
+
+    #[tokio::test]
+    async fn malformed_push_is_rejected() {
+        for body in ["nodara_ocw_lag seconds\n", "# TYPE nodara_ocw_lag meter\n", "nodara_ocw_lag{worker=oracle} 1\n"] {
+            let (status, _) = send(post(body)).await;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{}", body);
+        }
+        assert!(!push::gather_pushed().contains("nodara_ocw_lag"));
+    }
+
+    #[test]
+    fn label_values_beyond_limit_fold_into_other() {
+        let events = metrics::BoundedCounterVec::register(
+            "nodara_test_events_by_account_total",
+            "Événements par compte",
+            "account",
+            3,
+        )
+        .unwrap();
+        for account in ["a", "b", "c", "d", "e", "a"] {
+            events.inc(account);
+        }
+
+        assert_eq!(events.get("a"), 2.0);
+        assert_eq!(events.get("c"), 1.0);
+        assert_eq!(events.get(metrics::OVERFLOW_LABEL_VALUE), 2.0);
+        assert_eq!(
+            metrics::LABEL_OVERFLOW_TOTAL.with_label_values(&["nodara_test_events_by_account_total"]).get(),
+            2.0
+        );
+        let scrape = metrics::gather_metrics();
+        assert!(!scrape.contains("account=\"d\""));
+        assert!(scrape.contains("nodara_test_events_by_account_total{account=\"other\"} 2"));
+    }
+
+    #[test]
+    fn push_replaces_known_series_and_keeps_others() {
+        let mut registry = push::PushRegistry::default();
+        assert_eq!(registry.merge("a{x=\"1\"} 1\na{x=\"2\"} 2\n"), Ok(2));
+        assert_eq!(registry.merge("a{x=\"1\"} 5\n"), Ok(1));
+        assert_eq!(registry.render(), "a{x=\"1\"} 5\na{x=\"2\"} 2\n");
+    }
+}
@@ -67,6 +67,53 @@ pub fn sequential_sort(mut data: Vec<u32>) -> Vec<u32> {
     data
 }
 
+/// Computes the volatility of `u32` samples in parallel.
+///
+/// See [`sequential_volatility`] for the definition of the metric.
+#[cfg(feature = "std")]
+pub fn parallel_volatility(samples: Vec<u32>) -> u32 {
+    let (sum, sum_sq) = samples
+        .par_iter()
+        .map(|&x| (x as u128, (x as u128) * (x as u128)))
+        .reduce(|| (0, 0), |a, b| (a.0 + b.0, a.1 + b.1));
+    volatility_from_sums(samples.len() as u128, sum, sum_sq)
+}
+
+/// Computes the volatility of `u32` samples sequentially.
+///
+/// The metric is the population standard deviation, rounded down to an integer so that it can be
+/// fed directly to the stability guard's `update_volatility`. Returns 0 for an empty input.
+pub fn sequential_volatility(samples: Vec<u32>) -> u32 {
+    let (sum, sum_sq) = samples
+        .iter()
+        .fold((0u128, 0u128), |(sum, sum_sq), &x| (sum + x as u128, sum_sq + (x as u128) * (x as u128)));
+    volatility_from_sums(samples.len() as u128, sum, sum_sq)
+}
+
+/// Standard deviation from the sample count, the sum and the sum of squares, using
+/// `variance = (n * Σx² - (Σx)²) / n²` to stay exact in integer arithmetic.
+fn volatility_from_sums(n: u128, sum: u128, sum_sq: u128) -> u32 {
+    if n == 0 {
+        return 0;
+    }
+    let variance = (n * sum_sq - sum * sum) / (n * n);
+    integer_sqrt(variance) as u32
+}
+
+/// Integer square root (rounded down), by Newton's method.
+fn integer_sqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+    let mut x = value;
+    let mut y = x / 2 + x % 2;
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
 /// Trait defining an offchain task.
 pub trait OffchainTask {
     /// Executes the offchain task and returns the result as a vector of bytes.
@@ -127,9 +174,28 @@ impl OffchainTask for SortTask {
     }
 }
 
+/// Offchain task that computes the volatility of a series of samples (see [`sequential_volatility`]).
+///
+/// Samples are processed in parallel when `std` is available and sequentially otherwise. The result
+/// is the metric encoded as a little-endian `u32`.
+pub struct VolatilityTask {
+    pub samples: Vec<u32>,
+}
+
+impl OffchainTask for VolatilityTask {
+    fn execute(&self) -> Result<Vec<u8>, &'static str> {
+        #[cfg(feature = "std")]
+        let volatility = parallel_volatility(self.samples.clone());
+        #[cfg(not(feature = "std"))]
+        let volatility = sequential_volatility(self.samples.clone());
+        Ok(volatility.to_le_bytes().to_vec())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::vec;
 
     #[test]
     fn test_parallel_sum() {
@@ -208,4 +274,28 @@ mod tests {
         expected.sort();
         assert_eq!(sorted, expected);
     }
+
+    #[test]
+    fn test_parallel_volatility() {
+        let samples: Vec<u32> = (0..1_000u32).map(|i| (i * 7919) % 1_000).collect();
+        #[cfg(feature = "std")]
+        {
+            let result = parallel_volatility(samples.clone());
+            let expected = sequential_volatility(samples);
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_volatility_task_known_variance() {
+        // Mean 5, variance 4: standard deviation 2.
+        let task = VolatilityTask { samples: vec![2, 4, 4, 4, 5, 5, 7, 9] };
+        let result = task.execute().expect("Task should execute");
+        let volatility = u32::from_le_bytes(result.try_into().expect("Slice with incorrect length"));
+        assert_eq!(volatility, 2);
+        // Variance 1.25: rounded down to 1.
+        assert_eq!(sequential_volatility(vec![1, 2, 3, 4]), 1);
+        assert_eq!(sequential_volatility(vec![42; 10]), 0);
+        assert_eq!(sequential_volatility(Vec::new()), 0);
+    }
 }
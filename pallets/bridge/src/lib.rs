@@ -18,9 +18,14 @@ use frame_support::{
     transactional,
 };
 use frame_system::pallet_prelude::*;
+use nodara_emergency::HaltGuard;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::vec::Vec;
 use sp_std::prelude::*; // Inclut notamment le trait ToString
+use nodara_log::nodara_log;
+
+/// Cible des messages `nodara_log!` de ce module.
+pub const LOG_TARGET: &str = "runtime::bridge";
 
 /// Trait pour gérer le minting et le burning des tokens représentatifs sur Nodara.
 pub trait BridgeAssetManager<AccountId> {
@@ -30,12 +35,133 @@ pub trait BridgeAssetManager<AccountId> {
     fn burn(asset: Vec<u8>, from: &AccountId, amount: u128) -> DispatchResult;
 }
 
+/// Registre des gestionnaires d'actifs, sélectionnés selon la chaîne source de l'actif.
+///
+/// Chaque méthode retourne `None` lorsqu'aucun adaptateur n'est enregistré pour `source_chain` ;
+/// le pallet se rabat alors sur le gestionnaire par défaut (`Config::AssetManager`).
+pub trait BridgeAssetManagerRegistry<AccountId> {
+    /// Mint via l'adaptateur associé à `source_chain`, s'il existe.
+    fn mint_for(source_chain: &[u8], asset: Vec<u8>, to: &AccountId, amount: u128) -> Option<DispatchResult>;
+    /// Burn via l'adaptateur associé à `source_chain`, s'il existe.
+    fn burn_for(source_chain: &[u8], asset: Vec<u8>, from: &AccountId, amount: u128) -> Option<DispatchResult>;
+}
+
+/// Registre vide : tous les actifs utilisent le gestionnaire par défaut.
+impl<AccountId> BridgeAssetManagerRegistry<AccountId> for () {
+    fn mint_for(_source_chain: &[u8], _asset: Vec<u8>, _to: &AccountId, _amount: u128) -> Option<DispatchResult> {
+        None
+    }
+    fn burn_for(_source_chain: &[u8], _asset: Vec<u8>, _from: &AccountId, _amount: u128) -> Option<DispatchResult> {
+        None
+    }
+}
+
+/// Notifié à la finalisation d'un transfert, par exemple pour prévenir la chaîne homologue.
+///
+/// Appelé par `finalize_transfer` après le mint ou le burn. Une notification qui échoue est
+/// journalisée par l'implémentation et n'annule pas la finalisation.
+pub trait OnTransferFinalized<AccountId> {
+    /// Le transfert `request` vient d'être finalisé.
+    fn on_transfer_finalized(request: &pallet::TransferRequest<AccountId>);
+}
+
+/// Aucune notification.
+impl<AccountId> OnTransferFinalized<AccountId> for () {
+    fn on_transfer_finalized(_request: &pallet::TransferRequest<AccountId>) {}
+}
+
+/// Stake des validateurs du bridge, utilisé pour pondérer leurs confirmations.
+pub trait StakeSource<AccountId> {
+    /// Stake du validateur `who` (0 s'il n'en a pas).
+    fn stake_of(who: &AccountId) -> u128;
+    /// Stake total de l'ensemble des validateurs.
+    fn total_stake() -> u128;
+}
+
+/// Aucun stake : le quorum reste compté en nombre de confirmations (`RequiredConfirmations`).
+impl<AccountId> StakeSource<AccountId> for () {
+    fn stake_of(_who: &AccountId) -> u128 {
+        0
+    }
+    fn total_stake() -> u128 {
+        0
+    }
+}
+
+/// Contrôle de conformité réglementaire appliqué aux définitions d'actifs du bridge.
+pub trait AssetCompliance {
+    /// Indique si la définition de `asset`, dont `encoded_metadata` est l'encodage SCALE de ses
+    /// métadonnées, satisfait le standard configuré.
+    fn is_compliant(asset: &[u8], encoded_metadata: &[u8]) -> bool;
+}
+
+/// Aucun contrôle : toute définition valide est acceptée.
+impl AssetCompliance for () {
+    fn is_compliant(_asset: &[u8], _encoded_metadata: &[u8]) -> bool {
+        true
+    }
+}
+
+/// Associe un gestionnaire `M` à la chaîne source fournie par `Chain`.
+///
+/// Exemple de configuration runtime :
+/// `type AssetManagers = (SourceChainAdapter<EthChain, EthManager>, SourceChainAdapter<BtcChain, BtcManager>);`
+pub struct SourceChainAdapter<Chain, M>(sp_std::marker::PhantomData<(Chain, M)>);
+
+impl<AccountId, Chain: Get<Vec<u8>>, M: BridgeAssetManager<AccountId>> BridgeAssetManagerRegistry<AccountId>
+    for SourceChainAdapter<Chain, M>
+{
+    fn mint_for(source_chain: &[u8], asset: Vec<u8>, to: &AccountId, amount: u128) -> Option<DispatchResult> {
+        if Chain::get().as_slice() == source_chain {
+            Some(M::mint(asset, to, amount))
+        } else {
+            None
+        }
+    }
+    fn burn_for(source_chain: &[u8], asset: Vec<u8>, from: &AccountId, amount: u128) -> Option<DispatchResult> {
+        if Chain::get().as_slice() == source_chain {
+            Some(M::burn(asset, from, amount))
+        } else {
+            None
+        }
+    }
+}
+
+/// Composition de registres : le premier adaptateur correspondant est utilisé.
+macro_rules! impl_registry_for_tuples {
+    ($($name:ident),+) => {
+        impl<AccountId, $($name: BridgeAssetManagerRegistry<AccountId>),+> BridgeAssetManagerRegistry<AccountId> for ($($name,)+) {
+            fn mint_for(source_chain: &[u8], asset: Vec<u8>, to: &AccountId, amount: u128) -> Option<DispatchResult> {
+                $(
+                    if let Some(result) = $name::mint_for(source_chain, asset.clone(), to, amount) {
+                        return Some(result);
+                    }
+                )+
+                None
+            }
+            fn burn_for(source_chain: &[u8], asset: Vec<u8>, from: &AccountId, amount: u128) -> Option<DispatchResult> {
+                $(
+                    if let Some(result) = $name::burn_for(source_chain, asset.clone(), from, amount) {
+                        return Some(result);
+                    }
+                )+
+                None
+            }
+        }
+    };
+}
+
+impl_registry_for_tuples!(A);
+impl_registry_for_tuples!(A, B);
+impl_registry_for_tuples!(A, B, C);
+impl_registry_for_tuples!(A, B, C, D);
+
 pub use pallet::*;
 
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use sp_runtime::traits::Zero;
+    use sp_runtime::{traits::{SaturatedConversion, Zero}, Perbill};
 
     /// Type pour l'identifiant d'un actif (ex: b"BTC", b"ETH", etc.).
     pub type AssetId = Vec<u8>;
@@ -49,8 +175,10 @@ pub mod pallet {
         pub name: Vec<u8>,
         /// Symbole de l'actif.
         pub symbol: Vec<u8>,
-        /// Nombre de décimales.
+        /// Nombre de décimales sur la chaîne source.
         pub decimals: u8,
+        /// Nombre de décimales de la représentation sur Nodara.
+        pub nodara_decimals: u8,
         /// Chaîne source (ex: b"BTC", b"ETH", b"ERC20", etc.).
         pub source_chain: Vec<u8>,
     }
@@ -68,10 +196,24 @@ pub mod pallet {
         pub amount: u128,
         /// Compte destinataire.
         pub destination: AccountId,
-        /// Ensemble des validateurs ayant confirmé le transfert.
+        /// Ensemble des validateurs ayant confirmé le transfert, limité à `MaxConfirmations` et,
+        /// sans pondération par le stake, à `RequiredConfirmations`.
         pub confirmations: BTreeSet<AccountId>,
         /// Direction du transfert : true = vers Nodara (mint), false = depuis Nodara (burn).
         pub to_nodara: bool,
+        /// Bloc d'initiation, point de départ de `TransferTtl`.
+        pub initiated_at: u64,
+        /// Bloc de la dernière confirmation, point de départ de `MinConfirmationInterval`.
+        pub last_confirmed_at: Option<u64>,
+    }
+
+    /// Volume cumulé des transferts finalisés pour un actif, par direction.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, Default, TypeInfo)]
+    pub struct BridgedVolumeTotals {
+        /// Montant total transféré vers Nodara (mint).
+        pub to_nodara: u128,
+        /// Montant total transféré depuis Nodara (burn).
+        pub from_nodara: u128,
     }
 
     #[pallet::config]
@@ -80,11 +222,55 @@ pub mod pallet {
         type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
         /// Module monétaire (pour d'éventuelles opérations financières, si nécessaire).
         type Currency: Currency<Self::AccountId>;
-        /// Nombre minimum de confirmations requis pour finaliser un transfert.
+        /// Nombre minimum de confirmations requis pour finaliser un transfert, lorsque les
+        /// confirmations ne sont pas pondérées par le stake.
         #[pallet::constant]
         type RequiredConfirmations: Get<u32>;
-        /// Gestionnaire des tokens représentatifs pour le bridge.
+        /// Nombre maximal de confirmations stockées par transfert ; doit être au moins égal à
+        /// `RequiredConfirmations`.
+        #[pallet::constant]
+        type MaxConfirmations: Get<u32>;
+        /// Nombre maximal d'actifs pouvant être enregistrés dans le bridge.
+        #[pallet::constant]
+        type MaxSupportedAssets: Get<u32>;
+        /// Durée de vie (en blocs) d'une demande de transfert non finalisée ; au-delà, son initiateur
+        /// peut la retirer via `expire_transfer`.
+        #[pallet::constant]
+        type TransferTtl: Get<u64>;
+        /// Délai supplémentaire (en blocs) après `TransferTtl` avant que n'importe quel compte puisse
+        /// retirer une demande expirée, laissant d'abord la main à son initiateur.
+        #[pallet::constant]
+        type ReapGrace: Get<u64>;
+        /// Nombre minimal de blocs entre deux confirmations successives d'un même transfert
+        /// (0 désactive la contrainte). Empêche un même acteur de confirmer avec plusieurs clés
+        /// dans un seul bloc.
+        #[pallet::constant]
+        type MinConfirmationInterval: Get<u64>;
+        /// Gestionnaire des tokens représentatifs par défaut, utilisé lorsqu'aucun adaptateur
+        /// n'est enregistré pour la chaîne source de l'actif.
         type AssetManager: BridgeAssetManager<Self::AccountId>;
+        /// Adaptateurs de mint/burn spécifiques à chaque chaîne source.
+        type AssetManagers: BridgeAssetManagerRegistry<Self::AccountId>;
+        /// Origine autorisée à suspendre et réactiver le bridge.
+        type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Notification des transferts finalisés (`()` pour n'en émettre aucune).
+        type OnTransferFinalized: OnTransferFinalized<Self::AccountId>;
+        /// Arrêt d'urgence global consulté par chaque appel signé ; `()` le désactive.
+        type EmergencyHalt: nodara_emergency::HaltGuard;
+        /// Stake des validateurs. Tant que le stake total est nul (`()`), le quorum est compté en
+        /// nombre de confirmations ; sinon il est pondéré par le stake.
+        type StakeSource: crate::StakeSource<Self::AccountId>;
+        /// Part du stake total que doivent réunir les confirmations d'un transfert pour atteindre
+        /// le quorum pondéré.
+        #[pallet::constant]
+        type StakeQuorum: Get<Perbill>;
+        /// Contrôle de conformité des métadonnées lors de l'enregistrement ou de la mise à jour
+        /// d'un actif (`()` le désactive).
+        type AssetCompliance: crate::AssetCompliance;
+        /// Bornes `(minimum, maximum)` d'un transfert, en unités entières de l'actif, converties
+        /// selon les décimales de sa chaîne source (voir `nodara_math::validate_amount`).
+        /// `None` (ou `()`) désactive le contrôle.
+        type TransferAmountLimits: Get<Option<(u128, u128)>>;
     }
 
     #[pallet::pallet]
@@ -97,17 +283,64 @@ pub mod pallet {
     pub type SupportedAssets<T: Config> =
         StorageMap<_, Blake2_128Concat, AssetId, AssetMetadata, OptionQuery>;
 
+    /// Nombre d'entrées dans `SupportedAssets`, borné par `MaxSupportedAssets`.
+    #[pallet::storage]
+    #[pallet::getter(fn supported_asset_count)]
+    pub type SupportedAssetCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
     /// Stockage des demandes de transfert en attente.
     #[pallet::storage]
     #[pallet::getter(fn pending_transfers)]
     pub type PendingTransfers<T: Config> =
         StorageMap<_, Blake2_128Concat, TransferId, TransferRequest<T::AccountId>, OptionQuery>;
 
-    /// Compteur pour générer des identifiants uniques de transfert.
+    /// Stake de chaque validateur ayant confirmé un transfert en attente, relevé lors de sa
+    /// confirmation.
+    #[pallet::storage]
+    #[pallet::getter(fn confirmation_stake)]
+    pub type ConfirmationStakes<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, TransferId, Blake2_128Concat, T::AccountId, u128, OptionQuery>;
+
+    /// Compteur de transferts initiés, utilisé comme nonce dans `derive_transfer_id`.
     #[pallet::storage]
     #[pallet::getter(fn next_transfer_id)]
     pub type NextTransferId<T: Config> = StorageValue<_, TransferId, ValueQuery>;
 
+    /// Chaînes vers lesquelles un transfert sortant (`to_nodara = false`) est autorisé.
+    /// Un transfert sortant cible la chaîne source de l'actif.
+    #[pallet::storage]
+    pub type SupportedDestinationChains<T: Config> =
+        StorageMap<_, Blake2_128Concat, Vec<u8>, (), OptionQuery>;
+
+    /// Volume cumulé des transferts finalisés, par actif.
+    #[pallet::storage]
+    #[pallet::getter(fn bridged_volume)]
+    pub type BridgedVolume<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetId, BridgedVolumeTotals, OptionQuery>;
+
+    /// Actifs gelés : leurs transferts ne peuvent être ni initiés ni finalisés, les autres actifs
+    /// continuent de circuler (réponse à la compromission d'un contrat sur la chaîne source).
+    #[pallet::storage]
+    pub type FrozenAssets<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, (), OptionQuery>;
+
+    #[pallet::type_value]
+    pub fn DefaultMinTransfer() -> u128 {
+        1
+    }
+
+    /// Montant minimal d'un transfert, par actif, exprimé comme `amount` avec les décimales de la
+    /// chaîne source (1 par défaut). Défini par `GovernanceOrigin` pour refuser les transferts de
+    /// poussière.
+    #[pallet::storage]
+    #[pallet::getter(fn min_transfer)]
+    pub type MinTransferAmounts<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetId, u128, ValueQuery, DefaultMinTransfer>;
+
+    /// Indique si le bridge est suspendu (réponse à incident).
+    #[pallet::storage]
+    #[pallet::getter(fn is_paused)]
+    pub type Paused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -117,8 +350,25 @@ pub mod pallet {
         TransferInitiated(TransferId, T::AccountId, AssetId, u128, T::AccountId, bool),
         /// Un validateur a confirmé un transfert. [transfer_id, validateur]
         TransferConfirmed(TransferId, T::AccountId),
+        /// Une confirmation de plus faible stake a été remplacée, `MaxConfirmations` étant atteint.
+        /// [transfer_id, validateur évincé]
+        ConfirmationReplaced(TransferId, T::AccountId),
         /// Un transfert a été finalisé et exécuté (mint ou burn). [transfer_id]
         TransferFinalized(TransferId),
+        /// Le bridge a été suspendu ou réactivé. [nouvel état]
+        PauseStateChanged(bool),
+        /// Une chaîne de destination a été autorisée. [chaîne]
+        DestinationChainAdded(Vec<u8>),
+        /// Une chaîne de destination a été retirée. [chaîne]
+        DestinationChainRemoved(Vec<u8>),
+        /// Une demande de transfert expirée a été retirée. [transfer_id, compte l'ayant retirée]
+        TransferExpired(TransferId, T::AccountId),
+        /// Un actif a été gelé ou dégelé. [asset_id, gelé]
+        AssetFreezeChanged(AssetId, bool),
+        /// Les métadonnées d'un actif ont été mises à jour. [asset_id]
+        AssetMetadataUpdated(AssetId),
+        /// Le montant minimal de transfert d'un actif a été mis à jour. [asset_id, minimum]
+        MinTransferUpdated(AssetId, u128),
     }
 
     #[pallet::error]
@@ -135,30 +385,177 @@ pub mod pallet {
         InvalidAssetDefinition,
         /// Le montant doit être supérieur à zéro.
         InvalidAmount,
+        /// Le bridge est suspendu.
+        Paused,
+        /// L'identifiant dérivé est déjà attribué à un transfert en attente.
+        TransferIdCollision,
+        /// La chaîne de destination d'un transfert sortant n'est pas autorisée.
+        UnsupportedDestination,
+        /// La demande de transfert n'a pas encore atteint `TransferTtl`.
+        TransferNotExpired,
+        /// Seul l'initiateur peut retirer la demande tant que `ReapGrace` n'est pas écoulé.
+        ReapGracePeriodActive,
+        /// La confirmation précédente de ce transfert est trop récente (`MinConfirmationInterval`).
+        ConfirmationTooSoon,
+        /// Le nombre maximal d'actifs supportés (`MaxSupportedAssets`) est atteint.
+        TooManyAssets,
+        /// L'actif est déjà enregistré dans le bridge.
+        AssetAlreadyExists,
+        /// L'actif est gelé.
+        AssetFrozen,
+        /// L'actif n'est pas gelé.
+        AssetNotFrozen,
+        /// Le transfert a déjà atteint le seuil de confirmations.
+        QuorumAlreadyReached,
+        /// Le nombre maximal de confirmations (`MaxConfirmations`) est atteint et, avec la
+        /// pondération par le stake, aucune confirmation existante n'a un stake plus faible.
+        TooManyConfirmations,
+        /// Le montant est inférieur au minimum de transfert de l'actif.
+        BelowMinimumTransfer,
+        /// Le validateur n'a pas de stake alors que les confirmations sont pondérées par le stake.
+        NoValidatorStake,
+        /// Les métadonnées de l'actif ne satisfont pas le standard de conformité configuré.
+        StandardNotMet,
+        /// Le montant dépasse le maximum de `TransferAmountLimits`.
+        AboveMaximumTransfer,
+        /// Le montant converti en décimales Nodara dépasse `u128::MAX`.
+        AmountOverflow,
+        /// Les décimales d'un actif ne peuvent pas changer tant que des transferts de cet actif
+        /// sont en attente.
+        AssetHasPendingTransfers,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn integrity_test() {
+            assert!(
+                T::MaxConfirmations::get() >= T::RequiredConfirmations::get(),
+                "MaxConfirmations doit être au moins égal à RequiredConfirmations"
+            );
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), &'static str> {
+            Self::do_try_state()
+        }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
+        /// Suspend ou réactive toutes les opérations du bridge.
+        ///
+        /// Pendant la suspension, les lectures (`confirmation_status`, getters) restent disponibles.
+        #[pallet::weight(10_000)]
+        pub fn set_paused(origin: OriginFor<T>, paused: bool) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            Paused::<T>::put(paused);
+            Self::deposit_event(Event::PauseStateChanged(paused));
+            Ok(())
+        }
+
+        /// Autorise une chaîne de destination pour les transferts sortants.
+        #[pallet::weight(10_000)]
+        pub fn add_destination_chain(origin: OriginFor<T>, chain: Vec<u8>) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            ensure!(!chain.is_empty(), Error::<T>::InvalidAssetDefinition);
+            SupportedDestinationChains::<T>::insert(&chain, ());
+            Self::deposit_event(Event::DestinationChainAdded(chain));
+            Ok(())
+        }
+
+        /// Retire une chaîne de destination ; les transferts sortants vers cette chaîne sont alors refusés.
+        #[pallet::weight(10_000)]
+        pub fn remove_destination_chain(origin: OriginFor<T>, chain: Vec<u8>) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            ensure!(SupportedDestinationChains::<T>::contains_key(&chain), Error::<T>::UnsupportedDestination);
+            SupportedDestinationChains::<T>::remove(&chain);
+            Self::deposit_event(Event::DestinationChainRemoved(chain));
+            Ok(())
+        }
+
+        /// Gèle un actif : ses transferts sont refusés à l'initiation et à la finalisation.
+        #[pallet::weight(10_000)]
+        pub fn freeze_asset(origin: OriginFor<T>, asset: AssetId) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            ensure!(SupportedAssets::<T>::contains_key(&asset), Error::<T>::AssetNotSupported);
+            FrozenAssets::<T>::insert(&asset, ());
+            Self::deposit_event(Event::AssetFreezeChanged(asset, true));
+            Ok(())
+        }
+
+        /// Dégèle un actif ; les transferts en attente peuvent de nouveau être finalisés.
+        #[pallet::weight(10_000)]
+        pub fn unfreeze_asset(origin: OriginFor<T>, asset: AssetId) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            ensure!(FrozenAssets::<T>::contains_key(&asset), Error::<T>::AssetNotFrozen);
+            FrozenAssets::<T>::remove(&asset);
+            Self::deposit_event(Event::AssetFreezeChanged(asset, false));
+            Ok(())
+        }
+
         /// Enregistre un actif dans le bridge.
         ///
-        /// Vérifie que l'ID de l'actif, le nom et le symbole ne sont pas vides.
+        /// Vérifie que l'ID de l'actif, le nom et le symbole ne sont pas vides. Réservé à
+        /// `GovernanceOrigin` : le nombre d'actifs est plafonné par `MaxSupportedAssets` pour tout
+        /// le réseau, un compte quelconque ne doit pas pouvoir en épuiser les places. Échoue avec
+        /// `AssetAlreadyExists` si l'actif est déjà enregistré.
         #[pallet::weight(10_000)]
         pub fn register_asset(origin: OriginFor<T>, asset: AssetId, metadata: AssetMetadata) -> DispatchResult {
-            let _ = ensure_signed(origin)?;
-            ensure!(!asset.is_empty(), Error::<T>::InvalidAssetDefinition);
-            ensure!(!metadata.name.is_empty(), Error::<T>::InvalidAssetDefinition);
-            ensure!(!metadata.symbol.is_empty(), Error::<T>::InvalidAssetDefinition);
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::ensure_not_paused()?;
+            Self::ensure_valid_definition(&asset, &metadata)?;
             // Insertion sans doublon (on suppose qu'un asset est unique).
             ensure!(!SupportedAssets::<T>::contains_key(&asset), Error::<T>::AssetAlreadyExists);
+            let count = SupportedAssetCount::<T>::get();
+            ensure!(count < T::MaxSupportedAssets::get(), Error::<T>::TooManyAssets);
             SupportedAssets::<T>::insert(&asset, metadata);
+            SupportedAssetCount::<T>::put(count.saturating_add(1));
             Self::deposit_event(Event::AssetRegistered(asset));
             Ok(())
         }
 
+        /// Remplace les métadonnées d'un actif déjà enregistré (correction du nom, des décimales ou
+        /// de la chaîne source). Réservé à `GovernanceOrigin`, avec les mêmes vérifications que
+        /// `register_asset`. Les décimales (source ou Nodara) ne peuvent pas changer tant que des
+        /// transferts de l'actif sont en attente, ceux-ci ayant été initiés avec les anciennes
+        /// (`AssetHasPendingTransfers`).
+        #[pallet::weight(10_000)]
+        pub fn update_asset_metadata(origin: OriginFor<T>, asset: AssetId, metadata: AssetMetadata) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::ensure_not_paused()?;
+            Self::ensure_valid_definition(&asset, &metadata)?;
+            let current = SupportedAssets::<T>::get(&asset).ok_or(Error::<T>::AssetNotSupported)?;
+            if current.decimals != metadata.decimals || current.nodara_decimals != metadata.nodara_decimals {
+                ensure!(
+                    !PendingTransfers::<T>::iter_values().any(|request| request.asset == asset),
+                    Error::<T>::AssetHasPendingTransfers
+                );
+            }
+            SupportedAssets::<T>::insert(&asset, metadata);
+            Self::deposit_event(Event::AssetMetadataUpdated(asset));
+            Ok(())
+        }
+
+        /// Définit le montant minimal d'un transfert de `asset`, en unités de la chaîne source
+        /// (au moins 1). Réservé à `GovernanceOrigin`.
+        #[pallet::weight(10_000)]
+        pub fn set_min_transfer(origin: OriginFor<T>, asset: AssetId, min_transfer: u128) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            ensure!(SupportedAssets::<T>::contains_key(&asset), Error::<T>::AssetNotSupported);
+            ensure!(min_transfer > 0, Error::<T>::InvalidAmount);
+            MinTransferAmounts::<T>::insert(&asset, min_transfer);
+            Self::deposit_event(Event::MinTransferUpdated(asset, min_transfer));
+            Ok(())
+        }
+
         /// Initie une demande de transfert inter‑chaînes.
         ///
         /// `to_nodara` : true pour un transfert vers Nodara (verrouillage sur la source et mint sur Nodara),
         /// false pour un transfert inverse (burn sur Nodara et déverrouillage sur la source).
+        /// Un transfert inverse n'est accepté que si la chaîne source de l'actif figure dans
+        /// `SupportedDestinationChains`.
         #[pallet::weight(10_000)]
         #[transactional]
         pub fn initiate_transfer(
@@ -169,11 +566,23 @@ pub mod pallet {
             to_nodara: bool,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::ensure_not_paused()?;
             ensure!(amount > 0, Error::<T>::InvalidAmount);
-            ensure!(SupportedAssets::<T>::contains_key(&asset), Error::<T>::AssetNotSupported);
+            let metadata = SupportedAssets::<T>::get(&asset).ok_or(Error::<T>::AssetNotSupported)?;
+            ensure!(amount >= MinTransferAmounts::<T>::get(&asset), Error::<T>::BelowMinimumTransfer);
+            Self::ensure_amount_within_limits(amount, metadata.decimals)?;
+            ensure!(Self::scale_amount(&asset, amount, true).is_some(), Error::<T>::AmountOverflow);
+            Self::ensure_not_frozen(&asset)?;
+            if !to_nodara {
+                ensure!(Self::is_supported_destination(&metadata.source_chain), Error::<T>::UnsupportedDestination);
+            }
 
-            let transfer_id = NextTransferId::<T>::get();
-            NextTransferId::<T>::put(transfer_id.saturating_add(1));
+            let nonce = NextTransferId::<T>::get();
+            NextTransferId::<T>::put(nonce.saturating_add(1));
+            let block_number = <frame_system::Pallet<T>>::block_number();
+            let transfer_id = Self::derive_transfer_id(&sender, &asset, amount, block_number, nonce);
+            ensure!(!PendingTransfers::<T>::contains_key(transfer_id), Error::<T>::TransferIdCollision);
 
             let new_request = TransferRequest {
                 id: transfer_id,
@@ -183,6 +592,8 @@ pub mod pallet {
                 destination: destination.clone(),
                 confirmations: BTreeSet::new(),
                 to_nodara,
+                initiated_at: block_number.saturated_into::<u64>(),
+                last_confirmed_at: None,
             };
 
             PendingTransfers::<T>::insert(transfer_id, new_request);
@@ -198,43 +609,323 @@ pub mod pallet {
         }
 
         /// Permet à un validateur de confirmer un transfert.
+        ///
+        /// Deux confirmations successives d'un même transfert doivent être espacées d'au moins
+        /// `MinConfirmationInterval` blocs. Les confirmations sont refusées une fois le quorum
+        /// atteint. Avec la pondération par le stake, le stake du validateur est relevé à sa
+        /// confirmation ; un validateur sans stake ne peut pas confirmer. Une fois
+        /// `MaxConfirmations` atteint, une confirmation évince celle de plus faible stake si son
+        /// propre stake est supérieur, afin que des validateurs à faible stake ne puissent pas
+        /// bloquer le quorum.
         #[pallet::weight(10_000)]
         pub fn confirm_transfer(origin: OriginFor<T>, transfer_id: TransferId) -> DispatchResult {
             let validator = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::ensure_not_paused()?;
+            let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
             PendingTransfers::<T>::try_mutate(transfer_id, |maybe_request| -> DispatchResult {
                 let request = maybe_request.as_mut().ok_or(Error::<T>::TransferNotFound)?;
+                let confirmed = request.confirmations.len() as u32;
+                ensure!(!Self::quorum_reached(request), Error::<T>::QuorumAlreadyReached);
                 ensure!(!request.confirmations.contains(&validator), Error::<T>::AlreadyConfirmed);
+                let stake = T::StakeSource::stake_of(&validator);
+                ensure!(!Self::stake_weighted() || stake > 0, Error::<T>::NoValidatorStake);
+                let evicted = if confirmed < T::MaxConfirmations::get() {
+                    None
+                } else {
+                    ensure!(Self::stake_weighted(), Error::<T>::TooManyConfirmations);
+                    let (weakest, weakest_stake) = ConfirmationStakes::<T>::iter_prefix(transfer_id)
+                        .min_by_key(|(_, stake)| *stake)
+                        .ok_or(Error::<T>::TooManyConfirmations)?;
+                    ensure!(stake > weakest_stake, Error::<T>::TooManyConfirmations);
+                    Some(weakest)
+                };
+                if let Some(last) = request.last_confirmed_at {
+                    ensure!(
+                        now >= last.saturating_add(T::MinConfirmationInterval::get()),
+                        Error::<T>::ConfirmationTooSoon
+                    );
+                }
+                if let Some(weakest) = evicted {
+                    request.confirmations.remove(&weakest);
+                    ConfirmationStakes::<T>::remove(transfer_id, &weakest);
+                    Self::deposit_event(Event::ConfirmationReplaced(transfer_id, weakest));
+                }
                 request.confirmations.insert(validator.clone());
+                request.last_confirmed_at = Some(now);
+                if Self::stake_weighted() {
+                    ConfirmationStakes::<T>::insert(transfer_id, &validator, stake);
+                }
                 Self::deposit_event(Event::TransferConfirmed(transfer_id, validator));
                 Ok(())
             })
         }
 
-        /// Finalise le transfert une fois que le seuil de confirmations est atteint.
+        /// Finalise le transfert une fois le quorum atteint : `RequiredConfirmations` confirmations,
+        /// ou, avec la pondération par le stake, des confirmations réunissant au moins `StakeQuorum`
+        /// du stake total.
         ///
         /// Pour un transfert vers Nodara, mint les tokens représentatifs sur le compte destination.
         /// Pour un transfert inverse, burn les tokens représentatifs sur le compte source.
+        /// Le montant d'une demande est exprimé avec les décimales de la chaîne source ; il est
+        /// converti en décimales Nodara (`scale_amount`) avant le mint ou le burn ; une conversion
+        /// qui dépasse `u128::MAX` échoue avec `AmountOverflow` et la demande reste en attente.
         #[pallet::weight(10_000)]
         #[transactional]
         pub fn finalize_transfer(origin: OriginFor<T>, transfer_id: TransferId) -> DispatchResult {
             let _ = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::ensure_not_paused()?;
             PendingTransfers::<T>::try_mutate_exists(transfer_id, |maybe_request| -> DispatchResult {
                 let request = maybe_request.take().ok_or(Error::<T>::TransferNotFound)?;
-                ensure!(
-                    (request.confirmations.len() as u32) >= T::RequiredConfirmations::get(),
-                    Error::<T>::InsufficientConfirmations
-                );
+                ensure!(Self::quorum_reached(&request), Error::<T>::InsufficientConfirmations);
+                Self::ensure_not_frozen(&request.asset)?;
+                let source_chain = SupportedAssets::<T>::get(&request.asset)
+                    .map(|metadata| metadata.source_chain)
+                    .unwrap_or_default();
+                let nodara_amount =
+                    Self::scale_amount(&request.asset, request.amount, true).ok_or(Error::<T>::AmountOverflow)?;
                 if request.to_nodara {
                     // Transfert vers Nodara : mint des tokens représentatifs sur le compte destination.
-                    T::AssetManager::mint(request.asset.clone(), &request.destination, request.amount)?;
+                    Self::mint_with_adapter(&source_chain, request.asset.clone(), &request.destination, nodara_amount)?;
                 } else {
                     // Transfert depuis Nodara : burn des tokens représentatifs sur le compte source.
-                    T::AssetManager::burn(request.asset.clone(), &request.from, request.amount)?;
+                    Self::burn_with_adapter(&source_chain, request.asset.clone(), &request.from, nodara_amount)?;
                 }
+                BridgedVolume::<T>::mutate(&request.asset, |maybe_totals| {
+                    let totals = maybe_totals.get_or_insert_with(Default::default);
+                    if request.to_nodara {
+                        totals.to_nodara = totals.to_nodara.saturating_add(request.amount);
+                    } else {
+                        totals.from_nodara = totals.from_nodara.saturating_add(request.amount);
+                    }
+                });
+                nodara_log!(
+                    info,
+                    "transfert {} finalisé : {} unités de {:?} ({})",
+                    transfer_id,
+                    nodara_amount,
+                    request.asset,
+                    if request.to_nodara { "mint" } else { "burn" }
+                );
+                Self::clear_confirmation_stakes(transfer_id);
+                T::OnTransferFinalized::on_transfer_finalized(&request);
                 Self::deposit_event(Event::TransferFinalized(transfer_id));
                 Ok(())
             })
         }
+
+        /// Retire une demande de transfert expirée.
+        ///
+        /// L'initiateur peut la retirer dès que `TransferTtl` blocs se sont écoulés depuis son
+        /// initiation ; tout autre compte doit attendre `TransferTtl + ReapGrace` blocs.
+        #[pallet::weight(10_000)]
+        pub fn expire_transfer(origin: OriginFor<T>, transfer_id: TransferId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::ensure_not_paused()?;
+            let request = PendingTransfers::<T>::get(transfer_id).ok_or(Error::<T>::TransferNotFound)?;
+            let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+            let expires_at = request.initiated_at.saturating_add(T::TransferTtl::get());
+            ensure!(now >= expires_at, Error::<T>::TransferNotExpired);
+            if who != request.from {
+                ensure!(now >= expires_at.saturating_add(T::ReapGrace::get()), Error::<T>::ReapGracePeriodActive);
+            }
+            PendingTransfers::<T>::remove(transfer_id);
+            Self::clear_confirmation_stakes(transfer_id);
+            Self::deposit_event(Event::TransferExpired(transfer_id, who));
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Vérifie les invariants du bridge (hook `try_state`) :
+        /// - `SupportedAssetCount` est égal au nombre d'actifs enregistrés, au plus `MaxSupportedAssets` ;
+        /// - seuls des actifs enregistrés peuvent être gelés ;
+        /// - chaque transfert en attente est stocké sous son propre identifiant, porte sur un actif
+        ///   enregistré et compte au plus `RequiredConfirmations` confirmations (`MaxConfirmations`
+        ///   avec la pondération par le stake) ;
+        /// - un transfert a un bloc de dernière confirmation si et seulement s'il a été confirmé.
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), &'static str> {
+            let count = SupportedAssets::<T>::iter_keys().count() as u32;
+            ensure!(SupportedAssetCount::<T>::get() == count, "SupportedAssetCount désynchronisé");
+            ensure!(count <= T::MaxSupportedAssets::get(), "MaxSupportedAssets dépassé");
+            ensure!(
+                FrozenAssets::<T>::iter_keys().all(|asset| SupportedAssets::<T>::contains_key(&asset)),
+                "actif gelé non enregistré"
+            );
+            for (transfer_id, request) in PendingTransfers::<T>::iter() {
+                ensure!(request.id == transfer_id, "transfert stocké sous un autre identifiant");
+                ensure!(SupportedAssets::<T>::contains_key(&request.asset), "transfert sur un actif non enregistré");
+                let max_confirmations = if Self::stake_weighted() {
+                    T::MaxConfirmations::get()
+                } else {
+                    T::RequiredConfirmations::get()
+                };
+                ensure!(request.confirmations.len() as u32 <= max_confirmations, "confirmations au-delà du seuil");
+                ensure!(
+                    request.last_confirmed_at.is_some() == !request.confirmations.is_empty(),
+                    "bloc de dernière confirmation incohérent"
+                );
+            }
+            Ok(())
+        }
+
+        /// Vrai si les confirmations sont pondérées par le stake (stake total non nul).
+        fn stake_weighted() -> bool {
+            T::StakeSource::total_stake() > 0
+        }
+
+        /// Vrai si les confirmations de `request` atteignent le quorum : `RequiredConfirmations`
+        /// confirmations, ou `StakeQuorum` du stake total avec la pondération par le stake.
+        fn quorum_reached(request: &TransferRequest<T::AccountId>) -> bool {
+            let total_stake = T::StakeSource::total_stake();
+            if total_stake == 0 {
+                return request.confirmations.len() as u32 >= T::RequiredConfirmations::get();
+            }
+            let confirmed_stake = ConfirmationStakes::<T>::iter_prefix_values(request.id)
+                .fold(0u128, |total, stake| total.saturating_add(stake));
+            confirmed_stake >= T::StakeQuorum::get().mul_ceil(total_stake)
+        }
+
+        /// Supprime les stakes relevés pour `transfer_id` une fois le transfert retiré.
+        fn clear_confirmation_stakes(transfer_id: TransferId) {
+            let _ = ConfirmationStakes::<T>::clear_prefix(transfer_id, T::MaxConfirmations::get(), None);
+        }
+
+        /// Échoue avec `Paused` tant que le bridge est suspendu.
+        fn ensure_not_paused() -> DispatchResult {
+            ensure!(!Paused::<T>::get(), Error::<T>::Paused);
+            Ok(())
+        }
+
+        /// Vérifie que l'ID de l'actif, le nom et le symbole ne sont pas vides, puis que les métadonnées
+        /// satisfont `AssetCompliance`.
+        fn ensure_valid_definition(asset: &AssetId, metadata: &AssetMetadata) -> DispatchResult {
+            ensure!(!asset.is_empty(), Error::<T>::InvalidAssetDefinition);
+            ensure!(!metadata.name.is_empty(), Error::<T>::InvalidAssetDefinition);
+            ensure!(!metadata.symbol.is_empty(), Error::<T>::InvalidAssetDefinition);
+            ensure!(
+                T::AssetCompliance::is_compliant(asset, &metadata.encode()),
+                Error::<T>::StandardNotMet
+            );
+            Ok(())
+        }
+
+        /// Vérifie `amount`, exprimé avec `decimals` décimales, par rapport à `TransferAmountLimits`.
+        fn ensure_amount_within_limits(amount: u128, decimals: u8) -> DispatchResult {
+            let Some((min, max)) = T::TransferAmountLimits::get() else { return Ok(()) };
+            nodara_math::validate_amount(amount, decimals, min, max).map_err(|e| match e {
+                nodara_math::AmountError::BelowMinimum => Error::<T>::BelowMinimumTransfer,
+                nodara_math::AmountError::AboveMaximum => Error::<T>::AboveMaximumTransfer,
+                nodara_math::AmountError::UnsupportedDecimals => Error::<T>::InvalidAmount,
+            })?;
+            Ok(())
+        }
+
+        /// Échoue avec `AssetFrozen` si `asset` est gelé.
+        fn ensure_not_frozen(asset: &AssetId) -> DispatchResult {
+            ensure!(!FrozenAssets::<T>::contains_key(asset), Error::<T>::AssetFrozen);
+            Ok(())
+        }
+
+        /// Retourne la progression `(actuelle, requise)` d'un transfert en attente : en nombre de
+        /// confirmations, ou, avec la pondération par le stake, en stake confirmé par rapport à
+        /// `StakeQuorum` du stake total.
+        ///
+        /// Un événement émis juste avant `InsufficientConfirmations` serait annulé avec le reste de
+        /// l'extrinsèque ; les clients interrogent donc cette fonction (exposée via l'API runtime)
+        /// pour connaître le nombre de confirmations manquantes.
+        pub fn confirmation_status(transfer_id: TransferId) -> Option<(u128, u128)> {
+            PendingTransfers::<T>::get(transfer_id).map(|request| {
+                let total_stake = T::StakeSource::total_stake();
+                if total_stake == 0 {
+                    return (request.confirmations.len() as u128, T::RequiredConfirmations::get() as u128);
+                }
+                let confirmed_stake = ConfirmationStakes::<T>::iter_prefix_values(transfer_id)
+                    .fold(0u128, |total, stake| total.saturating_add(stake));
+                (confirmed_stake, T::StakeQuorum::get().mul_ceil(total_stake))
+            })
+        }
+
+        /// Dérive l'identifiant d'un transfert à partir de ses paramètres, du bloc courant et du nonce.
+        ///
+        /// Un simple compteur pourrait attribuer le même identifiant à deux transferts différents
+        /// sur deux forks lors d'une réorganisation ; inclure l'émetteur, l'actif, le montant et le
+        /// numéro de bloc dans le hash rend ces collisions impossibles en pratique.
+        pub fn derive_transfer_id(
+            sender: &T::AccountId,
+            asset: &[u8],
+            amount: u128,
+            block_number: BlockNumberFor<T>,
+            nonce: u64,
+        ) -> TransferId {
+            let hash = (b"nodara/bridge/transfer", sender, asset, amount, block_number, nonce)
+                .using_encoded(sp_io::hashing::blake2_256);
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&hash[..8]);
+            TransferId::from_le_bytes(bytes)
+        }
+
+        /// Convertit `amount` entre les décimales de la chaîne source et celles de Nodara.
+        ///
+        /// `to_nodara = true` convertit un montant source en montant Nodara, `false` fait l'inverse.
+        /// Une conversion vers plus de décimales retourne `None` si le résultat dépasse `u128::MAX` ;
+        /// une conversion vers moins de décimales tronque. Un actif inconnu n'est pas converti.
+        pub fn scale_amount(asset: &[u8], amount: u128, to_nodara: bool) -> Option<u128> {
+            let metadata = match SupportedAssets::<T>::get(asset) {
+                Some(metadata) => metadata,
+                None => return Some(amount),
+            };
+            let (from, to) = if to_nodara {
+                (metadata.decimals, metadata.nodara_decimals)
+            } else {
+                (metadata.nodara_decimals, metadata.decimals)
+            };
+            if to >= from {
+                if amount == 0 {
+                    return Some(0);
+                }
+                10u128.checked_pow((to - from) as u32).and_then(|factor| amount.checked_mul(factor))
+            } else {
+                Some(10u128.checked_pow((from - to) as u32).map_or(0, |factor| amount / factor))
+            }
+        }
+
+        /// Liste triée des identifiants d'actifs enregistrés (au plus `MaxSupportedAssets` entrées).
+        pub fn list_assets() -> Vec<AssetId> {
+            let mut assets: Vec<AssetId> = SupportedAssets::<T>::iter_keys().collect();
+            assets.sort();
+            assets
+        }
+
+        /// Indique si les transferts sortants vers `chain` sont autorisés.
+        pub fn is_supported_destination(chain: &[u8]) -> bool {
+            SupportedDestinationChains::<T>::contains_key(chain)
+        }
+
+        /// Retourne le volume cumulé `(vers Nodara, depuis Nodara)` d'un actif, ou `None` si aucun
+        /// transfert n'a encore été finalisé pour cet actif.
+        pub fn volume(asset: &[u8]) -> Option<(u128, u128)> {
+            BridgedVolume::<T>::get(asset).map(|totals| (totals.to_nodara, totals.from_nodara))
+        }
+
+        /// Mint via l'adaptateur de la chaîne source, ou via le gestionnaire par défaut.
+        fn mint_with_adapter(source_chain: &[u8], asset: AssetId, to: &T::AccountId, amount: u128) -> DispatchResult {
+            match T::AssetManagers::mint_for(source_chain, asset.clone(), to, amount) {
+                Some(result) => result,
+                None => T::AssetManager::mint(asset, to, amount),
+            }
+        }
+
+        /// Burn via l'adaptateur de la chaîne source, ou via le gestionnaire par défaut.
+        fn burn_with_adapter(source_chain: &[u8], asset: AssetId, from: &T::AccountId, amount: u128) -> DispatchResult {
+            match T::AssetManagers::burn_for(source_chain, asset.clone(), from, amount) {
+                Some(result) => result,
+                None => T::AssetManager::burn(asset, from, amount),
+            }
+        }
     }
 
     // --- Configuration de Genèse ---
@@ -242,31 +933,36 @@ pub mod pallet {
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub initial_assets: Vec<(AssetId, AssetMetadata)>,
+        /// Chaînes autorisées pour les transferts sortants (par défaut : les chaînes sources des actifs initiaux).
+        pub supported_destination_chains: Vec<Vec<u8>>,
     }
 
     #[cfg(feature = "std")]
     impl<T: Config> Default for GenesisConfig<T> {
         fn default() -> Self {
-            Self {
-                initial_assets: vec![
-                    (b"BTC".to_vec(), AssetMetadata { name: b"Bitcoin".to_vec(), symbol: b"BTC".to_vec(), decimals: 8, source_chain: b"BTC".to_vec() }),
-                    (b"ETH".to_vec(), AssetMetadata { name: b"Ethereum".to_vec(), symbol: b"ETH".to_vec(), decimals: 18, source_chain: b"ETH".to_vec() }),
-                    (b"BNB".to_vec(), AssetMetadata { name: b"Binance Coin".to_vec(), symbol: b"BNB".to_vec(), decimals: 18, source_chain: b"BNB".to_vec() }),
-                    (b"DOT".to_vec(), AssetMetadata { name: b"Polkadot".to_vec(), symbol: b"DOT".to_vec(), decimals: 10, source_chain: b"Polkadot".to_vec() }),
-                    (b"XRP".to_vec(), AssetMetadata { name: b"XRP".to_vec(), symbol: b"XRP".to_vec(), decimals: 6, source_chain: b"XRP".to_vec() }),
-                    (b"DOGE".to_vec(), AssetMetadata { name: b"Dogecoin".to_vec(), symbol: b"DOGE".to_vec(), decimals: 8, source_chain: b"DOGE".to_vec() }),
-                    (b"SOL".to_vec(), AssetMetadata { name: b"Solana".to_vec(), symbol: b"SOL".to_vec(), decimals: 9, source_chain: b"SOL".to_vec() }),
-                    (b"LINK".to_vec(), AssetMetadata { name: b"Chainlink".to_vec(), symbol: b"LINK".to_vec(), decimals: 18, source_chain: b"ETH".to_vec() }),
-                    (b"SUI".to_vec(), AssetMetadata { name: b"Sui".to_vec(), symbol: b"SUI".to_vec(), decimals: 9, source_chain: b"SUI".to_vec() }),
-                    (b"AVAX".to_vec(), AssetMetadata { name: b"Avalanche".to_vec(), symbol: b"AVAX".to_vec(), decimals: 18, source_chain: b"AVAX".to_vec() }),
-                    (b"USDT".to_vec(), AssetMetadata { name: b"Tether USD".to_vec(), symbol: b"USDT".to_vec(), decimals: 6, source_chain: b"ERC20".to_vec() }),
-                    (b"USDC".to_vec(), AssetMetadata { name: b"USD Coin".to_vec(), symbol: b"USDC".to_vec(), decimals: 6, source_chain: b"ERC20".to_vec() }),
-                    (b"ADA".to_vec(), AssetMetadata { name: b"Cardano".to_vec(), symbol: b"ADA".to_vec(), decimals: 6, source_chain: b"Cardano".to_vec() }),
-                    (b"TRX".to_vec(), AssetMetadata { name: b"Tron".to_vec(), symbol: b"TRX".to_vec(), decimals: 6, source_chain: b"TRX".to_vec() }),
-                    (b"XLM".to_vec(), AssetMetadata { name: b"Stellar".to_vec(), symbol: b"XLM".to_vec(), decimals: 7, source_chain: b"XLM".to_vec() }),
-                    (b"TON".to_vec(), AssetMetadata { name: b"Toncoin".to_vec(), symbol: b"TON".to_vec(), decimals: 9, source_chain: b"TON".to_vec() }),
-                ],
-            }
+            let initial_assets = vec![
+                (b"BTC".to_vec(), AssetMetadata { name: b"Bitcoin".to_vec(), symbol: b"BTC".to_vec(), decimals: 8, nodara_decimals: 8, source_chain: b"BTC".to_vec() }),
+                (b"ETH".to_vec(), AssetMetadata { name: b"Ethereum".to_vec(), symbol: b"ETH".to_vec(), decimals: 18, nodara_decimals: 18, source_chain: b"ETH".to_vec() }),
+                (b"BNB".to_vec(), AssetMetadata { name: b"Binance Coin".to_vec(), symbol: b"BNB".to_vec(), decimals: 18, nodara_decimals: 18, source_chain: b"BNB".to_vec() }),
+                (b"DOT".to_vec(), AssetMetadata { name: b"Polkadot".to_vec(), symbol: b"DOT".to_vec(), decimals: 10, nodara_decimals: 10, source_chain: b"Polkadot".to_vec() }),
+                (b"XRP".to_vec(), AssetMetadata { name: b"XRP".to_vec(), symbol: b"XRP".to_vec(), decimals: 6, nodara_decimals: 6, source_chain: b"XRP".to_vec() }),
+                (b"DOGE".to_vec(), AssetMetadata { name: b"Dogecoin".to_vec(), symbol: b"DOGE".to_vec(), decimals: 8, nodara_decimals: 8, source_chain: b"DOGE".to_vec() }),
+                (b"SOL".to_vec(), AssetMetadata { name: b"Solana".to_vec(), symbol: b"SOL".to_vec(), decimals: 9, nodara_decimals: 9, source_chain: b"SOL".to_vec() }),
+                (b"LINK".to_vec(), AssetMetadata { name: b"Chainlink".to_vec(), symbol: b"LINK".to_vec(), decimals: 18, nodara_decimals: 18, source_chain: b"ETH".to_vec() }),
+                (b"SUI".to_vec(), AssetMetadata { name: b"Sui".to_vec(), symbol: b"SUI".to_vec(), decimals: 9, nodara_decimals: 9, source_chain: b"SUI".to_vec() }),
+                (b"AVAX".to_vec(), AssetMetadata { name: b"Avalanche".to_vec(), symbol: b"AVAX".to_vec(), decimals: 18, nodara_decimals: 18, source_chain: b"AVAX".to_vec() }),
+                (b"USDT".to_vec(), AssetMetadata { name: b"Tether USD".to_vec(), symbol: b"USDT".to_vec(), decimals: 6, nodara_decimals: 6, source_chain: b"ERC20".to_vec() }),
+                (b"USDC".to_vec(), AssetMetadata { name: b"USD Coin".to_vec(), symbol: b"USDC".to_vec(), decimals: 6, nodara_decimals: 6, source_chain: b"ERC20".to_vec() }),
+                (b"ADA".to_vec(), AssetMetadata { name: b"Cardano".to_vec(), symbol: b"ADA".to_vec(), decimals: 6, nodara_decimals: 6, source_chain: b"Cardano".to_vec() }),
+                (b"TRX".to_vec(), AssetMetadata { name: b"Tron".to_vec(), symbol: b"TRX".to_vec(), decimals: 6, nodara_decimals: 6, source_chain: b"TRX".to_vec() }),
+                (b"XLM".to_vec(), AssetMetadata { name: b"Stellar".to_vec(), symbol: b"XLM".to_vec(), decimals: 7, nodara_decimals: 7, source_chain: b"XLM".to_vec() }),
+                (b"TON".to_vec(), AssetMetadata { name: b"Toncoin".to_vec(), symbol: b"TON".to_vec(), decimals: 9, nodara_decimals: 9, source_chain: b"TON".to_vec() }),
+            ];
+            let mut supported_destination_chains: Vec<Vec<u8>> =
+                initial_assets.iter().map(|(_, metadata)| metadata.source_chain.clone()).collect();
+            supported_destination_chains.sort();
+            supported_destination_chains.dedup();
+            Self { initial_assets, supported_destination_chains }
         }
     }
 
@@ -276,6 +972,12 @@ pub mod pallet {
             for (asset_id, metadata) in &self.initial_assets {
                 SupportedAssets::<T>::insert(asset_id, metadata);
             }
+            let count = SupportedAssets::<T>::iter_keys().count() as u32;
+            assert!(count <= T::MaxSupportedAssets::get(), "initial_assets exceeds MaxSupportedAssets");
+            SupportedAssetCount::<T>::put(count);
+            for chain in &self.supported_destination_chains {
+                SupportedDestinationChains::<T>::insert(chain, ());
+            }
         }
     }
     
@@ -284,7 +986,7 @@ pub mod pallet {
     mod tests {
         use super::*;
         use crate as pallet_bridge;
-        use frame_support::{assert_ok, parameter_types, traits::OnFinalize};
+        use frame_support::{assert_noop, assert_ok, parameter_types, traits::{Hooks, OnFinalize}};
         use sp_core::H256;
         use sp_runtime::{
             testing::Header,
@@ -309,6 +1011,7 @@ pub mod pallet {
         parameter_types! {
             pub const BlockHashCount: u64 = 250;
             pub const RequiredConfirmations: u32 = 2;
+            pub const MaxConfirmations: u32 = 4;
         }
 
         impl system::Config for Test {
@@ -341,21 +1044,141 @@ pub mod pallet {
         // Pour simplifier les tests, nous créons un gestionnaire d'actifs fictif.
         pub struct DummyAssetManager;
         impl BridgeAssetManager<u64> for DummyAssetManager {
+            fn mint(_asset: Vec<u8>, _to: &u64, amount: u128) -> DispatchResult {
+                DEFAULT_MANAGER_AMOUNTS.with(|amounts| amounts.borrow_mut().push(("mint", amount)));
+                Ok(())
+            }
+            fn burn(_asset: Vec<u8>, _from: &u64, amount: u128) -> DispatchResult {
+                DEFAULT_MANAGER_AMOUNTS.with(|amounts| amounts.borrow_mut().push(("burn", amount)));
+                Ok(())
+            }
+        }
+
+        thread_local! {
+            /// Montants reçus par le gestionnaire par défaut : (opération, montant).
+            pub static DEFAULT_MANAGER_AMOUNTS: std::cell::RefCell<Vec<(&'static str, u128)>> =
+                std::cell::RefCell::new(Vec::new());
+            /// Journal des appels aux adaptateurs : (adaptateur, opération, actif).
+            pub static ADAPTER_CALLS: std::cell::RefCell<Vec<(&'static str, &'static str, Vec<u8>)>> =
+                std::cell::RefCell::new(Vec::new());
+        }
+
+        fn record_call(adapter: &'static str, op: &'static str, asset: Vec<u8>) {
+            ADAPTER_CALLS.with(|calls| calls.borrow_mut().push((adapter, op, asset)));
+        }
+
+        fn adapter_calls() -> Vec<(&'static str, &'static str, Vec<u8>)> {
+            ADAPTER_CALLS.with(|calls| calls.borrow().clone())
+        }
+
+        pub struct EthAdapter;
+        impl BridgeAssetManager<u64> for EthAdapter {
             fn mint(asset: Vec<u8>, _to: &u64, _amount: u128) -> DispatchResult {
-                // Simulez ici le minting (pour le test, on ne fait rien)
+                record_call("eth", "mint", asset);
                 Ok(())
             }
             fn burn(asset: Vec<u8>, _from: &u64, _amount: u128) -> DispatchResult {
-                // Simulez ici le burning (pour le test, on ne fait rien)
+                record_call("eth", "burn", asset);
                 Ok(())
             }
         }
 
+        pub struct BtcAdapter;
+        impl BridgeAssetManager<u64> for BtcAdapter {
+            fn mint(asset: Vec<u8>, _to: &u64, _amount: u128) -> DispatchResult {
+                record_call("btc", "mint", asset);
+                Ok(())
+            }
+            fn burn(asset: Vec<u8>, _from: &u64, _amount: u128) -> DispatchResult {
+                record_call("btc", "burn", asset);
+                Ok(())
+            }
+        }
+
+        parameter_types! {
+            pub EthChain: Vec<u8> = b"ETH".to_vec();
+            pub BtcChain: Vec<u8> = b"BTC".to_vec();
+            pub const MaxSupportedAssets: u32 = 3;
+            pub const TransferTtl: u64 = 10;
+            pub const ReapGrace: u64 = 5;
+            pub const MinConfirmationInterval: u64 = 1;
+            pub const StakeQuorum: Perbill = Perbill::from_percent(67);
+            pub static TransferAmountLimits: Option<(u128, u128)> = None;
+        }
+
+        thread_local! {
+            /// Stakes des validateurs : (validateur, stake). Vide par défaut, ce qui conserve le
+            /// quorum en nombre de confirmations.
+            pub static STAKES: std::cell::RefCell<Vec<(u64, u128)>> = std::cell::RefCell::new(Vec::new());
+        }
+
+        /// Stakes lus dans `STAKES`.
+        pub struct MockStakes;
+        impl StakeSource<u64> for MockStakes {
+            fn stake_of(who: &u64) -> u128 {
+                STAKES.with(|stakes| stakes.borrow().iter().find(|(v, _)| v == who).map_or(0, |(_, stake)| *stake))
+            }
+            fn total_stake() -> u128 {
+                STAKES.with(|stakes| stakes.borrow().iter().map(|(_, stake)| stake).sum())
+            }
+        }
+
         impl Config for Test {
             type Event = ();
             type Currency = ();
             type RequiredConfirmations = RequiredConfirmations;
+            type MaxConfirmations = MaxConfirmations;
+            type MaxSupportedAssets = MaxSupportedAssets;
+            type TransferTtl = TransferTtl;
+            type ReapGrace = ReapGrace;
+            type MinConfirmationInterval = MinConfirmationInterval;
             type AssetManager = DummyAssetManager;
+            type AssetManagers = (
+                SourceChainAdapter<EthChain, EthAdapter>,
+                SourceChainAdapter<BtcChain, BtcAdapter>,
+            );
+            type GovernanceOrigin = frame_system::EnsureRoot<u64>;
+            type OnTransferFinalized = ();
+            type EmergencyHalt = ();
+            type StakeSource = MockStakes;
+            type StakeQuorum = StakeQuorum;
+            type AssetCompliance = ();
+            type TransferAmountLimits = TransferAmountLimits;
+        }
+
+        pub fn new_test_ext() -> sp_io::TestExternalities {
+            let storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+            storage.into()
+        }
+
+        fn metadata(symbol: &[u8], source_chain: &[u8]) -> AssetMetadata {
+            AssetMetadata {
+                name: symbol.to_vec(),
+                symbol: symbol.to_vec(),
+                decimals: 8,
+                nodara_decimals: 8,
+                source_chain: source_chain.to_vec(),
+            }
+        }
+
+        /// Identifiant que recevra le prochain transfert initié par le compte 1 dans le bloc courant.
+        fn next_id(asset_id: &[u8], amount: u128) -> TransferId {
+            Bridge::derive_transfer_id(&1, asset_id, amount, System::block_number(), Bridge::next_transfer_id())
+        }
+
+        /// Passe au bloc suivant, pour respecter `MinConfirmationInterval` entre deux confirmations.
+        fn next_block() {
+            System::set_block_number(System::block_number() + 1);
+        }
+
+        /// Initie un transfert sur un actif enregistré, le confirme deux fois et le finalise.
+        fn run_transfer(asset_id: &[u8], to_nodara: bool) {
+            let transfer_id = next_id(asset_id, 1_000);
+            assert_ok!(Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), asset_id.to_vec(), 1_000, 2, to_nodara));
+            assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(1).into(), transfer_id));
+            next_block();
+            assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(3).into(), transfer_id));
+            assert_ok!(Bridge::finalize_transfer(system::RawOrigin::Signed(1).into(), transfer_id));
         }
 
         #[test]
@@ -371,14 +1194,16 @@ pub mod pallet {
                 name: b"Bitcoin".to_vec(),
                 symbol: b"BTC".to_vec(),
                 decimals: 8,
+                nodara_decimals: 8,
                 source_chain: b"BTC".to_vec(),
             };
 
             // Enregistrer l'actif
-            assert_ok!(Bridge::register_asset(system::RawOrigin::Signed(1).into(), asset_id.clone(), metadata));
+            assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), asset_id.clone(), metadata));
 
             // Initier un transfert
             let amount = 1_000_000u128;
+            let transfer_id = next_id(&asset_id, amount);
             assert_ok!(Bridge::initiate_transfer(
                 system::RawOrigin::Signed(1).into(),
                 asset_id.clone(),
@@ -386,14 +1211,594 @@ pub mod pallet {
                 2,
                 true
             ));
-            let transfer_id = Bridge::next_transfer_id() - 1;
 
             // Confirmer le transfert avec deux comptes (1 et 3)
             assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(1).into(), transfer_id));
+            next_block();
             assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(3).into(), transfer_id));
 
             // Finaliser le transfert (le mint sera appelé via le DummyAssetManager)
             assert_ok!(Bridge::finalize_transfer(system::RawOrigin::Signed(1).into(), transfer_id));
         }
+
+        #[test]
+        fn finalize_dispatches_to_adapter_of_source_chain() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"LINK".to_vec(), metadata(b"LINK", b"ETH")));
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"WBTC".to_vec(), metadata(b"WBTC", b"BTC")));
+                assert_ok!(Bridge::add_destination_chain(system::RawOrigin::Root.into(), b"BTC".to_vec()));
+
+                run_transfer(b"LINK", true);
+                run_transfer(b"WBTC", false);
+
+                assert_eq!(
+                    adapter_calls(),
+                    vec![
+                        ("eth", "mint", b"LINK".to_vec()),
+                        ("btc", "burn", b"WBTC".to_vec()),
+                    ]
+                );
+            });
+        }
+
+        #[test]
+        fn confirmation_status_reports_shortfall_on_premature_finalize() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"LINK".to_vec(), metadata(b"LINK", b"ETH")));
+                let transfer_id = next_id(b"LINK", 1_000);
+                assert_ok!(Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"LINK".to_vec(), 1_000, 2, true));
+                assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(1).into(), transfer_id));
+
+                assert_noop!(
+                    Bridge::finalize_transfer(system::RawOrigin::Signed(1).into(), transfer_id),
+                    Error::<Test>::InsufficientConfirmations
+                );
+                assert_eq!(Bridge::confirmation_status(transfer_id), Some((1, RequiredConfirmations::get() as u128)));
+                assert_eq!(Bridge::confirmation_status(transfer_id.wrapping_add(1)), None);
+            });
+        }
+
+        #[test]
+        fn finalize_falls_back_to_default_manager() {
+            new_test_ext().execute_with(|| {
+                // Aucun adaptateur n'est enregistré pour la chaîne "SOL" : le DummyAssetManager est utilisé.
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"SOL".to_vec(), metadata(b"SOL", b"SOL")));
+                run_transfer(b"SOL", true);
+                assert!(adapter_calls().is_empty());
+            });
+        }
+
+        #[test]
+        fn bridged_volume_tracks_each_direction() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"LINK".to_vec(), metadata(b"LINK", b"ETH")));
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"SOL".to_vec(), metadata(b"SOL", b"SOL")));
+                assert_eq!(Bridge::volume(b"LINK"), None);
+                assert_ok!(Bridge::add_destination_chain(system::RawOrigin::Root.into(), b"ETH".to_vec()));
+                assert_ok!(Bridge::add_destination_chain(system::RawOrigin::Root.into(), b"SOL".to_vec()));
+
+                // `run_transfer` transfère 1 000 unités.
+                run_transfer(b"LINK", true);
+                run_transfer(b"LINK", true);
+                run_transfer(b"LINK", false);
+                run_transfer(b"SOL", false);
+
+                assert_eq!(Bridge::volume(b"LINK"), Some((2_000, 1_000)));
+                assert_eq!(Bridge::volume(b"SOL"), Some((0, 1_000)));
+
+                // Un transfert initié mais non finalisé n'est pas comptabilisé.
+                assert_ok!(Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"SOL".to_vec(), 500, 2, true));
+                assert_eq!(Bridge::volume(b"SOL"), Some((0, 1_000)));
+            });
+        }
+
+        #[test]
+        fn identical_transfers_in_different_blocks_get_distinct_ids() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"LINK".to_vec(), metadata(b"LINK", b"ETH")));
+
+                System::set_block_number(1);
+                let first = next_id(b"LINK", 1_000);
+                assert_ok!(Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"LINK".to_vec(), 1_000, 2, true));
+                System::set_block_number(2);
+                let second = next_id(b"LINK", 1_000);
+                assert_ok!(Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"LINK".to_vec(), 1_000, 2, true));
+
+                assert_ne!(first, second);
+                assert!(Bridge::pending_transfers(first).is_some());
+                assert!(Bridge::pending_transfers(second).is_some());
+
+                // Même nonce, même paramètres : seul le bloc diffère (cas d'une réorganisation).
+                assert_ne!(
+                    Bridge::derive_transfer_id(&1, b"LINK", 1_000, 1, 0),
+                    Bridge::derive_transfer_id(&1, b"LINK", 1_000, 2, 0)
+                );
+                // L'identifiant reste utilisable pour les recherches.
+                assert_eq!(Bridge::confirmation_status(second), Some((0, RequiredConfirmations::get() as u128)));
+            });
+        }
+
+        #[test]
+        fn outbound_transfers_require_supported_destination() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"LINK".to_vec(), metadata(b"LINK", b"ETH")));
+                let outbound = || Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"LINK".to_vec(), 1_000, 2, false);
+
+                assert_noop!(outbound(), Error::<Test>::UnsupportedDestination);
+                // Les transferts entrants ne dépendent pas de la liste.
+                assert_ok!(Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"LINK".to_vec(), 1_000, 2, true));
+
+                assert_noop!(
+                    Bridge::add_destination_chain(system::RawOrigin::Signed(1).into(), b"ETH".to_vec()),
+                    sp_runtime::DispatchError::BadOrigin
+                );
+                assert_ok!(Bridge::add_destination_chain(system::RawOrigin::Root.into(), b"ETH".to_vec()));
+                assert!(Bridge::is_supported_destination(b"ETH"));
+                assert_ok!(outbound());
+
+                assert_ok!(Bridge::remove_destination_chain(system::RawOrigin::Root.into(), b"ETH".to_vec()));
+                assert_noop!(outbound(), Error::<Test>::UnsupportedDestination);
+                assert_noop!(
+                    Bridge::remove_destination_chain(system::RawOrigin::Root.into(), b"ETH".to_vec()),
+                    Error::<Test>::UnsupportedDestination
+                );
+            });
+        }
+
+        fn metadata_with_decimals(symbol: &[u8], decimals: u8, nodara_decimals: u8) -> AssetMetadata {
+            AssetMetadata { decimals, nodara_decimals, ..metadata(symbol, b"SOL") }
+        }
+
+        #[test]
+        fn scale_amount_converts_between_decimals() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"USDC".to_vec(), metadata_with_decimals(b"USDC", 6, 18)));
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"WETH".to_vec(), metadata_with_decimals(b"WETH", 18, 12)));
+
+                // Vers plus de décimales.
+                assert_eq!(Bridge::scale_amount(b"USDC", 1_500_000, true), Some(1_500_000_000_000_000_000));
+                assert_eq!(Bridge::scale_amount(b"USDC", 1_500_000_000_000_000_000, false), Some(1_500_000));
+                // Vers moins de décimales : troncature.
+                assert_eq!(Bridge::scale_amount(b"WETH", 2_000_000_123_456, true), Some(2_000_000));
+                assert_eq!(Bridge::scale_amount(b"WETH", 2_000_000, false), Some(2_000_000_000_000));
+                // Dépassement : rejeté.
+                assert_eq!(Bridge::scale_amount(b"USDC", u128::MAX / 1_000, true), None);
+                assert_eq!(Bridge::scale_amount(b"USDC", 0, true), Some(0));
+                // Actif inconnu : montant inchangé.
+                assert_eq!(Bridge::scale_amount(b"NONE", 42, true), Some(42));
+            });
+        }
+
+        #[test]
+        fn finalize_mints_and_burns_scaled_amounts() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"USDC".to_vec(), metadata_with_decimals(b"USDC", 6, 9)));
+                assert_ok!(Bridge::add_destination_chain(system::RawOrigin::Root.into(), b"SOL".to_vec()));
+
+                // `run_transfer` transfère 1 000 unités de la chaîne source.
+                run_transfer(b"USDC", true);
+                run_transfer(b"USDC", false);
+
+                assert_eq!(
+                    DEFAULT_MANAGER_AMOUNTS.with(|amounts| amounts.borrow().clone()),
+                    vec![("mint", 1_000_000), ("burn", 1_000_000)]
+                );
+            });
+        }
+
+        #[test]
+        fn overflowing_amounts_are_rejected_instead_of_minting_the_maximum() {
+            new_test_ext().execute_with(|| {
+                System::set_block_number(1);
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"USDC".to_vec(), metadata_with_decimals(b"USDC", 6, 18)));
+                assert_noop!(
+                    Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"USDC".to_vec(), u128::MAX / 1_000, 2, true),
+                    Error::<Test>::AmountOverflow
+                );
+
+                // Une demande devenue inconvertible (décimales modifiées en stockage) n'est pas finalisée.
+                let amount = u128::MAX / 1_000;
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"DAI".to_vec(), metadata_with_decimals(b"DAI", 18, 18)));
+                let transfer_id = next_id(b"DAI", amount);
+                assert_ok!(Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"DAI".to_vec(), amount, 2, true));
+                assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(1).into(), transfer_id));
+                next_block();
+                assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(3).into(), transfer_id));
+                SupportedAssets::<Test>::mutate(b"DAI".to_vec(), |metadata| {
+                    if let Some(metadata) = metadata {
+                        metadata.decimals = 6;
+                    }
+                });
+                assert_noop!(
+                    Bridge::finalize_transfer(system::RawOrigin::Signed(1).into(), transfer_id),
+                    Error::<Test>::AmountOverflow
+                );
+                assert!(Bridge::pending_transfers(transfer_id).is_some());
+                assert!(DEFAULT_MANAGER_AMOUNTS.with(|amounts| amounts.borrow().is_empty()));
+            });
+        }
+
+        #[test]
+        fn bridged_volume_saturates() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"SOL".to_vec(), metadata(b"SOL", b"SOL")));
+                BridgedVolume::<Test>::insert(b"SOL".to_vec(), BridgedVolumeTotals { to_nodara: u128::MAX - 10, from_nodara: 0 });
+                run_transfer(b"SOL", true);
+                assert_eq!(Bridge::volume(b"SOL"), Some((u128::MAX, 0)));
+            });
+        }
+
+        #[test]
+        fn pausing_blocks_bridge_operations_until_unpaused() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"LINK".to_vec(), metadata(b"LINK", b"ETH")));
+                let transfer_id = next_id(b"LINK", 1_000);
+                assert_ok!(Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"LINK".to_vec(), 1_000, 2, true));
+
+                assert_noop!(Bridge::set_paused(system::RawOrigin::Signed(1).into(), true), sp_runtime::DispatchError::BadOrigin);
+                assert_ok!(Bridge::set_paused(system::RawOrigin::Root.into(), true));
+                assert!(Bridge::is_paused());
+
+                assert_noop!(
+                    Bridge::register_asset(system::RawOrigin::Root.into(), b"SOL".to_vec(), metadata(b"SOL", b"SOL")),
+                    Error::<Test>::Paused
+                );
+                assert_noop!(
+                    Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"LINK".to_vec(), 1_000, 2, true),
+                    Error::<Test>::Paused
+                );
+                assert_noop!(Bridge::confirm_transfer(system::RawOrigin::Signed(1).into(), transfer_id), Error::<Test>::Paused);
+                assert_noop!(Bridge::finalize_transfer(system::RawOrigin::Signed(1).into(), transfer_id), Error::<Test>::Paused);
+                // Les lectures restent disponibles.
+                assert_eq!(Bridge::confirmation_status(transfer_id), Some((0, RequiredConfirmations::get() as u128)));
+
+                assert_ok!(Bridge::set_paused(system::RawOrigin::Root.into(), false));
+                assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(1).into(), transfer_id));
+                next_block();
+                assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(3).into(), transfer_id));
+                assert_ok!(Bridge::finalize_transfer(system::RawOrigin::Signed(1).into(), transfer_id));
+            });
+        }
+
+        #[test]
+        fn register_asset_is_capped_and_assets_are_listed() {
+            new_test_ext().execute_with(|| {
+                for symbol in [&b"ETH"[..], b"BTC", b"DOT"] {
+                    assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), symbol.to_vec(), metadata(symbol, symbol)));
+                }
+                assert_eq!(Bridge::supported_asset_count(), MaxSupportedAssets::get());
+                // Réenregistrer un actif existant échoue sans toucher au compteur.
+                assert_noop!(
+                    Bridge::register_asset(system::RawOrigin::Root.into(), b"ETH".to_vec(), metadata(b"ETH", b"ETH")),
+                    Error::<Test>::AssetAlreadyExists
+                );
+                assert_eq!(Bridge::supported_asset_count(), MaxSupportedAssets::get());
+                assert_noop!(
+                    Bridge::register_asset(system::RawOrigin::Signed(1).into(), b"SOL".to_vec(), metadata(b"SOL", b"SOL")),
+                    sp_runtime::DispatchError::BadOrigin
+                );
+                assert_noop!(
+                    Bridge::register_asset(system::RawOrigin::Root.into(), b"SOL".to_vec(), metadata(b"SOL", b"SOL")),
+                    Error::<Test>::TooManyAssets
+                );
+                assert_eq!(Bridge::list_assets(), vec![b"BTC".to_vec(), b"DOT".to_vec(), b"ETH".to_vec()]);
+            });
+        }
+
+        #[test]
+        fn originator_reaps_after_ttl_and_others_after_grace() {
+            new_test_ext().execute_with(|| {
+                System::set_block_number(1);
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"BTC".to_vec(), metadata(b"BTC", b"BTC")));
+                let first = next_id(b"BTC", 1_000);
+                assert_ok!(Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"BTC".to_vec(), 1_000, 2, true));
+                let second = next_id(b"BTC", 1_000);
+                assert_ok!(Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"BTC".to_vec(), 1_000, 2, true));
+
+                // Avant le TTL, personne ne peut retirer la demande.
+                System::set_block_number(10);
+                assert_noop!(Bridge::expire_transfer(system::RawOrigin::Signed(1).into(), first), Error::<Test>::TransferNotExpired);
+                assert_noop!(Bridge::expire_transfer(system::RawOrigin::Signed(3).into(), first), Error::<Test>::TransferNotExpired);
+
+                // Au TTL, seul l'initiateur peut retirer la demande.
+                System::set_block_number(11);
+                assert_noop!(Bridge::expire_transfer(system::RawOrigin::Signed(3).into(), first), Error::<Test>::ReapGracePeriodActive);
+                assert_ok!(Bridge::expire_transfer(system::RawOrigin::Signed(1).into(), first));
+                assert!(Bridge::pending_transfers(first).is_none());
+
+                // Un tiers doit attendre TTL + grâce.
+                System::set_block_number(15);
+                assert_noop!(Bridge::expire_transfer(system::RawOrigin::Signed(3).into(), second), Error::<Test>::ReapGracePeriodActive);
+                System::set_block_number(16);
+                assert_ok!(Bridge::expire_transfer(system::RawOrigin::Signed(3).into(), second));
+                assert!(Bridge::pending_transfers(second).is_none());
+            });
+        }
+
+        #[test]
+        fn consecutive_confirmations_must_be_in_distinct_blocks() {
+            new_test_ext().execute_with(|| {
+                System::set_block_number(1);
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"BTC".to_vec(), metadata(b"BTC", b"BTC")));
+                let transfer_id = next_id(b"BTC", 1_000);
+                assert_ok!(Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"BTC".to_vec(), 1_000, 2, true));
+
+                assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(1).into(), transfer_id));
+                // Deuxième clé dans le même bloc : refusée.
+                assert_noop!(
+                    Bridge::confirm_transfer(system::RawOrigin::Signed(3).into(), transfer_id),
+                    Error::<Test>::ConfirmationTooSoon
+                );
+                assert_eq!(Bridge::confirmation_status(transfer_id), Some((1, RequiredConfirmations::get() as u128)));
+
+                // Au bloc suivant, la confirmation est acceptée.
+                next_block();
+                assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(3).into(), transfer_id));
+                assert_eq!(Bridge::pending_transfers(transfer_id).and_then(|r| r.last_confirmed_at), Some(2));
+                assert_ok!(Bridge::finalize_transfer(system::RawOrigin::Signed(1).into(), transfer_id));
+            });
+        }
+
+        #[test]
+        fn frozen_asset_is_blocked_while_others_flow() {
+            new_test_ext().execute_with(|| {
+                System::set_block_number(1);
+                for symbol in [&b"ETH"[..], b"BTC"] {
+                    assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), symbol.to_vec(), metadata(symbol, symbol)));
+                }
+                let pending = next_id(b"ETH", 1_000);
+                assert_ok!(Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"ETH".to_vec(), 1_000, 2, true));
+                assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(1).into(), pending));
+                next_block();
+                assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(3).into(), pending));
+
+                assert_noop!(Bridge::freeze_asset(system::RawOrigin::Signed(1).into(), b"ETH".to_vec()), sp_runtime::DispatchError::BadOrigin);
+                assert_noop!(Bridge::freeze_asset(system::RawOrigin::Root.into(), b"SOL".to_vec()), Error::<Test>::AssetNotSupported);
+                assert_ok!(Bridge::freeze_asset(system::RawOrigin::Root.into(), b"ETH".to_vec()));
+
+                assert_noop!(
+                    Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"ETH".to_vec(), 500, 2, true),
+                    Error::<Test>::AssetFrozen
+                );
+                assert_noop!(Bridge::finalize_transfer(system::RawOrigin::Signed(1).into(), pending), Error::<Test>::AssetFrozen);
+                // Les autres actifs ne sont pas affectés.
+                next_block();
+                run_transfer(b"BTC", true);
+                assert_eq!(Bridge::bridged_volume(b"BTC".to_vec()).map(|v| v.to_nodara), Some(1_000));
+
+                assert_ok!(Bridge::unfreeze_asset(system::RawOrigin::Root.into(), b"ETH".to_vec()));
+                assert_noop!(Bridge::unfreeze_asset(system::RawOrigin::Root.into(), b"ETH".to_vec()), Error::<Test>::AssetNotFrozen);
+                assert_ok!(Bridge::finalize_transfer(system::RawOrigin::Signed(1).into(), pending));
+            });
+        }
+
+        #[test]
+        fn confirmations_beyond_quorum_are_rejected() {
+            new_test_ext().execute_with(|| {
+                System::set_block_number(1);
+                Bridge::integrity_test();
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"BTC".to_vec(), metadata(b"BTC", b"BTC")));
+                let transfer_id = next_id(b"BTC", 1_000);
+                assert_ok!(Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"BTC".to_vec(), 1_000, 2, true));
+
+                for validator in 1..=RequiredConfirmations::get() as u64 {
+                    assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(validator).into(), transfer_id));
+                    next_block();
+                }
+                for validator in 10..20u64 {
+                    assert_noop!(
+                        Bridge::confirm_transfer(system::RawOrigin::Signed(validator).into(), transfer_id),
+                        Error::<Test>::QuorumAlreadyReached
+                    );
+                    next_block();
+                }
+                let confirmations = Bridge::pending_transfers(transfer_id).unwrap().confirmations;
+                assert_eq!(confirmations.len() as u32, RequiredConfirmations::get());
+                assert!(confirmations.len() as u32 <= MaxConfirmations::get());
+                assert_ok!(Bridge::finalize_transfer(system::RawOrigin::Signed(1).into(), transfer_id));
+            });
+        }
+
+        #[test]
+        fn governance_updates_registered_asset_metadata() {
+            new_test_ext().execute_with(|| {
+                System::set_block_number(1);
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"BTC".to_vec(), metadata(b"BTC", b"BTC")));
+                let fixed = AssetMetadata { name: b"Bitcoin".to_vec(), decimals: 10, ..metadata(b"BTC", b"BTC") };
+
+                assert_noop!(
+                    Bridge::update_asset_metadata(system::RawOrigin::Signed(1).into(), b"BTC".to_vec(), fixed.clone()),
+                    sp_runtime::DispatchError::BadOrigin
+                );
+                assert_noop!(
+                    Bridge::update_asset_metadata(system::RawOrigin::Root.into(), b"BTC".to_vec(), AssetMetadata { symbol: Vec::new(), ..fixed.clone() }),
+                    Error::<Test>::InvalidAssetDefinition
+                );
+                assert_ok!(Bridge::update_asset_metadata(system::RawOrigin::Root.into(), b"BTC".to_vec(), fixed.clone()));
+                assert_eq!(Bridge::supported_assets(b"BTC".to_vec()), Some(fixed));
+                assert_eq!(Bridge::supported_asset_count(), 1);
+            });
+        }
+
+        #[test]
+        fn asset_metadata_updates_respect_pause_and_pending_transfers() {
+            new_test_ext().execute_with(|| {
+                System::set_block_number(1);
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"BTC".to_vec(), metadata(b"BTC", b"BTC")));
+                let renamed = AssetMetadata { name: b"Bitcoin".to_vec(), ..metadata(b"BTC", b"BTC") };
+                let redenominated = AssetMetadata { decimals: 10, ..renamed.clone() };
+
+                assert_ok!(Bridge::set_paused(system::RawOrigin::Root.into(), true));
+                assert_noop!(
+                    Bridge::update_asset_metadata(system::RawOrigin::Root.into(), b"BTC".to_vec(), renamed.clone()),
+                    Error::<Test>::Paused
+                );
+                assert_ok!(Bridge::set_paused(system::RawOrigin::Root.into(), false));
+
+                // Un transfert en attente bloque le changement de décimales, pas celui du nom.
+                let transfer_id = next_id(b"BTC", 1_000);
+                assert_ok!(Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"BTC".to_vec(), 1_000, 2, true));
+                assert_noop!(
+                    Bridge::update_asset_metadata(system::RawOrigin::Root.into(), b"BTC".to_vec(), redenominated.clone()),
+                    Error::<Test>::AssetHasPendingTransfers
+                );
+                assert_ok!(Bridge::update_asset_metadata(system::RawOrigin::Root.into(), b"BTC".to_vec(), renamed));
+
+                assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(1).into(), transfer_id));
+                next_block();
+                assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(3).into(), transfer_id));
+                assert_ok!(Bridge::finalize_transfer(system::RawOrigin::Signed(1).into(), transfer_id));
+                assert_ok!(Bridge::update_asset_metadata(system::RawOrigin::Root.into(), b"BTC".to_vec(), redenominated.clone()));
+                assert_eq!(Bridge::supported_assets(b"BTC".to_vec()), Some(redenominated));
+            });
+        }
+
+        #[test]
+        fn updating_unknown_asset_metadata_fails() {
+            new_test_ext().execute_with(|| {
+                assert_noop!(
+                    Bridge::update_asset_metadata(system::RawOrigin::Root.into(), b"ETH".to_vec(), metadata(b"ETH", b"ETH")),
+                    Error::<Test>::AssetNotSupported
+                );
+                assert_eq!(Bridge::supported_assets(b"ETH".to_vec()), None);
+            });
+        }
+
+        #[test]
+        fn try_state_detects_asset_count_drift() {
+            new_test_ext().execute_with(|| {
+                System::set_block_number(1);
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"ETH".to_vec(), metadata(b"ETH", b"ETH")));
+                let transfer_id = next_id(b"ETH", 1_000);
+                assert_ok!(Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"ETH".to_vec(), 1_000, 2, true));
+                assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(1).into(), transfer_id));
+                assert_ok!(Bridge::do_try_state());
+
+                // Actif inséré sans passer par `register_asset` : le compteur n'est pas mis à jour.
+                SupportedAssets::<Test>::insert(b"BTC".to_vec(), metadata(b"BTC", b"BTC"));
+                assert_eq!(Bridge::do_try_state(), Err("SupportedAssetCount désynchronisé"));
+                SupportedAssetCount::<Test>::put(2);
+                assert_ok!(Bridge::do_try_state());
+            });
+        }
+
+        #[test]
+        fn transfers_below_the_asset_minimum_are_rejected() {
+            new_test_ext().execute_with(|| {
+                System::set_block_number(1);
+                let mut eth = metadata(b"ETH", b"ETH");
+                eth.decimals = 18;
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"ETH".to_vec(), eth));
+                assert_eq!(Bridge::min_transfer(b"ETH".to_vec()), 1);
+                assert_noop!(
+                    Bridge::set_min_transfer(system::RawOrigin::Signed(1).into(), b"ETH".to_vec(), 1_000),
+                    sp_runtime::DispatchError::BadOrigin
+                );
+                assert_noop!(
+                    Bridge::set_min_transfer(system::RawOrigin::Root.into(), b"BTC".to_vec(), 1_000),
+                    Error::<Test>::AssetNotSupported
+                );
+                // 10^15 unités d'un actif à 18 décimales : 0,001 ETH.
+                assert_ok!(Bridge::set_min_transfer(system::RawOrigin::Root.into(), b"ETH".to_vec(), 1_000_000_000_000_000));
+
+                assert_noop!(
+                    Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"ETH".to_vec(), 1, 2, true),
+                    Error::<Test>::BelowMinimumTransfer
+                );
+                assert_noop!(
+                    Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"ETH".to_vec(), 999_999_999_999_999, 2, true),
+                    Error::<Test>::BelowMinimumTransfer
+                );
+                assert_ok!(Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"ETH".to_vec(), 1_000_000_000_000_000, 2, true));
+            });
+        }
+
+        #[test]
+        fn transfer_amount_limits_follow_the_asset_decimals() {
+            new_test_ext().execute_with(|| {
+                System::set_block_number(1);
+                TransferAmountLimits::set(Some((1, 100)));
+                // Même bornes en unités entières : 1 BTC = 10^8, 1 ETH = 10^18.
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"BTC".to_vec(), metadata(b"BTC", b"BTC")));
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"ETH".to_vec(), metadata_with_decimals(b"ETH", 18, 18)));
+                let transfer = |asset: &[u8], amount| {
+                    Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), asset.to_vec(), amount, 2, true)
+                };
+
+                assert_noop!(transfer(b"BTC", 99_999_999), Error::<Test>::BelowMinimumTransfer);
+                assert_ok!(transfer(b"BTC", 100_000_000));
+                assert_ok!(transfer(b"BTC", 10_000_000_000));
+                assert_noop!(transfer(b"BTC", 10_000_000_001), Error::<Test>::AboveMaximumTransfer);
+
+                // 10^10 unités valent 100 BTC mais seulement 10^-8 ETH.
+                assert_noop!(transfer(b"ETH", 10_000_000_000), Error::<Test>::BelowMinimumTransfer);
+                assert_ok!(transfer(b"ETH", 1_000_000_000_000_000_000));
+                assert_noop!(transfer(b"ETH", 100_000_000_000_000_000_001), Error::<Test>::AboveMaximumTransfer);
+                TransferAmountLimits::set(None);
+            });
+        }
+
+        #[test]
+        fn stake_weighted_quorum_favours_high_stake_confirmations() {
+            new_test_ext().execute_with(|| {
+                // Stake total 1 050, quorum 67 % : 704. Deux gros validateurs (1, 2) à 400, cinq
+                // petits (10 à 14) à 50.
+                STAKES.with(|stakes| {
+                    *stakes.borrow_mut() = vec![(1, 400), (2, 400), (10, 50), (11, 50), (12, 50), (13, 50), (14, 50)]
+                });
+                System::set_block_number(1);
+                assert_ok!(Bridge::register_asset(system::RawOrigin::Root.into(), b"BTC".to_vec(), metadata(b"BTC", b"BTC")));
+
+                // Quatre petites confirmations (200) dépassent `RequiredConfirmations` sans atteindre le quorum.
+                let low = next_id(b"BTC", 1_000);
+                assert_ok!(Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"BTC".to_vec(), 1_000, 2, true));
+                for validator in 10..=13u64 {
+                    assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(validator).into(), low));
+                    next_block();
+                }
+                assert_noop!(
+                    Bridge::finalize_transfer(system::RawOrigin::Signed(1).into(), low),
+                    Error::<Test>::InsufficientConfirmations
+                );
+                assert_eq!(Bridge::confirmation_status(low), Some((200, 704)));
+                // Un petit validateur ne peut pas évincer une confirmation de même stake.
+                assert_noop!(
+                    Bridge::confirm_transfer(system::RawOrigin::Signed(14).into(), low),
+                    Error::<Test>::TooManyConfirmations
+                );
+                // Les gros validateurs remplacent les plus petites confirmations et débloquent le quorum.
+                assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(1).into(), low));
+                next_block();
+                assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(2).into(), low));
+                next_block();
+                let confirmations = Bridge::pending_transfers(low).unwrap().confirmations;
+                assert_eq!(confirmations.len() as u32, MaxConfirmations::get());
+                assert!(confirmations.contains(&1) && confirmations.contains(&2));
+                assert_eq!(ConfirmationStakes::<Test>::iter_prefix(low).count() as u32, MaxConfirmations::get());
+                assert_eq!(Bridge::confirmation_status(low), Some((900, 704)));
+                assert_ok!(Bridge::do_try_state());
+                assert_ok!(Bridge::finalize_transfer(system::RawOrigin::Signed(1).into(), low));
+
+                // Deux grosses confirmations (800) suffisent.
+                let high = next_id(b"BTC", 1_000);
+                assert_ok!(Bridge::initiate_transfer(system::RawOrigin::Signed(1).into(), b"BTC".to_vec(), 1_000, 2, true));
+                assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(1).into(), high));
+                next_block();
+                assert_noop!(
+                    Bridge::confirm_transfer(system::RawOrigin::Signed(30).into(), high),
+                    Error::<Test>::NoValidatorStake
+                );
+                assert_ok!(Bridge::confirm_transfer(system::RawOrigin::Signed(2).into(), high));
+                next_block();
+                assert_noop!(
+                    Bridge::confirm_transfer(system::RawOrigin::Signed(10).into(), high),
+                    Error::<Test>::QuorumAlreadyReached
+                );
+                assert_eq!(Bridge::confirmation_stake(high, 1), Some(400));
+                assert_ok!(Bridge::do_try_state());
+                assert_ok!(Bridge::finalize_transfer(system::RawOrigin::Signed(1).into(), high));
+                assert_eq!(ConfirmationStakes::<Test>::iter_prefix(high).count(), 0);
+            });
+        }
     }
 }
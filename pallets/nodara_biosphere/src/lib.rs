@@ -15,9 +15,19 @@ use frame_support::{
     transactional,
 };
 use frame_system::pallet_prelude::*;
+use nodara_emergency::HaltGuard;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::vec::Vec;
 use sp_std::prelude::*; // Inclut notamment ToString
+use nodara_log::nodara_log;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Cible des messages `nodara_log!` de ce module.
+pub const LOG_TARGET: &str = "runtime::nodara_biosphere";
+
+/// Somme des poids de l'énergie et du flux quantique dans la détermination de la phase.
+pub const PHASE_WEIGHT_TOTAL: u32 = 100;
 
 /// Trait pour gérer le minting et le burning des tokens représentatifs sur Nodara.
 pub trait BridgeAssetManager<AccountId> {
@@ -32,7 +42,7 @@ pub use pallet::*;
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use sp_runtime::traits::Zero;
+    use sp_runtime::traits::{SaturatedConversion, Zero};
 
     /// Identifiant de l'actif (exemple: b"BTC", b"ETH", etc.).
     pub type AssetId = Vec<u8>;
@@ -60,18 +70,52 @@ pub mod pallet {
         pub to_nodara: bool,
     }
 
-    /// État global de la biosphère.
-    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, Default, TypeInfo)]
-    pub struct BioState {
+    /// État global de la biosphère, dont l'historique est borné par `MaxHistory`.
+    #[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, DefaultNoBound, TypeInfo)]
+    #[scale_info(skip_type_params(MaxHistory))]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "serde", serde(bound(serialize = "", deserialize = "")))]
+    pub struct BioState<MaxHistory: Get<u32>> {
         pub current_phase: BioPhase,
         pub energy_level: u32,
         pub quantum_flux: u32,
         pub last_updated: u64,
-        pub history: Vec<(u64, BioPhase, u32, u32)>, // (timestamp, phase, energy, quantum_flux)
+        pub history: BoundedVec<BioRecord, MaxHistory>,
+    }
+
+    /// État de la biosphère borné par `Config::MaxHistoryLen`.
+    pub type BioStateOf<T> = BioState<<T as Config>::MaxHistoryLen>;
+
+    /// Nature d'une entrée de l'historique de la biosphère.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub enum BioRecordKind {
+        /// Transition calculée par `transition_phase`.
+        Transition,
+        /// Retour à l'état de référence via `reset_bio_state`.
+        Reset,
+        /// Changement de phase provoqué par la décroissance de `on_finalize`.
+        Decay,
+    }
+
+    /// Entrée de l'historique de la biosphère.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct BioRecord {
+        pub timestamp: u64,
+        pub phase: BioPhase,
+        pub energy: u32,
+        pub quantum_flux: u32,
+        pub kind: BioRecordKind,
     }
 
+    /// État de la biosphère avant la version 1 du stockage : l'historique est un `Vec` non borné de
+    /// tuples (timestamp, phase, énergie, flux quantique).
+    pub type LegacyBioState = (BioPhase, u32, u32, u64, Vec<(u64, BioPhase, u32, u32)>);
+
     /// Enumération des phases opérationnelles du réseau.
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
     pub enum BioPhase {
         Growth,
         Defense,
@@ -92,10 +136,47 @@ pub mod pallet {
         /// Facteur de lissage utilisé pour le calcul des moyennes mobiles exponentielles (EMA).
         #[pallet::constant]
         type SmoothingFactor: Get<u32>;
+        /// Niveau d'énergie de référence.
+        #[pallet::constant]
+        type BaselineEnergy: Get<u32>;
+        /// Flux quantique de référence.
+        #[pallet::constant]
+        type BaselineQuantumFlux: Get<u32>;
+        /// Phase de référence, restaurée par `reset_bio_state`.
+        type BaselinePhase: Get<BioPhase>;
+        /// Part (en pourcentage) de l'écart à `BaselineEnergy` et `BaselineQuantumFlux` résorbée à
+        /// chaque fin de bloc. 0 désactive la décroissance.
+        #[pallet::constant]
+        type DecayRate: Get<u32>;
+        /// Poids du flux quantique dans la détermination de la phase, sur `PHASE_WEIGHT_TOTAL` ;
+        /// l'énergie reçoit le complément. 0 (phase déterminée par la seule énergie) par défaut.
+        #[pallet::constant]
+        type FluxWeight: Get<u32>;
+        /// Nombre maximal de signaux appliqués par `transition_phase_batch`.
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+        /// Nombre maximal d'entrées de l'historique ; au-delà, les plus anciennes sont évincées.
+        #[pallet::constant]
+        type MaxHistoryLen: Get<u32>;
+        /// Nombre maximal de signaux appliqués par bloc, qu'ils proviennent de `transition_phase`
+        /// ou de `transition_phase_batch` ; doit être au moins égal à `MaxBatchSize`.
+        #[pallet::constant]
+        type MaxTransitionsPerBlock: Get<u32>;
+        /// Origine autorisée à réinitialiser l'état de la biosphère.
+        type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Origine autorisée à soumettre les signaux de transition (oracle de la biosphère). Le
+        /// plafond `MaxTransitionsPerBlock` étant global, il ne doit pas être ouvert à tout compte.
+        type OracleOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Arrêt d'urgence global consulté par chaque appel signé ; `()` le désactive.
+        type EmergencyHalt: nodara_emergency::HaltGuard;
     }
 
+    /// Version du stockage : la version 1 borne l'historique et le compose de `BioRecord`.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Stockage des actifs supportés par le bridge.
@@ -118,7 +199,12 @@ pub mod pallet {
     /// Stockage de l'état global de la biosphère.
     #[pallet::storage]
     #[pallet::getter(fn bio_state)]
-    pub type BioStateStorage<T: Config> = StorageValue<_, BioState, ValueQuery>;
+    pub type BioStateStorage<T: Config> = StorageValue<_, BioStateOf<T>, ValueQuery>;
+
+    /// Nombre de signaux appliqués dans le bloc courant, remis à zéro dans `on_initialize`.
+    #[pallet::storage]
+    #[pallet::getter(fn transitions_this_block)]
+    pub type TransitionsThisBlock<T: Config> = StorageValue<_, u32, ValueQuery>;
 
     /// Configuration de genèse permettant de pré-enregistrer des actifs supportés.
     #[pallet::genesis_config]
@@ -174,6 +260,12 @@ pub mod pallet {
         TransferFinalized(TransferId),
         /// Le bio state a été mis à jour. [ancien phase, nouvelle phase, nouvelle énergie, nouveau flux quantique]
         BioStateUpdated(BioPhase, BioPhase, u32, u32),
+        /// L'historique a été compacté. [taille avant, taille après]
+        HistoryCompacted(u32, u32),
+        /// Le bio state a été réinitialisé à l'état de référence. [ancienne phase, phase de référence, énergie, flux quantique]
+        BioStateReset(BioPhase, BioPhase, u32, u32),
+        /// Un lot de signaux a été appliqué par `transition_phase_batch`. [nombre de signaux]
+        BatchTransitioned(u32),
     }
 
     #[pallet::error]
@@ -190,6 +282,54 @@ pub mod pallet {
         InvalidAssetDefinition,
         /// Le montant doit être supérieur à zéro.
         InvalidAmount,
+        /// Le signal doit être strictement positif.
+        InvalidSignal,
+        /// La signature est absente ou invalide.
+        SignatureVerificationFailed,
+        /// Le facteur de lissage configuré est nul.
+        ZeroSmoothingFactor,
+        /// Le facteur de sous-échantillonnage doit être non nul.
+        InvalidCompactionParameters,
+        /// Le lot de signaux est vide.
+        EmptyBatch,
+        /// Le lot de signaux dépasse `MaxBatchSize`.
+        BatchTooLarge,
+        /// Le nombre maximal de transitions pour ce bloc (`MaxTransitionsPerBlock`) est atteint.
+        TooManyTransitions,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            TransitionsThisBlock::<T>::kill();
+            T::DbWeight::get().writes(1)
+        }
+
+        /// Fait décroître l'énergie et le flux quantique vers leurs valeurs de référence ; un
+        /// changement de phase qui en résulte est enregistré dans l'historique.
+        fn on_finalize(_n: BlockNumberFor<T>) {
+            Self::apply_decay();
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::get::<Pallet<T>>() >= STORAGE_VERSION {
+                return T::DbWeight::get().reads(1);
+            }
+            Self::migrate_history_to_v1();
+            STORAGE_VERSION.put::<Pallet<T>>();
+            T::DbWeight::get().reads_writes(2, 2)
+        }
+
+        fn integrity_test() {
+            assert!(
+                T::FluxWeight::get() <= crate::PHASE_WEIGHT_TOTAL,
+                "FluxWeight ne peut pas dépasser PHASE_WEIGHT_TOTAL"
+            );
+            assert!(
+                T::MaxTransitionsPerBlock::get() >= T::MaxBatchSize::get(),
+                "MaxTransitionsPerBlock doit être au moins égal à MaxBatchSize"
+            );
+        }
     }
 
     #[pallet::call]
@@ -198,6 +338,7 @@ pub mod pallet {
         #[pallet::weight(10_000)]
         pub fn register_asset(origin: OriginFor<T>, asset: AssetId, metadata: AssetMetadata) -> DispatchResult {
             let _ = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
             ensure!(!asset.is_empty(), Error::<T>::InvalidAssetDefinition);
             ensure!(!metadata.name.is_empty(), Error::<T>::InvalidAssetDefinition);
             ensure!(!metadata.symbol.is_empty(), Error::<T>::InvalidAssetDefinition);
@@ -221,6 +362,7 @@ pub mod pallet {
             to_nodara: bool,
         ) -> DispatchResult {
             let sender = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
             ensure!(amount > 0, Error::<T>::InvalidAmount);
             ensure!(SupportedAssets::<T>::contains_key(&asset), Error::<T>::AssetNotSupported);
 
@@ -253,6 +395,7 @@ pub mod pallet {
         #[pallet::weight(10_000)]
         pub fn confirm_transfer(origin: OriginFor<T>, transfer_id: TransferId) -> DispatchResult {
             let validator = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
             PendingTransfers::<T>::try_mutate(transfer_id, |maybe_request| -> DispatchResult {
                 let request = maybe_request.as_mut().ok_or(Error::<T>::TransferNotFound)?;
                 ensure!(!request.confirmations.contains(&validator), Error::<T>::AlreadyConfirmed);
@@ -270,6 +413,7 @@ pub mod pallet {
         #[transactional]
         pub fn finalize_transfer(origin: OriginFor<T>, transfer_id: TransferId) -> DispatchResult {
             let _ = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
             PendingTransfers::<T>::try_mutate_exists(transfer_id, |maybe_request| -> DispatchResult {
                 let request = maybe_request.take().ok_or(Error::<T>::TransferNotFound)?;
                 ensure!(
@@ -297,9 +441,98 @@ pub mod pallet {
         /// - Nouveau flux quantique = (flux mesuré + (smoothing - 1) * flux actuel) / smoothing
         ///
         /// La nouvelle phase est déterminée par des seuils appliqués à la nouvelle énergie.
+        /// Au-delà de `MaxTransitionsPerBlock` signaux dans le bloc, l'appel échoue avec
+        /// `TooManyTransitions`. Réservé à `OracleOrigin`.
         #[pallet::weight(10_000)]
         pub fn transition_phase(origin: OriginFor<T>, signal: u32, signature: Vec<u8>) -> DispatchResult {
-            ensure_signed(origin)?;
+            T::OracleOrigin::ensure_origin(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            let (old_phase, new_phase, new_energy, new_quantum_flux) = Self::apply_signal(signal, signature)?;
+            Self::deposit_event(Event::BioStateUpdated(old_phase, new_phase, new_energy, new_quantum_flux));
+            Ok(())
+        }
+
+        /// Applique dans l'ordre une série de signaux `(signal, signature)`, par exemple pour
+        /// rattraper les transitions manquées pendant une indisponibilité de l'oracle.
+        ///
+        /// Chaque signal est traité comme par `transition_phase` et ajoute une entrée à l'historique ;
+        /// un seul événement `BatchTransitioned` est émis. Si un signal est invalide, le lot entier
+        /// est annulé. Le lot est borné par `MaxBatchSize` et chaque signal compte dans
+        /// `MaxTransitionsPerBlock`. Réservé à `OracleOrigin`.
+        #[pallet::weight(Weight::from_parts(10_000u64.saturating_mul(signals.len() as u64), 0))]
+        #[transactional]
+        pub fn transition_phase_batch(origin: OriginFor<T>, signals: Vec<(u32, Vec<u8>)>) -> DispatchResult {
+            T::OracleOrigin::ensure_origin(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            ensure!(!signals.is_empty(), Error::<T>::EmptyBatch);
+            ensure!(signals.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+            let count = signals.len() as u32;
+            for (signal, signature) in signals {
+                Self::apply_signal(signal, signature)?;
+            }
+            Self::deposit_event(Event::BatchTransitioned(count));
+            Ok(())
+        }
+
+        /// Restaure l'état de référence de la biosphère (procédure de récupération).
+        ///
+        /// À utiliser lorsque l'état est corrompu ou resté à sa valeur par défaut (par exemple après
+        /// une migration échouée) : l'énergie, le flux quantique et la phase reprennent les valeurs
+        /// `BaselineEnergy`, `BaselineQuantumFlux` et `BaselinePhase`. L'historique est conservé et une
+        /// entrée `BioRecordKind::Reset` y est ajoutée.
+        #[pallet::weight(10_000)]
+        pub fn reset_bio_state(origin: OriginFor<T>) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+            let baseline_phase = T::BaselinePhase::get();
+            let energy = T::BaselineEnergy::get();
+            let quantum_flux = T::BaselineQuantumFlux::get();
+
+            let mut state = BioStateStorage::<T>::get();
+            let old_phase = state.current_phase.clone();
+            state.current_phase = baseline_phase.clone();
+            state.energy_level = energy;
+            state.quantum_flux = quantum_flux;
+            state.last_updated = now;
+            Self::record_history(&mut state.history, BioRecord {
+                timestamp: now,
+                phase: baseline_phase.clone(),
+                energy,
+                quantum_flux,
+                kind: BioRecordKind::Reset,
+            });
+            BioStateStorage::<T>::put(state);
+
+            Self::deposit_event(Event::BioStateReset(old_phase, baseline_phase, energy, quantum_flux));
+            Ok(())
+        }
+
+        /// Compacte l'historique au lieu de le tronquer.
+        ///
+        /// Les `keep_recent` entrées les plus récentes sont conservées telles quelles ; parmi les
+        /// entrées plus anciennes, seule une sur `downsample_factor` est gardée (la plus ancienne de
+        /// chaque groupe), ce qui borne la taille de l'historique tout en préservant la tendance longue.
+        #[pallet::weight(10_000)]
+        pub fn compact_history(origin: OriginFor<T>, keep_recent: u32, downsample_factor: u32) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            ensure!(downsample_factor > 0, Error::<T>::InvalidCompactionParameters);
+            let (before, after) = BioStateStorage::<T>::mutate(|state| {
+                let before = state.history.len() as u32;
+                let mut history = core::mem::take(&mut state.history).into_inner();
+                Self::downsample_history(&mut history, keep_recent as usize, downsample_factor as usize);
+                state.history = BoundedVec::truncate_from(history);
+                (before, state.history.len() as u32)
+            });
+            Self::deposit_event(Event::HistoryCompacted(before, after));
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Applique un signal à l'état de la biosphère (voir `transition_phase`) et l'historise.
+        ///
+        /// Retourne `(ancienne phase, nouvelle phase, nouvelle énergie, nouveau flux quantique)`.
+        fn apply_signal(signal: u32, signature: Vec<u8>) -> Result<(BioPhase, BioPhase, u32, u32), DispatchError> {
             ensure!(signal > 0, Error::<T>::InvalidSignal);
             ensure!(!signature.is_empty(), Error::<T>::SignatureVerificationFailed);
 
@@ -308,6 +541,7 @@ pub mod pallet {
 
             let smoothing = T::SmoothingFactor::get();
             ensure!(smoothing > 0, Error::<T>::ZeroSmoothingFactor);
+            Self::note_transition()?;
 
             // Calcul de l'énergie mesurée et de la nouvelle énergie via EMA.
             let measured_energy = signal.saturating_mul(10);
@@ -326,31 +560,162 @@ pub mod pallet {
             };
 
             // Détermination de la nouvelle phase basée sur de nouveaux seuils.
-            let new_phase = if new_energy > 150 {
-                BioPhase::Growth
-            } else if new_energy > 75 {
-                BioPhase::Defense
-            } else {
-                BioPhase::Mutation
-            };
+            let new_phase = Self::phase_for(new_energy, new_quantum_flux);
 
             let old_phase = state.current_phase.clone();
             state.current_phase = new_phase.clone();
             state.energy_level = new_energy;
             state.quantum_flux = new_quantum_flux;
             state.last_updated = now;
-            state.history.push((now, new_phase.clone(), new_energy, new_quantum_flux));
+            Self::record_history(&mut state.history, BioRecord {
+                timestamp: now,
+                phase: new_phase.clone(),
+                energy: new_energy,
+                quantum_flux: new_quantum_flux,
+                kind: BioRecordKind::Transition,
+            });
             BioStateStorage::<T>::put(state);
 
-            Self::deposit_event(Event::BioStateUpdated(old_phase, new_phase, new_energy, new_quantum_flux));
-            Ok(())
+            if old_phase != new_phase {
+                nodara_log!(info, "transition de phase {:?} -> {:?} au bloc {} (énergie {}, flux {})", old_phase, new_phase, now, new_energy, new_quantum_flux);
+            } else {
+                nodara_log!(debug, "phase {:?} maintenue au bloc {} (énergie {}, flux {})", new_phase, now, new_energy, new_quantum_flux);
+            }
+            Ok((old_phase, new_phase, new_energy, new_quantum_flux))
+        }
+
+        /// Comptabilise un signal dans le bloc courant, ou échoue si `MaxTransitionsPerBlock` est
+        /// atteint.
+        fn note_transition() -> DispatchResult {
+            TransitionsThisBlock::<T>::try_mutate(|count| -> DispatchResult {
+                ensure!(*count < T::MaxTransitionsPerBlock::get(), Error::<T>::TooManyTransitions);
+                *count += 1;
+                Ok(())
+            })
+        }
+
+        /// Phase correspondant à un niveau d'énergie et de flux quantique.
+        ///
+        /// Les seuils s'appliquent à la moyenne de l'énergie et du flux pondérée par `FluxWeight`
+        /// (sur `PHASE_WEIGHT_TOTAL`) ; avec un poids nul, seule l'énergie compte.
+        pub fn phase_for(energy: u32, quantum_flux: u32) -> BioPhase {
+            let total = crate::PHASE_WEIGHT_TOTAL as u64;
+            let flux_weight = (T::FluxWeight::get() as u64).min(total);
+            let score = (energy as u64 * (total - flux_weight) + quantum_flux as u64 * flux_weight) / total;
+            if score > 150 {
+                BioPhase::Growth
+            } else if score > 75 {
+                BioPhase::Defense
+            } else {
+                BioPhase::Mutation
+            }
+        }
+
+        /// Rapproche `value` de `baseline` de `rate` % de l'écart (au moins d'une unité).
+        fn decay_toward(value: u32, baseline: u32, rate: u32) -> u32 {
+            let gap = value.abs_diff(baseline);
+            let step = ((gap as u64 * rate.min(100) as u64 / 100) as u32).max(1).min(gap);
+            if value > baseline {
+                value - step
+            } else {
+                value + step
+            }
+        }
+
+        /// Applique un pas de décroissance à l'état de la biosphère, sans effet tant que l'état n'a
+        /// pas été initialisé ou si `DecayRate` est nul.
+        fn apply_decay() {
+            let rate = T::DecayRate::get();
+            if rate == 0 {
+                return;
+            }
+            let mut state = BioStateStorage::<T>::get();
+            if state.history.is_empty() {
+                return;
+            }
+            let energy = Self::decay_toward(state.energy_level, T::BaselineEnergy::get(), rate);
+            let quantum_flux = Self::decay_toward(state.quantum_flux, T::BaselineQuantumFlux::get(), rate);
+            if energy == state.energy_level && quantum_flux == state.quantum_flux {
+                return;
+            }
+            state.energy_level = energy;
+            state.quantum_flux = quantum_flux;
+
+            let old_phase = state.current_phase.clone();
+            let new_phase = Self::phase_for(energy, quantum_flux);
+            if old_phase != new_phase {
+                let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+                state.current_phase = new_phase.clone();
+                state.last_updated = now;
+                Self::record_history(&mut state.history, BioRecord {
+                    timestamp: now,
+                    phase: new_phase.clone(),
+                    energy,
+                    quantum_flux,
+                    kind: BioRecordKind::Decay,
+                });
+                nodara_log!(info, "décroissance : phase {:?} -> {:?} au bloc {} (énergie {}, flux {})", old_phase, new_phase, now, energy, quantum_flux);
+                Self::deposit_event(Event::BioStateUpdated(old_phase, new_phase, energy, quantum_flux));
+            }
+            BioStateStorage::<T>::put(state);
+        }
+
+        /// Ajoute `record` à l'historique en évinçant l'entrée la plus ancienne s'il est plein.
+        fn record_history(history: &mut BoundedVec<BioRecord, T::MaxHistoryLen>, record: BioRecord) {
+            if let Err(record) = history.try_push(record) {
+                if !history.is_empty() {
+                    history.remove(0);
+                    let _ = history.try_push(record);
+                }
+            }
+        }
+
+        /// Convertit l'état de la version 0 : les `MaxHistoryLen` entrées les plus récentes sont
+        /// conservées et enregistrées comme des transitions, seule opération historisée alors.
+        fn migrate_history_to_v1() {
+            let _ = BioStateStorage::<T>::translate::<LegacyBioState, _>(|legacy| {
+                legacy.map(|(current_phase, energy_level, quantum_flux, last_updated, mut history)| {
+                    let excess = history.len().saturating_sub(T::MaxHistoryLen::get() as usize);
+                    let history = history
+                        .split_off(excess)
+                        .into_iter()
+                        .map(|(timestamp, phase, energy, quantum_flux)| BioRecord {
+                            timestamp,
+                            phase,
+                            energy,
+                            quantum_flux,
+                            kind: BioRecordKind::Transition,
+                        })
+                        .collect();
+                    BioState {
+                        current_phase,
+                        energy_level,
+                        quantum_flux,
+                        last_updated,
+                        history: BoundedVec::truncate_from(history),
+                    }
+                })
+            });
+        }
+
+        /// Sous-échantillonne `history` : conserve les `keep_recent` dernières entrées et une entrée
+        /// sur `factor` parmi les précédentes. `factor` doit être non nul.
+        fn downsample_history<E>(history: &mut Vec<E>, keep_recent: usize, factor: usize) {
+            let recent = history.split_off(history.len().saturating_sub(keep_recent));
+            let mut index = 0usize;
+            history.retain(|_| {
+                let keep = index % factor == 0;
+                index += 1;
+                keep
+            });
+            history.extend(recent);
         }
     }
 
     #[cfg(test)]
     mod tests {
         use super::*;
-        use frame_support::{assert_ok, parameter_types};
+        use frame_support::{assert_ok, parameter_types, traits::Hooks};
         use sp_core::H256;
         use sp_runtime::{
             testing::Header,
@@ -375,6 +740,12 @@ pub mod pallet {
         parameter_types! {
             pub const BlockHashCount: u64 = 250;
             pub const SmoothingFactor: u32 = 2;
+            pub static BaselineEnergy: u32 = 100;
+            pub const DecayRate: u32 = 50;
+            pub const MaxBatchSize: u32 = 3;
+            pub const MaxHistoryLen: u32 = 12;
+            pub static FluxWeight: u32 = 0;
+            pub static MaxTransitionsPerBlock: u32 = 8;
         }
 
         // Type to provide a baseline phase.
@@ -414,10 +785,18 @@ pub mod pallet {
 
         impl Config for Test {
             type RuntimeEvent = ();
-            type BaselineEnergy = parameter_types::ConstU32<100>;
+            type BaselineEnergy = BaselineEnergy;
             type BaselineQuantumFlux = parameter_types::ConstU32<50>;
             type BaselinePhase = TestBaselinePhase;
             type SmoothingFactor = SmoothingFactor;
+            type DecayRate = DecayRate;
+            type FluxWeight = FluxWeight;
+            type MaxBatchSize = MaxBatchSize;
+            type MaxHistoryLen = MaxHistoryLen;
+            type MaxTransitionsPerBlock = MaxTransitionsPerBlock;
+            type GovernanceOrigin = frame_system::EnsureRoot<u64>;
+            type OracleOrigin = frame_system::EnsureRoot<u64>;
+            type EmergencyHalt = ();
         }
 
         #[test]
@@ -440,15 +819,15 @@ pub mod pallet {
             let root_origin = system::RawOrigin::Root.into();
             assert_ok!(Biosphere::initialize_state(root_origin));
 
-            // Transition phase with a valid signal and signature.
-            let signed_origin = system::RawOrigin::Signed(1).into();
+            // Transition phase with a valid signal and signature, submitted by the oracle.
+            let oracle_origin = system::RawOrigin::Root.into();
             // For signal = 120:
             // measured_energy = 120*10 = 1200,
             // new_energy = (1200 + (2-1)*100) / 2 = 650,
             // measured_flux = (120*120)/2 = 7200,
             // new_quantum_flux = (7200 + (2-1)*50)/2 = 3625.
             // Phase: new_energy = 650 > 150, so Growth.
-            assert_ok!(Biosphere::transition_phase(signed_origin, 120, vec![1,2,3]));
+            assert_ok!(Biosphere::transition_phase(oracle_origin, 120, vec![1,2,3]));
 
             // Verify that the bio state was updated.
             let state = Biosphere::bio_state();
@@ -458,5 +837,277 @@ pub mod pallet {
             // History should now have two entries.
             assert_eq!(state.history.len(), 2);
         }
+
+        pub fn new_test_ext() -> sp_io::TestExternalities {
+            let storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+            storage.into()
+        }
+
+        #[test]
+        fn reset_restores_configured_baseline() {
+            new_test_ext().execute_with(|| {
+                System::set_block_number(1);
+                assert_ok!(Biosphere::transition_phase(system::RawOrigin::Root.into(), 120, vec![1]));
+                System::set_block_number(2);
+                assert_ok!(Biosphere::transition_phase(system::RawOrigin::Root.into(), 3, vec![1]));
+                assert_ne!(Biosphere::bio_state().energy_level, 100);
+
+                frame_support::assert_noop!(
+                    Biosphere::reset_bio_state(system::RawOrigin::Signed(1).into()),
+                    sp_runtime::DispatchError::BadOrigin
+                );
+                System::set_block_number(3);
+                assert_ok!(Biosphere::reset_bio_state(system::RawOrigin::Root.into()));
+
+                let state = Biosphere::bio_state();
+                assert_eq!(state.current_phase, BioPhase::Defense);
+                assert_eq!(state.energy_level, 100);
+                assert_eq!(state.quantum_flux, 50);
+                assert_eq!(state.last_updated, 3);
+                // Les transitions précédentes sont conservées, suivies du marqueur de réinitialisation.
+                assert_eq!(state.history.len(), 3);
+                assert_eq!(
+                    state.history.last(),
+                    Some(&BioRecord { timestamp: 3, phase: BioPhase::Defense, energy: 100, quantum_flux: 50, kind: BioRecordKind::Reset })
+                );
+            });
+        }
+
+        #[test]
+        fn compact_history_downsamples_old_entries() {
+            new_test_ext().execute_with(|| {
+                for block in 1..=10u64 {
+                    System::set_block_number(block);
+                    assert_ok!(Biosphere::transition_phase(system::RawOrigin::Root.into(), block as u32 * 10, vec![1]));
+                }
+                let history = Biosphere::bio_state().history;
+                frame_support::assert_noop!(
+                    Biosphere::compact_history(system::RawOrigin::Root.into(), 3, 0),
+                    Error::<Test>::InvalidCompactionParameters
+                );
+
+                assert_ok!(Biosphere::compact_history(system::RawOrigin::Root.into(), 3, 2));
+
+                let compacted = Biosphere::bio_state().history;
+                // 7 entrées anciennes -> 4 (blocs 1, 3, 5, 7), puis les 3 récentes.
+                assert_eq!(compacted.len(), 7);
+                let blocks: Vec<u64> = compacted.iter().map(|record| record.timestamp).collect();
+                assert_eq!(blocks, vec![1, 3, 5, 7, 8, 9, 10]);
+                assert_eq!(&compacted[4..], &history[7..]);
+            });
+        }
+
+        #[test]
+        fn phase_transition_is_logged() {
+            nodara_log::testing::init();
+            new_test_ext().execute_with(|| {
+                System::set_block_number(1);
+                BioStateStorage::<Test>::mutate(|state| {
+                    state.current_phase = BioPhase::Mutation;
+                    state.energy_level = 0;
+                });
+                assert_ok!(Biosphere::transition_phase(system::RawOrigin::Root.into(), 20, vec![1]));
+                assert_eq!(Biosphere::bio_state().current_phase, BioPhase::Growth);
+
+                let messages = nodara_log::testing::messages(LOG_TARGET);
+                assert!(
+                    messages.iter().any(|m| m.starts_with("transition de phase Mutation -> Growth au bloc 1")),
+                    "{:?}",
+                    messages
+                );
+            });
+        }
+
+        #[test]
+        fn energy_and_flux_decay_toward_baseline_between_updates() {
+            new_test_ext().execute_with(|| {
+                BaselineEnergy::set(50);
+                // État non initialisé : la décroissance est sans effet.
+                Biosphere::on_finalize(0);
+                assert!(Biosphere::bio_state().history.is_empty());
+
+                System::set_block_number(1);
+                assert_ok!(Biosphere::transition_phase(system::RawOrigin::Root.into(), 20, vec![1]));
+                let state = Biosphere::bio_state();
+                assert_eq!((state.current_phase, state.energy_level, state.quantum_flux), (BioPhase::Growth, 200, 200));
+
+                // Écart de 150 résorbé de moitié : 200 -> 125, passage en Defense.
+                Biosphere::on_finalize(1);
+                let state = Biosphere::bio_state();
+                assert_eq!((state.current_phase, state.energy_level, state.quantum_flux), (BioPhase::Defense, 125, 125));
+
+                // 125 -> 88 : toujours Defense, aucun enregistrement.
+                System::set_block_number(2);
+                Biosphere::on_finalize(2);
+                let state = Biosphere::bio_state();
+                assert_eq!((state.current_phase, state.energy_level), (BioPhase::Defense, 88));
+                assert_eq!(state.history.len(), 2);
+
+                // 88 -> 69 : passage en Mutation.
+                System::set_block_number(3);
+                Biosphere::on_finalize(3);
+                let state = Biosphere::bio_state();
+                assert_eq!((state.current_phase, state.energy_level, state.quantum_flux), (BioPhase::Mutation, 69, 69));
+                assert_eq!(state.last_updated, 3);
+                let kinds: Vec<(BioPhase, BioRecordKind)> =
+                    state.history.iter().map(|record| (record.phase.clone(), record.kind.clone())).collect();
+                assert_eq!(
+                    kinds,
+                    vec![
+                        (BioPhase::Growth, BioRecordKind::Transition),
+                        (BioPhase::Defense, BioRecordKind::Decay),
+                        (BioPhase::Mutation, BioRecordKind::Decay),
+                    ]
+                );
+
+                // L'état finit par atteindre la référence puis ne bouge plus.
+                for block in 4..20u64 {
+                    Biosphere::on_finalize(block);
+                }
+                let state = Biosphere::bio_state();
+                assert_eq!((state.energy_level, state.quantum_flux), (50, 50));
+            });
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn bio_state_round_trips_through_json() {
+            let state: BioStateOf<Test> = BioState {
+                current_phase: BioPhase::Defense,
+                energy_level: 80,
+                quantum_flux: 12,
+                last_updated: 1_640_000_000,
+                history: BoundedVec::truncate_from(vec![BioRecord {
+                    timestamp: 1_640_000_000,
+                    phase: BioPhase::Defense,
+                    energy: 80,
+                    quantum_flux: 12,
+                    kind: BioRecordKind::Reset,
+                }]),
+            };
+            let json = serde_json::to_string(&state).expect("sérialisation JSON");
+            let decoded: BioStateOf<Test> = serde_json::from_str(&json).expect("désérialisation JSON");
+            assert_eq!(decoded, state);
+        }
+
+        #[test]
+        fn batch_applies_each_signal_in_order() {
+            new_test_ext().execute_with(|| {
+                System::set_block_number(1);
+                let signals = vec![(120, vec![1]), (3, vec![1]), (3, vec![1])];
+                assert_ok!(Biosphere::transition_phase_batch(system::RawOrigin::Root.into(), signals.clone()));
+                let batched = Biosphere::bio_state();
+                assert_eq!(batched.history.len(), 3);
+
+                // Même résultat que trois appels successifs à `transition_phase`.
+                crate::pallet::BioStateStorage::<Test>::kill();
+                for (signal, signature) in signals {
+                    assert_ok!(Biosphere::transition_phase(system::RawOrigin::Root.into(), signal, signature));
+                }
+                assert_eq!(Biosphere::bio_state(), batched);
+
+                frame_support::assert_noop!(
+                    Biosphere::transition_phase_batch(system::RawOrigin::Root.into(), vec![(1, vec![1]); 4]),
+                    Error::<Test>::BatchTooLarge
+                );
+            });
+        }
+
+        #[test]
+        fn batch_is_rolled_back_when_a_signal_is_invalid() {
+            new_test_ext().execute_with(|| {
+                System::set_block_number(1);
+                assert_ok!(Biosphere::transition_phase(system::RawOrigin::Root.into(), 120, vec![1]));
+                frame_support::assert_noop!(
+                    Biosphere::transition_phase_batch(system::RawOrigin::Root.into(), vec![(3, vec![1]), (0, vec![1])]),
+                    Error::<Test>::InvalidSignal
+                );
+                assert_eq!(Biosphere::bio_state().history.len(), 1);
+            });
+        }
+
+        #[test]
+        fn transitions_are_capped_per_block() {
+            new_test_ext().execute_with(|| {
+                MaxTransitionsPerBlock::set(4);
+                let transition = || Biosphere::transition_phase(system::RawOrigin::Root.into(), 120, vec![1]);
+                System::set_block_number(1);
+                Biosphere::on_initialize(1);
+                // Seul l'oracle consomme le plafond : un compte quelconque est rejeté.
+                frame_support::assert_noop!(
+                    Biosphere::transition_phase(system::RawOrigin::Signed(1).into(), 120, vec![1]),
+                    sp_runtime::DispatchError::BadOrigin
+                );
+                frame_support::assert_noop!(
+                    Biosphere::transition_phase_batch(system::RawOrigin::Signed(1).into(), vec![(3, vec![1]); 4]),
+                    sp_runtime::DispatchError::BadOrigin
+                );
+                assert_eq!(Biosphere::transitions_this_block(), 0);
+                assert_ok!(transition());
+                assert_ok!(Biosphere::transition_phase_batch(system::RawOrigin::Root.into(), vec![(3, vec![1]); 2]));
+                // Un lot qui dépasserait le plafond est annulé en entier.
+                frame_support::assert_noop!(
+                    Biosphere::transition_phase_batch(system::RawOrigin::Root.into(), vec![(3, vec![1]); 2]),
+                    Error::<Test>::TooManyTransitions
+                );
+                assert_ok!(transition());
+                frame_support::assert_noop!(transition(), Error::<Test>::TooManyTransitions);
+                assert_eq!(Biosphere::transitions_this_block(), 4);
+                assert_eq!(Biosphere::bio_state().history.len(), 4);
+
+                System::set_block_number(2);
+                Biosphere::on_initialize(2);
+                assert_eq!(Biosphere::transitions_this_block(), 0);
+                assert_ok!(transition());
+                assert_eq!(Biosphere::bio_state().history.len(), 5);
+                MaxTransitionsPerBlock::set(8);
+            });
+        }
+
+        #[test]
+        fn flux_weight_lets_flux_influence_the_phase() {
+            // Poids nul : seule l'énergie compte.
+            assert_eq!(Biosphere::phase_for(200, 0), BioPhase::Growth);
+            assert_eq!(Biosphere::phase_for(200, 200), BioPhase::Growth);
+
+            // Poids de 50 : (200 + 0) / 2 = 100 contre (200 + 200) / 2 = 200.
+            FluxWeight::set(50);
+            assert_eq!(Biosphere::phase_for(200, 0), BioPhase::Defense);
+            assert_eq!(Biosphere::phase_for(200, 200), BioPhase::Growth);
+            FluxWeight::set(0);
+        }
+
+        #[test]
+        fn history_is_bounded_and_evicts_the_oldest_entries() {
+            new_test_ext().execute_with(|| {
+                for block in 1..=15u64 {
+                    System::set_block_number(block);
+                    assert_ok!(Biosphere::transition_phase(system::RawOrigin::Root.into(), 10, vec![1]));
+                }
+                let history = Biosphere::bio_state().history;
+                assert_eq!(history.len() as u32, MaxHistoryLen::get());
+                let blocks: Vec<u64> = history.iter().map(|record| record.timestamp).collect();
+                assert_eq!(blocks, (4..=15).collect::<Vec<u64>>());
+            });
+        }
+
+        #[test]
+        fn history_migration_converts_the_most_recent_legacy_entries() {
+            new_test_ext().execute_with(|| {
+                // Encodage de la version 0 : historique non borné de tuples.
+                let history: Vec<(u64, BioPhase, u32, u32)> = (1..=15u64).map(|t| (t, BioPhase::Defense, 80, 12)).collect();
+                let legacy: LegacyBioState = (BioPhase::Defense, 80, 12, 15, history);
+                frame_support::storage::unhashed::put(&BioStateStorage::<Test>::hashed_key(), &legacy);
+                assert!(BioStateStorage::<Test>::try_get().is_err());
+
+                Biosphere::on_runtime_upgrade();
+                let state = Biosphere::bio_state();
+                assert_eq!((state.current_phase.clone(), state.energy_level, state.quantum_flux, state.last_updated), (BioPhase::Defense, 80, 12, 15));
+                let timestamps: Vec<u64> = state.history.iter().map(|record| record.timestamp).collect();
+                assert_eq!(timestamps, (4..=15).collect::<Vec<u64>>());
+                assert!(state.history.iter().all(|record| record.kind == BioRecordKind::Transition && record.energy == 80));
+                assert_eq!(StorageVersion::get::<Biosphere>(), STORAGE_VERSION);
+            });
+        }
     }
 }
@@ -40,6 +40,14 @@ mod tests {
         type BaselineQuantumFlux = sp_runtime::traits::ConstU32<10>;
         type BaselinePhase = sp_runtime::traits::ConstValue<BioPhase, BioPhase::Mutation>;
         type SmoothingFactor = sp_runtime::traits::ConstU32<10>;
+        type DecayRate = sp_runtime::traits::ConstU32<0>;
+        type FluxWeight = sp_runtime::traits::ConstU32<0>;
+        type MaxBatchSize = sp_runtime::traits::ConstU32<10>;
+        type MaxHistoryLen = sp_runtime::traits::ConstU32<1_000>;
+        type MaxTransitionsPerBlock = sp_runtime::traits::ConstU32<10>;
+        type GovernanceOrigin = frame_system::EnsureRoot<u64>;
+        type OracleOrigin = frame_system::EnsureRoot<u64>;
+        type EmergencyHalt = ();
     }
     
     // Dummy implementation for ConstValue trait for BioPhase
@@ -0,0 +1,191 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Nodara Emergency
+//!
+//! Arrêt d'urgence global des modules Nodara, en complément de la pause propre à chaque module.
+//!
+//! `halt_all`, réservé à `EmergencyOrigin`, lève le drapeau `EmergencyHalt` ; `resume_all` le
+//! retire. Chaque module déclare dans sa configuration un `type EmergencyHalt: nodara_emergency::HaltGuard`
+//! et le consulte au début de ses appels signés :
+//!
+//! - `nodara_emergency::Pallet<Runtime>` refuse ces appels tant que l'arrêt est actif ;
+//! - `()` ne bloque jamais, pour les tests et les runtimes sans arrêt d'urgence.
+//!
+//! Les appels root ou de gouvernance restent disponibles pendant l'arrêt, afin de pouvoir traiter
+//! l'incident.
+
+pub use pallet::*;
+
+use frame_support::dispatch::DispatchResult;
+
+/// Garde consultée par les modules avant chaque appel signé.
+pub trait HaltGuard {
+    /// Échoue tant que l'arrêt d'urgence est actif.
+    fn ensure_running() -> DispatchResult;
+}
+
+/// Aucun arrêt d'urgence : les appels ne sont jamais bloqués.
+impl HaltGuard for () {
+    fn ensure_running() -> DispatchResult {
+        Ok(())
+    }
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use frame_support::{dispatch::DispatchResult, pallet_prelude::*};
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Type d'événement du runtime.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// Origine autorisée à déclencher et lever l'arrêt d'urgence.
+        type EmergencyOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+    }
+
+    /// Indique si l'arrêt d'urgence global est actif.
+    #[pallet::storage]
+    #[pallet::getter(fn is_halted)]
+    pub type EmergencyHalt<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Arrêt d'urgence déclenché : les appels signés des modules Nodara sont bloqués.
+        AllHalted,
+        /// Arrêt d'urgence levé : les modules Nodara acceptent de nouveau les appels.
+        AllResumed,
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// L'arrêt d'urgence global est actif.
+        Halted,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Déclenche l'arrêt d'urgence de tous les modules Nodara.
+        #[pallet::weight(10_000)]
+        pub fn halt_all(origin: OriginFor<T>) -> DispatchResult {
+            T::EmergencyOrigin::ensure_origin(origin)?;
+            EmergencyHalt::<T>::put(true);
+            Self::deposit_event(Event::AllHalted);
+            Ok(())
+        }
+
+        /// Lève l'arrêt d'urgence déclenché par `halt_all`.
+        #[pallet::weight(10_000)]
+        pub fn resume_all(origin: OriginFor<T>) -> DispatchResult {
+            T::EmergencyOrigin::ensure_origin(origin)?;
+            EmergencyHalt::<T>::put(false);
+            Self::deposit_event(Event::AllResumed);
+            Ok(())
+        }
+    }
+
+    impl<T: Config> crate::HaltGuard for Pallet<T> {
+        fn ensure_running() -> DispatchResult {
+            ensure!(!EmergencyHalt::<T>::get(), Error::<T>::Halted);
+            Ok(())
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::HaltGuard;
+        use frame_support::{assert_noop, assert_ok, parameter_types};
+        use sp_core::H256;
+        use sp_runtime::{
+            traits::{BlakeTwo256, IdentityLookup},
+            testing::Header,
+            DispatchError,
+        };
+        use frame_system as system;
+
+        type UncheckedExtrinsic = system::mocking::MockUncheckedExtrinsic<Test>;
+        type Block = system::mocking::MockBlock<Test>;
+
+        frame_support::construct_runtime!(
+            pub enum Test where
+                Block = Block,
+                NodeBlock = Block,
+                UncheckedExtrinsic = UncheckedExtrinsic,
+            {
+                System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+                EmergencyModule: Pallet,
+            }
+        );
+
+        parameter_types! {
+            pub const BlockHashCount: u64 = 250;
+        }
+
+        impl system::Config for Test {
+            type BaseCallFilter = frame_support::traits::Everything;
+            type BlockWeights = ();
+            type BlockLength = ();
+            type DbWeight = ();
+            type RuntimeOrigin = system::mocking::Origin;
+            type RuntimeCall = Call;
+            type Index = u64;
+            type BlockNumber = u64;
+            type Hash = H256;
+            type Hashing = BlakeTwo256;
+            type AccountId = u64;
+            type Lookup = IdentityLookup<Self::AccountId>;
+            type Header = Header;
+            type RuntimeEvent = ();
+            type BlockHashCount = BlockHashCount;
+            type Version = ();
+            type PalletInfo = ();
+            type AccountData = ();
+            type OnNewAccount = ();
+            type OnKilledAccount = ();
+            type SystemWeightInfo = ();
+            type SS58Prefix = ();
+            type OnSetCode = ();
+            type MaxConsumers = ();
+        }
+
+        impl Config for Test {
+            type RuntimeEvent = ();
+            type EmergencyOrigin = frame_system::EnsureRoot<u64>;
+        }
+
+        pub fn new_test_ext() -> sp_io::TestExternalities {
+            let storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+            storage.into()
+        }
+
+        #[test]
+        fn halt_and_resume_toggle_the_guard() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(<EmergencyModule as HaltGuard>::ensure_running());
+
+                assert_ok!(EmergencyModule::halt_all(system::RawOrigin::Root.into()));
+                assert!(EmergencyModule::is_halted());
+                assert_noop!(<EmergencyModule as HaltGuard>::ensure_running(), Error::<Test>::Halted);
+
+                assert_ok!(EmergencyModule::resume_all(system::RawOrigin::Root.into()));
+                assert!(!EmergencyModule::is_halted());
+                assert_ok!(<EmergencyModule as HaltGuard>::ensure_running());
+            });
+        }
+
+        #[test]
+        fn only_emergency_origin_can_halt_or_resume() {
+            new_test_ext().execute_with(|| {
+                assert_noop!(EmergencyModule::halt_all(system::RawOrigin::Signed(1).into()), DispatchError::BadOrigin);
+                assert_ok!(EmergencyModule::halt_all(system::RawOrigin::Root.into()));
+                assert_noop!(EmergencyModule::resume_all(system::RawOrigin::Signed(1).into()), DispatchError::BadOrigin);
+                assert!(EmergencyModule::is_halted());
+            });
+        }
+    }
+}
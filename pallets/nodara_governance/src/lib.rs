@@ -0,0 +1,319 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Nodara Governance Module
+//!
+//! Ce module fournit une origine multi-signature M-sur-N réutilisable par n'importe quel module Nodara
+//! comme `GovernanceOrigin`. Un comité configuré approuve le hash d'un appel ; une fois le seuil atteint,
+//! l'appel peut être exécuté avec l'origine `Approved`, acceptée par [`EnsureThreshold`].
+//!
+//! **Fonctionnalités principales :**
+//! - Comité et seuil configurables (`Committee`, `Threshold`).
+//! - Suivi des approbations par hash d'appel.
+//! - Flux `approve` / `execute`, les approbations étant consommées à l'exécution.
+
+use frame_support::{dispatch::DispatchResult, pallet_prelude::*, traits::EnsureOrigin};
+use sp_std::marker::PhantomData;
+
+pub use pallet::*;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+    use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::{Dispatchable, Hash};
+    use sp_std::{boxed::Box, vec::Vec};
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    /// Configuration du module.
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// Type d'événement du runtime.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+        /// Origine du runtime, convertible depuis l'origine `Approved` de ce module.
+        type RuntimeOrigin: From<RawOrigin>;
+        /// Appel du runtime pouvant être soumis au comité.
+        type RuntimeCall: Parameter
+            + Dispatchable<RuntimeOrigin = <Self as Config>::RuntimeOrigin, PostInfo = PostDispatchInfo>
+            + GetDispatchInfo
+            + From<frame_system::Call<Self>>;
+        /// Membres du comité habilités à approuver.
+        type Committee: Get<Vec<Self::AccountId>>;
+        /// Nombre d'approbations distinctes requises (M). Une valeur nulle est traitée comme 1.
+        #[pallet::constant]
+        type Threshold: Get<u32>;
+    }
+
+    /// Origine émise par ce module lorsqu'un appel a réuni suffisamment d'approbations.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum RawOrigin {
+        /// Appel approuvé par le nombre indiqué de membres du comité.
+        Approved(u32),
+    }
+
+    #[pallet::origin]
+    pub type Origin = RawOrigin;
+
+    /// Approbations en cours, indexées par hash d'appel.
+    #[pallet::storage]
+    #[pallet::getter(fn approvals)]
+    pub type Approvals<T: Config> = StorageMap<_, Identity, T::Hash, Vec<T::AccountId>, ValueQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// Un membre a approuvé un appel : (membre, hash de l'appel, nombre d'approbations)
+        CallApproved(T::AccountId, T::Hash, u32),
+        /// Un appel approuvé a été exécuté : (hash de l'appel, résultat)
+        CallExecuted(T::Hash, DispatchResult),
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// L'appelant ne fait pas partie du comité.
+        NotCommitteeMember,
+        /// Ce membre a déjà approuvé cet appel.
+        AlreadyApproved,
+        /// Le seuil d'approbations n'est pas atteint.
+        InsufficientApprovals,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Approuve l'appel identifié par `call_hash`. Réservé aux membres du comité.
+        #[pallet::weight(10_000)]
+        pub fn approve(origin: OriginFor<T>, call_hash: T::Hash) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(T::Committee::get().contains(&who), Error::<T>::NotCommitteeMember);
+            let count = Approvals::<T>::try_mutate(call_hash, |approvers| -> Result<u32, DispatchError> {
+                ensure!(!approvers.contains(&who), Error::<T>::AlreadyApproved);
+                approvers.push(who.clone());
+                Ok(approvers.len() as u32)
+            })?;
+            Self::deposit_event(Event::CallApproved(who, call_hash, count));
+            Ok(())
+        }
+
+        /// Exécute `call` avec l'origine `Approved` si le seuil d'approbations est atteint.
+        ///
+        /// Seules les approbations de membres encore présents dans le comité sont comptées. Les
+        /// approbations sont consommées, y compris si l'appel échoue.
+        #[pallet::weight(call.get_dispatch_info().weight.saturating_add(Weight::from_parts(10_000, 0)))]
+        pub fn execute(origin: OriginFor<T>, call: Box<<T as Config>::RuntimeCall>) -> DispatchResult {
+            ensure_signed(origin)?;
+            let call_hash = T::Hashing::hash_of(&call);
+            let approvals = Self::valid_approvals(&call_hash);
+            ensure!(approvals >= Self::threshold(), Error::<T>::InsufficientApprovals);
+            Approvals::<T>::remove(call_hash);
+            let result = call
+                .dispatch(RawOrigin::Approved(approvals).into())
+                .map(|_| ())
+                .map_err(|e| e.error);
+            Self::deposit_event(Event::CallExecuted(call_hash, result));
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Seuil effectif, jamais inférieur à 1.
+        pub fn threshold() -> u32 {
+            T::Threshold::get().max(1)
+        }
+
+        /// Nombre d'approbations émises par des membres actuels du comité.
+        pub fn valid_approvals(call_hash: &T::Hash) -> u32 {
+            let committee = T::Committee::get();
+            Approvals::<T>::get(call_hash)
+                .iter()
+                .filter(|who| committee.contains(who))
+                .count() as u32
+        }
+    }
+}
+
+/// Origine satisfaite uniquement par un appel ayant réuni au moins `T::Threshold` approbations
+/// du comité. Utilisable comme `GovernanceOrigin` de n'importe quel module.
+pub struct EnsureThreshold<T>(PhantomData<T>);
+
+impl<T: Config, O> EnsureOrigin<O> for EnsureThreshold<T>
+where
+    O: Into<Result<RawOrigin, O>> + From<RawOrigin>,
+{
+    type Success = u32;
+
+    fn try_origin(o: O) -> Result<Self::Success, O> {
+        o.into().and_then(|raw| match raw {
+            RawOrigin::Approved(approvals) if approvals >= Pallet::<T>::threshold() => Ok(approvals),
+            other => Err(O::from(other)),
+        })
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn try_successful_origin() -> Result<O, ()> {
+        Ok(O::from(RawOrigin::Approved(Pallet::<T>::threshold())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frame_support::{assert_noop, assert_ok, parameter_types};
+    use frame_system as system;
+    use sp_core::H256;
+    use sp_runtime::{
+        testing::Header,
+        traits::{BlakeTwo256, Hash, IdentityLookup},
+        DispatchError,
+    };
+
+    type UncheckedExtrinsic = system::mocking::MockUncheckedExtrinsic<Test>;
+    type Block = system::mocking::MockBlock<Test>;
+
+    frame_support::construct_runtime!(
+        pub enum Test where
+            Block = Block,
+            NodeBlock = Block,
+            UncheckedExtrinsic = UncheckedExtrinsic,
+        {
+            System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+            Governance: pallet::{Pallet, Call, Storage, Event<T>, Origin},
+            Interop: nodara_interop::{Pallet, Call, Storage, Event<T>},
+        }
+    );
+
+    parameter_types! {
+        pub const BlockHashCount: u64 = 250;
+        pub CommitteeMembers: Vec<u64> = vec![1, 2, 3];
+        pub const Threshold: u32 = 2;
+        pub const BaseTimeout: u64 = 300;
+        pub const MaxPayloadLength: u32 = 1024;
+        pub const MaxMessagesPerBlock: u32 = 10;
+    }
+
+    impl system::Config for Test {
+        type BaseCallFilter = frame_support::traits::Everything;
+        type BlockWeights = ();
+        type BlockLength = ();
+        type DbWeight = ();
+        type RuntimeOrigin = RuntimeOrigin;
+        type RuntimeCall = RuntimeCall;
+        type Index = u64;
+        type BlockNumber = u64;
+        type Hash = H256;
+        type Hashing = BlakeTwo256;
+        type AccountId = u64;
+        type Lookup = IdentityLookup<Self::AccountId>;
+        type Header = Header;
+        type RuntimeEvent = RuntimeEvent;
+        type BlockHashCount = BlockHashCount;
+        type Version = ();
+        type PalletInfo = PalletInfo;
+        type AccountData = ();
+        type OnNewAccount = ();
+        type OnKilledAccount = ();
+        type SystemWeightInfo = ();
+        type SS58Prefix = ();
+        type OnSetCode = ();
+        type MaxConsumers = frame_support::traits::ConstU32<16>;
+    }
+
+    impl pallet::Config for Test {
+        type RuntimeEvent = RuntimeEvent;
+        type RuntimeOrigin = RuntimeOrigin;
+        type RuntimeCall = RuntimeCall;
+        type Committee = CommitteeMembers;
+        type Threshold = Threshold;
+    }
+
+    impl nodara_interop::Config for Test {
+        type RuntimeEvent = RuntimeEvent;
+        type BaseTimeout = BaseTimeout;
+        type MaxPayloadLength = MaxPayloadLength;
+        type MaxMessagesPerBlock = MaxMessagesPerBlock;
+        type GovernanceOrigin = EnsureThreshold<Test>;
+        type TimeSource = ();
+        type MaxHistoryLen = frame_support::traits::ConstU32<64>;
+        type EmergencyHalt = ();
+        type MaxSignatureLength = frame_support::traits::ConstU32<64>;
+    }
+
+    pub fn new_test_ext() -> sp_io::TestExternalities {
+        let storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+        let mut ext: sp_io::TestExternalities = storage.into();
+        ext.execute_with(|| System::set_block_number(1));
+        ext
+    }
+
+    fn update_params_call(timeout: u64, max_payload: u32) -> Box<RuntimeCall> {
+        Box::new(RuntimeCall::Interop(nodara_interop::Call::update_config_params {
+            new_timeout: timeout,
+            new_max_payload: max_payload,
+        }))
+    }
+
+    #[test]
+    fn update_config_params_requires_m_of_n_approvals() {
+        new_test_ext().execute_with(|| {
+            let call = update_params_call(600, 2048);
+            let call_hash = BlakeTwo256::hash_of(&call);
+
+            // Ni Root ni un compte signé ne satisfont l'origine à seuil.
+            assert_noop!(
+                Interop::update_config_params(RuntimeOrigin::root(), 600, 2048),
+                DispatchError::BadOrigin
+            );
+
+            assert_ok!(Governance::approve(RuntimeOrigin::signed(1), call_hash));
+            assert_noop!(
+                Governance::execute(RuntimeOrigin::signed(9), call.clone()),
+                Error::<Test>::InsufficientApprovals
+            );
+
+            assert_ok!(Governance::approve(RuntimeOrigin::signed(3), call_hash));
+            assert_ok!(Governance::execute(RuntimeOrigin::signed(9), call.clone()));
+            System::assert_last_event(RuntimeEvent::Governance(Event::CallExecuted(call_hash, Ok(()))));
+            let config = Interop::interop_config();
+            assert_eq!(config.base_timeout, 600);
+            assert_eq!(config.max_payload_length, 2048);
+
+            // Les approbations sont consommées : une seconde exécution exige un nouveau vote.
+            assert!(Governance::approvals(call_hash).is_empty());
+            assert_noop!(
+                Governance::execute(RuntimeOrigin::signed(9), call),
+                Error::<Test>::InsufficientApprovals
+            );
+        });
+    }
+
+    #[test]
+    fn only_committee_members_approve_once() {
+        new_test_ext().execute_with(|| {
+            let call_hash = BlakeTwo256::hash_of(&update_params_call(1, 1));
+            assert_noop!(
+                Governance::approve(RuntimeOrigin::signed(4), call_hash),
+                Error::<Test>::NotCommitteeMember
+            );
+            assert_ok!(Governance::approve(RuntimeOrigin::signed(2), call_hash));
+            assert_noop!(
+                Governance::approve(RuntimeOrigin::signed(2), call_hash),
+                Error::<Test>::AlreadyApproved
+            );
+            assert_eq!(Governance::valid_approvals(&call_hash), 1);
+        });
+    }
+
+    #[test]
+    fn ensure_threshold_checks_approval_count() {
+        new_test_ext().execute_with(|| {
+            assert!(EnsureThreshold::<Test>::try_origin(RuntimeOrigin::from(RawOrigin::Approved(1))).is_err());
+            assert_eq!(
+                EnsureThreshold::<Test>::try_origin(RuntimeOrigin::from(RawOrigin::Approved(2))).ok(),
+                Some(2)
+            );
+            assert!(EnsureThreshold::<Test>::try_origin(RuntimeOrigin::root()).is_err());
+            assert!(EnsureThreshold::<Test>::try_origin(RuntimeOrigin::signed(1)).is_err());
+        });
+    }
+}
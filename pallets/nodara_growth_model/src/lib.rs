@@ -11,21 +11,42 @@
 //!     new_multiplier = old_multiplier + (signal / smoothing_factor)
 //!
 //! Des vérifications garantissent que le signal et le facteur de lissage sont valides.
+//!
+//! En plus de `update_multiplier`, le signal peut être dérivé automatiquement à chaque bloc
+//! (`on_finalize`) des métriques réseau fournies par `Config::NetworkMetrics`.
 
 use frame_support::{
     dispatch::DispatchResult, pallet_prelude::*, traits::Get,
     transactional,
 };
 use frame_system::pallet_prelude::*;
+use nodara_emergency::HaltGuard;
 use sp_std::collections::btree_set::BTreeSet;
 use sp_std::vec::Vec;
 use sp_std::prelude::*; // Pour inclure ToString et autres traits utiles
 use sp_runtime::RuntimeDebug;
 use parity_scale_codec::{Encode, Decode};
 use scale_info::TypeInfo;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Source des métriques réseau (ex. nombre de transactions récentes) alimentant le signal de
+/// croissance automatique.
+pub trait NetworkMetricsSource {
+    /// Signal de croissance dérivé de l'activité récente ; 0 signifie aucun ajustement.
+    fn growth_signal() -> u32;
+}
+
+/// Source par défaut : aucun signal, la croissance n'évolue que via `update_multiplier`.
+impl NetworkMetricsSource for () {
+    fn growth_signal() -> u32 {
+        0
+    }
+}
 
 /// Structure regroupant les données de croissance.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GrowthData {
     pub multiplier: u32,
     pub signal: u32,
@@ -34,6 +55,7 @@ pub struct GrowthData {
 
 /// État global du module de croissance.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, Default, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct GrowthState {
     pub current_multiplier: u32,
     pub history: Vec<GrowthData>,
@@ -100,6 +122,10 @@ pub mod pallet {
         type RequiredConfirmations: Get<u32>;
         /// Gestionnaire des tokens représentatifs pour le bridge.
         type AssetManager: super::BridgeAssetManager<Self::AccountId>;
+        /// Source du signal de croissance appliqué automatiquement en fin de bloc.
+        type NetworkMetrics: crate::NetworkMetricsSource;
+        /// Arrêt d'urgence global consulté par chaque appel signé ; `()` le désactive.
+        type EmergencyHalt: nodara_emergency::HaltGuard;
     }
 
     #[pallet::pallet]
@@ -208,6 +234,18 @@ pub mod pallet {
         ZeroSmoothingFactor,
     }
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Applique le signal dérivé des métriques réseau, s'il est non nul.
+        fn on_finalize(_n: BlockNumberFor<T>) {
+            let signal = T::NetworkMetrics::growth_signal();
+            if signal > 0 {
+                // Seul un facteur de lissage nul peut échouer ; le bloc ne doit pas en dépendre.
+                let _ = Self::apply_signal(signal);
+            }
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Initialise l'état de croissance avec la valeur de base.
@@ -235,8 +273,15 @@ pub mod pallet {
         #[pallet::weight(10_000)]
         pub fn update_multiplier(origin: OriginFor<T>, signal: u32) -> DispatchResult {
             ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
             ensure!(signal > 0, Error::<T>::InvalidSignal);
+            Self::apply_signal(signal)
+        }
+    }
 
+    impl<T: Config> Pallet<T> {
+        /// Ajuste le multiplicateur selon `signal` et enregistre l'ajustement dans l'historique.
+        fn apply_signal(signal: u32) -> DispatchResult {
             let smoothing = T::SmoothingFactor::get();
             ensure!(smoothing != 0, Error::<T>::ZeroSmoothingFactor);
 
@@ -262,7 +307,7 @@ pub mod pallet {
     #[cfg(test)]
     mod tests {
         use super::*;
-        use frame_support::{assert_ok, parameter_types};
+        use frame_support::{assert_ok, parameter_types, traits::Hooks};
         use sp_core::H256;
         use sp_runtime::{
             traits::{BlakeTwo256, IdentityLookup},
@@ -317,11 +362,25 @@ pub mod pallet {
             type MaxConsumers = ();
         }
 
+        thread_local! {
+            static METRICS_SIGNAL: core::cell::Cell<u32> = core::cell::Cell::new(0);
+        }
+
+        /// Source de métriques renvoyant le signal fixé par le test.
+        pub struct MockMetrics;
+        impl crate::NetworkMetricsSource for MockMetrics {
+            fn growth_signal() -> u32 {
+                METRICS_SIGNAL.with(|signal| signal.get())
+            }
+        }
+
         impl Config for Test {
             type RuntimeEvent = ();
             type BaselineMultiplier = BaselineMultiplier;
             type SmoothingFactor = SmoothingFactor;
+            type NetworkMetrics = MockMetrics;
             // Pour la genèse, nous utilisons la valeur de base par défaut définie dans la configuration.
+            type EmergencyHalt = ();
         }
 
         #[test]
@@ -344,5 +403,37 @@ pub mod pallet {
             assert_eq!(state.current_multiplier, BaselineMultiplier::get() + 10);
             assert_eq!(state.history.len(), 2);
         }
+
+        #[test]
+        fn on_finalize_applies_network_metrics_signal() {
+            assert_ok!(Biosphere::initialize_state(system::RawOrigin::Root.into()));
+
+            // Aucune activité : le multiplicateur reste inchangé.
+            Biosphere::on_finalize(1);
+            assert_eq!(Biosphere::growth_state().current_multiplier, BaselineMultiplier::get());
+
+            // Signal de 50 avec un lissage de 5 : +10.
+            METRICS_SIGNAL.with(|signal| signal.set(50));
+            Biosphere::on_finalize(2);
+            let state = Biosphere::growth_state();
+            assert_eq!(state.current_multiplier, BaselineMultiplier::get() + 10);
+            assert_eq!(state.history.last().map(|entry| entry.signal), Some(50));
+
+            // La mise à jour manuelle reste disponible.
+            assert_ok!(Biosphere::update_multiplier(system::RawOrigin::Signed(1).into(), 25));
+            assert_eq!(Biosphere::growth_state().current_multiplier, BaselineMultiplier::get() + 15);
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn growth_state_round_trips_through_json() {
+            let state = GrowthState {
+                current_multiplier: 110,
+                history: vec![GrowthData { multiplier: 110, signal: 10, timestamp: 1_640_000_000 }],
+            };
+            let json = serde_json::to_string(&state).expect("sérialisation JSON");
+            let decoded: GrowthState = serde_json::from_str(&json).expect("désérialisation JSON");
+            assert_eq!(decoded, state);
+        }
     }
 }
@@ -38,6 +38,8 @@ mod tests {
         type RuntimeEvent = ();
         type BaselineMultiplier = sp_runtime::traits::ConstU32<100>;
         type SmoothingFactor = sp_runtime::traits::ConstU32<10>;
+        type NetworkMetrics = ();
+        type EmergencyHalt = ();
     }
 
     // Dummy function for creating a new test externalities environment.
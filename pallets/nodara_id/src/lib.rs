@@ -13,6 +13,22 @@
 
 pub use pallet::*;
 
+/// Validation applicative des détails KYC, fournie par le runtime.
+///
+/// Permet d'imposer un schéma (ex. un objet JSON contenant certains champs) au-delà du simple
+/// contrôle de longueur effectué par le pallet.
+pub trait KycValidator {
+    /// Retourne `true` si les détails KYC sont acceptés.
+    fn validate(kyc_details: &[u8]) -> bool;
+}
+
+/// Validateur par défaut : accepte tous les détails KYC.
+impl KycValidator for () {
+    fn validate(_kyc_details: &[u8]) -> bool {
+        true
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
@@ -20,6 +36,7 @@ pub mod pallet {
         traits::Get,
     };
     use frame_system::pallet_prelude::*;
+    use nodara_emergency::HaltGuard;
     use parity_scale_codec::{Encode, Decode};
     use scale_info::TypeInfo;
     use sp_std::vec::Vec;
@@ -43,6 +60,12 @@ pub mod pallet {
         /// Longueur maximale autorisée pour les détails KYC.
         #[pallet::constant]
         type MaxKycLength: Get<u32>;
+        /// Validateur de schéma des détails KYC (`()` pour aucune validation).
+        type KycValidator: crate::KycValidator;
+        /// Source des horodatages enregistrés dans l'historique (voir `nodara_time`).
+        type TimeSource: nodara_time::TimeSource;
+        /// Arrêt d'urgence global consulté par chaque appel signé ; `()` le désactive.
+        type EmergencyHalt: nodara_emergency::HaltGuard;
     }
 
     /// Erreurs spécifiques au module d'identité.
@@ -50,7 +73,7 @@ pub mod pallet {
     pub enum Error<T> {
         /// Les détails KYC dépassent la longueur maximale autorisée.
         KycTooLong,
-        /// Les détails KYC ne peuvent pas être vides.
+        /// Les détails KYC sont vides ou rejetés par le validateur du runtime.
         InvalidKycDetails,
         /// Une identité est déjà enregistrée pour ce compte.
         IdentityAlreadyExists,
@@ -87,11 +110,13 @@ pub mod pallet {
             kyc_details: Vec<u8>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
             ensure!(!kyc_details.is_empty(), Error::<T>::InvalidKycDetails);
             ensure!(
                 kyc_details.len() as u32 <= T::MaxKycLength::get(),
                 Error::<T>::KycTooLong
             );
+            ensure!(T::KycValidator::validate(&kyc_details), Error::<T>::InvalidKycDetails);
             ensure!(
                 !Identities::<T>::contains_key(&who),
                 Error::<T>::IdentityAlreadyExists
@@ -121,11 +146,13 @@ pub mod pallet {
             new_verified: bool,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
             ensure!(!new_kyc_details.is_empty(), Error::<T>::InvalidKycDetails);
             ensure!(
                 new_kyc_details.len() as u32 <= T::MaxKycLength::get(),
                 Error::<T>::KycTooLong
             );
+            ensure!(T::KycValidator::validate(&new_kyc_details), Error::<T>::InvalidKycDetails);
             Identities::<T>::try_mutate(&who, |maybe_identity| -> DispatchResult {
                 let identity = maybe_identity.as_mut().ok_or(Error::<T>::IdentityNotFound)?;
                 let prev_verified = identity.verified;
@@ -155,10 +182,17 @@ pub mod pallet {
     }
 
     impl<T: Config> Pallet<T> {
-        /// Retourne un timestamp fixe.
-        /// En production, remplacez par l'appel à `pallet_timestamp` pour obtenir le temps réel.
+        /// Indique si le compte possède une identité vérifiée.
+        ///
+        /// Retourne `false` pour un compte sans identité. Seul le statut est renvoyé, sans les
+        /// détails KYC, afin que les consommateurs n'aient pas à transporter ni décoder le payload.
+        pub fn is_verified(account: &T::AccountId) -> bool {
+            Identities::<T>::get(account).map_or(false, |identity| identity.verified)
+        }
+
+        /// Retourne l'horodatage courant fourni par `T::TimeSource`.
         fn current_timestamp() -> u64 {
-            1_640_000_000
+            <T::TimeSource as nodara_time::TimeSource>::now()
         }
     }
 
@@ -201,6 +235,8 @@ mod tests {
         pub const BlockHashCount: u64 = 250;
         pub const DefaultVerification: bool = true;
         pub const MaxKycLength: u32 = 256;
+        /// Active le schéma JSON de `JsonKycValidator` ; désactivé, tout détail KYC est accepté.
+        pub static RequireJsonKyc: bool = false;
     }
 
     impl system::Config for Test {
@@ -230,10 +266,31 @@ mod tests {
         type MaxConsumers = ();
     }
 
+    /// Validateur de test : si `RequireJsonKyc` est actif, exige un objet JSON non vide (`{...}`
+    /// avec au moins un caractère significatif) ; sinon accepte tout.
+    pub struct JsonKycValidator;
+    impl KycValidator for JsonKycValidator {
+        fn validate(kyc_details: &[u8]) -> bool {
+            if !RequireJsonKyc::get() {
+                return true;
+            }
+            let trimmed: Vec<u8> = kyc_details.iter().copied().filter(|b| !b.is_ascii_whitespace()).collect();
+            trimmed.len() > 2 && trimmed.first() == Some(&b'{') && trimmed.last() == Some(&b'}')
+        }
+    }
+
     impl pallet::Config for Test {
         type RuntimeEvent = ();
         type DefaultVerification = DefaultVerification;
         type MaxKycLength = MaxKycLength;
+        type KycValidator = JsonKycValidator;
+        type TimeSource = ();
+        type EmergencyHalt = ();
+    }
+
+    pub fn new_test_ext() -> sp_io::TestExternalities {
+        let storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+        storage.into()
     }
 
     #[test]
@@ -299,4 +356,39 @@ mod tests {
         assert_eq!(history_after.len() as u32, 5);
         assert!(len_before > 5);
     }
+
+    #[test]
+    fn register_and_update_reject_details_refused_by_validator() {
+        new_test_ext().execute_with(|| {
+            RequireJsonKyc::set(true);
+            assert_err!(
+                IdentityModule::register_identity(system::RawOrigin::Signed(1).into(), b"{}".to_vec()),
+                Error::<Test>::InvalidKycDetails
+            );
+            assert_err!(
+                IdentityModule::register_identity(system::RawOrigin::Signed(1).into(), b"not json".to_vec()),
+                Error::<Test>::InvalidKycDetails
+            );
+            assert_ok!(IdentityModule::register_identity(system::RawOrigin::Signed(1).into(), b"{\"name\": \"Alice\"}".to_vec()));
+            assert_err!(
+                IdentityModule::update_identity(system::RawOrigin::Signed(1).into(), b"{ }".to_vec(), true),
+                Error::<Test>::InvalidKycDetails
+            );
+            RequireJsonKyc::set(false);
+        });
+    }
+
+    #[test]
+    fn is_verified_reports_flag_only() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(IdentityModule::register_identity(system::RawOrigin::Signed(1).into(), b"KYC 1".to_vec()));
+            assert_ok!(IdentityModule::update_identity(system::RawOrigin::Signed(1).into(), b"KYC 1".to_vec(), true));
+            assert_ok!(IdentityModule::register_identity(system::RawOrigin::Signed(2).into(), b"KYC 2".to_vec()));
+            assert_ok!(IdentityModule::update_identity(system::RawOrigin::Signed(2).into(), b"KYC 2".to_vec(), false));
+
+            assert!(IdentityModule::is_verified(&1));
+            assert!(!IdentityModule::is_verified(&2));
+            assert!(!IdentityModule::is_verified(&99));
+        });
+    }
 }
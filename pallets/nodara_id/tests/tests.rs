@@ -38,6 +38,9 @@ mod tests {
         type RuntimeEvent = ();
         type MaxKycLength = sp_runtime::traits::ConstU32<256>;
         type DefaultVerification = sp_runtime::traits::ConstBool<true>;
+        type KycValidator = ();
+        type TimeSource = ();
+        type EmergencyHalt = ();
     }
 
     pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -6,7 +6,7 @@
 //! Ce module implémente une interopérabilité sécurisée entre Nodara et des chaînes externes. Il gère l'envoi,
 //! la réception et la vérification cryptographique des messages interop. Chaque événement est enregistré dans
 //! un historique immuable pour assurer une traçabilité complète. De plus, la configuration (timeout et longueur
-//! de payload) est dynamique et peut être mise à jour via une extrinsic réservée à Root (via DAO).
+//! de payload) est dynamique et peut être mise à jour via une extrinsic réservée à la gouvernance.
 //!
 //! **Fonctionnalités principales :**
 //! - Messagerie inter-chaînes sécurisée.
@@ -19,14 +19,15 @@ use frame_support::{
     traits::Get,
 };
 use frame_system::pallet_prelude::*;
-use parity_scale_codec::{Encode, Decode};
+use nodara_emergency::HaltGuard;
+use parity_scale_codec::{Encode, Decode, DecodeAll};
 use scale_info::TypeInfo;
 use sp_std::vec::Vec;
 use sp_runtime::RuntimeDebug;
 
 /// Structure représentant un message interop.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct InteropMessage {
+pub struct InteropMessage<AccountId> {
     /// Identifiant unique du message.
     pub id: u64,
     /// Charge utile du message.
@@ -35,6 +36,8 @@ pub struct InteropMessage {
     pub timestamp: u64,
     /// Signature cryptographique du message.
     pub signature: Vec<u8>,
+    /// Compte signataire de l'extrinsic, absent pour les messages émis par un autre module.
+    pub sender: Option<AccountId>,
 }
 
 /// Structure de configuration dynamique du module interop.
@@ -51,8 +54,13 @@ pub mod pallet {
     use super::*;
     use sp_io::hashing::blake2_128;
 
+    /// Version du stockage : la version 1 borne `InteropHistory`, entrées et champs d'octets, et
+    /// ajoute le signataire aux entrées de l'historique et aux messages.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// Configuration du module.
@@ -66,26 +74,90 @@ pub mod pallet {
         /// Longueur maximale autorisée pour la charge utile d'un message.
         #[pallet::constant]
         type MaxPayloadLength: Get<u32>;
+        /// Longueur maximale autorisée pour la signature d'un message.
+        #[pallet::constant]
+        type MaxSignatureLength: Get<u32>;
+        /// Nombre maximal de messages (envoyés et reçus confondus) acceptés par bloc.
+        #[pallet::constant]
+        type MaxMessagesPerBlock: Get<u32>;
+        /// Origine autorisée à suspendre et réactiver le module ainsi qu'à modifier ses paramètres
+        /// (par exemple `nodara_governance::EnsureThreshold` pour un comité M-sur-N).
+        type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Source des horodatages enregistrés dans l'historique (voir `nodara_time`).
+        type TimeSource: nodara_time::TimeSource;
+        /// Nombre maximal d'entrées conservées dans `InteropHistory` ; au-delà, les plus anciennes
+        /// sont évincées.
+        #[pallet::constant]
+        type MaxHistoryLen: Get<u32>;
+        /// Arrêt d'urgence global consulté par chaque appel signé ; `()` le désactive.
+        type EmergencyHalt: nodara_emergency::HaltGuard;
     }
 
     /// Stockage des messages sortants.
     #[pallet::storage]
     #[pallet::getter(fn outgoing_messages)]
     pub type OutgoingMessages<T: Config> =
-        StorageMap<_, Blake2_128Concat, u64, InteropMessage, OptionQuery>;
+        StorageMap<_, Blake2_128Concat, u64, InteropMessage<T::AccountId>, OptionQuery>;
 
     /// Stockage des messages entrants.
     #[pallet::storage]
     #[pallet::getter(fn incoming_messages)]
     pub type IncomingMessages<T: Config> =
-        StorageMap<_, Blake2_128Concat, u64, InteropMessage, OptionQuery>;
+        StorageMap<_, Blake2_128Concat, u64, InteropMessage<T::AccountId>, OptionQuery>;
+
+    /// Messages sortants émis par d'autres modules (par exemple les notifications de transferts du
+    /// bridge). Aucun extrinsèque n'y écrit : leurs identifiants ne peuvent pas être occupés par
+    /// les messages des utilisateurs.
+    #[pallet::storage]
+    #[pallet::getter(fn module_messages)]
+    pub type ModuleMessages<T: Config> =
+        StorageMap<_, Blake2_128Concat, u64, InteropMessage<T::AccountId>, OptionQuery>;
+
+    /// Payloads des messages de modules dont l'envoi a échoué pour une raison transitoire (module
+    /// suspendu, limite de messages du bloc atteinte) ; ils sont renvoyés au début des blocs suivants.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_module_messages)]
+    pub type PendingModuleMessages<T: Config> = StorageMap<_, Blake2_128Concat, u64, Vec<u8>, OptionQuery>;
+
+    /// Octets d'une entrée d'historique (type d'opération ou détails), tronqués à 256 octets ; le
+    /// payload complet reste consultable dans le message.
+    pub type HistoryBytes = BoundedVec<u8, ConstU32<256>>;
+
+    /// Entrée de l'historique interop : (timestamp, message id, type d'opération, détails, signataire).
+    pub type InteropHistoryEntry<AccountId> = (u64, u64, HistoryBytes, HistoryBytes, Option<AccountId>);
+
+    /// Message avant la version 1 du stockage : (id, payload, timestamp, signature).
+    pub type LegacyInteropMessage = (u64, Vec<u8>, u64, Vec<u8>);
 
-    /// Journalisation des événements interop.
-    /// Chaque entrée est un tuple : (timestamp, message id, type d'opération, détails)
+    /// Entrée de l'historique avant la version 1 du stockage : (timestamp, message id, type
+    /// d'opération, détails).
+    pub type LegacyInteropHistoryEntry = (u64, u64, Vec<u8>, Vec<u8>);
+
+    /// Journalisation des événements interop, bornée par `MaxHistoryLen`.
     #[pallet::storage]
     #[pallet::getter(fn interop_history)]
-    pub type InteropHistory<T: Config> =
-        StorageValue<_, Vec<(u64, u64, Vec<u8>, Vec<u8>)>, ValueQuery>;
+    pub type InteropHistory<T: Config> = StorageValue<
+        _,
+        BoundedVec<InteropHistoryEntry<T::AccountId>, T::MaxHistoryLen>,
+        ValueQuery,
+    >;
+
+    /// Index des identifiants de messages (envoyés et reçus) par compte signataire. Un identifiant
+    /// n'est attribué qu'à un seul message, toutes directions confondues.
+    #[pallet::storage]
+    #[pallet::getter(fn messages_by_account)]
+    pub type MessagesByAccount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, Vec<u64>, ValueQuery>;
+
+    /// Nombre de messages acceptés dans le bloc courant, remis à zéro dans `on_initialize`.
+    #[pallet::storage]
+    #[pallet::getter(fn messages_this_block)]
+    pub type MessagesThisBlock<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Indique si le module est suspendu (réponse à incident).
+    #[pallet::storage]
+    #[pallet::getter(fn is_paused)]
+    pub type Paused<T: Config> = StorageValue<_, bool, ValueQuery>;
 
     /// Stockage de la configuration dynamique du module interop.
     #[pallet::storage]
@@ -134,20 +206,68 @@ pub mod pallet {
         ConfigUpdated(Vec<u8>, Vec<u8>),
         /// Mise à jour des paramètres de configuration du module interop.
         ConfigParamsUpdated(u64, u32, u64, u32),
+        /// Module suspendu ou réactivé (nouvel état).
+        PauseStateChanged(bool),
+        /// Message émis par un autre module enregistré dans `ModuleMessages` (id, payload).
+        ModuleMessageSent(u64, Vec<u8>),
+        /// Message de module mis en attente, à renvoyer dans un bloc suivant (id).
+        ModuleMessageQueued(u64),
+        /// Message de module en attente abandonné car il ne peut plus être envoyé (id).
+        ModuleMessageDropped(u64),
     }
 
     #[pallet::error]
     pub enum Error<T> {
         /// La charge utile dépasse la longueur maximale autorisée.
         PayloadTooLong,
+        /// La signature dépasse la longueur maximale autorisée.
+        SignatureTooLong,
         /// Échec de la vérification cryptographique.
         VerificationFailed,
         /// Erreur lors du traitement du message.
         MessageProcessingError,
+        /// Le nombre maximal de messages pour ce bloc est atteint.
+        MessageRateExceeded,
+        /// Le module est suspendu.
+        Paused,
+        /// La configuration soumise ne se décode pas en `InteropConfig`.
+        InvalidConfig,
+        /// Un message (envoyé ou reçu) porte déjà cet identifiant.
+        MessageIdInUse,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            <MessagesThisBlock<T>>::kill();
+            T::DbWeight::get().writes(1).saturating_add(Self::retry_pending_module_messages())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::get::<Pallet<T>>() >= STORAGE_VERSION {
+                return T::DbWeight::get().reads(1);
+            }
+            Self::migrate_history_to_v1();
+            let messages = Self::migrate_messages_to_v1();
+            STORAGE_VERSION.put::<Pallet<T>>();
+            T::DbWeight::get().reads_writes(messages.saturating_add(2), messages.saturating_add(2))
+        }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
+        /// Suspend ou réactive l'envoi, la réception et la mise à jour DAO de la configuration.
+        ///
+        /// Les lectures ainsi que les extrinsèques de gouvernance (`update_config_params`, `prune_history`)
+        /// restent disponibles pendant la suspension.
+        #[pallet::weight(10_000)]
+        pub fn set_paused(origin: OriginFor<T>, paused: bool) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            <Paused<T>>::put(paused);
+            Self::deposit_event(Event::PauseStateChanged(paused));
+            Ok(())
+        }
+
         /// Envoie un message interop vers une chaîne externe.
         #[pallet::weight(10_000)]
         pub fn send_message(
@@ -156,26 +276,9 @@ pub mod pallet {
             payload: Vec<u8>,
             signature: Vec<u8>,
         ) -> DispatchResult {
-            let _sender = ensure_signed(origin)?;
-            // Utilise la configuration dynamique.
-            let config = InteropConfigStorage::<T>::get();
-            ensure!(
-                payload.len() as u32 <= config.max_payload_length,
-                Error::<T>::PayloadTooLong
-            );
-            let timestamp = Self::current_timestamp();
-            let message = InteropMessage {
-                id,
-                payload: payload.clone(),
-                timestamp,
-                signature,
-            };
-            <OutgoingMessages<T>>::insert(id, message);
-            <InteropHistory<T>>::mutate(|history| {
-                history.push((timestamp, id, b"Send".to_vec(), payload.clone()))
-            });
-            Self::deposit_event(Event::MessageSent(id, payload));
-            Ok(())
+            let sender = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::do_send_message(id, payload, signature, Some(sender))
         }
 
         /// Reçoit et vérifie un message interop provenant d'une chaîne externe.
@@ -186,7 +289,10 @@ pub mod pallet {
             payload: Vec<u8>,
             signature: Vec<u8>,
         ) -> DispatchResult {
-            let _sender = ensure_signed(origin)?;
+            let sender = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::ensure_not_paused()?;
+            Self::ensure_signature_length(&signature)?;
             // Vérification améliorée : le signature doit être égale au hash Blake2-128 du payload.
             ensure!(Self::verify_signature(&payload, &signature), Error::<T>::VerificationFailed);
             let config = InteropConfigStorage::<T>::get();
@@ -194,47 +300,64 @@ pub mod pallet {
                 payload.len() as u32 <= config.max_payload_length,
                 Error::<T>::PayloadTooLong
             );
+            Self::ensure_id_available(id)?;
+            Self::note_message()?;
             let timestamp = Self::current_timestamp();
             let message = InteropMessage {
                 id,
                 payload: payload.clone(),
                 timestamp,
                 signature,
+                sender: Some(sender.clone()),
             };
             <IncomingMessages<T>>::insert(id, message);
-            <InteropHistory<T>>::mutate(|history| {
-                history.push((timestamp, id, b"Receive".to_vec(), payload.clone()))
-            });
+            Self::record_history(timestamp, id, b"Receive", &payload, Some(sender.clone()));
+            <MessagesByAccount<T>>::append(&sender, id);
             Self::deposit_event(Event::MessageReceived(id, payload));
             Ok(())
         }
 
         /// Met à jour la configuration du module interop via DAO.
+        ///
+        /// `new_config` doit être l'encodage SCALE complet d'un `InteropConfig` ; il est appliqué à
+        /// `InteropConfigStorage` afin que la modification journalisée et la configuration effective
+        /// restent cohérentes. Un encodage invalide est rejeté avec `InvalidConfig`.
+        /// Réservé à `GovernanceOrigin`, comme `update_config_params`.
         #[pallet::weight(10_000)]
         pub fn update_config(
             origin: OriginFor<T>,
             new_config: Vec<u8>,
             details: Vec<u8>,
         ) -> DispatchResult {
-            let _sender = ensure_signed(origin)?;
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::ensure_not_paused()?;
             ensure!(!new_config.is_empty(), Error::<T>::MessageProcessingError);
+            let decoded = InteropConfig::decode_all(&mut &new_config[..])
+                .map_err(|_| Error::<T>::InvalidConfig)?;
+            let old = InteropConfigStorage::<T>::get();
+            InteropConfigStorage::<T>::put(decoded.clone());
             let timestamp = Self::current_timestamp();
-            <InteropHistory<T>>::mutate(|history| {
-                history.push((timestamp, 0, b"ConfigUpdate".to_vec(), details.clone()))
-            });
+            Self::record_history(timestamp, 0, b"ConfigUpdate", &details, None);
+            Self::deposit_event(Event::ConfigParamsUpdated(
+                old.base_timeout,
+                old.max_payload_length,
+                decoded.base_timeout,
+                decoded.max_payload_length,
+            ));
             Self::deposit_event(Event::ConfigUpdated(new_config, details));
             Ok(())
         }
 
         /// Met à jour dynamiquement les paramètres de configuration du module interop.
-        /// Seul Root peut appeler cette fonction.
+        /// Réservé à `GovernanceOrigin`.
         #[pallet::weight(10_000)]
         pub fn update_config_params(
             origin: OriginFor<T>,
             new_timeout: u64,
             new_max_payload: u32,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            T::GovernanceOrigin::ensure_origin(origin)?;
             let old = InteropConfigStorage::<T>::get();
             InteropConfigStorage::<T>::put(InteropConfig {
                 base_timeout: new_timeout,
@@ -250,24 +373,230 @@ pub mod pallet {
         pub fn prune_history(origin: OriginFor<T>, max_entries: usize) -> DispatchResult {
             ensure_root(origin)?;
             <InteropHistory<T>>::mutate(|history| {
-                if history.len() > max_entries {
-                    *history = history.split_off(history.len() - max_entries);
-                }
+                let excess = history.len().saturating_sub(max_entries);
+                let mut index = 0usize;
+                history.retain(|_| {
+                    index += 1;
+                    index > excess
+                });
             });
             Ok(())
         }
     }
 
     impl<T: Config> Pallet<T> {
+        /// Enregistre un message sortant, sous réserve de la suspension du module, des longueurs
+        /// maximales du payload et de la signature, et de la limite de messages par bloc. Un message signé par un compte
+        /// est ajouté à l'index `MessagesByAccount`.
+        pub fn do_send_message(
+            id: u64,
+            payload: Vec<u8>,
+            signature: Vec<u8>,
+            sender: Option<T::AccountId>,
+        ) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            Self::ensure_signature_length(&signature)?;
+            // Utilise la configuration dynamique.
+            let config = InteropConfigStorage::<T>::get();
+            ensure!(
+                payload.len() as u32 <= config.max_payload_length,
+                Error::<T>::PayloadTooLong
+            );
+            Self::ensure_id_available(id)?;
+            Self::note_message()?;
+            let timestamp = Self::current_timestamp();
+            let message = InteropMessage {
+                id,
+                payload: payload.clone(),
+                timestamp,
+                signature,
+                sender: sender.clone(),
+            };
+            <OutgoingMessages<T>>::insert(id, message);
+            Self::record_history(timestamp, id, b"Send", &payload, sender.clone());
+            if let Some(account) = sender {
+                <MessagesByAccount<T>>::append(&account, id);
+            }
+            Self::deposit_event(Event::MessageSent(id, payload));
+            Ok(())
+        }
+
+        /// Enregistre dans `ModuleMessages` un message sortant émis par un autre module, signé avec
+        /// le hash Blake2-128 de son payload (le schéma vérifié par `receive_message`).
+        ///
+        /// Un identifiant déjà porté par un message de module, envoyé ou en attente, est rejeté avec
+        /// `MessageIdInUse`. Si le module est suspendu ou si la limite de messages du bloc est
+        /// atteinte, le message est placé dans `PendingModuleMessages` et renvoyé plus tard.
+        pub fn enqueue_outgoing(id: u64, payload: Vec<u8>) -> DispatchResult {
+            ensure!(
+                !<ModuleMessages<T>>::contains_key(id) && !<PendingModuleMessages<T>>::contains_key(id),
+                Error::<T>::MessageIdInUse
+            );
+            match Self::do_send_module_message(id, payload.clone()) {
+                Err(e) if Self::is_transient(&e) => {
+                    <PendingModuleMessages<T>>::insert(id, payload);
+                    Self::deposit_event(Event::ModuleMessageQueued(id));
+                    Ok(())
+                },
+                result => result,
+            }
+        }
+
+        /// Envoie un message de module, sous réserve de la suspension du module, de la longueur
+        /// maximale du payload et de la limite de messages par bloc.
+        fn do_send_module_message(id: u64, payload: Vec<u8>) -> DispatchResult {
+            Self::ensure_not_paused()?;
+            let config = InteropConfigStorage::<T>::get();
+            ensure!(
+                payload.len() as u32 <= config.max_payload_length,
+                Error::<T>::PayloadTooLong
+            );
+            Self::note_message()?;
+            let timestamp = Self::current_timestamp();
+            let message = InteropMessage {
+                id,
+                payload: payload.clone(),
+                timestamp,
+                signature: blake2_128(&payload).to_vec(),
+                sender: None,
+            };
+            <ModuleMessages<T>>::insert(id, message);
+            Self::record_history(timestamp, id, b"ModuleSend", &payload, None);
+            Self::deposit_event(Event::ModuleMessageSent(id, payload));
+            Ok(())
+        }
+
+        /// Renvoie au plus `MaxMessagesPerBlock` messages de modules en attente, sauf si le module
+        /// est suspendu. Un message qui échoue encore pour une raison transitoire reste en attente ;
+        /// les autres échecs (payload devenu trop long) l'abandonnent. Retourne le poids consommé.
+        fn retry_pending_module_messages() -> Weight {
+            let db = T::DbWeight::get();
+            if <Paused<T>>::get() {
+                return db.reads(1);
+            }
+            let batch: Vec<(u64, Vec<u8>)> = <PendingModuleMessages<T>>::iter()
+                .take(T::MaxMessagesPerBlock::get() as usize)
+                .collect();
+            let attempts = batch.len() as u64;
+            for (id, payload) in batch {
+                match Self::do_send_module_message(id, payload) {
+                    Ok(()) => <PendingModuleMessages<T>>::remove(id),
+                    Err(e) if Self::is_transient(&e) => {},
+                    Err(_) => {
+                        <PendingModuleMessages<T>>::remove(id);
+                        Self::deposit_event(Event::ModuleMessageDropped(id));
+                    },
+                }
+            }
+            db.reads_writes(1 + 4 * attempts, 4 * attempts)
+        }
+
+        /// Indique si `error` disparaîtra d'elle-même : suspension du module ou limite par bloc.
+        fn is_transient(error: &DispatchError) -> bool {
+            *error == Error::<T>::Paused.into() || *error == Error::<T>::MessageRateExceeded.into()
+        }
+
+        /// Identifiants des messages envoyés ou reçus par `account`, dans l'ordre de traitement.
+        pub fn messages_for(account: &T::AccountId) -> Vec<u64> {
+            <MessagesByAccount<T>>::get(account)
+        }
+
+        /// Ajoute une entrée à l'historique, `operation` et `details` tronqués à la taille de
+        /// `HistoryBytes` ; s'il est plein, l'entrée la plus ancienne est évincée.
+        fn record_history(timestamp: u64, id: u64, operation: &[u8], details: &[u8], signer: Option<T::AccountId>) {
+            let entry = (timestamp, id, Self::history_bytes(operation), Self::history_bytes(details), signer);
+            <InteropHistory<T>>::mutate(|history| {
+                if let Err(entry) = history.try_push(entry) {
+                    if !history.is_empty() {
+                        history.remove(0);
+                        let _ = history.try_push(entry);
+                    }
+                }
+            });
+        }
+
+        /// Copie les premiers octets de `bytes` qui tiennent dans `HistoryBytes`.
+        fn history_bytes(bytes: &[u8]) -> HistoryBytes {
+            BoundedVec::truncate_from(bytes.to_vec())
+        }
+
+        /// Convertit l'historique non borné de la version 0 : seules les `MaxHistoryLen` entrées
+        /// les plus récentes sont conservées, sans signataire connu, et leurs champs d'octets sont
+        /// tronqués à la taille de `HistoryBytes`.
+        fn migrate_history_to_v1() {
+            let _ = <InteropHistory<T>>::translate::<Vec<LegacyInteropHistoryEntry>, _>(|legacy| {
+                legacy.map(|mut entries| {
+                    let excess = entries.len().saturating_sub(T::MaxHistoryLen::get() as usize);
+                    let recent = entries.split_off(excess);
+                    BoundedVec::truncate_from(
+                        recent
+                            .into_iter()
+                            .map(|(timestamp, id, operation, details)| {
+                                (timestamp, id, Self::history_bytes(&operation), Self::history_bytes(&details), None)
+                            })
+                            .collect(),
+                    )
+                })
+            });
+        }
+
+        /// Ajoute un signataire inconnu (`None`) aux messages de la version 0 ; retourne le nombre
+        /// de messages convertis.
+        fn migrate_messages_to_v1() -> u64 {
+            let mut translated = 0u64;
+            let mut upgrade = |_: u64, (id, payload, timestamp, signature): LegacyInteropMessage| {
+                translated = translated.saturating_add(1);
+                Some(InteropMessage { id, payload, timestamp, signature, sender: None })
+            };
+            <OutgoingMessages<T>>::translate(&mut upgrade);
+            <IncomingMessages<T>>::translate(&mut upgrade);
+            translated
+        }
+
+        /// Échoue avec `MessageIdInUse` si un message envoyé ou reçu porte déjà `id`, afin qu'aucun
+        /// message ne soit écrasé et que `MessagesByAccount` ne désigne qu'un seul message par
+        /// identifiant.
+        fn ensure_id_available(id: u64) -> DispatchResult {
+            ensure!(
+                !<OutgoingMessages<T>>::contains_key(id) && !<IncomingMessages<T>>::contains_key(id),
+                Error::<T>::MessageIdInUse
+            );
+            Ok(())
+        }
+
+        /// Échoue avec `Paused` tant que le module est suspendu.
+        fn ensure_not_paused() -> DispatchResult {
+            ensure!(!<Paused<T>>::get(), Error::<T>::Paused);
+            Ok(())
+        }
+
+        /// Comptabilise un message dans le bloc courant, ou échoue si `MaxMessagesPerBlock` est atteint.
+        fn note_message() -> DispatchResult {
+            <MessagesThisBlock<T>>::try_mutate(|count| -> DispatchResult {
+                ensure!(*count < T::MaxMessagesPerBlock::get(), Error::<T>::MessageRateExceeded);
+                *count += 1;
+                Ok(())
+            })
+        }
+
+        /// Rejette une signature plus longue que `MaxSignatureLength`.
+        fn ensure_signature_length(signature: &[u8]) -> DispatchResult {
+            ensure!(
+                signature.len() as u32 <= T::MaxSignatureLength::get(),
+                Error::<T>::SignatureTooLong
+            );
+            Ok(())
+        }
+
         /// Vérifie la signature du message en comparant le hash Blake2-128 du payload avec la signature.
         fn verify_signature(payload: &Vec<u8>, signature: &Vec<u8>) -> bool {
             let hash = sp_io::hashing::blake2_128(&payload);
             signature.len() == 16 && signature == &hash.to_vec()
         }
 
-        /// Retourne un horodatage fixe (à remplacer par `pallet_timestamp` en production).
+        /// Retourne l'horodatage courant fourni par `T::TimeSource`.
         fn current_timestamp() -> u64 {
-            1_640_000_000
+            <T::TimeSource as nodara_time::TimeSource>::now()
         }
     }
 
@@ -309,7 +638,7 @@ pub mod pallet {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use frame_support::{assert_err, assert_ok, parameter_types};
+    use frame_support::{assert_err, assert_ok, parameter_types, traits::Hooks};
     use sp_core::H256;
     use sp_runtime::{
         traits::{BlakeTwo256, IdentityLookup},
@@ -335,6 +664,11 @@ mod tests {
         pub const BlockHashCount: u64 = 250;
         pub const BaseTimeout: u64 = 300;
         pub const MaxPayloadLength: u32 = 1024;
+        pub const MaxSignatureLength: u32 = 64;
+        pub const MaxMessagesPerBlock: u32 = 3;
+        pub const ExpectedBlockTime: u64 = 6_000;
+        pub const GenesisTime: u64 = 1_700_000_000;
+        pub const MaxHistoryLen: u32 = 8;
     }
 
     impl system::Config for Test {
@@ -368,6 +702,17 @@ mod tests {
         type RuntimeEvent = ();
         type BaseTimeout = BaseTimeout;
         type MaxPayloadLength = MaxPayloadLength;
+        type MaxSignatureLength = MaxSignatureLength;
+        type MaxMessagesPerBlock = MaxMessagesPerBlock;
+        type GovernanceOrigin = frame_system::EnsureRoot<u64>;
+        type TimeSource = nodara_time::BlockTimeSource<Test, ExpectedBlockTime, GenesisTime>;
+        type MaxHistoryLen = MaxHistoryLen;
+        type EmergencyHalt = ();
+    }
+
+    pub fn new_test_ext() -> sp_io::TestExternalities {
+        let storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+        storage.into()
     }
 
     #[test]
@@ -394,6 +739,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn messages_with_an_over_length_signature_are_rejected() {
+        new_test_ext().execute_with(|| {
+            let payload = b"Test payload".to_vec();
+            let signature = vec![0u8; (MaxSignatureLength::get() + 1) as usize];
+            assert_err!(
+                InteropModule::send_message(system::RawOrigin::Signed(1).into(), 1, payload.clone(), signature.clone()),
+                Error::<Test>::SignatureTooLong
+            );
+            assert_err!(
+                InteropModule::receive_message(system::RawOrigin::Signed(1).into(), 2, payload.clone(), signature),
+                Error::<Test>::SignatureTooLong
+            );
+            assert!(InteropModule::outgoing_messages(1).is_none());
+            assert!(InteropModule::incoming_messages(2).is_none());
+
+            // Une signature à la longueur maximale reste acceptée à l'envoi.
+            let signature = vec![0u8; MaxSignatureLength::get() as usize];
+            assert_ok!(InteropModule::send_message(system::RawOrigin::Signed(1).into(), 1, payload, signature));
+            assert!(InteropModule::outgoing_messages(1).is_some());
+        });
+    }
+
     #[test]
     fn receive_message_should_work() {
         let origin = system::RawOrigin::Signed(1).into();
@@ -419,15 +787,52 @@ mod tests {
 
     #[test]
     fn update_config_should_work() {
-        let origin = system::RawOrigin::Signed(1).into();
-        let new_config = b"NewConfig".to_vec();
+        let origin = system::RawOrigin::Root.into();
+        let new_config = pallet::InteropConfig { base_timeout: 600, max_payload_length: 2048 };
         let details = b"Update details".to_vec();
-        assert_ok!(InteropModule::update_config(origin, new_config.clone(), details.clone()));
+        assert_ok!(InteropModule::update_config(origin, new_config.encode(), details.clone()));
         let history = InteropModule::interop_history();
-        let config_updates: Vec<_> = history.into_iter().filter(|(_, id, op, _)| {
-            *id == 0 && op == b"ConfigUpdate".to_vec()
+        let config_updates: Vec<_> = history.into_iter().filter(|(_, id, op, _, _)| {
+            *id == 0 && op.as_slice() == b"ConfigUpdate"
         }).collect();
         assert!(!config_updates.is_empty());
+        // La configuration journalisée est bien celle qui s'applique.
+        assert_eq!(InteropModule::interop_config(), new_config);
+    }
+
+    #[test]
+    fn update_config_rejects_bytes_that_do_not_decode() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(InteropModule::update_config_params(system::RawOrigin::Root.into(), BaseTimeout::get(), MaxPayloadLength::get()));
+            let before = InteropModule::interop_config();
+            let valid = pallet::InteropConfig { base_timeout: 600, max_payload_length: 2048 }.encode();
+            let mut trailing = valid.clone();
+            trailing.push(0);
+
+            for invalid in [b"NewConfig".to_vec(), valid[..valid.len() - 1].to_vec(), trailing] {
+                assert_err!(
+                    InteropModule::update_config(system::RawOrigin::Root.into(), invalid, b"details".to_vec()),
+                    Error::<Test>::InvalidConfig
+                );
+            }
+            assert_eq!(InteropModule::interop_config(), before);
+            assert!(InteropModule::interop_history().is_empty());
+        });
+    }
+
+    #[test]
+    fn update_config_requires_governance_origin() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(InteropModule::update_config_params(system::RawOrigin::Root.into(), BaseTimeout::get(), MaxPayloadLength::get()));
+            let before = InteropModule::interop_config();
+            let config = pallet::InteropConfig { base_timeout: 1, max_payload_length: u32::MAX };
+            assert_err!(
+                InteropModule::update_config(system::RawOrigin::Signed(1).into(), config.encode(), b"details".to_vec()),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_eq!(InteropModule::interop_config(), before);
+            assert!(InteropModule::interop_history().is_empty());
+        });
     }
 
     #[test]
@@ -457,4 +862,249 @@ mod tests {
         assert_eq!(history_after.len(), 1);
         assert!(len_before > 1);
     }
+
+    #[test]
+    fn message_rate_is_capped_per_block() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(InteropModule::update_config_params(system::RawOrigin::Root.into(), BaseTimeout::get(), MaxPayloadLength::get()));
+            let payload = b"Payload".to_vec();
+            let signature = sp_io::hashing::blake2_128(&payload).to_vec();
+            let user = || -> <Test as system::Config>::RuntimeOrigin { system::RawOrigin::Signed(1).into() };
+
+            System::set_block_number(1);
+            InteropModule::on_initialize(1);
+            assert_ok!(InteropModule::send_message(user(), 1, payload.clone(), signature.clone()));
+            assert_ok!(InteropModule::receive_message(user(), 2, payload.clone(), signature.clone()));
+            assert_ok!(InteropModule::send_message(user(), 3, payload.clone(), signature.clone()));
+            assert_err!(
+                InteropModule::receive_message(user(), 4, payload.clone(), signature.clone()),
+                Error::<Test>::MessageRateExceeded
+            );
+            assert_eq!(InteropModule::messages_this_block(), MaxMessagesPerBlock::get());
+
+            System::set_block_number(2);
+            InteropModule::on_initialize(2);
+            assert_ok!(InteropModule::receive_message(user(), 4, payload, signature));
+            assert_eq!(InteropModule::messages_this_block(), 1);
+        });
+    }
+    #[test]
+    fn pausing_blocks_messages_until_unpaused() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(InteropModule::update_config_params(system::RawOrigin::Root.into(), BaseTimeout::get(), MaxPayloadLength::get()));
+            let payload = b"Payload".to_vec();
+            let signature = sp_io::hashing::blake2_128(&payload).to_vec();
+            let user = || -> <Test as system::Config>::RuntimeOrigin { system::RawOrigin::Signed(1).into() };
+
+            assert_err!(InteropModule::set_paused(user(), true), sp_runtime::DispatchError::BadOrigin);
+            assert_ok!(InteropModule::set_paused(system::RawOrigin::Root.into(), true));
+            assert!(InteropModule::is_paused());
+            assert_err!(InteropModule::send_message(user(), 1, payload.clone(), signature.clone()), Error::<Test>::Paused);
+            assert_err!(InteropModule::receive_message(user(), 2, payload.clone(), signature.clone()), Error::<Test>::Paused);
+            assert_err!(
+                InteropModule::update_config(system::RawOrigin::Root.into(), b"Cfg".to_vec(), b"details".to_vec()),
+                Error::<Test>::Paused
+            );
+            // Les extrinsèques Root restent disponibles.
+            assert_ok!(InteropModule::prune_history(system::RawOrigin::Root.into(), 0));
+
+            assert_ok!(InteropModule::set_paused(system::RawOrigin::Root.into(), false));
+            assert_ok!(InteropModule::send_message(user(), 1, payload.clone(), signature.clone()));
+            assert_ok!(InteropModule::receive_message(user(), 2, payload, signature));
+        });
+    }
+
+    #[test]
+    fn block_time_source_increases_with_block_number() {
+        use nodara_time::TimeSource;
+        type Clock = nodara_time::BlockTimeSource<Test, ExpectedBlockTime, GenesisTime>;
+        new_test_ext().execute_with(|| {
+            assert_ok!(InteropModule::update_config_params(system::RawOrigin::Root.into(), BaseTimeout::get(), MaxPayloadLength::get()));
+            let payload = b"Payload".to_vec();
+            let signature = sp_io::hashing::blake2_128(&payload).to_vec();
+
+            System::set_block_number(1);
+            let first = Clock::now();
+            assert_eq!(first, GenesisTime::get() + 6);
+            assert_ok!(InteropModule::send_message(system::RawOrigin::Signed(1).into(), 1, payload.clone(), signature.clone()));
+
+            System::set_block_number(11);
+            assert_eq!(Clock::now(), first + 60);
+            assert_ok!(InteropModule::send_message(system::RawOrigin::Signed(1).into(), 2, payload, signature));
+
+            let stamps: Vec<u64> = InteropModule::interop_history().iter().map(|(timestamp, ..)| *timestamp).collect();
+            assert_eq!(stamps, vec![first, first + 60]);
+        });
+    }
+
+    #[test]
+    fn messages_are_indexed_by_signing_account() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(InteropModule::update_config_params(system::RawOrigin::Root.into(), BaseTimeout::get(), MaxPayloadLength::get()));
+            let payload = b"Payload".to_vec();
+            let signature = sp_io::hashing::blake2_128(&payload).to_vec();
+
+            assert_ok!(InteropModule::send_message(system::RawOrigin::Signed(1).into(), 1, payload.clone(), signature.clone()));
+            assert_ok!(InteropModule::send_message(system::RawOrigin::Signed(2).into(), 2, payload.clone(), signature.clone()));
+            assert_ok!(InteropModule::receive_message(system::RawOrigin::Signed(1).into(), 3, payload.clone(), signature.clone()));
+            InteropModule::on_initialize(2);
+            assert_ok!(InteropModule::receive_message(system::RawOrigin::Signed(2).into(), 4, payload.clone(), signature));
+            // Un message émis par un autre module n'est rattaché à aucun compte.
+            assert_ok!(InteropModule::enqueue_outgoing(5, payload));
+
+            assert_eq!(InteropModule::messages_for(&1), vec![1, 3]);
+            assert_eq!(InteropModule::messages_for(&2), vec![2, 4]);
+            assert_eq!(InteropModule::outgoing_messages(1).unwrap().sender, Some(1));
+            assert_eq!(InteropModule::module_messages(5).unwrap().sender, None);
+            let signers: Vec<Option<u64>> = InteropModule::interop_history().into_iter().map(|(.., signer)| signer).collect();
+            assert_eq!(signers, vec![Some(1), Some(2), Some(1), Some(2), None]);
+        });
+    }
+
+    #[test]
+    fn message_ids_cannot_be_reused_across_accounts_or_directions() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(InteropModule::update_config_params(system::RawOrigin::Root.into(), BaseTimeout::get(), MaxPayloadLength::get()));
+            let payload = b"Payload".to_vec();
+            let signature = sp_io::hashing::blake2_128(&payload).to_vec();
+
+            assert_ok!(InteropModule::send_message(system::RawOrigin::Signed(1).into(), 1, payload.clone(), signature.clone()));
+            assert_err!(
+                InteropModule::send_message(system::RawOrigin::Signed(2).into(), 1, b"Other".to_vec(), signature.clone()),
+                Error::<Test>::MessageIdInUse
+            );
+            assert_err!(
+                InteropModule::receive_message(system::RawOrigin::Signed(2).into(), 1, payload.clone(), signature.clone()),
+                Error::<Test>::MessageIdInUse
+            );
+
+            assert_eq!(InteropModule::outgoing_messages(1).unwrap().sender, Some(1));
+            assert!(InteropModule::incoming_messages(1).is_none());
+            assert_eq!(InteropModule::messages_for(&1), vec![1]);
+            assert!(InteropModule::messages_for(&2).is_empty());
+        });
+    }
+
+    #[test]
+    fn module_messages_are_kept_apart_from_user_messages() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(InteropModule::update_config_params(system::RawOrigin::Root.into(), BaseTimeout::get(), MaxPayloadLength::get()));
+            let payload = b"Payload".to_vec();
+            let signature = sp_io::hashing::blake2_128(&payload).to_vec();
+
+            // Un utilisateur ne peut pas occuper l'identifiant d'une notification de module.
+            assert_ok!(InteropModule::send_message(system::RawOrigin::Signed(1).into(), 7, b"Forged".to_vec(), sp_io::hashing::blake2_128(b"Forged").to_vec()));
+            assert_ok!(InteropModule::enqueue_outgoing(7, payload.clone()));
+            assert_eq!(InteropModule::module_messages(7).unwrap().payload, payload);
+            assert_eq!(InteropModule::module_messages(7).unwrap().signature, signature);
+            assert_eq!(InteropModule::outgoing_messages(7).unwrap().payload, b"Forged".to_vec());
+
+            // Un identifiant de module n'est attribué qu'une fois.
+            assert_err!(InteropModule::enqueue_outgoing(7, b"Other".to_vec()), Error::<Test>::MessageIdInUse);
+            assert_eq!(InteropModule::module_messages(7).unwrap().payload, payload);
+        });
+    }
+
+    #[test]
+    fn failed_module_messages_are_queued_and_retried() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(InteropModule::update_config_params(system::RawOrigin::Root.into(), BaseTimeout::get(), MaxPayloadLength::get()));
+            let payload = b"Payload".to_vec();
+            let signature = sp_io::hashing::blake2_128(&payload).to_vec();
+
+            // Les utilisateurs épuisent la limite du bloc : la notification est mise en attente.
+            for id in 1..=MaxMessagesPerBlock::get() as u64 {
+                assert_ok!(InteropModule::send_message(system::RawOrigin::Signed(1).into(), id, payload.clone(), signature.clone()));
+            }
+            assert_ok!(InteropModule::enqueue_outgoing(10, payload.clone()));
+            assert!(InteropModule::module_messages(10).is_none());
+            assert_eq!(InteropModule::pending_module_messages(10), Some(payload.clone()));
+            assert_err!(InteropModule::enqueue_outgoing(10, payload.clone()), Error::<Test>::MessageIdInUse);
+
+            // Pendant une suspension, elle reste en attente.
+            assert_ok!(InteropModule::set_paused(system::RawOrigin::Root.into(), true));
+            InteropModule::on_initialize(2);
+            assert!(InteropModule::module_messages(10).is_none());
+            assert_ok!(InteropModule::enqueue_outgoing(11, payload.clone()));
+            assert_eq!(InteropModule::pending_module_messages(11), Some(payload.clone()));
+
+            // Une fois le module réactivé, les deux sont envoyées au bloc suivant.
+            assert_ok!(InteropModule::set_paused(system::RawOrigin::Root.into(), false));
+            InteropModule::on_initialize(3);
+            assert_eq!(InteropModule::module_messages(10).unwrap().payload, payload);
+            assert_eq!(InteropModule::module_messages(11).unwrap().payload, payload);
+            assert!(InteropModule::pending_module_messages(10).is_none());
+            assert!(InteropModule::pending_module_messages(11).is_none());
+            assert_eq!(InteropModule::messages_this_block(), 2);
+        });
+    }
+
+    #[test]
+    fn message_migration_adds_an_unknown_sender() {
+        new_test_ext().execute_with(|| {
+            // Encodage de la version 0 : message sans signataire.
+            let legacy: LegacyInteropMessage = (7, b"Payload".to_vec(), 42, vec![1, 2]);
+            frame_support::storage::unhashed::put(&OutgoingMessages::<Test>::hashed_key_for(7), &legacy);
+            frame_support::storage::unhashed::put(&IncomingMessages::<Test>::hashed_key_for(8), &legacy);
+            assert!(InteropModule::outgoing_messages(7).is_none());
+
+            InteropModule::on_runtime_upgrade();
+            let expected = InteropMessage { id: 7, payload: b"Payload".to_vec(), timestamp: 42, signature: vec![1, 2], sender: None };
+            assert_eq!(InteropModule::outgoing_messages(7), Some(expected.clone()));
+            assert_eq!(InteropModule::incoming_messages(8), Some(expected));
+        });
+    }
+
+    #[test]
+    fn history_is_bounded_and_evicts_the_oldest_entries() {
+        new_test_ext().execute_with(|| {
+            for n in 0..10u8 {
+                let config = pallet::InteropConfig { base_timeout: BaseTimeout::get(), max_payload_length: MaxPayloadLength::get() };
+                assert_ok!(InteropModule::update_config(system::RawOrigin::Root.into(), config.encode(), vec![n]));
+            }
+            let details: Vec<Vec<u8>> = InteropModule::interop_history().into_iter().map(|(_, _, _, details, _)| details.into_inner()).collect();
+            assert_eq!(details, (2..10u8).map(|n| vec![n]).collect::<Vec<_>>());
+
+            assert_ok!(InteropModule::prune_history(system::RawOrigin::Root.into(), 3));
+            let details: Vec<Vec<u8>> = InteropModule::interop_history().into_iter().map(|(_, _, _, details, _)| details.into_inner()).collect();
+            assert_eq!(details, vec![vec![7], vec![8], vec![9]]);
+        });
+    }
+
+    #[test]
+    fn history_entries_have_a_bounded_encoding() {
+        // Horodatage, identifiant, deux champs d'octets (préfixe compact de 2 octets et 256 octets)
+        // et signataire optionnel.
+        assert_eq!(InteropHistoryEntry::<u64>::max_encoded_len(), 8 + 8 + 2 * (2 + 256) + 1 + 8);
+        new_test_ext().execute_with(|| {
+            let config = pallet::InteropConfig { base_timeout: BaseTimeout::get(), max_payload_length: MaxPayloadLength::get() };
+            assert_ok!(InteropModule::update_config(system::RawOrigin::Root.into(), config.encode(), vec![7u8; 300]));
+            let (_, _, operation, details, _) = InteropModule::interop_history().last().cloned().expect("entrée historisée");
+            assert_eq!(operation.as_slice(), b"ConfigUpdate");
+            assert_eq!(details.into_inner(), vec![7u8; 256]);
+        });
+    }
+
+    #[test]
+    fn history_migration_keeps_the_most_recent_legacy_entries() {
+        new_test_ext().execute_with(|| {
+            // Encodage de la version 0 : `Vec` non borné d'entrées sans signataire.
+            let legacy: Vec<LegacyInteropHistoryEntry> = (0..10u64).map(|id| (id, id, b"Send".to_vec(), vec![1u8; 300])).collect();
+            frame_support::storage::unhashed::put(&InteropHistory::<Test>::hashed_key(), &legacy);
+            assert!(InteropHistory::<Test>::try_get().is_err());
+
+            InteropModule::on_runtime_upgrade();
+            let ids: Vec<u64> = InteropModule::interop_history().into_iter().map(|(_, id, ..)| id).collect();
+            assert_eq!(ids, (2..10).collect::<Vec<u64>>());
+            assert!(InteropModule::interop_history().iter().all(|(.., signer)| signer.is_none()));
+            // Les détails trop longs sont tronqués à la taille de `HistoryBytes`.
+            assert!(InteropModule::interop_history().iter().all(|(_, _, _, details, _)| details.len() == 256));
+            assert_eq!(StorageVersion::get::<InteropModule>(), StorageVersion::new(1));
+
+            // Une seconde mise à niveau ne modifie plus rien.
+            let migrated = InteropModule::interop_history();
+            InteropModule::on_runtime_upgrade();
+            assert_eq!(InteropModule::interop_history(), migrated);
+        });
+    }
 }
@@ -37,6 +37,12 @@ mod tests {
     impl pallet::Config for TestConfig {
         type RuntimeEvent = ();
         type MaxPayloadLength = sp_runtime::traits::ConstU32<256>;
+        type MaxSignatureLength = sp_runtime::traits::ConstU32<64>;
+        type MaxMessagesPerBlock = sp_runtime::traits::ConstU32<100>;
+        type GovernanceOrigin = frame_system::EnsureRoot<u64>;
+        type TimeSource = ();
+        type MaxHistoryLen = sp_runtime::traits::ConstU32<1_000>;
+        type EmergencyHalt = ();
     }
 
     pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -22,6 +22,7 @@ pub mod pallet {
         traits::Get,
     };
     use frame_system::pallet_prelude::*;
+    use nodara_emergency::HaltGuard;
     use parity_scale_codec::{Encode, Decode};
     use scale_info::TypeInfo;
     use sp_std::vec::Vec;
@@ -41,9 +42,17 @@ pub mod pallet {
         pub signature: Vec<u8>,
     }
 
+    /// Paramètres d'un dispositif IoT, fixés lors de son enregistrement.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct DeviceSettings {
+        /// Si `false`, `submit_iot_data` n'émet pas d'événement pour ce dispositif (mode silencieux,
+        /// destiné aux capteurs haute fréquence). Les données et l'historique restent enregistrés.
+        pub emit_events: bool,
+    }
+
     /// Structure de configuration dynamique pour le module IoT Bridge.
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
-    pub struct InteropConfig {
+    pub struct IotConfig {
         pub base_timeout: u64,
         pub max_payload_length: u32,
     }
@@ -53,16 +62,43 @@ pub mod pallet {
     #[pallet::getter(fn iot_data)]
     pub type IotData<T: Config> = StorageMap<_, Blake2_128Concat, u64, IotRecord, OptionQuery>;
 
-    /// Journal d'audit des événements IoT.
-    /// Chaque entrée : (timestamp, message id, type d'opération, détails)
+    /// Champ d'octets du journal d'audit IoT, limité à 256 octets : au-delà, les détails sont
+    /// tronqués, l'enregistrement `IotData` conservant le payload complet.
+    pub type HistoryBytes = BoundedVec<u8, ConstU32<256>>;
+
+    /// Entrée du journal d'audit IoT : (timestamp, message id, type d'opération, détails).
+    pub type IotHistoryEntry = (u64, u64, HistoryBytes, HistoryBytes);
+
+    /// Entrée du journal avant la version 1 du stockage, à champs non bornés.
+    pub type LegacyIotHistoryEntry = (u64, u64, Vec<u8>, Vec<u8>);
+
+    /// Journal d'audit des événements IoT, borné par `MaxHistoryLen`.
     #[pallet::storage]
     #[pallet::getter(fn iot_history)]
-    pub type IotHistory<T: Config> = StorageValue<_, Vec<(u64, u64, Vec<u8>, Vec<u8>)>, ValueQuery>;
+    pub type IotHistory<T: Config> = StorageValue<_, BoundedVec<IotHistoryEntry, T::MaxHistoryLen>, ValueQuery>;
 
-    /// Stockage de la configuration dynamique du module IoT.
+    /// Dispositifs enregistrés, indexés par identifiant de dispositif.
     #[pallet::storage]
-    #[pallet::getter(fn interop_config)]
-    pub type InteropConfigStorage<T: Config> = StorageValue<_, InteropConfig, ValueQuery>;
+    #[pallet::getter(fn devices)]
+    pub type Devices<T: Config> = StorageMap<_, Blake2_128Concat, Vec<u8>, DeviceSettings, OptionQuery>;
+
+    /// Nombre d'entrées dans `Devices`, borné par `MaxDevices`.
+    #[pallet::storage]
+    #[pallet::getter(fn device_count)]
+    pub type DeviceCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Identifiants des entrées de `IotData`, de la plus ancienne à la plus récente ; au-delà de
+    /// `MaxStoredRecords`, les plus anciennes sont évincées.
+    #[pallet::storage]
+    #[pallet::getter(fn record_queue)]
+    pub type RecordQueue<T: Config> = StorageValue<_, Vec<u64>, ValueQuery>;
+
+    /// Stockage de la configuration dynamique du module IoT, distincte de celle de `nodara_interop`.
+    /// Le préfixe d'origine est conservé pour ne pas déplacer la valeur déjà stockée.
+    #[pallet::storage]
+    #[pallet::storage_prefix = "InteropConfigStorage"]
+    #[pallet::getter(fn iot_config)]
+    pub type IotConfigStorage<T: Config> = StorageValue<_, IotConfig, ValueQuery>;
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -74,15 +110,37 @@ pub mod pallet {
         /// Timeout de base pour la validation des données (en secondes).
         #[pallet::constant]
         type BaseTimeout: Get<u64>;
+        /// Source des horodatages des enregistrements IoT (voir `nodara_time`).
+        type TimeSource: nodara_time::TimeSource;
+        /// Origine autorisée à appeler `update_config` (gouvernance ou ensemble d'administrateurs),
+        /// afin que seuls des comptes habilités alimentent l'historique de configuration.
+        type ConfigOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Nombre maximal de dispositifs enregistrés.
+        #[pallet::constant]
+        type MaxDevices: Get<u32>;
+        /// Nombre maximal d'enregistrements conservés dans `IotData`.
+        #[pallet::constant]
+        type MaxStoredRecords: Get<u32>;
+        /// Nombre maximal d'entrées conservées dans `IotHistory` ; au-delà, les plus anciennes
+        /// sont évincées.
+        #[pallet::constant]
+        type MaxHistoryLen: Get<u32>;
+        /// Arrêt d'urgence global consulté par chaque appel signé ; `()` le désactive.
+        type EmergencyHalt: nodara_emergency::HaltGuard;
     }
 
+    /// Version du stockage : la version 1 borne `IotHistory` par `MaxHistoryLen` et ses champs
+    /// d'octets par `HistoryBytes`.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
-        pub initial_config: Option<InteropConfig>,
+        pub initial_config: Option<IotConfig>,
     }
 
     #[cfg(feature = "std")]
@@ -98,12 +156,12 @@ pub mod pallet {
             let config = if let Some(cfg) = &self.initial_config {
                 cfg.clone()
             } else {
-                InteropConfig {
+                IotConfig {
                     base_timeout: T::BaseTimeout::get(),
                     max_payload_length: T::MaxPayloadLength::get(),
                 }
             };
-            <InteropConfigStorage<T>>::put(config);
+            <IotConfigStorage<T>>::put(config);
         }
     }
 
@@ -118,6 +176,10 @@ pub mod pallet {
         ConfigUpdated(Vec<u8>, Vec<u8>),
         /// Mise à jour des paramètres de configuration du module IoT.
         ConfigParamsUpdated(u64, u32, u64, u32),
+        /// Dispositif enregistré (device_id, emit_events).
+        DeviceRegistered(Vec<u8>, bool),
+        /// Enregistrement le plus ancien retiré de `IotData` pour respecter `MaxStoredRecords` (id).
+        RecordEvicted(u64),
     }
 
     #[pallet::error]
@@ -130,16 +192,55 @@ pub mod pallet {
         VerificationFailed,
         /// Erreur de traitement du message.
         MessageProcessingError,
+        /// Le dispositif est déjà enregistré.
+        DeviceAlreadyRegistered,
+        /// L'origine n'est pas autorisée par `ConfigOrigin`.
+        NotAuthorized,
+        /// Le nombre maximal de dispositifs (`MaxDevices`) est atteint.
+        TooManyDevices,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::get::<Pallet<T>>() >= STORAGE_VERSION {
+                return T::DbWeight::get().reads(1);
+            }
+            Self::migrate_history_to_v1();
+            STORAGE_VERSION.put::<Pallet<T>>();
+            T::DbWeight::get().reads_writes(2, 2)
+        }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
+        /// Enregistre un dispositif IoT.
+        ///
+        /// - `device_id` : Identifiant du dispositif (non vide requis).
+        /// - `emit_events` : `false` active le mode silencieux (aucun `MessageSent` lors des soumissions).
+        #[pallet::weight(10_000)]
+        pub fn register_device(origin: OriginFor<T>, device_id: Vec<u8>, emit_events: bool) -> DispatchResult {
+            let _sender = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            ensure!(!device_id.is_empty(), Error::<T>::InvalidDeviceId);
+            ensure!(!<Devices<T>>::contains_key(&device_id), Error::<T>::DeviceAlreadyRegistered);
+            let count = <DeviceCount<T>>::get();
+            ensure!(count < T::MaxDevices::get(), Error::<T>::TooManyDevices);
+            <Devices<T>>::insert(&device_id, DeviceSettings { emit_events });
+            <DeviceCount<T>>::put(count.saturating_add(1));
+            Self::deposit_event(Event::DeviceRegistered(device_id, emit_events));
+            Ok(())
+        }
+
         /// Soumet des données IoT après vérification.
         ///
         /// - `id` : Identifiant unique du message.
         /// - `payload` : Données envoyées par le dispositif.
         /// - `device_id` : Identifiant du dispositif (non vide requis).
         /// - `signature` : Signature pour vérifier l'intégrité (doit être égale au hash Blake2-128 du payload).
+        ///
+        /// L'événement `MessageSent` est omis pour un dispositif enregistré en mode silencieux ;
+        /// les dispositifs non enregistrés émettent toujours l'événement.
         #[pallet::weight(10_000)]
         pub fn submit_iot_data(
             origin: OriginFor<T>,
@@ -149,7 +250,8 @@ pub mod pallet {
             signature: Vec<u8>,
         ) -> DispatchResult {
             let _sender = ensure_signed(origin)?;
-            let config = InteropConfigStorage::<T>::get();
+            T::EmergencyHalt::ensure_running()?;
+            let config = IotConfigStorage::<T>::get();
             ensure!(
                 payload.len() as u32 <= config.max_payload_length,
                 Error::<T>::PayloadTooLong
@@ -158,6 +260,7 @@ pub mod pallet {
             // Vérification cryptographique : la signature doit correspondre au hash Blake2-128 du payload.
             ensure!(Self::verify_signature(&payload, &signature), Error::<T>::VerificationFailed);
             let timestamp = Self::current_timestamp();
+            let emit_events = Self::device_emits_events(&device_id).unwrap_or(true);
             let record = IotRecord {
                 id,
                 payload: payload.clone(),
@@ -165,11 +268,11 @@ pub mod pallet {
                 timestamp,
                 signature,
             };
-            <IotData<T>>::insert(id, record);
-            <IotHistory<T>>::mutate(|history| {
-                history.push((timestamp, id, b"Submit".to_vec(), payload.clone()))
-            });
-            Self::deposit_event(Event::MessageSent(id, payload));
+            Self::store_record(record);
+            Self::record_history(timestamp, id, b"Submit", &payload);
+            if emit_events {
+                Self::deposit_event(Event::MessageSent(id, payload));
+            }
             Ok(())
         }
 
@@ -177,18 +280,18 @@ pub mod pallet {
         ///
         /// - `new_config` : Nouvelle configuration en bytes.
         /// - `details` : Détails ou justification de la mise à jour.
+        ///
+        /// Réservé à `ConfigOrigin`.
         #[pallet::weight(10_000)]
         pub fn update_config(
             origin: OriginFor<T>,
             new_config: Vec<u8>,
             details: Vec<u8>,
         ) -> DispatchResult {
-            let _sender = ensure_signed(origin)?;
+            T::ConfigOrigin::ensure_origin(origin).map_err(|_| Error::<T>::NotAuthorized)?;
             ensure!(!new_config.is_empty(), Error::<T>::MessageProcessingError);
             let timestamp = Self::current_timestamp();
-            <IotHistory<T>>::mutate(|history| {
-                history.push((timestamp, 0, b"ConfigUpdate".to_vec(), details.clone()))
-            });
+            Self::record_history(timestamp, 0, b"ConfigUpdate", &details);
             Self::deposit_event(Event::ConfigUpdated(new_config, details));
             Ok(())
         }
@@ -202,8 +305,8 @@ pub mod pallet {
             new_max_payload: u32,
         ) -> DispatchResult {
             ensure_root(origin)?;
-            let old = InteropConfigStorage::<T>::get();
-            InteropConfigStorage::<T>::put(InteropConfig {
+            let old = IotConfigStorage::<T>::get();
+            IotConfigStorage::<T>::put(IotConfig {
                 base_timeout: new_timeout,
                 max_payload_length: new_max_payload,
             });
@@ -217,25 +320,91 @@ pub mod pallet {
         pub fn prune_history(origin: OriginFor<T>, max_entries: usize) -> DispatchResult {
             ensure_root(origin)?;
             <IotHistory<T>>::mutate(|history| {
-                if history.len() > max_entries {
-                    *history = history.split_off(history.len() - max_entries);
-                }
+                let excess = history.len().saturating_sub(max_entries);
+                let mut index = 0usize;
+                history.retain(|_| {
+                    index += 1;
+                    index > excess
+                });
             });
             Ok(())
         }
     }
 
     impl<T: Config> Pallet<T> {
+        /// Stocke `record` dans `IotData` et évince les enregistrements les plus anciens au-delà de
+        /// `MaxStoredRecords`. Un identifiant déjà présent est remplacé sans changer de rang.
+        fn store_record(record: IotRecord) {
+            let id = record.id;
+            let is_new = !<IotData<T>>::contains_key(id);
+            <IotData<T>>::insert(id, record);
+            if !is_new {
+                return;
+            }
+            let max = T::MaxStoredRecords::get() as usize;
+            let evicted = <RecordQueue<T>>::mutate(|queue| {
+                queue.push(id);
+                let excess = queue.len().saturating_sub(max);
+                queue.drain(..excess).collect::<Vec<_>>()
+            });
+            for old in evicted {
+                <IotData<T>>::remove(old);
+                Self::deposit_event(Event::RecordEvicted(old));
+            }
+        }
+
+        /// Ajoute une entrée au journal d'audit, en évinçant l'entrée la plus ancienne si le journal
+        /// est plein. `operation` et `details` sont tronqués à la taille de `HistoryBytes`.
+        fn record_history(timestamp: u64, id: u64, operation: &[u8], details: &[u8]) {
+            let entry = (
+                timestamp,
+                id,
+                BoundedVec::truncate_from(operation.to_vec()),
+                BoundedVec::truncate_from(details.to_vec()),
+            );
+            <IotHistory<T>>::mutate(|history| {
+                if let Err(entry) = history.try_push(entry) {
+                    if !history.is_empty() {
+                        history.remove(0);
+                        let _ = history.try_push(entry);
+                    }
+                }
+            });
+        }
+
+        /// Borne le journal non borné de la version 0 en ne conservant que les `MaxHistoryLen`
+        /// entrées les plus récentes, dont les champs d'octets sont tronqués à `HistoryBytes`.
+        fn migrate_history_to_v1() {
+            let _ = <IotHistory<T>>::translate::<Vec<LegacyIotHistoryEntry>, _>(|legacy| {
+                legacy.map(|mut entries| {
+                    let excess = entries.len().saturating_sub(T::MaxHistoryLen::get() as usize);
+                    BoundedVec::truncate_from(
+                        entries
+                            .split_off(excess)
+                            .into_iter()
+                            .map(|(timestamp, id, operation, details)| {
+                                (timestamp, id, BoundedVec::truncate_from(operation), BoundedVec::truncate_from(details))
+                            })
+                            .collect(),
+                    )
+                })
+            });
+        }
+
+        /// Retourne le drapeau `emit_events` d'un dispositif, ou `None` s'il n'est pas enregistré.
+        pub fn device_emits_events(device_id: &[u8]) -> Option<bool> {
+            <Devices<T>>::get(device_id).map(|settings| settings.emit_events)
+        }
+
         /// Vérifie la signature du message en comparant le hash Blake2-128 du payload avec la signature.
         fn verify_signature(payload: &Vec<u8>, signature: &Vec<u8>) -> bool {
             let hash = sp_io::hashing::blake2_128(&payload);
             signature.len() == 16 && signature == &hash.to_vec()
         }
 
-        /// Retourne un horodatage fixe pour les tests.
-        /// En production, remplacez par l'appel à `pallet_timestamp` pour obtenir le temps réel.
+        /// Retourne l'horodatage courant fourni par `T::TimeSource`.
         fn current_timestamp() -> u64 {
-            1_640_000_000
+            <T::TimeSource as nodara_time::TimeSource>::now()
         }
     }
 }
@@ -268,7 +437,17 @@ mod tests {
     parameter_types! {
         pub const BlockHashCount: u64 = 250;
         pub const MaxPayloadLength: u32 = 512;
-        pub const BaseTimeout: u64 = 300,
+        pub const BaseTimeout: u64 = 300;
+        pub const ExpectedBlockTime: u64 = 6_000;
+        pub const GenesisTime: u64 = 1_700_000_000;
+        pub const MaxDevices: u32 = 2;
+        pub const MaxStoredRecords: u32 = 3;
+        pub const MaxHistoryLen: u32 = 8;
+    }
+
+    frame_support::ord_parameter_types! {
+        /// Seul compte habilité à appeler `update_config` dans les tests.
+        pub const ConfigAdmin: u64 = 1;
     }
 
     impl system::Config for Test {
@@ -285,7 +464,7 @@ mod tests {
         type AccountId = u64;
         type Lookup = IdentityLookup<Self::AccountId>;
         type Header = Header;
-        type RuntimeEvent = ();
+        type RuntimeEvent = RuntimeEvent;
         type BlockHashCount = BlockHashCount;
         type Version = ();
         type PalletInfo = ();
@@ -299,9 +478,15 @@ mod tests {
     }
 
     impl pallet::Config for Test {
-        type RuntimeEvent = ();
+        type RuntimeEvent = RuntimeEvent;
         type MaxPayloadLength = MaxPayloadLength;
         type BaseTimeout = BaseTimeout;
+        type TimeSource = nodara_time::BlockTimeSource<Test, ExpectedBlockTime, GenesisTime>;
+        type ConfigOrigin = frame_system::EnsureSignedBy<ConfigAdmin, u64>;
+        type MaxDevices = MaxDevices;
+        type MaxStoredRecords = MaxStoredRecords;
+        type MaxHistoryLen = MaxHistoryLen;
+        type EmergencyHalt = ();
     }
 
     #[test]
@@ -365,6 +550,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn update_config_requires_config_origin() {
+        new_test_ext().execute_with(|| {
+            let history_len = || IotBridgeModule::iot_history().len();
+            assert_err!(
+                IotBridgeModule::update_config(system::RawOrigin::Signed(2).into(), b"Cfg".to_vec(), b"bogus".to_vec()),
+                Error::<Test>::NotAuthorized
+            );
+            assert_err!(
+                IotBridgeModule::update_config(system::RawOrigin::Root.into(), b"Cfg".to_vec(), b"bogus".to_vec()),
+                Error::<Test>::NotAuthorized
+            );
+            assert_eq!(history_len(), 0);
+
+            assert_ok!(IotBridgeModule::update_config(system::RawOrigin::Signed(ConfigAdmin::get()).into(), b"Cfg".to_vec(), b"details".to_vec()));
+            assert_eq!(history_len(), 1);
+        });
+    }
+
     #[test]
     fn update_config_should_work() {
         let origin = system::RawOrigin::Signed(1).into();
@@ -373,7 +577,7 @@ mod tests {
         assert_ok!(IotBridgeModule::update_config(origin, new_config.clone(), details.clone()));
         let history = IotBridgeModule::iot_history();
         let config_updates: Vec<_> = history.into_iter().filter(|(_, id, op, _)| {
-            *id == 0 && op == b"ConfigUpdate".to_vec()
+            *id == 0 && op.as_slice() == b"ConfigUpdate"
         }).collect();
         assert!(!config_updates.is_empty());
     }
@@ -381,11 +585,11 @@ mod tests {
     #[test]
     fn update_config_params_should_work() {
         let root_origin = system::RawOrigin::Root.into();
-        let old_config = IotBridgeModule::interop_config();
+        let old_config = IotBridgeModule::iot_config();
         let new_timeout = old_config.base_timeout + 100;
         let new_max_payload = old_config.max_payload_length + 100;
         assert_ok!(IotBridgeModule::update_config_params(root_origin, new_timeout, new_max_payload));
-        let new_config = IotBridgeModule::interop_config();
+        let new_config = IotBridgeModule::iot_config();
         assert_eq!(new_config.base_timeout, new_timeout);
         assert_eq!(new_config.max_payload_length, new_max_payload);
     }
@@ -405,4 +609,148 @@ mod tests {
         assert_eq!(history_after.len(), 1);
         assert!(len_before > 1);
     }
+    pub fn new_test_ext() -> sp_io::TestExternalities {
+        let storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+        storage.into()
+    }
+
+    #[test]
+    fn silent_device_submissions_emit_no_event() {
+        new_test_ext().execute_with(|| {
+            // Les événements ne sont pas enregistrés au bloc 0.
+            System::set_block_number(1);
+            assert_ok!(IotBridgeModule::update_config_params(system::RawOrigin::Root.into(), BaseTimeout::get(), MaxPayloadLength::get()));
+            let user = || -> <Test as system::Config>::RuntimeOrigin { system::RawOrigin::Signed(1).into() };
+            assert_ok!(IotBridgeModule::register_device(user(), b"Sensor".to_vec(), false));
+            assert_ok!(IotBridgeModule::register_device(user(), b"Gateway".to_vec(), true));
+            assert_err!(IotBridgeModule::register_device(user(), b"Sensor".to_vec(), true), Error::<Test>::DeviceAlreadyRegistered);
+            assert_eq!(IotBridgeModule::device_emits_events(b"Sensor"), Some(false));
+            assert_eq!(IotBridgeModule::device_emits_events(b"Gateway"), Some(true));
+            assert_eq!(IotBridgeModule::device_emits_events(b"Unknown"), None);
+
+            let payload = b"Reading".to_vec();
+            let signature = sp_io::hashing::blake2_128(&payload).to_vec();
+            assert_ok!(IotBridgeModule::submit_iot_data(user(), 1, payload.clone(), b"Sensor".to_vec(), signature.clone()));
+            assert_ok!(IotBridgeModule::submit_iot_data(user(), 2, payload.clone(), b"Gateway".to_vec(), signature));
+
+            let sent: Vec<u64> = System::events()
+                .into_iter()
+                .filter_map(|record| match record.event {
+                    RuntimeEvent::IotBridgeModule(Event::MessageSent(id, _)) => Some(id),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(sent, vec![2]);
+            // Le dispositif silencieux conserve ses données et son historique.
+            assert!(IotBridgeModule::iot_data(1).is_some());
+            assert!(IotBridgeModule::iot_history().iter().any(|(_, id, op, _)| *id == 1 && op.as_slice() == b"Submit"));
+        });
+    }
+
+    #[test]
+    fn records_are_stamped_from_the_block_number() {
+        new_test_ext().execute_with(|| {
+            let payload = b"Reading".to_vec();
+            let signature = sp_io::hashing::blake2_128(&payload).to_vec();
+            for (block, id) in [(5, 1), (6, 2)] {
+                System::set_block_number(block);
+                assert_ok!(IotBridgeModule::submit_iot_data(
+                    system::RawOrigin::Signed(1).into(), id, payload.clone(), b"Sensor".to_vec(), signature.clone()
+                ));
+            }
+            let stamps: Vec<u64> = [1, 2].iter().map(|id| IotBridgeModule::iot_data(id).unwrap().timestamp).collect();
+            assert_eq!(stamps, vec![GenesisTime::get() + 30, GenesisTime::get() + 36]);
+        });
+    }
+
+    #[test]
+    fn device_registration_is_capped() {
+        new_test_ext().execute_with(|| {
+            let user = || -> <Test as system::Config>::RuntimeOrigin { system::RawOrigin::Signed(1).into() };
+            assert_ok!(IotBridgeModule::register_device(user(), b"Sensor".to_vec(), true));
+            assert_ok!(IotBridgeModule::register_device(user(), b"Gateway".to_vec(), true));
+            assert_err!(IotBridgeModule::register_device(user(), b"Camera".to_vec(), true), Error::<Test>::TooManyDevices);
+            assert_eq!(IotBridgeModule::device_count(), MaxDevices::get());
+            assert_eq!(IotBridgeModule::devices(b"Camera".to_vec()), None);
+        });
+    }
+
+    #[test]
+    fn oldest_records_are_evicted_beyond_max_stored() {
+        new_test_ext().execute_with(|| {
+            System::set_block_number(1);
+            assert_ok!(IotBridgeModule::update_config_params(system::RawOrigin::Root.into(), BaseTimeout::get(), MaxPayloadLength::get()));
+            let payload = b"Reading".to_vec();
+            let signature = sp_io::hashing::blake2_128(&payload).to_vec();
+            for id in 1..=5u64 {
+                assert_ok!(IotBridgeModule::submit_iot_data(system::RawOrigin::Signed(1).into(), id, payload.clone(), b"Sensor".to_vec(), signature.clone()));
+            }
+            // Une nouvelle soumission d'un identifiant conservé ne provoque pas d'éviction.
+            assert_ok!(IotBridgeModule::submit_iot_data(system::RawOrigin::Signed(1).into(), 4, payload, b"Sensor".to_vec(), signature));
+
+            assert_eq!(IotBridgeModule::record_queue(), vec![3, 4, 5]);
+            assert!(IotBridgeModule::iot_data(1).is_none());
+            assert!(IotBridgeModule::iot_data(2).is_none());
+            assert!((3..=5u64).all(|id| IotBridgeModule::iot_data(id).is_some()));
+            let evicted: Vec<u64> = System::events()
+                .into_iter()
+                .filter_map(|record| match record.event {
+                    RuntimeEvent::IotBridgeModule(Event::RecordEvicted(id)) => Some(id),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(evicted, vec![1, 2]);
+        });
+    }
+
+    #[test]
+    fn history_is_bounded_and_evicts_the_oldest_entries() {
+        new_test_ext().execute_with(|| {
+            for n in 0..10u8 {
+                assert_ok!(IotBridgeModule::update_config(system::RawOrigin::Signed(1).into(), b"Cfg".to_vec(), vec![n]));
+            }
+            let details: Vec<Vec<u8>> = IotBridgeModule::iot_history().into_iter().map(|(_, _, _, details)| details.into_inner()).collect();
+            assert_eq!(details, (2..10u8).map(|n| vec![n]).collect::<Vec<_>>());
+
+            assert_ok!(IotBridgeModule::prune_history(system::RawOrigin::Root.into(), 3));
+            let details: Vec<Vec<u8>> = IotBridgeModule::iot_history().into_iter().map(|(_, _, _, details)| details.into_inner()).collect();
+            assert_eq!(details, vec![vec![7], vec![8], vec![9]]);
+        });
+    }
+
+    #[test]
+    fn history_entries_have_a_bounded_encoding() {
+        use frame_support::pallet_prelude::MaxEncodedLen;
+        // Horodatage, identifiant, puis deux champs d'octets : préfixe compact de 2 octets et 256 octets.
+        assert_eq!(IotHistoryEntry::max_encoded_len(), 8 + 8 + 2 * (2 + 256));
+        new_test_ext().execute_with(|| {
+            assert_ok!(IotBridgeModule::update_config(system::RawOrigin::Signed(1).into(), b"Cfg".to_vec(), vec![7u8; 300]));
+            let (_, _, operation, details) = IotBridgeModule::iot_history().last().cloned().expect("entrée journalisée");
+            assert_eq!(operation.as_slice(), b"ConfigUpdate");
+            assert_eq!(details.into_inner(), vec![7u8; 256]);
+        });
+    }
+
+    #[test]
+    fn history_migration_keeps_the_most_recent_legacy_entries() {
+        use frame_support::traits::{Hooks, StorageVersion};
+        new_test_ext().execute_with(|| {
+            // Encodage de la version 0 : `Vec` non borné, plus long que `MaxHistoryLen`.
+            let legacy: Vec<LegacyIotHistoryEntry> = (0..10u64).map(|id| (id, id, b"Submit".to_vec(), vec![1u8; 300])).collect();
+            frame_support::storage::unhashed::put(&IotHistory::<Test>::hashed_key(), &legacy);
+            assert!(IotHistory::<Test>::try_get().is_err());
+
+            // Les huit entrées les plus récentes, aux détails tronqués à 256 octets.
+            let expected: Vec<IotHistoryEntry> = (2..10u64)
+                .map(|id| (id, id, BoundedVec::truncate_from(b"Submit".to_vec()), BoundedVec::truncate_from(vec![1u8; 256])))
+                .collect();
+            IotBridgeModule::on_runtime_upgrade();
+            assert_eq!(IotBridgeModule::iot_history().into_inner(), expected);
+            assert_eq!(StorageVersion::get::<IotBridgeModule>(), StorageVersion::new(1));
+
+            // Une seconde mise à niveau ne modifie plus rien.
+            IotBridgeModule::on_runtime_upgrade();
+            assert_eq!(IotBridgeModule::iot_history().into_inner(), expected);
+        });
+    }
 }
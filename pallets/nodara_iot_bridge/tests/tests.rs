@@ -38,6 +38,12 @@ mod tests {
         type RuntimeEvent = ();
         type MaxPayloadLength = sp_runtime::traits::ConstU32<256>;
         type BaseTimeout = sp_runtime::traits::ConstU64<30>;
+        type TimeSource = ();
+        type ConfigOrigin = frame_system::EnsureRoot<u64>;
+        type MaxDevices = sp_runtime::traits::ConstU32<100>;
+        type MaxStoredRecords = sp_runtime::traits::ConstU32<1_000>;
+        type MaxHistoryLen = sp_runtime::traits::ConstU32<1_000>;
+        type EmergencyHalt = ();
     }
 
     pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -12,13 +12,17 @@ use frame_support::{
     transactional,
 };
 use frame_system::pallet_prelude::*;
+use nodara_emergency::HaltGuard;
 use sp_std::vec::Vec;
 use sp_runtime::RuntimeDebug;
 use parity_scale_codec::{Encode, Decode};
 use scale_info::TypeInfo;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Structure représentant un enregistrement d'ajustement de liquidité.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LiquidityRecord {
     /// Horodatage de l'opération.
     pub timestamp: u64,
@@ -32,6 +36,7 @@ pub struct LiquidityRecord {
 
 /// État global du module de liquidité.
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, Default, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LiquidityState {
     /// Niveau de liquidité actuel.
     pub current_level: u32,
@@ -39,6 +44,18 @@ pub struct LiquidityState {
     pub history: Vec<LiquidityRecord>,
 }
 
+/// Position du niveau de liquidité par rapport à la bande cible.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
+pub enum BandPosition {
+    /// Sous `target_low`.
+    Below,
+    /// Entre `target_low` et `target_high` inclus.
+    #[default]
+    Within,
+    /// Au-dessus de `target_high`.
+    Above,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -56,9 +73,11 @@ pub mod pallet {
         /// Niveau de liquidité de base pour l'initialisation.
         #[pallet::constant]
         type BaselineLiquidity: Get<u32>;
-        /// Facteur de lissage pour le calcul de l'ajustement.
+        /// Facteur de lissage pour le calcul de l'ajustement (ne doit pas être zéro, vérifié à la genèse).
         #[pallet::constant]
         type SmoothingFactor: Get<u32>;
+        /// Arrêt d'urgence global consulté par chaque appel signé ; `()` le désactive.
+        type EmergencyHalt: nodara_emergency::HaltGuard;
     }
 
     /// Stockage de l'état de liquidité.
@@ -66,6 +85,26 @@ pub mod pallet {
     #[pallet::getter(fn liquidity_state)]
     pub type LiquidityStateStorage<T: Config> = StorageValue<_, LiquidityState, ValueQuery>;
 
+    #[pallet::type_value]
+    pub fn DefaultTargetHigh() -> u32 {
+        u32::MAX
+    }
+
+    /// Borne basse de la bande de liquidité cible (incluse).
+    #[pallet::storage]
+    #[pallet::getter(fn target_low)]
+    pub type TargetLow<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Borne haute de la bande de liquidité cible (incluse) ; sans bande définie, aucune alerte.
+    #[pallet::storage]
+    #[pallet::getter(fn target_high)]
+    pub type TargetHigh<T: Config> = StorageValue<_, u32, ValueQuery, DefaultTargetHigh>;
+
+    /// Dernière position connue du niveau par rapport à la bande, pour n'alerter qu'une fois par sortie.
+    #[pallet::storage]
+    #[pallet::getter(fn band_position)]
+    pub type LastBandPosition<T: Config> = StorageValue<_, BandPosition, ValueQuery>;
+
     /// Configuration de genèse pour pré‑initialiser l'état de liquidité.
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
@@ -82,6 +121,7 @@ pub mod pallet {
     #[pallet::genesis_build]
     impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
         fn build(&self) {
+            assert!(T::SmoothingFactor::get() != 0, "SmoothingFactor ne peut pas être nul");
             if let Some(state) = &self.liquidity_state {
                 <LiquidityStateStorage<T>>::put(state.clone());
             } else {
@@ -107,6 +147,10 @@ pub mod pallet {
         /// Événement émis lors d'une mise à jour de liquidité.
         /// (niveau précédent, nouveau niveau, métrique d'ajustement)
         LiquidityUpdated(u32, u32, u32),
+        /// Le niveau de liquidité est sorti de la bande cible. (niveau, borne basse, borne haute)
+        LiquidityOutOfBand(u32, u32, u32),
+        /// Bande de liquidité cible mise à jour. (borne basse, borne haute)
+        TargetBandUpdated(u32, u32),
     }
 
     #[pallet::error]
@@ -115,6 +159,8 @@ pub mod pallet {
         InvalidAdjustmentMetric,
         /// Le facteur de lissage ne peut pas être nul.
         ZeroSmoothingFactor,
+        /// La borne basse de la bande cible dépasse sa borne haute.
+        InvalidTargetBand,
     }
 
     #[pallet::call]
@@ -146,6 +192,7 @@ pub mod pallet {
         #[pallet::weight(10_000)]
         pub fn update_liquidity(origin: OriginFor<T>, adjustment_metric: u32) -> DispatchResult {
             ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
             ensure!(adjustment_metric > 0, Error::<T>::InvalidAdjustmentMetric);
 
             let smoothing = T::SmoothingFactor::get();
@@ -167,11 +214,48 @@ pub mod pallet {
             <LiquidityStateStorage<T>>::put(state);
 
             Self::deposit_event(Event::LiquidityUpdated(previous_level, new_level, adjustment_metric));
+            Self::check_target_band(new_level);
+            Ok(())
+        }
+
+        /// Définit la bande de liquidité cible `[target_low, target_high]`.
+        /// Seul Root peut appeler cette fonction ; l'alerte est réarmée pour la nouvelle bande.
+        #[pallet::weight(10_000)]
+        pub fn set_target_band(origin: OriginFor<T>, target_low: u32, target_high: u32) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(target_low <= target_high, Error::<T>::InvalidTargetBand);
+            TargetLow::<T>::put(target_low);
+            TargetHigh::<T>::put(target_high);
+            LastBandPosition::<T>::kill();
+            Self::deposit_event(Event::TargetBandUpdated(target_low, target_high));
             Ok(())
         }
     }
 
     impl<T: Config> Pallet<T> {
+        /// Position de `level` par rapport à la bande cible.
+        pub fn band_position_of(level: u32) -> BandPosition {
+            if level < TargetLow::<T>::get() {
+                BandPosition::Below
+            } else if level > TargetHigh::<T>::get() {
+                BandPosition::Above
+            } else {
+                BandPosition::Within
+            }
+        }
+
+        /// Émet `LiquidityOutOfBand` lorsque `level` sort de la bande cible (ou passe d'un côté à
+        /// l'autre), une seule fois par sortie. À appeler après chaque changement de niveau.
+        fn check_target_band(level: u32) {
+            let position = Self::band_position_of(level);
+            if position != LastBandPosition::<T>::get() {
+                LastBandPosition::<T>::put(position);
+                if position != BandPosition::Within {
+                    Self::deposit_event(Event::LiquidityOutOfBand(level, TargetLow::<T>::get(), TargetHigh::<T>::get()));
+                }
+            }
+        }
+
         /// Retourne un horodatage fixe.
         /// En production, remplacez par `pallet_timestamp` pour obtenir un temps réel.
         fn current_timestamp() -> u64 {
@@ -200,14 +284,14 @@ pub mod pallet {
                 UncheckedExtrinsic = UncheckedExtrinsic,
             {
                 System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-                LiquidityFlowModule: Pallet,
+                LiquidityFlowModule: crate::pallet::{Pallet, Call, Storage, Event<T>},
             }
         );
 
         parameter_types! {
             pub const BlockHashCount: u64 = 250;
             pub const BaselineLiquidity: u32 = 1000;
-            pub const SmoothingFactor: u32 = 10;
+            pub static SmoothingFactor: u32 = 10;
         }
 
         impl system::Config for Test {
@@ -224,7 +308,7 @@ pub mod pallet {
             type AccountId = u64;
             type Lookup = IdentityLookup<Self::AccountId>;
             type Header = Header;
-            type RuntimeEvent = ();
+            type RuntimeEvent = RuntimeEvent;
             type BlockHashCount = BlockHashCount;
             type Version = ();
             type PalletInfo = ();
@@ -238,9 +322,10 @@ pub mod pallet {
         }
 
         impl Config for Test {
-            type RuntimeEvent = ();
+            type RuntimeEvent = RuntimeEvent;
             type BaselineLiquidity = BaselineLiquidity;
             type SmoothingFactor = SmoothingFactor;
+            type EmergencyHalt = ();
         }
 
         #[test]
@@ -275,6 +360,81 @@ pub mod pallet {
                 Error::<Test>::InvalidAdjustmentMetric
             );
         }
+
+        #[test]
+        fn test_update_liquidity_fail_zero_smoothing_factor() {
+            SmoothingFactor::set(0);
+            assert_ok!(LiquidityFlowModule::initialize_state(system::RawOrigin::Root.into()));
+            assert_err!(
+                LiquidityFlowModule::update_liquidity(system::RawOrigin::Signed(1).into(), 50),
+                Error::<Test>::ZeroSmoothingFactor
+            );
+            assert_eq!(LiquidityFlowModule::liquidity_state().current_level, BaselineLiquidity::get());
+        }
+
+        #[test]
+        #[should_panic(expected = "SmoothingFactor ne peut pas être nul")]
+        fn genesis_rejects_zero_smoothing_factor() {
+            SmoothingFactor::set(0);
+            sp_io::TestExternalities::default().execute_with(|| {
+                GenesisBuild::<Test>::build(&GenesisConfig::<Test>::default());
+            });
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn liquidity_state_round_trips_through_json() {
+            let state = LiquidityState {
+                current_level: 1_200,
+                history: vec![LiquidityRecord { timestamp: 1_640_000_000, previous_level: 1_000, new_level: 1_200, adjustment_metric: 200 }],
+            };
+            let json = serde_json::to_string(&state).expect("sérialisation JSON");
+            let decoded: LiquidityState = serde_json::from_str(&json).expect("désérialisation JSON");
+            assert_eq!(decoded, state);
+        }
+
+        #[test]
+        fn leaving_the_target_band_alerts_once_per_crossing() {
+            sp_io::TestExternalities::default().execute_with(|| {
+                let band_alerts = || -> Vec<RuntimeEvent> {
+                    System::events()
+                        .into_iter()
+                        .map(|record| record.event)
+                        .filter(|event| matches!(event, RuntimeEvent::LiquidityFlowModule(Event::LiquidityOutOfBand(..))))
+                        .collect()
+                };
+                let update = |metric: u32| {
+                    assert_ok!(LiquidityFlowModule::update_liquidity(system::RawOrigin::Signed(1).into(), metric));
+                };
+                System::set_block_number(1);
+                assert_ok!(LiquidityFlowModule::initialize_state(system::RawOrigin::Root.into()));
+                assert_err!(
+                    LiquidityFlowModule::set_target_band(system::RawOrigin::Root.into(), 1_020, 1_010),
+                    Error::<Test>::InvalidTargetBand
+                );
+                assert_ok!(LiquidityFlowModule::set_target_band(system::RawOrigin::Root.into(), 1_010, 1_020));
+
+                // 1_000 -> 1_005 -> 1_006 : sous la bande, une seule alerte.
+                update(50);
+                update(10);
+                assert_eq!(band_alerts(), vec![RuntimeEvent::LiquidityFlowModule(Event::LiquidityOutOfBand(1_005, 1_010, 1_020))]);
+
+                // 1_006 -> 1_016 : retour dans la bande, sans alerte.
+                update(100);
+                assert_eq!(LiquidityFlowModule::band_position(), BandPosition::Within);
+                assert_eq!(band_alerts().len(), 1);
+
+                // 1_016 -> 1_026 -> 1_031 : au-dessus de la bande, une seule alerte.
+                update(100);
+                update(50);
+                assert_eq!(
+                    band_alerts(),
+                    vec![
+                        RuntimeEvent::LiquidityFlowModule(Event::LiquidityOutOfBand(1_005, 1_010, 1_020)),
+                        RuntimeEvent::LiquidityFlowModule(Event::LiquidityOutOfBand(1_026, 1_010, 1_020)),
+                    ]
+                );
+            });
+        }
     }
 }
-
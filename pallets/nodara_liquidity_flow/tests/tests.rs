@@ -38,6 +38,7 @@ mod tests {
         type RuntimeEvent = ();
         type BaselineLiquidity = sp_runtime::traits::ConstU32<1000>;
         type LiquiditySmoothingFactor = sp_runtime::traits::ConstU32<10>;
+        type EmergencyHalt = ();
     }
 
     pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -0,0 +1,74 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Nodara Log
+//!
+//! Macro de journalisation structurée commune aux modules Nodara, compatible `no_std`.
+//!
+//! Chaque crate utilisatrice déclare à sa racine une constante `LOG_TARGET` (par exemple
+//! `"runtime::nodara_biosphere"`), utilisée comme cible de tous ses messages :
+//!
+//! ```ignore
+//! pub const LOG_TARGET: &str = "runtime::nodara_biosphere";
+//!
+//! nodara_log!(info, "transition de phase {:?} -> {:?}", old_phase, new_phase);
+//! ```
+//!
+//! Les niveaux acceptés sont ceux de `log` : `error`, `warn`, `info`, `debug` et `trace`.
+
+#[doc(hidden)]
+pub use frame_support::log;
+
+/// Journalise un message au niveau donné avec la cible `crate::LOG_TARGET` de la crate appelante.
+#[macro_export]
+macro_rules! nodara_log {
+    ($level:ident, $($arg:tt)+) => {
+        $crate::log::$level!(target: crate::LOG_TARGET, $($arg)+)
+    };
+}
+
+/// Capture des messages journalisés, pour les tests.
+#[cfg(feature = "std")]
+pub mod testing {
+    use super::log;
+    use std::sync::Mutex;
+
+    static CAPTURED: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+    static LOGGER: CaptureLogger = CaptureLogger;
+
+    struct CaptureLogger;
+
+    impl log::Log for CaptureLogger {
+        fn enabled(&self, _metadata: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            if let Ok(mut captured) = CAPTURED.lock() {
+                captured.push((record.target().to_string(), record.args().to_string()));
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    /// Installe le logger de capture. Sans effet si un logger est déjà installé.
+    pub fn init() {
+        if log::set_logger(&LOGGER).is_ok() {
+            log::set_max_level(log::LevelFilter::Trace);
+        }
+    }
+
+    /// Messages capturés jusqu'ici pour `target`, dans l'ordre d'émission.
+    pub fn messages(target: &str) -> Vec<String> {
+        CAPTURED
+            .lock()
+            .map(|captured| {
+                captured
+                    .iter()
+                    .filter(|(t, _)| t == target)
+                    .map(|(_, message)| message.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
@@ -3,6 +3,13 @@ use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, account};
 use frame_system::RawOrigin;
 use sp_std::vec::Vec;
 use core::ops::Div;
+use frame_support::traits::Currency;
+
+/// Endows the benchmark buyer so that its buy orders can be escrowed.
+fn fund_buyer<T: pallet::Config>() {
+    let buyer: T::AccountId = account("user", 0, 0);
+    T::Currency::make_free_balance_be(&buyer, 1_000_000u32.into());
+}
 
 benchmarks! {
     register_asset {
@@ -16,6 +23,7 @@ benchmarks! {
     }
 
     place_order {
+        fund_buyer::<T>();
         let order = pallet::Order {
             id: 1,
             asset_id: 100,
@@ -24,6 +32,9 @@ benchmarks! {
             quantity: 10,
             account: account("user", 0, 0),
             timestamp: 1000,
+            expiry_block: None,
+            execution: pallet::ExecutionPolicy::GTC,
+            max_slippage_bps: None,
         };
     }: {
         <pallet::Pallet<T>>::place_order(RawOrigin::Signed(account("user", 0, 0)).into(), order.clone())?;
@@ -34,6 +45,7 @@ benchmarks! {
     }
 
     cancel_order {
+        fund_buyer::<T>();
         let order = pallet::Order {
             id: 1,
             asset_id: 100,
@@ -42,6 +54,9 @@ benchmarks! {
             quantity: 10,
             account: account("user", 0, 0),
             timestamp: 1000,
+            expiry_block: None,
+            execution: pallet::ExecutionPolicy::GTC,
+            max_slippage_bps: None,
         };
         <pallet::Pallet<T>>::place_order(RawOrigin::Signed(account("user", 0, 0)).into(), order.clone())?;
     }: {
@@ -53,6 +68,7 @@ benchmarks! {
     }
 
     execute_trade {
+        fund_buyer::<T>();
         // Register asset
         let metadata: Vec<u8> = b"Asset Metadata".to_vec();
         <pallet::Pallet<T>>::register_asset(RawOrigin::Signed(account("user", 0, 0)).into(), 100, metadata)?;
@@ -65,6 +81,9 @@ benchmarks! {
             quantity: 10,
             account: account("user", 0, 0),
             timestamp: 1000,
+            expiry_block: None,
+            execution: pallet::ExecutionPolicy::GTC,
+            max_slippage_bps: None,
         };
         let sell_order = pallet::Order {
             id: 2,
@@ -74,6 +93,9 @@ benchmarks! {
             quantity: 10,
             account: account("user", 1, 0),
             timestamp: 1000,
+            expiry_block: None,
+            execution: pallet::ExecutionPolicy::GTC,
+            max_slippage_bps: None,
         };
         <pallet::Pallet<T>>::place_order(RawOrigin::Signed(account("user", 0, 0)).into(), buy_order)?;
         <pallet::Pallet<T>>::place_order(RawOrigin::Signed(account("user", 1, 0)).into(), sell_order)?;
@@ -11,6 +11,11 @@
 //! - **Asset Registration:** Secure registration and management of asset metadata.
 //! - **Order Placement and Matching:** Buy and sell order placement with a matching engine.
 //! - **Trade Execution:** Secure execution of trades with proper asset and fund transfers.
+//! - **Escrow:** Resting buy orders reserve their value, returned to their owner when the order
+//!   is cancelled or expires; governance decides where the escrow of force-cancelled orders goes
+//!   (`sweep_escrow`).
+//! - **Reputation Fee Discounts:** The taker's trade fee can optionally be discounted by its
+//!   reputation tier, never below `MinTradeFee`.
 //! - **Audit Logging:** Immutable logging of all marketplace events for traceability.
 //! - **DAO Governance Integration:** On-chain proposals for updating marketplace parameters.
 //!
@@ -18,24 +23,78 @@
 
 pub use pallet::*;
 
+use frame_support::dispatch::DispatchResult;
+
+/// Routes revenue collected by a pallet (e.g. trade fees) to its destinations.
+///
+/// Implemented by the runtime, which knows the receiving pallets. The pallet has already
+/// withdrawn `amount` from the payer, so the router must credit it wherever it should end up.
+pub trait RevenueRouter {
+    /// Routes `amount` of collected revenue.
+    fn route_revenue(amount: u128) -> DispatchResult;
+}
+
+/// Default router: revenue is not routed anywhere.
+impl RevenueRouter for () {
+    fn route_revenue(_amount: u128) -> DispatchResult {
+        Ok(())
+    }
+}
+
+/// Reference registry of asset definitions (e.g. the bridge's supported assets) against which
+/// marketplace registrations are cross-checked.
+pub trait AssetDefinitionSource {
+    /// Returns the decimals of the asset known under `symbol`, if the registry defines it.
+    fn decimals_of(symbol: &[u8]) -> Option<u8>;
+}
+
+/// Default source: no reference registry, registrations are not cross-checked.
+impl AssetDefinitionSource for () {
+    fn decimals_of(_symbol: &[u8]) -> Option<u8> {
+        None
+    }
+}
+
+/// Source of reputation scores consulted to discount the trade fee paid by a taker and to gate
+/// order placement.
+///
+/// The `()` implementation knows no scores, which leaves every fee undiscounted.
+pub trait ReputationSource<AccountId> {
+    /// Current reputation score of `who`, or `None` if the account has no record.
+    fn reputation_of(who: &AccountId) -> Option<u32>;
+}
+
+impl<AccountId> ReputationSource<AccountId> for () {
+    fn reputation_of(_who: &AccountId) -> Option<u32> {
+        None
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
         dispatch::DispatchResult, pallet_prelude::*,
-        traits::Get,
+        traits::{BalanceStatus, Currency, ExistenceRequirement, Get, ReservableCurrency, WithdrawReasons},
     };
     use frame_system::pallet_prelude::*;
+    use nodara_emergency::HaltGuard;
     use parity_scale_codec::{Decode, Encode};
     use scale_info::TypeInfo;
     use sp_std::vec::Vec;
     use sp_std::collections::btree_map::BTreeMap;
+    use sp_runtime::traits::{SaturatedConversion, Saturating, Zero};
+
+    /// Balance type of the currency used for order escrow.
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
     /// Structure representing an asset registered on the marketplace.
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     pub struct Asset {
         /// Unique asset identifier.
         pub id: u64,
-        /// Metadata associated with the asset (e.g. JSON encoded data).
+        /// Metadata associated with the asset, as `key=value;` pairs readable on-chain
+        /// through `metadata_field`.
         pub metadata: Vec<u8>,
         /// Owner of the asset.
         pub owner: u64, // For simplicity, using u64. In production, use T::AccountId.
@@ -48,6 +107,17 @@ pub mod pallet {
         Sell,
     }
 
+    /// How an order interacts with the book when it is placed.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum ExecutionPolicy {
+        /// Good-til-cancelled: the order rests on the book until cancelled, traded or expired.
+        GTC,
+        /// Fill-or-kill: the order is matched in full immediately or rejected without touching the book.
+        FOK,
+        /// Immediate-or-cancel: whatever matches immediately is filled, the remainder is dropped.
+        IOC,
+    }
+
     /// Structure representing an order in the marketplace.
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     pub struct Order {
@@ -65,6 +135,15 @@ pub mod pallet {
         pub account: u64,
         /// Timestamp of order placement.
         pub timestamp: u64,
+        /// Block at which the order is automatically removed, if any.
+        pub expiry_block: Option<u64>,
+        /// Execution policy applied when the order is placed.
+        pub execution: ExecutionPolicy,
+        /// Maximum adverse deviation, in basis points of `price`, allowed for the execution price
+        /// of any fill when the order is matched on placement (`FOK`/`IOC`): above `price` for a
+        /// buy, below it for a sell. Fills at a better price than `price` are always accepted.
+        /// When set, `price` is the expected price rather than a hard limit.
+        pub max_slippage_bps: Option<u32>,
     }
 
     /// Structure representing a trade execution.
@@ -96,6 +175,38 @@ pub mod pallet {
         /// Base fee for executing a trade.
         #[pallet::constant]
         type BaseTradeFee: Get<u32>;
+        /// Destination of collected trade fees.
+        type RevenueRouter: crate::RevenueRouter;
+        /// Origin allowed to pause and unpause the marketplace.
+        type GovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Maximum number of order expiries processed in a single block.
+        #[pallet::constant]
+        type MaxExpiriesPerBlock: Get<u32>;
+        /// Currency in which buy orders are escrowed.
+        type Currency: ReservableCurrency<Self::AccountId>;
+        /// Reserve fund account receiving escrow swept to the treasury.
+        type ReserveFundAccount: Get<Self::AccountId>;
+        /// Registry that `register_asset` cross-checks `symbol`/`decimals` metadata against.
+        /// Use `()` to disable the check.
+        type AssetDefinitions: crate::AssetDefinitionSource;
+        /// Reputation scores used to discount the taker's trade fee; `()` disables discounts.
+        type ReputationSource: crate::ReputationSource<Self::AccountId>;
+        /// Fee discount tiers as `(minimum score, discount in percent)`, sorted by ascending score.
+        /// The highest tier reached applies; an empty list disables discounts.
+        type FeeDiscountTiers: Get<Vec<(u32, u32)>>;
+        /// Lowest fee a discounted trade can be charged. Never raises a fee above `BaseTradeFee`.
+        #[pallet::constant]
+        type MinTradeFee: Get<u32>;
+        /// Minimum reputation score required to place an order; accounts without a score are
+        /// rejected. `None` (or `()`) disables the check.
+        type MinReputationToTrade: Get<Option<u32>>;
+        /// `(minimum, maximum)` order quantity in whole units of the asset, scaled through
+        /// `nodara_math::validate_amount` by the decimals that `AssetDefinitions` gives for the
+        /// `symbol` of the registered asset. While set, orders on assets without such vetted
+        /// decimals are rejected. `None` (or `()`) disables the check.
+        type OrderAmountLimits: Get<Option<(u128, u128)>>;
+        /// Global emergency halt checked by every signed call; `()` disables it.
+        type EmergencyHalt: nodara_emergency::HaltGuard;
     }
 
     /// Storage for registered assets.
@@ -118,11 +229,43 @@ pub mod pallet {
     #[pallet::getter(fn order_book)]
     pub type OrderBook<T: Config> = StorageMap<_, Blake2_128Concat, u64, Vec<u64>, ValueQuery>;
 
+    /// Orders scheduled to expire at a given block. Entries not processed at their block
+    /// (because of `MaxExpiriesPerBlock`) are carried over to the next block.
+    #[pallet::storage]
+    #[pallet::getter(fn order_expiries)]
+    pub type OrderExpiries<T: Config> = StorageMap<_, Blake2_128Concat, u64, Vec<(u64, OrderType)>, ValueQuery>;
+
+    /// Whether the marketplace is paused. While set, every extrinsic except `set_paused` fails.
+    #[pallet::storage]
+    #[pallet::getter(fn is_paused)]
+    pub type Paused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// Total trade fees collected since genesis, independent of where the router sends them.
+    #[pallet::storage]
+    #[pallet::getter(fn fees_accrued)]
+    pub type FeesAccrued<T: Config> = StorageValue<_, u128, ValueQuery>;
+
+    /// Account that placed each resting order, the only one allowed to cancel it.
+    #[pallet::storage]
+    #[pallet::getter(fn order_owner)]
+    pub type OrderOwners<T: Config> = StorageMap<_, Blake2_128Concat, u64, T::AccountId, OptionQuery>;
+
+    /// Funds reserved for a resting buy order (owner, amount). Released when the order trades,
+    /// expires or is cancelled by its owner, or moved by governance through `sweep_escrow`.
+    #[pallet::storage]
+    #[pallet::getter(fn escrow)]
+    pub type Escrows<T: Config> = StorageMap<_, Blake2_128Concat, u64, (T::AccountId, BalanceOf<T>), OptionQuery>;
+
     /// History of executed trades.
     #[pallet::storage]
     #[pallet::getter(fn trades_history)]
     pub type TradesHistory<T: Config> = StorageValue<_, Vec<Trade>, ValueQuery>;
 
+    /// Identifier assigned to the next trade produced by the matching engine.
+    #[pallet::storage]
+    #[pallet::getter(fn next_trade_id)]
+    pub type NextTradeId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -134,6 +277,18 @@ pub mod pallet {
         OrderCancelled(u64),
         /// Trade executed (trade ID, asset ID, quantity, price).
         TradeExecuted(u64, u64, u32, u32),
+        /// Order removed after reaching its expiry block (order ID).
+        OrderExpired(u64),
+        /// Trade fee collected and routed (trade ID, fee).
+        TradeFeeCollected(u64, u128),
+        /// Marketplace paused or unpaused (new state).
+        PauseStateChanged(bool),
+        /// Unfilled remainder of an immediate-or-cancel order dropped (order ID, quantity).
+        OrderRemainderCancelled(u64, u32),
+        /// Escrow of an order swept by governance (order ID, owner, amount, sent to the reserve fund).
+        EscrowSwept(u64, T::AccountId, BalanceOf<T>, bool),
+        /// Order book index of an asset rebuilt from the order maps (asset ID, number of orders).
+        OrderBookRebuilt(u64, u32),
     }
 
     #[pallet::error]
@@ -150,14 +305,66 @@ pub mod pallet {
         InsufficientOrderQuantity,
         /// Invalid order parameters.
         InvalidOrder,
+        /// The marketplace is paused.
+        Paused,
+        /// Not enough matching liquidity to fill a fill-or-kill order in full.
+        FillOrKillNotFilled,
+        /// The order has no escrowed funds.
+        NoEscrow,
+        /// The asset's `decimals` disagree with the reference registry's definition of its `symbol`.
+        AssetDefinitionConflict,
+        /// A fill would execute worse than the order's price by more than its `max_slippage_bps`.
+        SlippageExceeded,
+        /// The caller's reputation is below `MinReputationToTrade`.
+        ReputationTooLow,
+        /// The order quantity is below the minimum of `OrderAmountLimits`.
+        OrderBelowMinimum,
+        /// The order quantity is above the maximum of `OrderAmountLimits`.
+        OrderAboveMaximum,
+        /// `OrderAmountLimits` is set but `AssetDefinitions` defines no decimals for the asset's
+        /// `symbol`, so its quantities cannot be checked.
+        UnvettedAssetDecimals,
+        /// An order or escrow already exists under this order id.
+        OrderIdInUse,
+        /// Only the account that placed an order can cancel it.
+        NotOrderOwner,
+        /// The buy and sell orders of a trade are for different assets, or the trade price is
+        /// not between the sell price and the buy price.
+        OrdersDoNotCross,
     }
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            Self::sweep_expired_orders(n.saturated_into::<u64>())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), &'static str> {
+            Self::do_try_state()
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
+        /// Pauses or unpauses the marketplace (incident response).
+        ///
+        /// Read paths (getters, runtime API) and the order expiry sweep stay available while paused.
+        #[pallet::weight(10_000)]
+        pub fn set_paused(origin: OriginFor<T>, paused: bool) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            Paused::<T>::put(paused);
+            Self::deposit_event(Event::PauseStateChanged(paused));
+            Ok(())
+        }
+
         /// Registers a new asset in the marketplace.
+        ///
+        /// When the metadata carries a `symbol` known to `T::AssetDefinitions`, its `decimals`
+        /// field must match the registry's definition.
         #[pallet::weight(10_000)]
         pub fn register_asset(
             origin: OriginFor<T>,
@@ -165,6 +372,8 @@ pub mod pallet {
             metadata: Vec<u8>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::ensure_not_paused()?;
             ensure!(
                 metadata.len() as u32 <= T::MaxAssetMetadataLength::get(),
                 Error::<T>::AssetMetadataTooLong
@@ -173,6 +382,7 @@ pub mod pallet {
                 !Assets::<T>::contains_key(&asset_id),
                 Error::<T>::AssetAlreadyRegistered
             );
+            Self::ensure_consistent_definition(&metadata)?;
             let asset = Asset {
                 id: asset_id,
                 metadata: metadata.clone(),
@@ -184,62 +394,523 @@ pub mod pallet {
         }
 
         /// Places an order (buy or sell) for an asset.
+        ///
+        /// `GTC` orders rest on the book; a resting buy order reserves its value from the sender.
+        /// `FOK` and `IOC` orders are matched immediately against resting orders at a crossing
+        /// price, best price first, and never rest themselves.
+        ///
+        /// Fails with `ReputationTooLow` if the sender's reputation is below `MinReputationToTrade`,
+        /// and with `OrderBelowMinimum`/`OrderAboveMaximum` if the quantity is outside
+        /// `OrderAmountLimits` (`UnvettedAssetDecimals` if the asset's decimals are not vetted).
         #[pallet::weight(10_000)]
         pub fn place_order(
             origin: OriginFor<T>,
             order: Order,
         ) -> DispatchResult {
-            let _sender = ensure_signed(origin)?;
-            match order.order_type {
-                OrderType::Buy => <BuyOrders<T>>::insert(order.id, order.clone()),
-                OrderType::Sell => <SellOrders<T>>::insert(order.id, order.clone()),
-            };
-            OrderBook::<T>::mutate(order.asset_id, |orders| orders.push(order.id));
-            Self::deposit_event(Event::OrderPlaced(order.id, order.order_type, order.asset_id));
-            Ok(())
+            let sender = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::ensure_not_paused()?;
+            Self::ensure_reputation_to_trade(&sender)?;
+            Self::ensure_quantity_within_limits(&order)?;
+            match order.execution {
+                ExecutionPolicy::GTC => Self::rest_order(&sender, order),
+                ExecutionPolicy::FOK | ExecutionPolicy::IOC => Self::take_order(&sender, order),
+            }
         }
 
-        /// Cancels an existing order.
+        /// Cancels an existing order. Only the account that placed it can cancel it.
         #[pallet::weight(10_000)]
         pub fn cancel_order(
             origin: OriginFor<T>,
             order_id: u64,
             order_type: OrderType,
         ) -> DispatchResult {
-            let _sender = ensure_signed(origin)?;
+            let sender = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::ensure_not_paused()?;
+            let exists = match order_type {
+                OrderType::Buy => <BuyOrders<T>>::contains_key(&order_id),
+                OrderType::Sell => <SellOrders<T>>::contains_key(&order_id),
+            };
+            ensure!(exists, Error::<T>::OrderNotFound);
+            ensure!(OrderOwners::<T>::get(order_id).as_ref() == Some(&sender), Error::<T>::NotOrderOwner);
+            OrderOwners::<T>::remove(order_id);
             match order_type {
                 OrderType::Buy => {
-                    ensure!(<BuyOrders<T>>::contains_key(&order_id), Error::<T>::OrderNotFound);
                     <BuyOrders<T>>::remove(order_id);
+                    Self::release_escrow(order_id, None);
                 },
-                OrderType::Sell => {
-                    ensure!(<SellOrders<T>>::contains_key(&order_id), Error::<T>::OrderNotFound);
-                    <SellOrders<T>>::remove(order_id);
-                },
+                OrderType::Sell => <SellOrders<T>>::remove(order_id),
             };
             Self::deposit_event(Event::OrderCancelled(order_id));
             Ok(())
         }
 
-        /// Executes a trade by matching a buy order and a sell order.
+        /// Executes a trade by matching a resting buy order and a resting sell order.
+        ///
+        /// Both orders must be for `trade.asset_id`, and `trade.price` must lie between the sell
+        /// price and the buy price (`OrdersDoNotCross`). `trade.quantity` (at most the quantity
+        /// of each order) is paid to the owner of the sell order from the escrow of the buy order;
+        /// the escrow saved by trading below the buy price goes back to the buyer. Filled orders
+        /// leave the book, partially filled ones keep their remaining quantity.
+        ///
+        /// The sender is the taker: the `BaseTradeFee`, discounted by its reputation, is collected
+        /// and handed to `T::RevenueRouter`.
         #[pallet::weight(10_000)]
         pub fn execute_trade(
             origin: OriginFor<T>,
             trade: Trade,
         ) -> DispatchResult {
-            let _sender = ensure_signed(origin)?;
-            ensure!(<BuyOrders<T>>::contains_key(&trade.buy_order_id), Error::<T>::OrderNotFound);
-            ensure!(<SellOrders<T>>::contains_key(&trade.sell_order_id), Error::<T>::OrderNotFound);
-            // For simplicity, assume a direct match and remove the orders.
-            <BuyOrders<T>>::remove(trade.buy_order_id);
-            <SellOrders<T>>::remove(trade.sell_order_id);
-            <TradesHistory<T>>::mutate(|history| history.push(trade.clone()));
-            Self::deposit_event(Event::TradeExecuted(trade.id, trade.asset_id, trade.quantity, trade.price));
+            let sender = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::ensure_not_paused()?;
+            let mut buy = <BuyOrders<T>>::get(trade.buy_order_id).ok_or(Error::<T>::OrderNotFound)?;
+            let mut sell = <SellOrders<T>>::get(trade.sell_order_id).ok_or(Error::<T>::OrderNotFound)?;
+            ensure!(
+                buy.asset_id == trade.asset_id
+                    && sell.asset_id == trade.asset_id
+                    && sell.price <= trade.price
+                    && trade.price <= buy.price,
+                Error::<T>::OrdersDoNotCross
+            );
+            ensure!(
+                trade.quantity > 0 && trade.quantity <= buy.quantity && trade.quantity <= sell.quantity,
+                Error::<T>::InsufficientOrderQuantity
+            );
+
+            let seller = OrderOwners::<T>::get(sell.id).ok_or(Error::<T>::OrderNotFound)?;
+            Self::pay_from_escrow(buy.id, &seller, Self::trade_value(&trade))?;
+            Self::release_escrow(buy.id, Some(Self::total_value(buy.price - trade.price, trade.quantity)));
+
+            buy.quantity -= trade.quantity;
+            sell.quantity -= trade.quantity;
+            Self::store_remaining(buy);
+            Self::store_remaining(sell);
+            Self::settle_trade(&sender, trade)
+        }
+
+        /// Force-cancels a buy order if it is still resting and moves its escrow either to the
+        /// reserve fund (`to_treasury`) or back to its owner.
+        ///
+        /// Available while paused, for incident response.
+        #[pallet::weight(10_000)]
+        pub fn sweep_escrow(origin: OriginFor<T>, order_id: u64, to_treasury: bool) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            let (owner, amount) = Escrows::<T>::take(order_id).ok_or(Error::<T>::NoEscrow)?;
+            if let Some(order) = <BuyOrders<T>>::take(order_id) {
+                OrderOwners::<T>::remove(order_id);
+                OrderBook::<T>::mutate(order.asset_id, |orders| orders.retain(|id| *id != order_id));
+                Self::deposit_event(Event::OrderCancelled(order_id));
+            }
+            if to_treasury {
+                T::Currency::repatriate_reserved(&owner, &T::ReserveFundAccount::get(), amount, BalanceStatus::Free)?;
+            } else {
+                T::Currency::unreserve(&owner, amount);
+            }
+            Self::deposit_event(Event::EscrowSwept(order_id, owner, amount, to_treasury));
+            Ok(())
+        }
+
+        /// Rebuilds `OrderBook[asset_id]` from `BuyOrders` and `SellOrders`, repairing an index
+        /// that drifted from the orders it points to. Order ids are listed in ascending order.
+        ///
+        /// Scans both order maps, so it is Root-only and meant for repairs. Available while paused.
+        #[pallet::weight(10_000)]
+        pub fn rebuild_order_book(origin: OriginFor<T>, asset_id: u64) -> DispatchResult {
+            ensure_root(origin)?;
+            let mut ids: Vec<u64> = <BuyOrders<T>>::iter_values()
+                .chain(<SellOrders<T>>::iter_values())
+                .filter(|order| order.asset_id == asset_id)
+                .map(|order| order.id)
+                .collect();
+            ids.sort_unstable();
+            ids.dedup();
+            let count = ids.len() as u32;
+            if ids.is_empty() {
+                OrderBook::<T>::remove(asset_id);
+            } else {
+                OrderBook::<T>::insert(asset_id, ids);
+            }
+            Self::deposit_event(Event::OrderBookRebuilt(asset_id, count));
             Ok(())
         }
     }
 
     impl<T: Config> Pallet<T> {
+        /// Checks the marketplace invariants (`try_state` hook):
+        /// - every order in `BuyOrders`/`SellOrders` is stored under its own id, on its own side;
+        /// - every resting order is listed in the `OrderBook` of its asset and has an owner. The
+        ///   book may still hold ids of cancelled orders, which matching skips and
+        ///   `rebuild_order_book` drops;
+        /// - no escrow entry holds a zero amount.
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), &'static str> {
+            let buys = <BuyOrders<T>>::iter().map(|(id, order)| (id, order, OrderType::Buy));
+            let sells = <SellOrders<T>>::iter().map(|(id, order)| (id, order, OrderType::Sell));
+            for (id, order, side) in buys.chain(sells) {
+                ensure!(order.id == id, "order stored under another id");
+                ensure!(order.order_type == side, "order stored on the wrong side");
+                ensure!(OrderBook::<T>::get(order.asset_id).contains(&id), "resting order missing from the order book");
+                ensure!(OrderOwners::<T>::contains_key(id), "resting order without owner");
+            }
+            ensure!(Escrows::<T>::iter_values().all(|(_, amount)| !amount.is_zero()), "empty escrow entry");
+            Ok(())
+        }
+
+        /// Fails with `Paused` while the marketplace is paused.
+        fn ensure_not_paused() -> DispatchResult {
+            ensure!(!Paused::<T>::get(), Error::<T>::Paused);
+            Ok(())
+        }
+
+        /// Fails with `ReputationTooLow` if `MinReputationToTrade` is set and `who` has no score or
+        /// a score below it.
+        fn ensure_reputation_to_trade(who: &T::AccountId) -> DispatchResult {
+            if let Some(min_score) = T::MinReputationToTrade::get() {
+                let score = <T::ReputationSource as crate::ReputationSource<T::AccountId>>::reputation_of(who);
+                ensure!(score.map_or(false, |score| score >= min_score), Error::<T>::ReputationTooLow);
+            }
+            Ok(())
+        }
+
+        /// Checks the quantity of `order` against `OrderAmountLimits`, using the decimals that
+        /// `T::AssetDefinitions` gives for the `symbol` of its asset. The `decimals` metadata field
+        /// is written by whoever registered the asset and is never trusted here.
+        fn ensure_quantity_within_limits(order: &Order) -> DispatchResult {
+            use crate::AssetDefinitionSource;
+            let Some((min, max)) = T::OrderAmountLimits::get() else { return Ok(()) };
+            let decimals = Self::metadata_field(order.asset_id, b"symbol")
+                .and_then(|symbol| T::AssetDefinitions::decimals_of(&symbol))
+                .ok_or(Error::<T>::UnvettedAssetDecimals)?;
+            nodara_math::validate_amount(order.quantity as u128, decimals, min, max).map_err(|e| match e {
+                nodara_math::AmountError::BelowMinimum => Error::<T>::OrderBelowMinimum,
+                nodara_math::AmountError::AboveMaximum => Error::<T>::OrderAboveMaximum,
+                nodara_math::AmountError::UnsupportedDecimals => Error::<T>::InvalidOrder,
+            })?;
+            Ok(())
+        }
+
+        /// Puts `order` on the book under owner `who`, scheduling its expiry if it has one. A buy
+        /// order reserves its value from `who` until it trades, is cancelled, expires or is swept.
+        ///
+        /// Fails with `OrderIdInUse` if an order or an escrow already exists under `order.id`.
+        fn rest_order(who: &T::AccountId, order: Order) -> DispatchResult {
+            ensure!(
+                !<BuyOrders<T>>::contains_key(order.id)
+                    && !<SellOrders<T>>::contains_key(order.id)
+                    && !Escrows::<T>::contains_key(order.id),
+                Error::<T>::OrderIdInUse
+            );
+            if let Some(expiry) = order.expiry_block {
+                let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+                ensure!(expiry > now, Error::<T>::InvalidOrder);
+            }
+            if order.order_type == OrderType::Buy {
+                let value: BalanceOf<T> = Self::order_value(&order).saturated_into();
+                T::Currency::reserve(who, value)?;
+                Escrows::<T>::insert(order.id, (who.clone(), value));
+            }
+            if let Some(expiry) = order.expiry_block {
+                OrderExpiries::<T>::mutate(expiry, |expiring| expiring.push((order.id, order.order_type.clone())));
+            }
+            match order.order_type {
+                OrderType::Buy => <BuyOrders<T>>::insert(order.id, order.clone()),
+                OrderType::Sell => <SellOrders<T>>::insert(order.id, order.clone()),
+            };
+            OrderOwners::<T>::insert(order.id, who);
+            OrderBook::<T>::mutate(order.asset_id, |orders| orders.push(order.id));
+            Self::deposit_event(Event::OrderPlaced(order.id, order.order_type, order.asset_id));
+            Ok(())
+        }
+
+        /// Resting orders on the opposite side of `order` whose price crosses it, best price
+        /// first and in arrival order at equal price. With a `max_slippage_bps`, every order on
+        /// the opposite side is returned and `ensure_within_slippage` bounds the worse prices.
+        fn crossing_orders(order: &Order) -> Vec<Order> {
+            let mut makers: Vec<Order> = OrderBook::<T>::get(order.asset_id)
+                .into_iter()
+                .filter_map(|id| match order.order_type {
+                    OrderType::Buy => SellOrders::<T>::get(id),
+                    OrderType::Sell => BuyOrders::<T>::get(id),
+                })
+                .filter(|maker| {
+                    order.max_slippage_bps.is_some()
+                        || match order.order_type {
+                            OrderType::Buy => maker.price <= order.price,
+                            OrderType::Sell => maker.price >= order.price,
+                        }
+                })
+                .collect();
+            match order.order_type {
+                OrderType::Buy => makers.sort_by_key(|maker| maker.price),
+                OrderType::Sell => makers.sort_by(|a, b| b.price.cmp(&a.price)),
+            }
+            makers
+        }
+
+        /// Matches a `FOK` or `IOC` order against the book. Each fill trades at the resting
+        /// order's price; a `FOK` order without enough crossing liquidity fails before any fill,
+        /// and so does an order with a fill outside its `max_slippage_bps`. `who` pays the trade
+        /// fee of every fill as taker.
+        ///
+        /// Each fill moves its value between the two accounts: a buying taker pays the owner of
+        /// the sell order from its free balance, a selling taker is paid from the escrow of the
+        /// buy order.
+        fn take_order(who: &T::AccountId, order: Order) -> DispatchResult {
+            let makers = Self::crossing_orders(&order);
+            if order.execution == ExecutionPolicy::FOK {
+                let available = makers.iter().fold(0u64, |total, maker| total.saturating_add(maker.quantity as u64));
+                ensure!(available >= order.quantity as u64, Error::<T>::FillOrKillNotFilled);
+            }
+            Self::ensure_within_slippage(&order, &makers)?;
+
+            let mut remaining = order.quantity;
+            for mut maker in makers {
+                if remaining == 0 {
+                    break;
+                }
+                let fill = remaining.min(maker.quantity);
+                remaining -= fill;
+                maker.quantity -= fill;
+                let (buy_order_id, sell_order_id) = match order.order_type {
+                    OrderType::Buy => (order.id, maker.id),
+                    OrderType::Sell => (maker.id, order.id),
+                };
+                let value = Self::total_value(maker.price, fill);
+                match maker.order_type {
+                    OrderType::Buy => Self::pay_from_escrow(maker.id, who, value)?,
+                    OrderType::Sell => {
+                        let seller = OrderOwners::<T>::get(maker.id).ok_or(Error::<T>::OrderNotFound)?;
+                        T::Currency::transfer(who, &seller, value.saturated_into(), ExistenceRequirement::AllowDeath)?;
+                    },
+                }
+                Self::store_remaining(maker.clone());
+                let trade = Trade {
+                    id: NextTradeId::<T>::mutate(|next| {
+                        let id = *next;
+                        *next = next.saturating_add(1);
+                        id
+                    }),
+                    buy_order_id,
+                    sell_order_id,
+                    asset_id: order.asset_id,
+                    price: maker.price,
+                    quantity: fill,
+                    timestamp: Self::current_timestamp(),
+                };
+                Self::settle_trade(who, trade)?;
+            }
+
+            if remaining > 0 {
+                Self::deposit_event(Event::OrderRemainderCancelled(order.id, remaining));
+            }
+            Ok(())
+        }
+
+        /// Fails with `SlippageExceeded` if one of the `makers` that `order` would fill trades
+        /// worse than `order.price` by more than `order.max_slippage_bps`: an ask above it for a
+        /// buy, a bid below it for a sell.
+        fn ensure_within_slippage(order: &Order, makers: &[Order]) -> DispatchResult {
+            let max_bps = match order.max_slippage_bps {
+                Some(max_bps) => max_bps as u128,
+                None => return Ok(()),
+            };
+            let mut remaining = order.quantity;
+            for maker in makers.iter().take_while(|_| remaining > 0) {
+                remaining = remaining.saturating_sub(maker.quantity);
+                let deviation = match order.order_type {
+                    OrderType::Buy => (maker.price as u128).saturating_sub(order.price as u128),
+                    OrderType::Sell => (order.price as u128).saturating_sub(maker.price as u128),
+                };
+                ensure!(
+                    deviation.saturating_mul(10_000) <= max_bps.saturating_mul(order.price as u128),
+                    Error::<T>::SlippageExceeded
+                );
+            }
+            Ok(())
+        }
+
+        /// Writes back a resting order after a fill, or removes it from the book (with its owner
+        /// and any escrow left) once its quantity reaches zero.
+        fn store_remaining(order: Order) {
+            if order.quantity > 0 {
+                match order.order_type {
+                    OrderType::Buy => BuyOrders::<T>::insert(order.id, order),
+                    OrderType::Sell => SellOrders::<T>::insert(order.id, order),
+                };
+                return;
+            }
+            match order.order_type {
+                OrderType::Buy => {
+                    BuyOrders::<T>::remove(order.id);
+                    Self::release_escrow(order.id, None);
+                },
+                OrderType::Sell => SellOrders::<T>::remove(order.id),
+            };
+            OrderOwners::<T>::remove(order.id);
+            OrderBook::<T>::mutate(order.asset_id, |orders| orders.retain(|id| *id != order.id));
+        }
+
+        /// Pays `amount` from the escrow of `order_id` to `to`, dropping the entry once empty.
+        fn pay_from_escrow(order_id: u64, to: &T::AccountId, amount: u128) -> DispatchResult {
+            Escrows::<T>::try_mutate_exists(order_id, |maybe_escrow| -> DispatchResult {
+                let (owner, held) = maybe_escrow.as_mut().ok_or(Error::<T>::NoEscrow)?;
+                let payment = amount.saturated_into::<BalanceOf<T>>().min(*held);
+                T::Currency::repatriate_reserved(owner, to, payment, BalanceStatus::Free)?;
+                *held = held.saturating_sub(payment);
+                if held.is_zero() {
+                    *maybe_escrow = None;
+                }
+                Ok(())
+            })
+        }
+
+        /// Returns `amount` (all of it if `None`) of the escrow of `order_id` to its owner,
+        /// dropping the entry once empty. Does nothing for orders without escrow.
+        fn release_escrow(order_id: u64, amount: Option<u128>) {
+            Escrows::<T>::mutate_exists(order_id, |maybe_escrow| {
+                let emptied = match maybe_escrow {
+                    Some((owner, held)) => {
+                        let release = amount.map_or(*held, |amount| amount.saturated_into::<BalanceOf<T>>().min(*held));
+                        T::Currency::unreserve(owner, release);
+                        *held = held.saturating_sub(release);
+                        held.is_zero()
+                    },
+                    None => false,
+                };
+                if emptied {
+                    *maybe_escrow = None;
+                }
+            });
+        }
+
+        /// Fee discount (in percent) applying to trades taken by `taker`.
+        ///
+        /// Returns 0 when discounts are disabled, when the account has no reputation score or when
+        /// its score is below the lowest tier.
+        pub fn fee_discount(taker: &T::AccountId) -> u32 {
+            let tiers = T::FeeDiscountTiers::get();
+            if tiers.is_empty() {
+                return 0;
+            }
+            <T::ReputationSource as crate::ReputationSource<T::AccountId>>::reputation_of(taker)
+                .and_then(|score| tiers.iter().rev().find(|(min_score, _)| score >= *min_score))
+                .map(|(_, discount)| (*discount).min(100))
+                .unwrap_or(0)
+        }
+
+        /// `BaseTradeFee` discounted by the reputation of `taker`, clamped to `MinTradeFee`.
+        pub fn trade_fee(taker: &T::AccountId) -> u128 {
+            let base = T::BaseTradeFee::get() as u128;
+            let discount = Self::fee_discount(taker);
+            if discount == 0 {
+                return base;
+            }
+            let discounted = base.saturating_mul((100 - discount) as u128) / 100;
+            discounted.max(T::MinTradeFee::get() as u128).min(base)
+        }
+
+        /// Withdraws the trade fee owed by `taker` for `trade` from its free balance, hands it to
+        /// `T::RevenueRouter` and records the trade.
+        fn settle_trade(taker: &T::AccountId, trade: Trade) -> DispatchResult {
+            let fee = Self::trade_fee(taker);
+            // The router credits the fee to its destinations; the withdrawn imbalance is dropped.
+            let _ = T::Currency::withdraw(
+                taker,
+                fee.saturated_into(),
+                WithdrawReasons::FEE,
+                ExistenceRequirement::KeepAlive,
+            )?;
+            T::RevenueRouter::route_revenue(fee)?;
+            FeesAccrued::<T>::mutate(|total| *total = total.saturating_add(fee));
+            <TradesHistory<T>>::mutate(|history| history.push(trade.clone()));
+            Self::deposit_event(Event::TradeFeeCollected(trade.id, fee));
+            Self::deposit_event(Event::TradeExecuted(trade.id, trade.asset_id, trade.quantity, trade.price));
+            Ok(())
+        }
+
+        /// Removes up to `MaxExpiriesPerBlock` orders scheduled to expire at `block`, carrying
+        /// the remainder over to the next block. Orders already cancelled or matched are skipped.
+        /// The escrow of an expired buy order is returned to its owner.
+        fn sweep_expired_orders(block: u64) -> Weight {
+            let db = T::DbWeight::get();
+            let mut expiring = OrderExpiries::<T>::take(block);
+            if expiring.is_empty() {
+                return db.reads_writes(1, 1);
+            }
+            let max = T::MaxExpiriesPerBlock::get() as usize;
+            let remainder = if expiring.len() > max { expiring.split_off(max) } else { Vec::new() };
+            let processed = expiring.len() as u64;
+            for (order_id, order_type) in expiring {
+                let removed = match order_type {
+                    OrderType::Buy => <BuyOrders<T>>::take(order_id),
+                    OrderType::Sell => <SellOrders<T>>::take(order_id),
+                };
+                if let Some(order) = removed {
+                    OrderOwners::<T>::remove(order_id);
+                    if order.order_type == OrderType::Buy {
+                        Self::release_escrow(order_id, None);
+                    }
+                    OrderBook::<T>::mutate(order.asset_id, |orders| orders.retain(|id| *id != order_id));
+                    Self::deposit_event(Event::OrderExpired(order_id));
+                }
+            }
+            if !remainder.is_empty() {
+                OrderExpiries::<T>::mutate(block.saturating_add(1), |next| next.extend(remainder));
+            }
+            db.reads_writes(2 + 4 * processed, 2 + 4 * processed)
+        }
+
+        /// Total value of an order (`price * quantity`).
+        ///
+        /// Both factors are `u32`, so the product is computed in `u128` where it cannot overflow.
+        /// Use this (or `trade_value`) wherever a total is needed instead of multiplying the fields.
+        pub fn order_value(order: &Order) -> u128 {
+            Self::total_value(order.price, order.quantity)
+        }
+
+        /// Total value of an executed trade (`price * quantity`), see `order_value`.
+        pub fn trade_value(trade: &Trade) -> u128 {
+            Self::total_value(trade.price, trade.quantity)
+        }
+
+        fn total_value(price: u32, quantity: u32) -> u128 {
+            (price as u128) * (quantity as u128)
+        }
+
+        /// Returns the value of `key` in the metadata of `asset_id`, if both exist.
+        pub fn metadata_field(asset_id: u64, key: &[u8]) -> Option<Vec<u8>> {
+            Assets::<T>::get(asset_id).and_then(|asset| Self::parse_metadata_field(&asset.metadata, key))
+        }
+
+        /// Checks the `symbol`/`decimals` metadata fields against `T::AssetDefinitions`.
+        ///
+        /// Assets without a `symbol`, or whose symbol the registry does not define, pass.
+        fn ensure_consistent_definition(metadata: &[u8]) -> DispatchResult {
+            use crate::AssetDefinitionSource;
+            let Some(symbol) = Self::parse_metadata_field(metadata, b"symbol") else { return Ok(()) };
+            let Some(expected) = T::AssetDefinitions::decimals_of(&symbol) else { return Ok(()) };
+            let decimals = Self::parse_metadata_field(metadata, b"decimals")
+                .and_then(|raw| core::str::from_utf8(&raw).ok()?.parse::<u8>().ok());
+            ensure!(decimals == Some(expected), Error::<T>::AssetDefinitionConflict);
+            Ok(())
+        }
+
+        /// Looks up `key` in metadata encoded as `key=value;` pairs.
+        ///
+        /// The trailing `;` is optional, entries without `=` are ignored and the first
+        /// occurrence of a key wins. Values may contain `=` but not `;`.
+        pub fn parse_metadata_field(metadata: &[u8], key: &[u8]) -> Option<Vec<u8>> {
+            metadata
+                .split(|b| *b == b';')
+                .filter_map(|entry| {
+                    let pos = entry.iter().position(|b| *b == b'=')?;
+                    Some((&entry[..pos], &entry[pos + 1..]))
+                })
+                .find(|(k, _)| *k == key)
+                .map(|(_, v)| v.to_vec())
+        }
+
         /// Returns a fixed timestamp for testing purposes.
         /// In production, integrate with `pallet_timestamp`.
         fn current_timestamp() -> u64 {
@@ -250,7 +921,7 @@ pub mod pallet {
     #[cfg(test)]
     mod tests {
         use super::*;
-        use frame_support::{assert_ok, assert_err, parameter_types};
+        use frame_support::{assert_err, assert_noop, assert_ok, parameter_types, traits::Hooks};
         use sp_core::H256;
         use sp_runtime::{
             traits::{BlakeTwo256, IdentityLookup},
@@ -268,6 +939,7 @@ pub mod pallet {
                 UncheckedExtrinsic = UncheckedExtrinsic,
             {
                 System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+                Balances: pallet_balances,
                 MarketplaceModule: Pallet,
             }
         );
@@ -276,6 +948,45 @@ pub mod pallet {
             pub const BlockHashCount: u64 = 250;
             pub const MaxAssetMetadataLength: u32 = 256;
             pub const BaseTradeFee: u32 = 10;
+            pub const MaxExpiriesPerBlock: u32 = 2;
+            pub const ExistentialDeposit: u128 = 1;
+            pub const ReserveFundAccount: u64 = 99;
+            pub const MinTradeFee: u32 = 4;
+            pub FeeDiscountTiers: Vec<(u32, u32)> = vec![(500, 30), (800, 90)];
+            pub static OrderAmountLimits: Option<(u128, u128)> = None;
+        }
+
+        /// Reference registry in tests: `CENT` has 2 decimals, `UNIT` none.
+        pub struct TestAssetDefinitions;
+        impl crate::AssetDefinitionSource for TestAssetDefinitions {
+            fn decimals_of(symbol: &[u8]) -> Option<u8> {
+                match symbol {
+                    b"CENT" => Some(2),
+                    b"UNIT" => Some(0),
+                    _ => None,
+                }
+            }
+        }
+
+        /// Reputation score of an account in tests: 100 per unit of account id.
+        pub struct AccountIdReputation;
+        impl crate::ReputationSource<u64> for AccountIdReputation {
+            fn reputation_of(who: &u64) -> Option<u32> {
+                Some(*who as u32 * 100)
+            }
+        }
+
+        thread_local! {
+            static ROUTED: core::cell::RefCell<Vec<u128>> = core::cell::RefCell::new(Vec::new());
+        }
+
+        /// Router recording the revenue it receives.
+        pub struct RecordingRouter;
+        impl crate::RevenueRouter for RecordingRouter {
+            fn route_revenue(amount: u128) -> DispatchResult {
+                ROUTED.with(|r| r.borrow_mut().push(amount));
+                Ok(())
+            }
         }
 
         impl system::Config for Test {
@@ -296,7 +1007,7 @@ pub mod pallet {
             type BlockHashCount = BlockHashCount;
             type Version = ();
             type PalletInfo = ();
-            type AccountData = ();
+            type AccountData = pallet_balances::AccountData<u128>;
             type OnNewAccount = ();
             type OnKilledAccount = ();
             type SystemWeightInfo = ();
@@ -305,10 +1016,79 @@ pub mod pallet {
             type MaxConsumers = ();
         }
 
+        impl pallet_balances::Config for Test {
+            type RuntimeEvent = ();
+            type Balance = u128;
+            type DustRemoval = ();
+            type ExistentialDeposit = ExistentialDeposit;
+            type AccountStore = System;
+            type WeightInfo = ();
+            type MaxLocks = ();
+            type MaxReserves = ();
+            type ReserveIdentifier = [u8; 8];
+            type RuntimeHoldReason = ();
+            type RuntimeFreezeReason = ();
+            type FreezeIdentifier = ();
+            type MaxFreezes = ();
+        }
+
         impl Config for Test {
             type RuntimeEvent = ();
             type MaxAssetMetadataLength = MaxAssetMetadataLength;
             type BaseTradeFee = BaseTradeFee;
+            type RevenueRouter = RecordingRouter;
+            type GovernanceOrigin = frame_system::EnsureRoot<u64>;
+            type MaxExpiriesPerBlock = MaxExpiriesPerBlock;
+            type Currency = Balances;
+            type ReserveFundAccount = ReserveFundAccount;
+            type AssetDefinitions = TestAssetDefinitions;
+            type ReputationSource = AccountIdReputation;
+            type FeeDiscountTiers = FeeDiscountTiers;
+            type MinTradeFee = MinTradeFee;
+            type MinReputationToTrade = ();
+            type OrderAmountLimits = OrderAmountLimits;
+            type EmergencyHalt = ();
+        }
+
+        pub fn new_test_ext() -> sp_io::TestExternalities {
+            let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+            pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 1_000), (2, 1_000)] }
+                .assimilate_storage(&mut storage)
+                .unwrap();
+            storage.into()
+        }
+
+        fn order(id: u64, order_type: OrderType, expiry_block: Option<u64>) -> Order {
+            Order {
+                id,
+                asset_id: 300,
+                order_type,
+                price: 10,
+                quantity: 1,
+                account: 1,
+                timestamp: MarketplaceModule::current_timestamp(),
+                expiry_block,
+                execution: ExecutionPolicy::GTC,
+                max_slippage_bps: None,
+            }
+        }
+
+        /// Sell side with partial liquidity for a buy at 10: 2 @ 9 and 1 @ 10 cross, 5 @ 20 does not.
+        fn seed_sell_side() {
+            let user = || -> <Test as system::Config>::RuntimeOrigin { system::RawOrigin::Signed(2).into() };
+            for (id, price, quantity) in [(1, 9, 2), (2, 10, 1), (3, 20, 5)] {
+                let mut sell = order(id, OrderType::Sell, None);
+                sell.price = price;
+                sell.quantity = quantity;
+                assert_ok!(MarketplaceModule::place_order(user(), sell));
+            }
+        }
+
+        fn taker(id: u64, quantity: u32, execution: ExecutionPolicy) -> Order {
+            let mut buy = order(id, OrderType::Buy, None);
+            buy.quantity = quantity;
+            buy.execution = execution;
+            buy
         }
 
         #[test]
@@ -356,6 +1136,9 @@ pub mod pallet {
                 quantity: 10,
                 account: 1,
                 timestamp: MarketplaceModule::current_timestamp(),
+                expiry_block: None,
+                execution: ExecutionPolicy::GTC,
+                max_slippage_bps: None,
             };
             assert_ok!(MarketplaceModule::place_order(origin.clone(), order.clone()));
             let book = MarketplaceModule::order_book(order.asset_id);
@@ -379,6 +1162,9 @@ pub mod pallet {
                 quantity: 5,
                 account: 1,
                 timestamp: MarketplaceModule::current_timestamp(),
+                expiry_block: None,
+                execution: ExecutionPolicy::GTC,
+                max_slippage_bps: None,
             };
             let sell_order = Order {
                 id: 3,
@@ -388,6 +1174,9 @@ pub mod pallet {
                 quantity: 5,
                 account: 2,
                 timestamp: MarketplaceModule::current_timestamp(),
+                expiry_block: None,
+                execution: ExecutionPolicy::GTC,
+                max_slippage_bps: None,
             };
             assert_ok!(MarketplaceModule::place_order(origin.clone(), buy_order.clone()));
             assert_ok!(MarketplaceModule::place_order(origin.clone(), sell_order.clone()));
@@ -407,6 +1196,477 @@ pub mod pallet {
             assert!(!MarketplaceModule::sell_orders(sell_order.id).is_some());
             let history = MarketplaceModule::trades_history();
             assert!(history.iter().any(|t| t.id == trade.id));
+            // The base fee is handed to the revenue router.
+            assert_eq!(ROUTED.with(|r| r.borrow().clone()), vec![BaseTradeFee::get() as u128]);
+        }
+
+        #[test]
+        fn execute_trade_requires_crossing_orders_and_pays_the_seller() {
+            new_test_ext().execute_with(|| {
+                let signed = |who: u64| -> <Test as system::Config>::RuntimeOrigin { system::RawOrigin::Signed(who).into() };
+                Balances::make_free_balance_be(&3, 100);
+                let mut buy = order(1, OrderType::Buy, None);
+                buy.price = 12;
+                buy.quantity = 3;
+                assert_ok!(MarketplaceModule::place_order(signed(1), buy));
+                let mut sell = order(2, OrderType::Sell, None);
+                sell.quantity = 2;
+                assert_ok!(MarketplaceModule::place_order(signed(2), sell));
+                let mut ask = order(3, OrderType::Sell, None);
+                ask.price = 13;
+                assert_ok!(MarketplaceModule::place_order(signed(2), ask));
+
+                let trade = |sell_order_id, asset_id, price, quantity| Trade {
+                    id: 1,
+                    buy_order_id: 1,
+                    sell_order_id,
+                    asset_id,
+                    price,
+                    quantity,
+                    timestamp: 0,
+                };
+                // A third party can only match orders that cross.
+                assert_noop!(MarketplaceModule::execute_trade(signed(3), trade(3, 300, 12, 1)), Error::<Test>::OrdersDoNotCross);
+                assert_noop!(MarketplaceModule::execute_trade(signed(3), trade(2, 300, 9, 1)), Error::<Test>::OrdersDoNotCross);
+                assert_noop!(MarketplaceModule::execute_trade(signed(3), trade(2, 301, 11, 1)), Error::<Test>::OrdersDoNotCross);
+                assert_noop!(
+                    MarketplaceModule::execute_trade(signed(3), trade(2, 300, 11, 3)),
+                    Error::<Test>::InsufficientOrderQuantity
+                );
+
+                // 2 @ 11: the seller receives 22 from the escrow, the buyer gets back the 2 saved on
+                // its bid of 12 and keeps 1 @ 12 escrowed.
+                assert_ok!(MarketplaceModule::execute_trade(signed(3), trade(2, 300, 11, 2)));
+                assert_eq!((Balances::free_balance(1), Balances::reserved_balance(1)), (966, 12));
+                assert_eq!(Balances::free_balance(2), 1_022);
+                // The matching account is the taker and pays the fee.
+                assert_eq!(Balances::free_balance(3), 100 - BaseTradeFee::get() as u128);
+                assert!(MarketplaceModule::sell_orders(2).is_none());
+                assert_eq!(MarketplaceModule::order_owner(2), None);
+                assert_eq!(MarketplaceModule::buy_orders(1).map(|order| order.quantity), Some(1));
+                assert_eq!(MarketplaceModule::escrow(1), Some((1, 12)));
+                assert_eq!(MarketplaceModule::order_book(300), vec![1, 3]);
+                assert_ok!(MarketplaceModule::do_try_state());
+            });
+        }
+
+        #[test]
+        fn order_value_does_not_overflow_at_u32_bounds() {
+            let mut o = order(1, OrderType::Buy, None);
+            o.price = 10;
+            o.quantity = 3;
+            assert_eq!(MarketplaceModule::order_value(&o), 30);
+
+            // Product just past u32::MAX.
+            o.price = u32::MAX;
+            o.quantity = 2;
+            assert_eq!(MarketplaceModule::order_value(&o), 2 * u32::MAX as u128);
+
+            o.quantity = u32::MAX;
+            assert_eq!(MarketplaceModule::order_value(&o), 18_446_744_065_119_617_025);
+
+            o.price = 0;
+            assert_eq!(MarketplaceModule::order_value(&o), 0);
+        }
+
+        #[test]
+        fn trade_value_matches_order_value() {
+            let trade = Trade {
+                id: 1,
+                buy_order_id: 1,
+                sell_order_id: 2,
+                asset_id: 300,
+                price: u32::MAX,
+                quantity: u32::MAX - 1,
+                timestamp: 0,
+            };
+            assert_eq!(MarketplaceModule::trade_value(&trade), (u32::MAX as u128) * ((u32::MAX - 1) as u128));
+        }
+
+        #[test]
+        fn fees_accrue_with_each_trade() {
+            new_test_ext().execute_with(|| {
+                let user = || -> <Test as system::Config>::RuntimeOrigin { system::RawOrigin::Signed(1).into() };
+                assert_eq!(MarketplaceModule::fees_accrued(), 0);
+                for n in 1..=2u64 {
+                    assert_ok!(MarketplaceModule::place_order(user(), order(2 * n, OrderType::Buy, None)));
+                    assert_ok!(MarketplaceModule::place_order(user(), order(2 * n + 1, OrderType::Sell, None)));
+                    let trade = Trade {
+                        id: n,
+                        buy_order_id: 2 * n,
+                        sell_order_id: 2 * n + 1,
+                        asset_id: 300,
+                        price: 10,
+                        quantity: 1,
+                        timestamp: MarketplaceModule::current_timestamp(),
+                    };
+                    assert_ok!(MarketplaceModule::execute_trade(user(), trade));
+                    assert_eq!(MarketplaceModule::fees_accrued(), n as u128 * BaseTradeFee::get() as u128);
+                    // The taker trades with itself, so only the fee leaves its balance.
+                    assert_eq!(Balances::free_balance(1), 1_000 - n as u128 * BaseTradeFee::get() as u128);
+                }
+            });
+        }
+
+        #[test]
+        fn taker_fee_is_discounted_by_reputation_down_to_the_floor() {
+            new_test_ext().execute_with(|| {
+                // Scores 100, 500 and 900: no discount, 30 % off, 90 % off clamped to `MinTradeFee`.
+                assert_eq!(MarketplaceModule::trade_fee(&1), 10);
+                assert_eq!(MarketplaceModule::trade_fee(&5), 7);
+                assert_eq!(MarketplaceModule::trade_fee(&9), MinTradeFee::get() as u128);
+
+                seed_sell_side();
+                Balances::make_free_balance_be(&5, 1_000);
+                let reputable = || -> <Test as system::Config>::RuntimeOrigin { system::RawOrigin::Signed(5).into() };
+                assert_ok!(MarketplaceModule::place_order(reputable(), taker(10, 3, ExecutionPolicy::IOC)));
+                assert_eq!(MarketplaceModule::fees_accrued(), 2 * 7);
+                assert_eq!(ROUTED.with(|r| r.borrow().clone()), vec![7, 7]);
+                // The taker paid 2 * 9 + 10 to the seller and two discounted fees.
+                assert_eq!(Balances::free_balance(5), 1_000 - 28 - 2 * 7);
+            });
+        }
+
+        #[test]
+        fn parse_metadata_field_extracts_values() {
+            let metadata = b"name=Asset42;kind=art;uri=ipfs://x=1;flag".as_slice();
+            assert_eq!(MarketplaceModule::parse_metadata_field(metadata, b"name"), Some(b"Asset42".to_vec()));
+            assert_eq!(MarketplaceModule::parse_metadata_field(metadata, b"uri"), Some(b"ipfs://x=1".to_vec()));
+            assert_eq!(MarketplaceModule::parse_metadata_field(metadata, b"flag"), None);
+            assert_eq!(MarketplaceModule::parse_metadata_field(metadata, b"missing"), None);
+            assert_eq!(MarketplaceModule::parse_metadata_field(b"", b"name"), None);
+        }
+
+        #[test]
+        fn metadata_field_reads_registered_asset() {
+            let origin = system::RawOrigin::Signed(1).into();
+            assert_ok!(MarketplaceModule::register_asset(origin, 45, b"name=Asset45;rarity=rare;".to_vec()));
+            assert_eq!(MarketplaceModule::metadata_field(45, b"rarity"), Some(b"rare".to_vec()));
+            assert_eq!(MarketplaceModule::metadata_field(45, b"color"), None);
+            assert_eq!(MarketplaceModule::metadata_field(99, b"name"), None);
+        }
+
+        #[test]
+        fn pausing_blocks_mutations_until_unpaused() {
+            new_test_ext().execute_with(|| {
+                let user = || -> <Test as system::Config>::RuntimeOrigin { system::RawOrigin::Signed(1).into() };
+                assert_ok!(MarketplaceModule::register_asset(user(), 300, b"name=A".to_vec()));
+                assert_ok!(MarketplaceModule::place_order(user(), order(1, OrderType::Buy, None)));
+
+                assert_err!(MarketplaceModule::set_paused(user(), true), sp_runtime::DispatchError::BadOrigin);
+                assert_ok!(MarketplaceModule::set_paused(system::RawOrigin::Root.into(), true));
+                assert!(MarketplaceModule::is_paused());
+
+                assert_err!(MarketplaceModule::register_asset(user(), 301, b"name=B".to_vec()), Error::<Test>::Paused);
+                assert_err!(MarketplaceModule::place_order(user(), order(2, OrderType::Sell, None)), Error::<Test>::Paused);
+                assert_err!(MarketplaceModule::cancel_order(user(), 1, OrderType::Buy), Error::<Test>::Paused);
+                // Reads stay available.
+                assert_eq!(MarketplaceModule::metadata_field(300, b"name"), Some(b"A".to_vec()));
+                assert!(MarketplaceModule::buy_orders(1).is_some());
+
+                assert_ok!(MarketplaceModule::set_paused(system::RawOrigin::Root.into(), false));
+                assert_ok!(MarketplaceModule::place_order(user(), order(2, OrderType::Sell, None)));
+                assert_ok!(MarketplaceModule::cancel_order(user(), 1, OrderType::Buy));
+            });
+        }
+
+        #[test]
+        fn expired_orders_are_swept() {
+            new_test_ext().execute_with(|| {
+                System::set_block_number(1);
+                let origin = || -> <Test as system::Config>::RuntimeOrigin { system::RawOrigin::Signed(1).into() };
+                assert_ok!(MarketplaceModule::place_order(origin(), order(1, OrderType::Buy, Some(3))));
+                assert_ok!(MarketplaceModule::place_order(origin(), order(2, OrderType::Sell, None)));
+                assert_err!(MarketplaceModule::place_order(origin(), order(3, OrderType::Buy, Some(1))), Error::<Test>::InvalidOrder);
+
+                MarketplaceModule::on_initialize(2);
+                assert!(MarketplaceModule::buy_orders(1).is_some());
+
+                MarketplaceModule::on_initialize(3);
+                assert!(MarketplaceModule::buy_orders(1).is_none());
+                assert!(MarketplaceModule::sell_orders(2).is_some());
+                assert_eq!(MarketplaceModule::order_book(300), vec![2]);
+            });
+        }
+
+        #[test]
+        fn expiry_sweep_is_bounded_per_block() {
+            new_test_ext().execute_with(|| {
+                System::set_block_number(1);
+                for id in 1..=3 {
+                    assert_ok!(MarketplaceModule::place_order(system::RawOrigin::Signed(1).into(), order(id, OrderType::Sell, Some(2))));
+                }
+                MarketplaceModule::on_initialize(2);
+                assert_eq!(MarketplaceModule::order_book(300), vec![3]);
+                assert_eq!(MarketplaceModule::order_expiries(3), vec![(3, OrderType::Sell)]);
+                MarketplaceModule::on_initialize(3);
+                assert!(MarketplaceModule::order_book(300).is_empty());
+            });
+        }
+
+        #[test]
+        fn order_quantity_limits_follow_the_asset_decimals() {
+            new_test_ext().execute_with(|| {
+                let user = || -> <Test as system::Config>::RuntimeOrigin { system::RawOrigin::Signed(2).into() };
+                OrderAmountLimits::set(Some((1, 100)));
+                assert_ok!(MarketplaceModule::register_asset(user(), 301, b"name=Cents;symbol=CENT;decimals=2".to_vec()));
+                assert_ok!(MarketplaceModule::register_asset(user(), 302, b"name=Units;symbol=UNIT;decimals=0".to_vec()));
+                // Decimals declared without a symbol known to the registry are not trusted.
+                assert_ok!(MarketplaceModule::register_asset(user(), 303, b"name=Loose;decimals=18".to_vec()));
+                let sell = |id, asset_id, quantity| {
+                    let mut sell = order(id, OrderType::Sell, None);
+                    sell.asset_id = asset_id;
+                    sell.quantity = quantity;
+                    sell
+                };
+
+                // Unregistered and unvetted assets are rejected.
+                assert_err!(MarketplaceModule::place_order(user(), sell(1, 300, 100)), Error::<Test>::UnvettedAssetDecimals);
+                assert_err!(MarketplaceModule::place_order(user(), sell(1, 303, 100)), Error::<Test>::UnvettedAssetDecimals);
+
+                // Asset 302 has no decimals: quantities are whole units.
+                assert_err!(MarketplaceModule::place_order(user(), sell(1, 302, 0)), Error::<Test>::OrderBelowMinimum);
+                assert_ok!(MarketplaceModule::place_order(user(), sell(2, 302, 100)));
+                assert_err!(MarketplaceModule::place_order(user(), sell(3, 302, 101)), Error::<Test>::OrderAboveMaximum);
+
+                // Asset 301 has 2 decimals: 1 to 100 units is 100 to 10 000.
+                assert_err!(MarketplaceModule::place_order(user(), sell(4, 301, 99)), Error::<Test>::OrderBelowMinimum);
+                assert_ok!(MarketplaceModule::place_order(user(), sell(5, 301, 100)));
+                assert_ok!(MarketplaceModule::place_order(user(), sell(6, 301, 10_000)));
+                assert_err!(MarketplaceModule::place_order(user(), sell(7, 301, 10_001)), Error::<Test>::OrderAboveMaximum);
+                assert!(MarketplaceModule::sell_orders(4).is_none());
+                assert!(MarketplaceModule::sell_orders(6).is_some());
+                OrderAmountLimits::set(None);
+            });
+        }
+
+        #[test]
+        fn gtc_order_rests_without_matching() {
+            new_test_ext().execute_with(|| {
+                seed_sell_side();
+                assert_ok!(MarketplaceModule::place_order(system::RawOrigin::Signed(1).into(), taker(10, 5, ExecutionPolicy::GTC)));
+                assert!(MarketplaceModule::buy_orders(10).is_some());
+                assert_eq!(MarketplaceModule::order_book(300), vec![1, 2, 3, 10]);
+                assert!(MarketplaceModule::trades_history().is_empty());
+            });
+        }
+
+        #[test]
+        fn fok_order_is_rejected_without_full_liquidity() {
+            new_test_ext().execute_with(|| {
+                seed_sell_side();
+                let user = || -> <Test as system::Config>::RuntimeOrigin { system::RawOrigin::Signed(1).into() };
+                assert_err!(
+                    MarketplaceModule::place_order(user(), taker(10, 5, ExecutionPolicy::FOK)),
+                    Error::<Test>::FillOrKillNotFilled
+                );
+                assert_eq!(MarketplaceModule::sell_orders(1).map(|o| o.quantity), Some(2));
+                assert_eq!(MarketplaceModule::order_book(300), vec![1, 2, 3]);
+                assert!(MarketplaceModule::trades_history().is_empty());
+
+                // One unit is available at the best price: filled in full, maker partially consumed.
+                assert_ok!(MarketplaceModule::place_order(user(), taker(11, 1, ExecutionPolicy::FOK)));
+                assert_eq!(MarketplaceModule::sell_orders(1).map(|o| o.quantity), Some(1));
+                let history = MarketplaceModule::trades_history();
+                assert_eq!(history.len(), 1);
+                assert_eq!((history[0].buy_order_id, history[0].sell_order_id, history[0].price, history[0].quantity), (11, 1, 9, 1));
+                assert!(MarketplaceModule::buy_orders(11).is_none());
+            });
+        }
+
+        #[test]
+        fn ioc_order_fills_available_liquidity_and_drops_the_rest() {
+            new_test_ext().execute_with(|| {
+                seed_sell_side();
+                assert_ok!(MarketplaceModule::place_order(system::RawOrigin::Signed(1).into(), taker(10, 5, ExecutionPolicy::IOC)));
+                let fills: Vec<(u64, u32, u32)> = MarketplaceModule::trades_history()
+                    .iter()
+                    .map(|t| (t.sell_order_id, t.price, t.quantity))
+                    .collect();
+                assert_eq!(fills, vec![(1, 9, 2), (2, 10, 1)]);
+                assert!(MarketplaceModule::sell_orders(1).is_none());
+                assert!(MarketplaceModule::sell_orders(2).is_none());
+                assert!(MarketplaceModule::sell_orders(3).is_some());
+                // The unfilled remainder does not rest on the book.
+                assert!(MarketplaceModule::buy_orders(10).is_none());
+                assert_eq!(MarketplaceModule::order_book(300), vec![3]);
+                assert_eq!(MarketplaceModule::fees_accrued(), 2 * BaseTradeFee::get() as u128);
+                // The taker paid 2 * 9 + 10 to the seller, plus a fee per fill.
+                assert_eq!((Balances::free_balance(1), Balances::free_balance(2)), (952, 1_028));
+            });
+        }
+
+        #[test]
+        fn taker_fills_are_bounded_by_max_slippage() {
+            new_test_ext().execute_with(|| {
+                seed_sell_side();
+                let user = || -> <Test as system::Config>::RuntimeOrigin { system::RawOrigin::Signed(1).into() };
+                let fills = || -> Vec<(u64, u32, u32)> {
+                    MarketplaceModule::trades_history()
+                        .iter()
+                        .map(|t| (t.sell_order_id, t.price, t.quantity))
+                        .collect()
+                };
+                // Buying at 10, fills at 9 are better than expected and never count as slippage.
+                let mut favourable = taker(10, 2, ExecutionPolicy::IOC);
+                favourable.max_slippage_bps = Some(500);
+                assert_ok!(MarketplaceModule::place_order(user(), favourable));
+                assert_eq!(fills(), vec![(1, 9, 2)]);
+
+                // Buying 3 more at 10 needs 1 @ 10 and 2 @ 20, 10_000 bps above the expected price.
+                let mut tight = taker(11, 3, ExecutionPolicy::IOC);
+                tight.max_slippage_bps = Some(500);
+                assert_err!(MarketplaceModule::place_order(user(), tight), Error::<Test>::SlippageExceeded);
+                assert_eq!(MarketplaceModule::order_book(300), vec![2, 3]);
+                assert_eq!(fills().len(), 1);
+
+                let mut tolerant = taker(12, 3, ExecutionPolicy::IOC);
+                tolerant.max_slippage_bps = Some(10_000);
+                assert_ok!(MarketplaceModule::place_order(user(), tolerant));
+                assert_eq!(fills(), vec![(1, 9, 2), (2, 10, 1), (3, 20, 2)]);
+            });
+        }
+
+        /// Places a resting buy order from account 1 escrowing `price * quantity` = 10 * 5.
+        fn escrowed_buy(id: u64) {
+            let mut buy = order(id, OrderType::Buy, None);
+            buy.quantity = 5;
+            assert_ok!(MarketplaceModule::place_order(system::RawOrigin::Signed(1).into(), buy));
+            assert_eq!(MarketplaceModule::escrow(id), Some((1, 50)));
+        }
+
+        #[test]
+        fn sweep_escrow_to_treasury_moves_reserved_funds() {
+            new_test_ext().execute_with(|| {
+                escrowed_buy(1);
+                assert_eq!((Balances::free_balance(1), Balances::reserved_balance(1)), (950, 50));
+
+                assert_err!(MarketplaceModule::sweep_escrow(system::RawOrigin::Signed(1).into(), 1, true), sp_runtime::DispatchError::BadOrigin);
+                assert_ok!(MarketplaceModule::sweep_escrow(system::RawOrigin::Root.into(), 1, true));
+
+                // Force-cancelled and paid to the reserve fund.
+                assert!(MarketplaceModule::buy_orders(1).is_none());
+                assert!(MarketplaceModule::order_book(300).is_empty());
+                assert!(MarketplaceModule::escrow(1).is_none());
+                assert_eq!((Balances::free_balance(1), Balances::reserved_balance(1)), (950, 0));
+                assert_eq!(Balances::free_balance(ReserveFundAccount::get()), 50);
+                assert_err!(MarketplaceModule::sweep_escrow(system::RawOrigin::Root.into(), 1, true), Error::<Test>::NoEscrow);
+            });
+        }
+
+        #[test]
+        fn expiry_returns_the_escrow_to_its_owner() {
+            new_test_ext().execute_with(|| {
+                System::set_block_number(1);
+                let mut buy = order(1, OrderType::Buy, Some(2));
+                buy.quantity = 5;
+                assert_ok!(MarketplaceModule::place_order(system::RawOrigin::Signed(1).into(), buy));
+                assert_eq!(Balances::reserved_balance(1), 50);
+                MarketplaceModule::on_initialize(2);
+                assert!(MarketplaceModule::buy_orders(1).is_none());
+                assert!(MarketplaceModule::escrow(1).is_none());
+                assert!(MarketplaceModule::order_owner(1).is_none());
+                assert_eq!((Balances::free_balance(1), Balances::reserved_balance(1)), (1_000, 0));
+
+                // Nothing is left for governance to sweep.
+                assert_err!(MarketplaceModule::sweep_escrow(system::RawOrigin::Root.into(), 1, false), Error::<Test>::NoEscrow);
+            });
+        }
+
+        #[test]
+        fn order_ids_cannot_be_reused_while_resting() {
+            new_test_ext().execute_with(|| {
+                escrowed_buy(1);
+                let other = || -> <Test as system::Config>::RuntimeOrigin { system::RawOrigin::Signed(2).into() };
+                assert_err!(MarketplaceModule::place_order(other(), order(1, OrderType::Buy, None)), Error::<Test>::OrderIdInUse);
+                assert_err!(MarketplaceModule::place_order(other(), order(1, OrderType::Sell, None)), Error::<Test>::OrderIdInUse);
+                assert_eq!(MarketplaceModule::escrow(1), Some((1, 50)));
+                assert_eq!(MarketplaceModule::order_owner(1), Some(1));
+                assert_eq!(Balances::reserved_balance(2), 0);
+
+                // Once cancelled, the id is free again.
+                assert_ok!(MarketplaceModule::cancel_order(system::RawOrigin::Signed(1).into(), 1, OrderType::Buy));
+                assert_ok!(MarketplaceModule::place_order(other(), order(1, OrderType::Sell, None)));
+            });
+        }
+
+        #[test]
+        fn only_the_owner_can_cancel_an_order() {
+            new_test_ext().execute_with(|| {
+                escrowed_buy(1);
+                assert_err!(
+                    MarketplaceModule::cancel_order(system::RawOrigin::Signed(2).into(), 1, OrderType::Buy),
+                    Error::<Test>::NotOrderOwner
+                );
+                assert!(MarketplaceModule::buy_orders(1).is_some());
+                assert_eq!(Balances::reserved_balance(1), 50);
+
+                assert_ok!(MarketplaceModule::cancel_order(system::RawOrigin::Signed(1).into(), 1, OrderType::Buy));
+                assert!(MarketplaceModule::order_owner(1).is_none());
+                assert_ok!(MarketplaceModule::do_try_state());
+            });
+        }
+
+        #[test]
+        fn escrow_is_released_when_cancelled_and_paid_to_the_seller_when_filled() {
+            new_test_ext().execute_with(|| {
+                escrowed_buy(1);
+                assert_ok!(MarketplaceModule::cancel_order(system::RawOrigin::Signed(1).into(), 1, OrderType::Buy));
+                assert!(MarketplaceModule::escrow(1).is_none());
+                assert_eq!(Balances::reserved_balance(1), 0);
+
+                // A partial IOC sell fill pays the matching share of the escrow to the seller.
+                escrowed_buy(2);
+                let mut sell = order(3, OrderType::Sell, None);
+                sell.quantity = 2;
+                sell.execution = ExecutionPolicy::IOC;
+                assert_ok!(MarketplaceModule::place_order(system::RawOrigin::Signed(2).into(), sell));
+                assert_eq!(MarketplaceModule::escrow(2), Some((1, 30)));
+                assert_eq!((Balances::free_balance(1), Balances::reserved_balance(1)), (950, 30));
+                // The selling taker receives 20 and pays the fee of its fill.
+                assert_eq!((Balances::free_balance(2), Balances::reserved_balance(2)), (1_010, 0));
+            });
+        }
+
+        #[test]
+        fn rebuild_order_book_restores_index_from_orders() {
+            new_test_ext().execute_with(|| {
+                let user = || -> <Test as system::Config>::RuntimeOrigin { system::RawOrigin::Signed(1).into() };
+                assert_ok!(MarketplaceModule::place_order(user(), order(3, OrderType::Sell, None)));
+                assert_ok!(MarketplaceModule::place_order(user(), order(1, OrderType::Buy, None)));
+                let mut other_asset = order(2, OrderType::Sell, None);
+                other_asset.asset_id = 301;
+                assert_ok!(MarketplaceModule::place_order(user(), other_asset));
+
+                // Corrupt the index: a dangling id, a duplicate and a missing order.
+                OrderBook::<Test>::insert(300, vec![3, 42, 3]);
+
+                assert_err!(MarketplaceModule::rebuild_order_book(user(), 300), sp_runtime::DispatchError::BadOrigin);
+                assert_ok!(MarketplaceModule::rebuild_order_book(system::RawOrigin::Root.into(), 300));
+                assert_eq!(MarketplaceModule::order_book(300), vec![1, 3]);
+                for id in MarketplaceModule::order_book(300) {
+                    let order = MarketplaceModule::buy_orders(id).or_else(|| MarketplaceModule::sell_orders(id)).unwrap();
+                    assert_eq!(order.asset_id, 300);
+                }
+                assert_eq!(MarketplaceModule::order_book(301), vec![2]);
+            });
+        }
+
+        #[test]
+        fn try_state_detects_order_missing_from_book() {
+            new_test_ext().execute_with(|| {
+                let user = || -> <Test as system::Config>::RuntimeOrigin { system::RawOrigin::Signed(1).into() };
+                assert_ok!(MarketplaceModule::place_order(user(), order(1, OrderType::Buy, None)));
+                assert_ok!(MarketplaceModule::place_order(user(), order(2, OrderType::Sell, None)));
+                assert_ok!(MarketplaceModule::do_try_state());
+
+                // Drop the sell order from the index while it still rests in `SellOrders`.
+                OrderBook::<Test>::insert(300, vec![1]);
+                assert_err!(MarketplaceModule::do_try_state(), "resting order missing from the order book");
+
+                assert_ok!(MarketplaceModule::rebuild_order_book(system::RawOrigin::Root.into(), 300));
+                assert_ok!(MarketplaceModule::do_try_state());
+            });
         }
     }
 }
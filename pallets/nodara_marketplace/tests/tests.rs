@@ -37,6 +37,16 @@ mod tests {
     impl pallet::Config for TestConfig {
         type RuntimeEvent = ();
         type MaxAssetMetadataLength = sp_runtime::traits::ConstU32<256>;
+        type RevenueRouter = ();
+        type GovernanceOrigin = frame_system::EnsureRoot<u64>;
+        type MaxExpiriesPerBlock = sp_runtime::traits::ConstU32<16>;
+        type AssetDefinitions = ();
+        type ReputationSource = ();
+        type FeeDiscountTiers = ();
+        type MinTradeFee = ();
+        type MinReputationToTrade = ();
+        type OrderAmountLimits = ();
+        type EmergencyHalt = ();
     }
 
     pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -67,6 +77,9 @@ mod tests {
                 quantity: 10,
                 account: 1,
                 timestamp: 1000,
+                expiry_block: None,
+                execution: pallet::ExecutionPolicy::GTC,
+                max_slippage_bps: None,
             };
             // Place the order
             assert_ok!(<pallet::Pallet<TestConfig>>::place_order(RawOrigin::Signed(1).into(), order.clone()));
@@ -90,6 +103,9 @@ mod tests {
                 quantity: 10,
                 account: 1,
                 timestamp: 1000,
+                expiry_block: None,
+                execution: pallet::ExecutionPolicy::GTC,
+                max_slippage_bps: None,
             };
             let sell_order = pallet::Order {
                 id: 2,
@@ -99,6 +115,9 @@ mod tests {
                 quantity: 10,
                 account: 2,
                 timestamp: 1000,
+                expiry_block: None,
+                execution: pallet::ExecutionPolicy::GTC,
+                max_slippage_bps: None,
             };
             assert_ok!(<pallet::Pallet<TestConfig>>::place_order(RawOrigin::Signed(1).into(), buy_order));
             assert_ok!(<pallet::Pallet<TestConfig>>::place_order(RawOrigin::Signed(2).into(), sell_order));
@@ -0,0 +1,188 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Nodara Math
+//!
+//! Arithmétique commune aux modules Nodara, compatible `no_std`.
+//!
+//! `proportional_split` répartit un montant entre plusieurs destinataires selon leurs poids
+//! (redistribution pondérée du fonds de réserve, récompenses pondérées, partage des frais) :
+//!
+//! ```ignore
+//! let (shares, remainder) = nodara_math::proportional_split(1_000, &[1, 2]);
+//! assert_eq!((shares, remainder), (vec![333, 666], 1));
+//! ```
+//!
+//! `validate_amount` borne un montant exprimé en plus petites unités d'un actif par des limites
+//! exprimées en unités entières, quelles que soient ses décimales (bridge, marketplace) :
+//!
+//! ```ignore
+//! // 1,5 unité d'un actif à 6 décimales, entre 1 et 10 unités.
+//! assert_eq!(nodara_math::validate_amount(1_500_000, 6, 1, 10), Ok(()));
+//! ```
+
+use sp_runtime::{helpers_128bit::multiply_by_rational_with_rounding, Rounding, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// Répartit `total` proportionnellement à `weights`.
+///
+/// Retourne la part de chaque poids, dans l'ordre de `weights`, et le reliquat non attribué.
+/// Chaque part est arrondie à l'inférieur, si bien que la somme des parts et du reliquat vaut
+/// toujours exactement `total` ; le reliquat est inférieur au nombre de poids non nuls. Si tous
+/// les poids sont nuls (ou `weights` est vide), toutes les parts sont nulles et `total` est
+/// entièrement retourné comme reliquat.
+pub fn proportional_split(total: u128, weights: &[u32]) -> (Vec<u128>, u128) {
+    let total_weight = weights.iter().fold(0u128, |sum, weight| sum + *weight as u128);
+    if total_weight == 0 {
+        return (weights.iter().map(|_| 0).collect(), total);
+    }
+    let shares: Vec<u128> = weights
+        .iter()
+        .map(|weight| {
+            // `weight <= total_weight` : la part ne dépasse jamais `total`, le calcul ne peut échouer.
+            multiply_by_rational_with_rounding(total, *weight as u128, total_weight, Rounding::Down).unwrap_or(0)
+        })
+        .collect();
+    let distributed = shares.iter().fold(0u128, |sum, share| sum.saturating_add(*share));
+    (shares, total - distributed)
+}
+
+/// Motif de rejet d'un montant par `validate_amount`.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum AmountError {
+    /// Le montant est inférieur au minimum.
+    BelowMinimum,
+    /// Le montant est supérieur au maximum.
+    AboveMaximum,
+    /// `10^decimals` ne tient pas sur un `u128` (plus de 38 décimales).
+    UnsupportedDecimals,
+}
+
+/// Vérifie que `amount`, exprimé en plus petites unités d'un actif à `decimals` décimales, est
+/// compris entre `min` et `max` unités entières (bornes incluses).
+///
+/// Une borne dont la conversion en plus petites unités dépasse `u128::MAX` est plafonnée à
+/// `u128::MAX`.
+pub fn validate_amount(amount: u128, decimals: u8, min: u128, max: u128) -> Result<(), AmountError> {
+    let unit = 10u128.checked_pow(decimals as u32).ok_or(AmountError::UnsupportedDecimals)?;
+    if amount < min.saturating_mul(unit) {
+        return Err(AmountError::BelowMinimum);
+    }
+    if amount > max.saturating_mul(unit) {
+        return Err(AmountError::AboveMaximum);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Vérifie les invariants de `proportional_split` pour `total` et `weights`.
+    fn check(total: u128, weights: &[u32]) -> (Vec<u128>, u128) {
+        let (shares, remainder) = proportional_split(total, weights);
+        assert_eq!(shares.len(), weights.len());
+        assert_eq!(shares.iter().sum::<u128>() + remainder, total, "{total} / {weights:?}");
+        let non_zero = weights.iter().filter(|weight| **weight > 0).count() as u128;
+        if non_zero > 0 {
+            assert!(remainder < non_zero, "reliquat {remainder} pour {total} / {weights:?}");
+        }
+        for (share, weight) in shares.iter().zip(weights) {
+            if *weight == 0 {
+                assert_eq!(*share, 0);
+            }
+        }
+        (shares, remainder)
+    }
+
+    #[test]
+    fn exact_split_leaves_no_remainder() {
+        assert_eq!(check(1_000, &[1, 1, 2]), (vec![250, 250, 500], 0));
+    }
+
+    #[test]
+    fn rounding_loss_is_returned_as_remainder() {
+        assert_eq!(check(1_000, &[1, 2]), (vec![333, 666], 1));
+        assert_eq!(check(10, &[1, 1, 1]), (vec![3, 3, 3], 1));
+        assert_eq!(check(2, &[1, 1, 1]), (vec![0, 0, 0], 2));
+    }
+
+    #[test]
+    fn single_recipient_takes_everything() {
+        assert_eq!(check(1_000, &[7]), (vec![1_000], 0));
+        assert_eq!(check(u128::MAX, &[u32::MAX]), (vec![u128::MAX], 0));
+    }
+
+    #[test]
+    fn zero_weights_receive_nothing() {
+        assert_eq!(check(1_000, &[0, 3, 0, 1]), (vec![0, 750, 0, 250], 0));
+        assert_eq!(check(1_000, &[0, 0]), (vec![0, 0], 1_000));
+        assert_eq!(check(1_000, &[]), (vec![], 1_000));
+    }
+
+    #[test]
+    fn zero_total_splits_into_zero_shares() {
+        assert_eq!(check(0, &[1, 2, 3]), (vec![0, 0, 0], 0));
+    }
+
+    #[test]
+    fn extreme_values_do_not_overflow() {
+        check(u128::MAX, &[u32::MAX, u32::MAX, 1]);
+        check(u128::MAX, &[u32::MAX; 64]);
+        check(u128::MAX - 1, &[1, u32::MAX - 1, 12_345]);
+    }
+
+    #[test]
+    fn invariants_hold_for_all_small_inputs() {
+        for total in 0..=50u128 {
+            for a in 0..=4u32 {
+                for b in 0..=4u32 {
+                    for c in 0..=4u32 {
+                        check(total, &[a]);
+                        check(total, &[a, b]);
+                        check(total, &[a, b, c]);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn amounts_within_bounds_are_valid_for_any_decimals() {
+        for decimals in [0u8, 6, 8, 12, 18] {
+            let unit = 10u128.pow(decimals as u32);
+            assert_eq!(validate_amount(unit, decimals, 1, 10), Ok(()));
+            assert_eq!(validate_amount(10 * unit, decimals, 1, 10), Ok(()));
+            assert_eq!(validate_amount(3 * unit / 2 + 1, decimals, 1, 10), Ok(()));
+            assert_eq!(validate_amount(0, decimals, 0, 10), Ok(()));
+        }
+    }
+
+    #[test]
+    fn amounts_below_minimum_are_rejected() {
+        for decimals in [0u8, 6, 8, 12, 18] {
+            let unit = 10u128.pow(decimals as u32);
+            assert_eq!(validate_amount(unit - 1, decimals, 1, 10), Err(AmountError::BelowMinimum));
+            assert_eq!(validate_amount(0, decimals, 1, 10), Err(AmountError::BelowMinimum));
+        }
+        // Un même montant brut est suffisant à 6 décimales mais pas à 18.
+        assert_eq!(validate_amount(1_000_000, 6, 1, 10), Ok(()));
+        assert_eq!(validate_amount(1_000_000, 18, 1, 10), Err(AmountError::BelowMinimum));
+    }
+
+    #[test]
+    fn amounts_above_maximum_are_rejected() {
+        for decimals in [0u8, 6, 8, 12, 18] {
+            let unit = 10u128.pow(decimals as u32);
+            assert_eq!(validate_amount(10 * unit + 1, decimals, 1, 10), Err(AmountError::AboveMaximum));
+        }
+        assert_eq!(validate_amount(10u128.pow(8), 6, 1, 10), Err(AmountError::AboveMaximum));
+        assert_eq!(validate_amount(10u128.pow(8), 8, 1, 10), Ok(()));
+    }
+
+    #[test]
+    fn oversized_bounds_saturate_and_excess_decimals_are_rejected() {
+        assert_eq!(validate_amount(u128::MAX, 38, 0, u128::MAX), Ok(()));
+        assert_eq!(validate_amount(u128::MAX, 18, u128::MAX, u128::MAX), Ok(()));
+        assert_eq!(validate_amount(1, 39, 0, 10), Err(AmountError::UnsupportedDecimals));
+    }
+}
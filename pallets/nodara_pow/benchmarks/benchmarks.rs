@@ -30,13 +30,13 @@ benchmarks! {
 
     adjust_difficulty {
         <pallet::Pallet<T>>::initialize_pow()?;
-        let signal: u32 = 50;
+        let signal: i32 = 50;
     }: {
         <pallet::Pallet<T>>::adjust_difficulty(signal)?;
     }
     verify {
         let state = <pallet::PowStateStorage<T>>::get();
-        let expected = T::BaselineDifficulty::get().saturating_add(signal / T::PowSmoothingFactor::get());
+        let expected = T::BaselineDifficulty::get().saturating_add(signal as u32 / T::PowSmoothingFactor::get());
         assert_eq!(state.difficulty, expected);
     }
 }
@@ -17,19 +17,40 @@ use frame_support::{
     transactional,
 };
 use frame_system::pallet_prelude::*;
+use nodara_emergency::HaltGuard;
 use sp_std::vec::Vec;
 use sp_runtime::{RuntimeDebug, traits::SaturatedConversion};
 use parity_scale_codec::{Encode, Decode};
+use scale_info::TypeInfo;
+
+/// Entrée de l'historique des ajustements de difficulté.
+///
+/// L'ordre des champs reproduit l'ancien tuple `(timestamp, ancien niveau, nouveau niveau, signal)`,
+/// mais le signal est passé de `u32` à `i32`. Les deux s'encodent sur 4 octets : les entrées
+/// existantes se décodent sans migration, un ancien signal supérieur à `i32::MAX` se relisant
+/// toutefois comme négatif. Le tuple renvoyé par l'API runtime `pow_history_page` porte lui aussi
+/// un `i32` ; les clients décodant l'ancien `(u64, u32, u32, u32)` doivent être mis à jour.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, Default, TypeInfo)]
+pub struct PowAdjustment {
+    /// Numéro de bloc de l'ajustement.
+    pub timestamp: u64,
+    /// Difficulté avant l'ajustement.
+    pub old_difficulty: u32,
+    /// Difficulté après l'ajustement.
+    pub new_difficulty: u32,
+    /// Signal soumis (négatif pour une baisse de difficulté).
+    pub signal: i32,
+}
 
 /// Structure représentant l'état de PoW.
-#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, Default)]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, Default, TypeInfo)]
 pub struct PowState {
     /// Difficulté actuelle du PoW.
     pub difficulty: u32,
     /// Total cumulé de travail soumis.
     pub total_work: u32,
-    /// Historique des ajustements : (timestamp, ancien niveau, nouveau niveau, signal soumis).
-    pub history: Vec<(u64, u32, u32, u32)>,
+    /// Historique des ajustements de difficulté.
+    pub history: Vec<PowAdjustment>,
 }
 
 #[frame_support::pallet]
@@ -51,6 +72,17 @@ pub mod pallet {
         /// Facteur de lissage pour l'ajustement de la difficulté (doit être > 0).
         #[pallet::constant]
         type PowSmoothingFactor: Get<u32>;
+        /// Difficulté minimale atteignable par ajustement.
+        #[pallet::constant]
+        type MinDifficulty: Get<u32>;
+        /// Difficulté maximale atteignable par ajustement.
+        #[pallet::constant]
+        type MaxDifficulty: Get<u32>;
+        /// Origine autorisée à soumettre les signaux d'ajustement de la difficulté (oracle de
+        /// réseau ou gouvernance).
+        type SignalOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Arrêt d'urgence global consulté par chaque appel signé ; `()` le désactive.
+        type EmergencyHalt: nodara_emergency::HaltGuard;
     }
 
     /// Stockage de l'état PoW.
@@ -58,13 +90,23 @@ pub mod pallet {
     #[pallet::getter(fn pow_state)]
     pub type PowStateStorage<T: Config> = StorageValue<_, PowState, ValueQuery>;
 
+    /// Travail accepté cumulé par mineur, remis à zéro avec l'état PoW.
+    #[pallet::storage]
+    #[pallet::getter(fn work_by_miner)]
+    pub type WorkByMiner<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Indique si `initialize_pow` a été exécutée.
+    #[pallet::storage]
+    #[pallet::getter(fn is_initialized)]
+    pub type Initialized<T: Config> = StorageValue<_, bool, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         /// Un travail a été soumis et validé. (mineur, work_value)
         PowSubmitted(T::AccountId, u32),
         /// La difficulté a été ajustée. (ancien niveau, nouveau niveau, signal)
-        DifficultyAdjusted(u32, u32, u32),
+        DifficultyAdjusted(u32, u32, i32),
     }
 
     #[pallet::error]
@@ -75,25 +117,50 @@ pub mod pallet {
         WorkRejected,
         /// La vérification de la signature a échoué.
         SignatureVerificationFailed,
+        /// Le module PoW n'a pas encore été initialisé.
+        NotInitialized,
+        /// Le module est déjà initialisé ; utiliser la réinitialisation explicite.
+        AlreadyInitialized,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn integrity_test() {
+            assert!(
+                T::MinDifficulty::get() <= T::BaselineDifficulty::get()
+                    && T::BaselineDifficulty::get() <= T::MaxDifficulty::get(),
+                "MinDifficulty <= BaselineDifficulty <= MaxDifficulty doit être respecté"
+            );
+        }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
-        /// Initialise l'état PoW avec la difficulté de base.
+        /// Initialise l'état PoW.
+        ///
+        /// Sans `recent_work`, la difficulté part de `BaselineDifficulty`. Une chaîne qui reprend
+        /// après un redémarrage peut fournir une estimation du travail récent : la difficulté
+        /// initiale en est dérivée, bornée à `[MinDifficulty, MaxDifficulty]`.
         ///
         /// Doit être appelé par Root.
         #[pallet::weight(10_000)]
-        pub fn initialize_pow(origin: OriginFor<T>) -> DispatchResult {
+        pub fn initialize_pow(origin: OriginFor<T>, recent_work: Option<u32>) -> DispatchResult {
             ensure_root(origin)?;
-            let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
-            let baseline = T::BaselineDifficulty::get();
-            let state = PowState {
-                difficulty: baseline,
-                total_work: 0,
-                history: vec![(now, 0, baseline, 0)],
+            ensure!(!Initialized::<T>::get(), Error::<T>::AlreadyInitialized);
+            let difficulty = match recent_work {
+                Some(work) => work.clamp(T::MinDifficulty::get(), T::MaxDifficulty::get()),
+                None => T::BaselineDifficulty::get(),
             };
-            <PowStateStorage<T>>::put(state);
-            Ok(())
+            Self::do_initialize_pow(difficulty)
+        }
+
+        /// Réinitialise délibérément l'état PoW (difficulté de base, travail et historique).
+        ///
+        /// Doit être appelé par Root ; `initialize_pow` refuse d'écraser un état existant.
+        #[pallet::weight(10_000)]
+        pub fn reset_pow(origin: OriginFor<T>) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::do_initialize_pow(T::BaselineDifficulty::get())
         }
 
         /// Soumet un travail de minage.
@@ -107,6 +174,8 @@ pub mod pallet {
             signature: Vec<u8>,
         ) -> DispatchResult {
             let miner = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::ensure_initialized()?;
             ensure!(work_value > 0, Error::<T>::InvalidWork);
             // Vérification de la signature (simulation)
             ensure!(Self::verify_signature(work_value, &signature), Error::<T>::SignatureVerificationFailed);
@@ -118,6 +187,7 @@ pub mod pallet {
             <PowStateStorage<T>>::mutate(|s| {
                 s.total_work = s.total_work.saturating_add(work_value);
             });
+            WorkByMiner::<T>::mutate(&miner, |work| *work = work.saturating_add(work_value));
 
             Self::deposit_event(Event::PowSubmitted(miner, work_value));
             Ok(())
@@ -126,34 +196,101 @@ pub mod pallet {
         /// Ajuste la difficulté en fonction d'un signal.
         ///
         /// Le nouveau niveau de difficulté est calculé par :
-        ///     new_difficulty = current_difficulty + (signal / PowSmoothingFactor)
+        ///     new_difficulty = clamp(current_difficulty + (signal / PowSmoothingFactor), MinDifficulty, MaxDifficulty)
+        /// Un signal négatif fait baisser la difficulté.
+        ///
+        /// Réservé à `SignalOrigin`.
         #[pallet::weight(10_000)]
         pub fn adjust_difficulty(
             origin: OriginFor<T>,
-            signal: u32,
+            signal: i32,
         ) -> DispatchResult {
-            ensure_signed(origin)?;
-            // Vérifier que le signal est positif.
-            ensure!(signal > 0, Error::<T>::InvalidWork);
+            T::SignalOrigin::ensure_origin(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::ensure_initialized()?;
+            // Vérifier que le signal est non nul.
+            ensure!(signal != 0, Error::<T>::InvalidWork);
             let smoothing = T::PowSmoothingFactor::get();
             ensure!(smoothing > 0, "Smoothing factor must be non-zero");
 
             <PowStateStorage<T>>::mutate(|s| {
                 let previous = s.difficulty;
-                let adjustment = signal / smoothing;
-                let new_difficulty = previous.saturating_add(adjustment);
+                let adjustment = i64::from(signal) / i64::from(smoothing);
+                let new_difficulty = i64::from(previous)
+                    .saturating_add(adjustment)
+                    .clamp(i64::from(T::MinDifficulty::get()), i64::from(T::MaxDifficulty::get()))
+                    as u32;
                 let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
-                s.history.push((now, previous, new_difficulty, signal));
+                s.history.push(PowAdjustment {
+                    timestamp: now,
+                    old_difficulty: previous,
+                    new_difficulty,
+                    signal,
+                });
                 s.difficulty = new_difficulty;
             });
             let state = <PowStateStorage<T>>::get();
             let last_record = state.history.last().unwrap();
-            Self::deposit_event(Event::DifficultyAdjusted(last_record.1, state.difficulty, signal));
+            Self::deposit_event(Event::DifficultyAdjusted(last_record.old_difficulty, state.difficulty, signal));
             Ok(())
         }
     }
 
     impl<T: Config> Pallet<T> {
+        /// Échoue si `initialize_pow` n'a pas encore été exécutée.
+        fn ensure_initialized() -> DispatchResult {
+            ensure!(Initialized::<T>::get(), Error::<T>::NotInitialized);
+            Ok(())
+        }
+
+        /// Met en place l'état PoW initial à la difficulté `difficulty`, en écrasant l'état courant
+        /// et le travail cumulé par mineur.
+        fn do_initialize_pow(difficulty: u32) -> DispatchResult {
+            let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+            let state = PowState {
+                difficulty,
+                total_work: 0,
+                history: vec![PowAdjustment {
+                    timestamp: now,
+                    old_difficulty: 0,
+                    new_difficulty: difficulty,
+                    signal: 0,
+                }],
+            };
+            <PowStateStorage<T>>::put(state);
+            let _ = WorkByMiner::<T>::clear(u32::MAX, None);
+            Initialized::<T>::put(true);
+            Ok(())
+        }
+
+        /// Nombre d'entrées dans l'historique des ajustements de difficulté.
+        pub fn pow_history_len() -> u32 {
+            <PowStateStorage<T>>::get().history.len() as u32
+        }
+
+        /// Retourne au plus `len` entrées de l'historique à partir de l'index `start`.
+        ///
+        /// Une page commençant au-delà de la fin de l'historique est vide. Les entrées sont
+        /// renvoyées sous forme de tuples pour conserver la signature de l'API runtime.
+        pub fn pow_history_page(start: u32, len: u32) -> Vec<(u64, u32, u32, i32)> {
+            <PowStateStorage<T>>::get()
+                .history
+                .into_iter()
+                .skip(start as usize)
+                .take(len as usize)
+                .map(|a| (a.timestamp, a.old_difficulty, a.new_difficulty, a.signal))
+                .collect()
+        }
+
+        /// Retourne les `n` mineurs ayant soumis le plus de travail, par travail décroissant puis
+        /// par compte croissant en cas d'égalité.
+        pub fn top_miners(n: u32) -> Vec<(T::AccountId, u32)> {
+            let mut miners: Vec<(T::AccountId, u32)> = WorkByMiner::<T>::iter().collect();
+            miners.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            miners.truncate(n as usize);
+            miners
+        }
+
         /// Vérifie la signature du travail.
         /// Ici, nous simulons la vérification en comparant la signature au hash Blake2-128 du work_value encodé.
         fn verify_signature(work_value: u32, signature: &Vec<u8>) -> bool {
@@ -193,6 +330,8 @@ mod tests {
         pub const BlockHashCount: u64 = 250;
         pub const BaselineDifficulty: u32 = 100;
         pub const PowSmoothingFactor: u32 = 10;
+        pub const MinDifficulty: u32 = 50;
+        pub const MaxDifficulty: u32 = 200;
     }
 
     impl system::Config for Test {
@@ -226,23 +365,27 @@ mod tests {
         type RuntimeEvent = ();
         type BaselineDifficulty = BaselineDifficulty;
         type PowSmoothingFactor = PowSmoothingFactor;
+        type MinDifficulty = MinDifficulty;
+        type MaxDifficulty = MaxDifficulty;
+        type SignalOrigin = frame_system::EnsureRoot<u64>;
+        type EmergencyHalt = ();
     }
 
     #[test]
     fn test_initialize_pow() {
         let origin = system::RawOrigin::Root.into();
-        assert_ok!(PowModule::initialize_pow(origin));
+        assert_ok!(PowModule::initialize_pow(origin, None));
         let state = PowModule::pow_state();
         assert_eq!(state.difficulty, BaselineDifficulty::get());
         assert_eq!(state.history.len(), 1);
         let record = &state.history[0];
-        assert_eq!(record.new_level, BaselineDifficulty::get());
+        assert_eq!(record.new_difficulty, BaselineDifficulty::get());
     }
 
     #[test]
     fn test_submit_work_should_work() {
         // Initialize state.
-        assert_ok!(PowModule::initialize_pow(system::RawOrigin::Root.into()));
+        assert_ok!(PowModule::initialize_pow(system::RawOrigin::Root.into(), None));
         let origin = system::RawOrigin::Signed(1).into();
         let work_value = 150;
         // Génère une signature valide en utilisant le hash Blake2-128 du work_value encodé.
@@ -256,7 +399,7 @@ mod tests {
 
     #[test]
     fn test_submit_work_should_fail_if_work_too_low() {
-        assert_ok!(PowModule::initialize_pow(system::RawOrigin::Root.into()));
+        assert_ok!(PowModule::initialize_pow(system::RawOrigin::Root.into(), None));
         let origin = system::RawOrigin::Signed(1).into();
         let work_value = 50; // en dessous de la difficulté de 100
         let signature = work_value.encode();
@@ -270,8 +413,8 @@ mod tests {
     #[test]
     fn test_adjust_difficulty() {
         // Initialize state.
-        assert_ok!(PowModule::initialize_pow(system::RawOrigin::Root.into()));
-        let origin = system::RawOrigin::Signed(1).into();
+        assert_ok!(PowModule::initialize_pow(system::RawOrigin::Root.into(), None));
+        let origin = system::RawOrigin::Root.into();
         // Avec signal 50 et smoothing factor 10, adjustment = 50/10 = 5.
         let signal = 50;
         assert_ok!(PowModule::adjust_difficulty(origin, signal));
@@ -279,4 +422,187 @@ mod tests {
         assert_eq!(state.difficulty, BaselineDifficulty::get() + 5);
         assert_eq!(state.history.len(), 2);
     }
+
+    #[test]
+    fn test_adjust_difficulty_downward() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(PowModule::initialize_pow(system::RawOrigin::Root.into(), None));
+            assert_ok!(PowModule::adjust_difficulty(system::RawOrigin::Root.into(), -30));
+            assert_eq!(PowModule::pow_state().difficulty, BaselineDifficulty::get() - 3);
+        });
+    }
+
+    #[test]
+    fn test_adjust_difficulty_clamped_to_bounds() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(PowModule::initialize_pow(system::RawOrigin::Root.into(), None));
+            assert_ok!(PowModule::adjust_difficulty(system::RawOrigin::Root.into(), 5_000));
+            assert_eq!(PowModule::pow_state().difficulty, MaxDifficulty::get());
+            assert_ok!(PowModule::adjust_difficulty(system::RawOrigin::Root.into(), -5_000));
+            assert_eq!(PowModule::pow_state().difficulty, MinDifficulty::get());
+            let record = PowModule::pow_state().history.last().cloned().unwrap();
+            assert_eq!(record.old_difficulty, MaxDifficulty::get());
+            assert_eq!(record.new_difficulty, MinDifficulty::get());
+        });
+    }
+
+    #[test]
+    fn test_adjustment_record_fields() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(PowModule::initialize_pow(system::RawOrigin::Root.into(), None));
+            System::set_block_number(7);
+            assert_ok!(PowModule::adjust_difficulty(system::RawOrigin::Root.into(), 40));
+            let record = PowModule::pow_state().history.last().cloned().unwrap();
+            assert_eq!(
+                record,
+                PowAdjustment {
+                    timestamp: 7,
+                    old_difficulty: BaselineDifficulty::get(),
+                    new_difficulty: BaselineDifficulty::get() + 4,
+                    signal: 40,
+                }
+            );
+        });
+    }
+
+    pub fn new_test_ext() -> sp_io::TestExternalities {
+        let storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+        storage.into()
+    }
+
+    #[test]
+    fn test_history_paging() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(PowModule::initialize_pow(system::RawOrigin::Root.into(), None));
+            for (block, signal) in [(2u64, 10i32), (3, 20), (4, 30)] {
+                System::set_block_number(block);
+                assert_ok!(PowModule::adjust_difficulty(system::RawOrigin::Root.into(), signal));
+            }
+            assert_eq!(PowModule::pow_history_len(), 4);
+
+            let first = PowModule::pow_history_page(0, 2);
+            assert_eq!(first, vec![(0, 0, 100, 0), (2, 100, 101, 10)]);
+            let second = PowModule::pow_history_page(2, 2);
+            assert_eq!(second, vec![(3, 101, 103, 20), (4, 103, 106, 30)]);
+            assert!(PowModule::pow_history_page(4, 2).is_empty());
+            assert_eq!(PowModule::pow_history_page(3, 10).len(), 1);
+        });
+    }
+
+    #[test]
+    fn test_operations_require_initialization() {
+        new_test_ext().execute_with(|| {
+            let work_value: u32 = 150;
+            let signature = sp_io::hashing::blake2_128(&work_value.encode()).to_vec();
+            assert_err!(
+                PowModule::submit_work(system::RawOrigin::Signed(1).into(), work_value, signature.clone()),
+                Error::<Test>::NotInitialized
+            );
+            assert_err!(
+                PowModule::adjust_difficulty(system::RawOrigin::Root.into(), 10),
+                Error::<Test>::NotInitialized
+            );
+            assert_ok!(PowModule::initialize_pow(system::RawOrigin::Root.into(), None));
+            assert_ok!(PowModule::submit_work(system::RawOrigin::Signed(1).into(), work_value, signature));
+            assert_ok!(PowModule::adjust_difficulty(system::RawOrigin::Root.into(), 10));
+        });
+    }
+
+    #[test]
+    fn test_adjust_difficulty_requires_signal_origin() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(PowModule::initialize_pow(system::RawOrigin::Root.into(), None));
+            assert_err!(
+                PowModule::adjust_difficulty(system::RawOrigin::Signed(1).into(), -5_000),
+                sp_runtime::DispatchError::BadOrigin
+            );
+            assert_eq!(PowModule::pow_state().difficulty, BaselineDifficulty::get());
+            assert_eq!(PowModule::pow_history_len(), 1);
+        });
+    }
+
+    #[test]
+    fn test_integrity_test_accepts_ordered_bounds() {
+        use frame_support::traits::Hooks;
+        <PowModule as Hooks<u64>>::integrity_test();
+    }
+
+    #[test]
+    fn test_second_initialize_is_rejected() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(PowModule::initialize_pow(system::RawOrigin::Root.into(), None));
+            assert_ok!(PowModule::adjust_difficulty(system::RawOrigin::Root.into(), 40));
+            let adjusted = PowModule::pow_state();
+            assert_err!(PowModule::initialize_pow(system::RawOrigin::Root.into(), None), Error::<Test>::AlreadyInitialized);
+            assert_eq!(PowModule::pow_state(), adjusted);
+
+            assert_err!(PowModule::reset_pow(system::RawOrigin::Signed(1).into()), sp_runtime::DispatchError::BadOrigin);
+            assert_ok!(PowModule::reset_pow(system::RawOrigin::Root.into()));
+            assert_eq!(PowModule::pow_state().difficulty, BaselineDifficulty::get());
+            assert_eq!(PowModule::pow_history_len(), 1);
+        });
+    }
+
+    #[test]
+    fn test_initialize_pow_seeded_from_recent_work() {
+        for (recent_work, expected) in [
+            (None, BaselineDifficulty::get()),
+            (Some(150), 150),
+            (Some(10), MinDifficulty::get()),
+            (Some(5_000), MaxDifficulty::get()),
+        ] {
+            new_test_ext().execute_with(|| {
+                assert_ok!(PowModule::initialize_pow(system::RawOrigin::Root.into(), recent_work));
+                let state = PowModule::pow_state();
+                assert_eq!(state.difficulty, expected);
+                assert_eq!(state.history.len(), 1);
+                assert_eq!(state.history[0].new_difficulty, expected);
+            });
+        }
+    }
+
+    #[test]
+    fn test_history_seeded_difficulty_gates_submissions() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(PowModule::initialize_pow(system::RawOrigin::Root.into(), Some(180)));
+            let signature = sp_io::hashing::blake2_128(&150u32.encode()).to_vec();
+            // Accepté avec la difficulté de base, rejeté avec la difficulté reprise de l'historique.
+            assert_err!(
+                PowModule::submit_work(system::RawOrigin::Signed(1).into(), 150, signature),
+                Error::<Test>::WorkRejected
+            );
+
+            assert_ok!(PowModule::reset_pow(system::RawOrigin::Root.into()));
+            assert_eq!(PowModule::pow_state().difficulty, BaselineDifficulty::get());
+        });
+    }
+
+    #[test]
+    fn test_work_by_miner_leaderboard() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(PowModule::initialize_pow(system::RawOrigin::Root.into(), None));
+            let submit = |miner: u64, work_value: u32| {
+                let signature = sp_io::hashing::blake2_128(&work_value.encode()).to_vec();
+                PowModule::submit_work(system::RawOrigin::Signed(miner).into(), work_value, signature)
+            };
+            for (miner, work_value) in [(1, 120), (2, 150), (3, 110), (1, 130), (3, 140)] {
+                assert_ok!(submit(miner, work_value));
+            }
+            // Un travail rejeté n'est pas comptabilisé.
+            assert_err!(submit(4, 60), Error::<Test>::WorkRejected);
+
+            assert_eq!(PowModule::work_by_miner(1), 250);
+            assert_eq!(PowModule::work_by_miner(4), 0);
+            assert_eq!(PowModule::top_miners(10), vec![(1, 250), (3, 250), (2, 150)]);
+            assert_eq!(PowModule::top_miners(1), vec![(1, 250)]);
+            assert_eq!(PowModule::pow_state().total_work, 650);
+
+            WorkByMiner::<Test>::insert(2, u32::MAX - 10);
+            assert_ok!(submit(2, 150));
+            assert_eq!(PowModule::top_miners(1), vec![(2, u32::MAX)]);
+
+            assert_ok!(PowModule::reset_pow(system::RawOrigin::Root.into()));
+            assert!(PowModule::top_miners(10).is_empty());
+        });
+    }
 }
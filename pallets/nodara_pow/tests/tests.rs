@@ -38,6 +38,10 @@ mod tests {
         type RuntimeEvent = ();
         type BaselineDifficulty = sp_runtime::traits::ConstU32<100>;
         type PowSmoothingFactor = sp_runtime::traits::ConstU32<10>;
+        type MinDifficulty = sp_runtime::traits::ConstU32<50>;
+        type MaxDifficulty = sp_runtime::traits::ConstU32<1_000>;
+        type SignalOrigin = frame_system::EnsureRoot<u64>;
+        type EmergencyHalt = ();
     }
 
     pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -24,6 +24,7 @@ pub mod pallet {
         traits::Get,
     };
     use frame_system::pallet_prelude::*;
+    use nodara_emergency::HaltGuard;
     use parity_scale_codec::{Encode, Decode};
     use scale_info::TypeInfo;
     use sp_std::vec::Vec;
@@ -54,6 +55,8 @@ pub mod pallet {
         /// Valeur minimale autorisée.
         #[pallet::constant]
         type MinPredictiveValue: Get<u32>;
+        /// Arrêt d'urgence global consulté par chaque appel signé ; `()` le désactive.
+        type EmergencyHalt: nodara_emergency::HaltGuard;
     }
 
     /// Storage du paramètre prédictif courant.
@@ -66,6 +69,11 @@ pub mod pallet {
     #[pallet::getter(fn predictive_history)]
     pub type PredictiveHistory<T: Config> = StorageValue<_, Vec<PredictiveLog>, ValueQuery>;
 
+    /// Indique si `initialize_predictive` a été exécutée.
+    #[pallet::storage]
+    #[pallet::getter(fn is_initialized)]
+    pub type Initialized<T: Config> = StorageValue<_, bool, ValueQuery>;
+
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
@@ -82,15 +90,25 @@ pub mod pallet {
         PredictiveValueOutOfBounds,
         /// Signal économique invalide.
         InvalidEconomicSignal,
+        /// Le paramètre prédictif n'a pas encore été initialisé.
+        NotInitialized,
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Initialise le paramètre prédictif avec la valeur de base.
+        ///
+        /// Idempotent : si le module est déjà initialisé, l'appel réussit sans modifier la valeur
+        /// ni ajouter d'entrée à l'historique.
         #[pallet::weight(10_000)]
         pub fn initialize_predictive(origin: OriginFor<T>) -> DispatchResult {
             // On accepte un appel signé (pour cet exemple).
             let _ = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            if Initialized::<T>::get() {
+                return Ok(());
+            }
+            Initialized::<T>::put(true);
             let baseline = T::BaselinePredictiveValue::get();
             <PredictiveValue<T>>::put(baseline);
             let timestamp = Self::current_timestamp();
@@ -111,6 +129,8 @@ pub mod pallet {
         #[pallet::weight(10_000)]
         pub fn update_predictive(origin: OriginFor<T>, economic_signal: u32) -> DispatchResult {
             let _ = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            ensure!(Initialized::<T>::get(), Error::<T>::NotInitialized);
             ensure!(economic_signal > 0, Error::<T>::InvalidEconomicSignal);
 
             let current = <PredictiveValue<T>>::get();
@@ -209,6 +229,7 @@ pub mod pallet {
             type BaselinePredictiveValue = BaselinePredictiveValue;
             type MaxPredictiveValue = MaxPredictiveValue;
             type MinPredictiveValue = MinPredictiveValue;
+            type EmergencyHalt = ();
         }
 
         #[test]
@@ -263,5 +284,33 @@ pub mod pallet {
                 Error::<Test>::PredictiveValueOutOfBounds
             );
         }
+
+        pub fn new_test_ext() -> sp_io::TestExternalities {
+            let storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+            storage.into()
+        }
+
+        #[test]
+        fn initialize_predictive_is_idempotent() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(PredictiveGuardModule::initialize_predictive(system::RawOrigin::Signed(1).into()));
+                assert_ok!(PredictiveGuardModule::update_predictive(system::RawOrigin::Signed(1).into(), 50));
+                assert_ok!(PredictiveGuardModule::initialize_predictive(system::RawOrigin::Signed(2).into()));
+                assert_eq!(PredictiveGuardModule::predictive_value(), BaselinePredictiveValue::get() + 5);
+                assert_eq!(PredictiveGuardModule::predictive_history().len(), 2);
+            });
+        }
+
+        #[test]
+        fn update_predictive_requires_initialization() {
+            new_test_ext().execute_with(|| {
+                assert_err!(
+                    PredictiveGuardModule::update_predictive(system::RawOrigin::Signed(1).into(), 500),
+                    Error::<Test>::NotInitialized
+                );
+                assert_eq!(PredictiveGuardModule::predictive_value(), 0);
+                assert!(PredictiveGuardModule::predictive_history().is_empty());
+            });
+        }
     }
 }
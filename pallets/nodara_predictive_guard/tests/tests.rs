@@ -38,6 +38,7 @@ mod tests {
         type RuntimeEvent = ();
         type BaselineParameter = sp_runtime::traits::ConstU32<100>;
         type PredictionSmoothingFactor = sp_runtime::traits::ConstU32<10>;
+        type EmergencyHalt = ();
     }
 
     pub fn new_test_ext() -> sp_io::TestExternalities {
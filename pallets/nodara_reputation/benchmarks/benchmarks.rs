@@ -15,12 +15,27 @@ benchmarks! {
     }
 
     update_reputation {
+        let caller = account("user", 0, 0);
+        <pallet::Pallet<T>>::initialize_reputation(RawOrigin::Signed(caller.clone()).into())?;
+        let delta: i32 = -1;
+        let reason: Vec<u8> = b"Benchmark Negative Update".to_vec();
+    }: {
+        <pallet::Pallet<T>>::update_reputation(RawOrigin::Signed(caller.clone()).into(), delta, pallet::ReputationReason::Penalty, reason.clone())?;
+    }
+    verify {
+        let state = <pallet::ReputationStateStorage<T>>::get(&caller).unwrap();
+        let expected = (T::DefaultReputation::get() as i32 + <pallet::Pallet<T>>::preview_delta(delta)) as u32;
+        assert_eq!(state.score, expected);
+    }
+
+    adjust_reputation {
         let caller = account("user", 0, 0);
         <pallet::Pallet<T>>::initialize_reputation(RawOrigin::Signed(caller.clone()).into())?;
         let delta: i32 = 20;
         let reason: Vec<u8> = b"Benchmark Positive Update".to_vec();
+        let origin = T::GovernanceOrigin::try_successful_origin().map_err(|_| "no governance origin")?;
     }: {
-        <pallet::Pallet<T>>::update_reputation(RawOrigin::Signed(caller.clone()).into(), delta, reason.clone())?;
+        <pallet::Pallet<T>>::adjust_reputation(origin, caller.clone(), delta, pallet::ReputationReason::GoodBehavior, reason.clone())?;
     }
     verify {
         let state = <pallet::ReputationStateStorage<T>>::get(&caller).unwrap();
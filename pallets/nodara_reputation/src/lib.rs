@@ -3,17 +3,57 @@
 
 pub use pallet::*;
 
+use frame_support::dispatch::DispatchResult;
+
+/// Comptabilise un dépôt prélevé dans le module qui le reçoit : le transfert vers
+/// `ReserveFundAccount` ou `RewardPoolAccount` ne met pas à jour à lui seul le solde du fonds de
+/// réserve ni le pool de récompenses.
+///
+/// Implémenté par le runtime, qui connaît les modules destinataires.
+pub trait SlashedDepositHandler {
+    /// Crédite `amount`, déjà versé au compte de `destination`, au module correspondant.
+    fn on_deposit_slashed(destination: SlashDestination, amount: u128) -> DispatchResult;
+}
+
+/// Par défaut, aucun module n'est crédité.
+impl SlashedDepositHandler for () {
+    fn on_deposit_slashed(_destination: SlashDestination, _amount: u128) -> DispatchResult {
+        Ok(())
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
         dispatch::DispatchResult, pallet_prelude::*,
-        traits::{Get, EnsureOrigin, Currency, ReservableCurrency},
+        traits::{BalanceStatus, Get, EnsureOrigin, Currency, ReservableCurrency},
     };
     use frame_system::pallet_prelude::*;
+    use nodara_emergency::HaltGuard;
     use pallet_timestamp as timestamp;
     use sp_std::vec::Vec;
     use parity_scale_codec::{Encode, Decode};
     use scale_info::TypeInfo;
+    use sp_runtime::traits::{SaturatedConversion, Saturating};
+
+    /// Type de solde de la monnaie utilisée pour les dépôts de proposition.
+    pub type BalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+    /// Cause structurée d'un ajustement de réputation, agrégeable on-chain.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum ReputationReason {
+        /// Comportement positif sur le réseau.
+        GoodBehavior,
+        /// Pénalité pour comportement fautif.
+        Penalty,
+        /// Sanction sévère (slashing).
+        Slashing,
+        /// Ajustement automatique effectué par le pallet.
+        Automated,
+        /// Autre cause, détaillée dans le texte libre.
+        Other,
+    }
 
     /// Log d'ajustement de réputation.
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -22,7 +62,9 @@ pub mod pallet {
         pub timestamp: u64,
         /// Variation de réputation (positive ou négative).
         pub delta: i32,
-        /// Raison de l'ajustement.
+        /// Cause structurée de l'ajustement.
+        pub reason_code: ReputationReason,
+        /// Détail libre de l'ajustement.
         pub reason: Vec<u8>,
     }
 
@@ -43,9 +85,21 @@ pub mod pallet {
         // D'autres types de propositions pourront être ajoutés.
     }
 
+    /// Destination du dépôt d'une proposition expirée sans atteindre le seuil.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum SlashDestination {
+        /// Dépôt transféré au compte du fonds de réserve.
+        ReserveFund,
+        /// Dépôt transféré au compte de la réserve de récompenses.
+        RewardPool,
+        /// Dépôt détruit, l'émission totale diminue d'autant.
+        #[default]
+        Burn,
+    }
+
     /// Proposition de gouvernance.
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-    pub struct Proposal<AccountId> {
+    pub struct Proposal<AccountId, Balance> {
         /// Identifiant unique de la proposition.
         pub id: u32,
         /// Auteur de la proposition.
@@ -60,6 +114,10 @@ pub mod pallet {
         pub vote_count: u32,
         /// Indique si la proposition a été finalisée.
         pub finalized: bool,
+        /// Dépôt réservé sur le compte de l'auteur jusqu'à la finalisation ou l'expiration.
+        pub deposit: Balance,
+        /// Bloc de création, point de départ de `VotingPeriod`.
+        pub created_at: u64,
     }
 
     #[pallet::config]
@@ -76,6 +134,34 @@ pub mod pallet {
         type ProposalThreshold: Get<u32>;
         /// Monnaie utilisée pour la réservation éventuelle lors des votes.
         type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
+        /// Dépôt réservé à la création d'une proposition, restitué à sa finalisation.
+        #[pallet::constant]
+        type ProposalDeposit: Get<BalanceOf<Self>>;
+        /// Durée (en blocs) pendant laquelle une proposition peut recevoir des votes. À son terme,
+        /// la proposition est finalisée si elle a atteint `ProposalThreshold`, sinon elle expire
+        /// et son dépôt est prélevé selon `SlashDestination`.
+        #[pallet::constant]
+        type VotingPeriod: Get<u64>;
+        /// Longueur maximale (en octets) de la description d'une proposition et du motif d'un
+        /// ajustement de réputation.
+        #[pallet::constant]
+        type MaxProposalDescriptionLen: Get<u32>;
+        /// Destination des dépôts prélevés sur les propositions expirées (`Burn` par défaut).
+        #[pallet::constant]
+        type SlashDestination: Get<SlashDestination>;
+        /// Compte du fonds de réserve, crédité lorsque `SlashDestination` vaut `ReserveFund`.
+        type ReserveFundAccount: Get<Self::AccountId>;
+        /// Compte de la réserve de récompenses, crédité lorsque `SlashDestination` vaut
+        /// `RewardPool`.
+        type RewardPoolAccount: Get<Self::AccountId>;
+        /// Crédite le dépôt prélevé au module destinataire (solde du fonds de réserve, pool de
+        /// récompenses) ; `()` se contente du transfert.
+        type SlashHandler: crate::SlashedDepositHandler;
+        /// Arrêt d'urgence global consulté par chaque appel signé ; `()` le désactive.
+        type EmergencyHalt: nodara_emergency::HaltGuard;
+        /// Nombre maximal de comptes initialisés par `bulk_initialize_reputation`.
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
     }
 
     /// Stockage de la réputation par compte.
@@ -93,13 +179,19 @@ pub mod pallet {
     #[pallet::storage]
     #[pallet::getter(fn proposals)]
     pub type Proposals<T: Config> =
-        StorageMap<_, Blake2_128Concat, u32, Proposal<T::AccountId>, OptionQuery>;
+        StorageMap<_, Blake2_128Concat, u32, Proposal<T::AccountId, BalanceOf<T>>, OptionQuery>;
 
     /// Compteur pour générer des identifiants uniques pour les propositions.
     #[pallet::storage]
     #[pallet::getter(fn proposal_count)]
     pub type ProposalCount<T: Config> = StorageValue<_, u32, ValueQuery>;
 
+    /// Propositions arrivant au terme de leur période de vote à un bloc donné, traitées par
+    /// `on_initialize`.
+    #[pallet::storage]
+    #[pallet::getter(fn proposal_deadlines)]
+    pub type ProposalDeadlines<T: Config> = StorageMap<_, Blake2_128Concat, u64, Vec<u32>, ValueQuery>;
+
     /// Stockage des votes sur propositions : (id_proposition, compte) -> bool (vote exprimé).
     #[pallet::storage]
     #[pallet::getter(fn proposal_votes)]
@@ -128,6 +220,14 @@ pub mod pallet {
         ProposalFinalized(u32, u32),
         /// Ajustement automatique de réputation réalisé (nombre de comptes affectés).
         AutomatedReputationAdjustment(u32),
+        /// Proposition expirée sans atteindre le seuil, dépôt prélevé selon `SlashDestination` (ID).
+        ProposalExpired(u32),
+        /// Réputations initialisées par la gouvernance (comptes initialisés, comptes ignorés car
+        /// déjà initialisés).
+        ReputationsBulkInitialized(u32, u32),
+        /// Dépôt prélevé versé au compte de destination mais refusé par `SlashHandler`
+        /// (destination, montant).
+        SlashedDepositNotCredited(SlashDestination, u128),
     }
 
     #[pallet::error]
@@ -146,6 +246,17 @@ pub mod pallet {
         ProposalThresholdNotMet,
         /// La proposition est déjà finalisée.
         ProposalAlreadyFinalized,
+        /// Solde libre insuffisant pour réserver le dépôt de proposition.
+        InsufficientDeposit,
+        /// La période de vote de la proposition est écoulée.
+        VotingPeriodEnded,
+        /// La description ou le motif dépasse `MaxProposalDescriptionLen`.
+        DescriptionTooLong,
+        /// Le lot de comptes dépasse `MaxBatchSize`.
+        BatchTooLarge,
+        /// Un compte ne peut pas augmenter sa propre réputation ; les deltas positifs passent par
+        /// `adjust_reputation`.
+        PositiveSelfAdjustment,
     }
 
     #[pallet::pallet]
@@ -154,6 +265,11 @@ pub mod pallet {
     /// Hooks utilisés pour l'automatisation et le reporting.
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Clôt les propositions dont la période de vote se termine à ce bloc.
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            Self::close_proposals(n.saturated_into::<u64>())
+        }
+
         /// Appel périodique pour ajuster automatiquement la réputation.
         fn on_finalize(_n: BlockNumberFor<T>) {
             let affected = Self::automated_reputation_adjustment();
@@ -169,6 +285,7 @@ pub mod pallet {
         #[pallet::weight(10_000)]
         pub fn initialize_reputation(origin: OriginFor<T>) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
             ensure!(!Reputations::<T>::contains_key(&who), Error::<T>::ReputationAlreadyInitialized);
             let record = ReputationRecord {
                 score: T::InitialReputation::get(),
@@ -178,39 +295,82 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Initialise la réputation d'un lot de comptes avec les scores fournis (amorçage des
+        /// validateurs connus, à la genèse ou lors de leur intégration).
+        ///
+        /// Les comptes disposant déjà d'un enregistrement sont ignorés, de même que les doublons
+        /// suivant leur première occurrence dans le lot. Le lot est borné par `MaxBatchSize`.
+        /// Réservé à `GovernanceOrigin`.
+        #[pallet::weight(Weight::from_parts(10_000u64.saturating_mul(accounts.len() as u64), 0))]
+        pub fn bulk_initialize_reputation(
+            origin: OriginFor<T>,
+            accounts: Vec<(T::AccountId, u32)>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            ensure!(accounts.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+            let mut seeded = 0u32;
+            let mut skipped = 0u32;
+            for (account, score) in accounts {
+                if Reputations::<T>::contains_key(&account) {
+                    skipped = skipped.saturating_add(1);
+                    continue;
+                }
+                Reputations::<T>::insert(&account, ReputationRecord { score, history: Vec::new() });
+                seeded = seeded.saturating_add(1);
+            }
+            Self::deposit_event(Event::ReputationsBulkInitialized(seeded, skipped));
+            Ok(())
+        }
+
         /// Met à jour la réputation du compte appelant.
         /// Pour les ajustements négatifs, le delta est multiplié par le facteur de pénalité.
+        ///
+        /// Seuls les deltas nuls ou négatifs sont acceptés : le score conditionne l'accès au
+        /// marché, les récompenses et les remises de frais, un compte ne peut donc pas l'augmenter
+        /// lui-même (`PositiveSelfAdjustment`).
         #[pallet::weight(10_000)]
-        pub fn update_reputation(origin: OriginFor<T>, delta: i32, reason: Vec<u8>) -> DispatchResult {
+        pub fn update_reputation(
+            origin: OriginFor<T>,
+            delta: i32,
+            reason_code: ReputationReason,
+            reason: Vec<u8>,
+        ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            Reputations::<T>::try_mutate(&who, |maybe_record| -> DispatchResult {
-                let record = maybe_record.as_mut().ok_or(Error::<T>::ReputationNotFound)?;
-                let current = record.score as i32;
-                let adjusted_delta = if delta < 0 {
-                    delta.saturating_mul(PenaltyFactor::<T>::get() as i32)
-                } else {
-                    delta
-                };
-                let new_score = current.checked_add(adjusted_delta).ok_or(Error::<T>::ReputationUnderflow)?;
-                ensure!(new_score >= 0, Error::<T>::ReputationUnderflow);
-                record.score = new_score as u32;
-                let now = <timestamp::Pallet<T>>::get();
-                record.history.push(ReputationLog {
-                    timestamp: now,
-                    delta: adjusted_delta,
-                    reason,
-                });
-                Self::deposit_event(Event::ReputationUpdated(who.clone(), adjusted_delta, record.score));
-                Ok(())
-            })
+            T::EmergencyHalt::ensure_running()?;
+            ensure!(delta <= 0, Error::<T>::PositiveSelfAdjustment);
+            Self::apply_delta(who, delta, reason_code, reason)
+        }
+
+        /// Ajuste la réputation de `account`, à la hausse comme à la baisse, avec les mêmes
+        /// règles que `update_reputation`.
+        ///
+        /// Réservé à `GovernanceOrigin` (gouvernance ou oracle de réputation).
+        #[pallet::weight(10_000)]
+        pub fn adjust_reputation(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            delta: i32,
+            reason_code: ReputationReason,
+            reason: Vec<u8>,
+        ) -> DispatchResult {
+            T::GovernanceOrigin::ensure_origin(origin)?;
+            Self::apply_delta(account, delta, reason_code, reason)
         }
 
         /// Permet à un utilisateur de proposer une mise à jour du facteur de pénalité.
+        ///
+        /// `ProposalDeposit` est réservé sur le compte de l'auteur : restitué à la finalisation,
+        /// prélevé selon `SlashDestination` si la proposition expire au terme de `VotingPeriod`.
         #[pallet::weight(10_000)]
         pub fn propose_parameter_update(origin: OriginFor<T>, new_value: u32, description: Vec<u8>) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::ensure_description_len(&description)?;
             // Extension potentielle : vérification d'identité via un module d'interopérabilité.
+            let deposit = T::ProposalDeposit::get();
+            T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
             let proposal_id = ProposalCount::<T>::get().checked_add(1).unwrap_or(1);
+            let created_at = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
             let proposal = Proposal {
                 id: proposal_id,
                 proposer: who.clone(),
@@ -219,8 +379,11 @@ pub mod pallet {
                 description,
                 vote_count: 0,
                 finalized: false,
+                deposit,
+                created_at,
             };
             Proposals::<T>::insert(proposal_id, proposal);
+            ProposalDeadlines::<T>::append(created_at.saturating_add(T::VotingPeriod::get()), proposal_id);
             ProposalCount::<T>::put(proposal_id);
             Self::deposit_event(Event::ProposalCreated(proposal_id, who));
             Ok(())
@@ -230,9 +393,15 @@ pub mod pallet {
         #[pallet::weight(10_000)]
         pub fn vote_on_proposal(origin: OriginFor<T>, proposal_id: u32) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
             Proposals::<T>::try_mutate(proposal_id, |maybe_proposal| -> DispatchResult {
                 let proposal = maybe_proposal.as_mut().ok_or(Error::<T>::ProposalNotFound)?;
                 ensure!(!proposal.finalized, Error::<T>::ProposalAlreadyFinalized);
+                let now = <frame_system::Pallet<T>>::block_number().saturated_into::<u64>();
+                ensure!(
+                    now < proposal.created_at.saturating_add(T::VotingPeriod::get()),
+                    Error::<T>::VotingPeriodEnded
+                );
                 ensure!(ProposalVotes::<T>::get(proposal_id, &who).is_none(), Error::<T>::AlreadyVoted);
                 ProposalVotes::<T>::insert(proposal_id, &who, true);
                 proposal.vote_count = proposal.vote_count.saturating_add(1);
@@ -250,20 +419,133 @@ pub mod pallet {
                 let proposal = maybe_proposal.as_mut().ok_or(Error::<T>::ProposalNotFound)?;
                 ensure!(!proposal.finalized, Error::<T>::ProposalAlreadyFinalized);
                 ensure!(proposal.vote_count >= T::ProposalThreshold::get(), Error::<T>::ProposalThresholdNotMet);
-                match proposal.proposal_type {
-                    ProposalType::UpdatePenaltyFactor => {
-                        PenaltyFactor::<T>::put(proposal.new_value);
-                        Self::deposit_event(Event::GovernanceParameterUpdated(proposal.new_value));
-                    }
-                }
-                proposal.finalized = true;
-                Self::deposit_event(Event::ProposalFinalized(proposal_id, proposal.new_value));
+                Self::apply_proposal(proposal_id, proposal);
                 Ok(())
             })
         }
     }
 
     impl<T: Config> Pallet<T> {
+        /// Applique une proposition ayant atteint le seuil et restitue son dépôt.
+        fn apply_proposal(proposal_id: u32, proposal: &mut Proposal<T::AccountId, BalanceOf<T>>) {
+            match proposal.proposal_type {
+                ProposalType::UpdatePenaltyFactor => {
+                    PenaltyFactor::<T>::put(proposal.new_value);
+                    Self::deposit_event(Event::GovernanceParameterUpdated(proposal.new_value));
+                }
+            }
+            proposal.finalized = true;
+            T::Currency::unreserve(&proposal.proposer, proposal.deposit);
+            Self::deposit_event(Event::ProposalFinalized(proposal_id, proposal.new_value));
+        }
+
+        /// Clôt les propositions dont la période de vote se termine à `block` : celles ayant
+        /// atteint le seuil sont finalisées, les autres sont supprimées avec leurs votes et leur
+        /// dépôt est prélevé selon `SlashDestination`. Les propositions déjà finalisées sont ignorées.
+        fn close_proposals(block: u64) -> Weight {
+            let db = T::DbWeight::get();
+            let ids = ProposalDeadlines::<T>::take(block);
+            if ids.is_empty() {
+                return db.reads_writes(1, 1);
+            }
+            let processed = ids.len() as u64;
+            for proposal_id in ids {
+                let Some(mut proposal) = Proposals::<T>::get(proposal_id) else { continue };
+                if proposal.finalized {
+                    continue;
+                }
+                if proposal.vote_count >= T::ProposalThreshold::get() {
+                    Self::apply_proposal(proposal_id, &mut proposal);
+                    Proposals::<T>::insert(proposal_id, proposal);
+                } else {
+                    Self::slash_deposit(&proposal.proposer, proposal.deposit);
+                    Proposals::<T>::remove(proposal_id);
+                    let _ = ProposalVotes::<T>::clear_prefix(proposal_id, u32::MAX, None);
+                    Self::deposit_event(Event::ProposalExpired(proposal_id));
+                }
+            }
+            db.reads_writes(1 + 2 * processed, 1 + 3 * processed)
+        }
+
+        /// Vérifie que `text` ne dépasse pas `MaxProposalDescriptionLen` octets.
+        fn ensure_description_len(text: &[u8]) -> DispatchResult {
+            ensure!(
+                text.len() <= T::MaxProposalDescriptionLen::get() as usize,
+                Error::<T>::DescriptionTooLong
+            );
+            Ok(())
+        }
+
+        /// Prélève le dépôt réservé de `who` et l'achemine vers `SlashDestination` : transfert
+        /// vers le compte configuré puis crédit du module destinataire via `SlashHandler`, ou
+        /// destruction pour `Burn`.
+        fn slash_deposit(who: &T::AccountId, deposit: BalanceOf<T>) {
+            let destination = T::SlashDestination::get();
+            let beneficiary = match destination {
+                SlashDestination::ReserveFund => T::ReserveFundAccount::get(),
+                SlashDestination::RewardPool => T::RewardPoolAccount::get(),
+                SlashDestination::Burn => {
+                    // L'imbalance négative est abandonnée : l'émission totale est réduite.
+                    let _ = T::Currency::slash_reserved(who, deposit);
+                    return;
+                }
+            };
+            let remaining = match T::Currency::repatriate_reserved(who, &beneficiary, deposit, BalanceStatus::Free) {
+                Ok(remaining) => remaining,
+                Err(_) => return,
+            };
+            let moved = deposit.saturating_sub(remaining).saturated_into::<u128>();
+            if moved > 0 && <T::SlashHandler as crate::SlashedDepositHandler>::on_deposit_slashed(destination, moved).is_err() {
+                Self::deposit_event(Event::SlashedDepositNotCredited(destination, moved));
+            }
+        }
+
+        /// Applique `delta` (ajusté par `preview_delta`) au score de `who` et l'inscrit dans son
+        /// historique.
+        fn apply_delta(who: T::AccountId, delta: i32, reason_code: ReputationReason, reason: Vec<u8>) -> DispatchResult {
+            Self::ensure_description_len(&reason)?;
+            Reputations::<T>::try_mutate(&who, |maybe_record| -> DispatchResult {
+                let record = maybe_record.as_mut().ok_or(Error::<T>::ReputationNotFound)?;
+                let current = record.score as i32;
+                let adjusted_delta = Self::preview_delta(delta);
+                let new_score = current.checked_add(adjusted_delta).ok_or(Error::<T>::ReputationUnderflow)?;
+                ensure!(new_score >= 0, Error::<T>::ReputationUnderflow);
+                record.score = new_score as u32;
+                let now = <timestamp::Pallet<T>>::get();
+                record.history.push(ReputationLog {
+                    timestamp: now,
+                    delta: adjusted_delta,
+                    reason_code,
+                    reason,
+                });
+                Self::deposit_event(Event::ReputationUpdated(who.clone(), adjusted_delta, record.score));
+                Ok(())
+            })
+        }
+
+        /// Delta effectivement appliqué par `update_reputation` et `adjust_reputation` pour
+        /// `raw_delta` : les deltas
+        /// négatifs sont multipliés par le facteur de pénalité courant.
+        pub fn preview_delta(raw_delta: i32) -> i32 {
+            if raw_delta < 0 {
+                raw_delta.saturating_mul(PenaltyFactor::<T>::get() as i32)
+            } else {
+                raw_delta
+            }
+        }
+
+        /// Nombre d'ajustements de réputation d'un compte par cause, dans l'ordre de `ReputationReason`.
+        /// Seules les causes présentes dans l'historique sont retournées.
+        pub fn counts_by_reason(account: &T::AccountId) -> Vec<(ReputationReason, u32)> {
+            let mut counts = sp_std::collections::btree_map::BTreeMap::new();
+            if let Some(record) = Reputations::<T>::get(account) {
+                for log in record.history {
+                    *counts.entry(log.reason_code).or_insert(0u32) += 1;
+                }
+            }
+            counts.into_iter().collect()
+        }
+
         /// Ajuste automatiquement la réputation en fonction d'indicateurs d'activité (ici simulés).
         /// Retourne le nombre de comptes affectés.
         fn automated_reputation_adjustment() -> u32 {
@@ -275,6 +557,7 @@ pub mod pallet {
                     record.history.push(ReputationLog {
                         timestamp: now,
                         delta: 1,
+                        reason_code: ReputationReason::Automated,
                         reason: b"Automated adjustment".to_vec(),
                     });
                     Reputations::<T>::insert(&account, record);
@@ -285,6 +568,13 @@ pub mod pallet {
         }
     }
 
+    /// Expose le score de réputation au moteur de récompenses pour la modulation des paiements.
+    impl<T: Config> nodara_reward_engine::ReputationSource<T::AccountId> for Pallet<T> {
+        fn reputation_of(who: &T::AccountId) -> Option<u32> {
+            Reputations::<T>::get(who).map(|record| record.score)
+        }
+    }
+
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
         pub initial_penalty_factor: u32,
@@ -307,4 +597,375 @@ pub mod pallet {
             PenaltyFactor::<T>::put(self.initial_penalty_factor);
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use frame_support::{assert_err, assert_ok, parameter_types, traits::Hooks};
+        use sp_core::H256;
+        use sp_runtime::{
+            traits::{BlakeTwo256, IdentityLookup},
+            testing::Header,
+        };
+        use frame_system as system;
+
+        type UncheckedExtrinsic = system::mocking::MockUncheckedExtrinsic<Test>;
+        type Block = system::mocking::MockBlock<Test>;
+
+        frame_support::construct_runtime!(
+            pub enum Test where
+                Block = Block,
+                NodeBlock = Block,
+                UncheckedExtrinsic = UncheckedExtrinsic,
+            {
+                System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+                Timestamp: timestamp::Pallet,
+                Balances: pallet_balances,
+                ReputationModule: Pallet,
+            }
+        );
+
+        parameter_types! {
+            pub const BlockHashCount: u64 = 250;
+            pub const MinimumPeriod: u64 = 1;
+            pub const ExistentialDeposit: u128 = 1;
+            pub const InitialReputation: u32 = 100;
+            pub const ProposalThreshold: u32 = 2;
+            pub const ProposalDeposit: u128 = 100;
+            pub const VotingPeriod: u64 = 10;
+            pub const MaxProposalDescriptionLen: u32 = 16;
+            pub const ReserveFundAccount: u64 = 99;
+            pub const RewardPoolAccount: u64 = 98;
+            pub static SlashTo: SlashDestination = SlashDestination::ReserveFund;
+            pub const MaxBatchSize: u32 = 4;
+        }
+
+        impl system::Config for Test {
+            type BaseCallFilter = frame_support::traits::Everything;
+            type BlockWeights = ();
+            type BlockLength = ();
+            type DbWeight = ();
+            type RuntimeOrigin = system::mocking::Origin;
+            type RuntimeCall = Call;
+            type Index = u64;
+            type BlockNumber = u64;
+            type Hash = H256;
+            type Hashing = BlakeTwo256;
+            type AccountId = u64;
+            type Lookup = IdentityLookup<Self::AccountId>;
+            type Header = Header;
+            type RuntimeEvent = ();
+            type BlockHashCount = BlockHashCount;
+            type Version = ();
+            type PalletInfo = ();
+            type AccountData = pallet_balances::AccountData<u128>;
+            type OnNewAccount = ();
+            type OnKilledAccount = ();
+            type SystemWeightInfo = ();
+            type SS58Prefix = ();
+            type OnSetCode = ();
+            type MaxConsumers = ();
+        }
+
+        impl timestamp::Config for Test {
+            type Moment = u64;
+            type OnTimestampSet = ();
+            type MinimumPeriod = MinimumPeriod;
+            type WeightInfo = ();
+        }
+
+        impl pallet_balances::Config for Test {
+            type RuntimeEvent = ();
+            type Balance = u128;
+            type DustRemoval = ();
+            type ExistentialDeposit = ExistentialDeposit;
+            type AccountStore = System;
+            type WeightInfo = ();
+            type MaxLocks = ();
+            type MaxReserves = ();
+            type ReserveIdentifier = [u8; 8];
+            type RuntimeHoldReason = ();
+            type RuntimeFreezeReason = ();
+            type FreezeIdentifier = ();
+            type MaxFreezes = ();
+        }
+
+        impl Config for Test {
+            type RuntimeEvent = ();
+            type InitialReputation = InitialReputation;
+            type GovernanceOrigin = frame_system::EnsureRoot<u64>;
+            type ProposalThreshold = ProposalThreshold;
+            type Currency = Balances;
+            type ProposalDeposit = ProposalDeposit;
+            type VotingPeriod = VotingPeriod;
+            type MaxProposalDescriptionLen = MaxProposalDescriptionLen;
+            type SlashDestination = SlashTo;
+            type ReserveFundAccount = ReserveFundAccount;
+            type RewardPoolAccount = RewardPoolAccount;
+            type SlashHandler = RecordingSlashHandler;
+            type EmergencyHalt = ();
+            type MaxBatchSize = MaxBatchSize;
+        }
+
+        thread_local! {
+            static CREDITED: core::cell::RefCell<Vec<(SlashDestination, u128)>> = core::cell::RefCell::new(Vec::new());
+        }
+
+        /// Enregistre les dépôts prélevés qui lui sont crédités.
+        pub struct RecordingSlashHandler;
+        impl crate::SlashedDepositHandler for RecordingSlashHandler {
+            fn on_deposit_slashed(destination: SlashDestination, amount: u128) -> DispatchResult {
+                CREDITED.with(|credited| credited.borrow_mut().push((destination, amount)));
+                Ok(())
+            }
+        }
+
+        fn credited() -> Vec<(SlashDestination, u128)> {
+            CREDITED.with(|credited| credited.borrow_mut().drain(..).collect())
+        }
+
+        pub fn new_test_ext() -> sp_io::TestExternalities {
+            let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+            pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 1_000), (2, 50)] }
+                .assimilate_storage(&mut storage)
+                .unwrap();
+            let mut ext: sp_io::TestExternalities = storage.into();
+            ext.execute_with(|| System::set_block_number(1));
+            ext
+        }
+
+        fn propose(who: u64) -> DispatchResult {
+            ReputationModule::propose_parameter_update(system::RawOrigin::Signed(who).into(), 3, b"penalite x3".to_vec())
+        }
+
+        #[test]
+        fn proposing_reserves_the_deposit() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(propose(1));
+                assert_eq!((Balances::free_balance(1), Balances::reserved_balance(1)), (900, 100));
+                assert_eq!(ReputationModule::proposals(1).map(|p| (p.deposit, p.created_at)), Some((100, 1)));
+
+                // Le compte 2 ne peut pas couvrir le dépôt.
+                assert_err!(propose(2), Error::<Test>::InsufficientDeposit);
+                assert_eq!(ReputationModule::proposal_count(), 1);
+            });
+        }
+
+        #[test]
+        fn deposit_is_refunded_on_finalization() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(propose(1));
+                for voter in [3, 4] {
+                    assert_ok!(ReputationModule::vote_on_proposal(system::RawOrigin::Signed(voter).into(), 1));
+                }
+                assert_ok!(ReputationModule::finalize_proposal(system::RawOrigin::Root.into(), 1));
+                assert_eq!(ReputationModule::penalty_factor(), 3);
+                assert_eq!((Balances::free_balance(1), Balances::reserved_balance(1)), (1_000, 0));
+
+                // Déjà finalisée : ignorée au terme de la période de vote.
+                ReputationModule::on_initialize(11);
+                assert_eq!(ReputationModule::proposals(1).map(|p| p.finalized), Some(true));
+                assert_eq!(Balances::free_balance(ReserveFundAccount::get()), 0);
+            });
+        }
+
+        #[test]
+        fn proposal_without_enough_votes_expires_and_deposit_is_slashed() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(propose(1));
+                assert_ok!(ReputationModule::vote_on_proposal(system::RawOrigin::Signed(3).into(), 1));
+                assert_eq!(ReputationModule::proposal_deadlines(11), vec![1]);
+
+                ReputationModule::on_initialize(10);
+                assert!(ReputationModule::proposals(1).is_some());
+
+                System::set_block_number(11);
+                ReputationModule::on_initialize(11);
+                assert!(ReputationModule::proposals(1).is_none());
+                assert!(ReputationModule::proposal_votes(1, 3).is_none());
+                assert!(ReputationModule::proposal_deadlines(11).is_empty());
+                assert_eq!(ReputationModule::penalty_factor(), 0);
+                assert_eq!((Balances::free_balance(1), Balances::reserved_balance(1)), (900, 0));
+                assert_eq!(Balances::free_balance(ReserveFundAccount::get()), 100);
+                assert_err!(
+                    ReputationModule::vote_on_proposal(system::RawOrigin::Signed(4).into(), 1),
+                    Error::<Test>::ProposalNotFound
+                );
+            });
+        }
+
+        #[test]
+        fn expired_deposit_is_routed_to_the_configured_destination() {
+            for (destination, reserve, pool, burned) in [
+                (SlashDestination::ReserveFund, 100, 0, 0),
+                (SlashDestination::RewardPool, 0, 100, 0),
+                (SlashDestination::Burn, 0, 0, 100),
+            ] {
+                new_test_ext().execute_with(|| {
+                    SlashTo::set(destination);
+                    let issuance = Balances::total_issuance();
+                    assert_ok!(propose(1));
+
+                    ReputationModule::on_initialize(11);
+                    assert!(ReputationModule::proposals(1).is_none());
+                    assert_eq!((Balances::free_balance(1), Balances::reserved_balance(1)), (900, 0));
+                    assert_eq!(Balances::free_balance(ReserveFundAccount::get()), reserve);
+                    assert_eq!(Balances::free_balance(RewardPoolAccount::get()), pool);
+                    assert_eq!(Balances::total_issuance(), issuance - burned);
+                    // Le module destinataire est crédité du montant transféré ; rien pour `Burn`.
+                    let expected = if burned > 0 { Vec::new() } else { vec![(destination, 100)] };
+                    assert_eq!(credited(), expected);
+                });
+            }
+            SlashTo::set(SlashDestination::ReserveFund);
+        }
+
+        #[test]
+        fn proposal_reaching_threshold_before_deadline_is_finalized() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(propose(1));
+                assert_ok!(ReputationModule::vote_on_proposal(system::RawOrigin::Signed(3).into(), 1));
+
+                // Dernier bloc de la période de vote.
+                System::set_block_number(10);
+                assert_ok!(ReputationModule::vote_on_proposal(system::RawOrigin::Signed(4).into(), 1));
+
+                System::set_block_number(11);
+                assert_err!(
+                    ReputationModule::vote_on_proposal(system::RawOrigin::Signed(5).into(), 1),
+                    Error::<Test>::VotingPeriodEnded
+                );
+                ReputationModule::on_initialize(11);
+                assert_eq!(ReputationModule::proposals(1).map(|p| p.finalized), Some(true));
+                assert_eq!(ReputationModule::penalty_factor(), 3);
+                assert_eq!((Balances::free_balance(1), Balances::reserved_balance(1)), (1_000, 0));
+                assert_eq!(Balances::free_balance(ReserveFundAccount::get()), 0);
+            });
+        }
+
+        #[test]
+        fn preview_delta_matches_applied_score_change() {
+            new_test_ext().execute_with(|| {
+                PenaltyFactor::<Test>::put(3);
+                assert_ok!(ReputationModule::initialize_reputation(system::RawOrigin::Signed(1).into()));
+                for raw in [7, -5] {
+                    let before = ReputationModule::reputations(1).unwrap().score as i32;
+                    let preview = ReputationModule::preview_delta(raw);
+                    assert_ok!(ReputationModule::adjust_reputation(
+                        system::RawOrigin::Root.into(),
+                        1,
+                        raw,
+                        ReputationReason::Other,
+                        Vec::new()
+                    ));
+                    let after = ReputationModule::reputations(1).unwrap().score as i32;
+                    assert_eq!(after - before, preview);
+                }
+                assert_eq!(ReputationModule::preview_delta(-5), -15);
+            });
+        }
+
+        #[test]
+        fn only_governance_can_raise_a_reputation() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(ReputationModule::initialize_reputation(system::RawOrigin::Signed(1).into()));
+                let initial = ReputationModule::reputations(1).unwrap().score;
+                assert_err!(
+                    ReputationModule::update_reputation(
+                        system::RawOrigin::Signed(1).into(),
+                        50,
+                        ReputationReason::GoodBehavior,
+                        Vec::new()
+                    ),
+                    Error::<Test>::PositiveSelfAdjustment
+                );
+                assert_err!(
+                    ReputationModule::adjust_reputation(
+                        system::RawOrigin::Signed(1).into(),
+                        1,
+                        50,
+                        ReputationReason::GoodBehavior,
+                        Vec::new()
+                    ),
+                    sp_runtime::DispatchError::BadOrigin
+                );
+                assert_eq!(ReputationModule::reputations(1).unwrap().score, initial);
+
+                assert_ok!(ReputationModule::adjust_reputation(
+                    system::RawOrigin::Root.into(),
+                    1,
+                    50,
+                    ReputationReason::GoodBehavior,
+                    Vec::new()
+                ));
+                assert_eq!(ReputationModule::reputations(1).unwrap().score, initial + 50);
+                assert_err!(
+                    ReputationModule::adjust_reputation(system::RawOrigin::Root.into(), 2, 50, ReputationReason::GoodBehavior, Vec::new()),
+                    Error::<Test>::ReputationNotFound
+                );
+            });
+        }
+
+        #[test]
+        fn descriptions_and_reasons_are_bounded() {
+            new_test_ext().execute_with(|| {
+                let at_limit = vec![b'a'; 16];
+                let over_limit = vec![b'a'; 17];
+                assert_err!(
+                    ReputationModule::propose_parameter_update(system::RawOrigin::Signed(1).into(), 3, over_limit.clone()),
+                    Error::<Test>::DescriptionTooLong
+                );
+                assert_eq!((ReputationModule::proposal_count(), Balances::reserved_balance(1)), (0, 0));
+                assert_ok!(ReputationModule::propose_parameter_update(
+                    system::RawOrigin::Signed(1).into(),
+                    3,
+                    at_limit.clone()
+                ));
+                assert_eq!(ReputationModule::proposals(1).map(|p| p.description), Some(at_limit.clone()));
+
+                assert_ok!(ReputationModule::initialize_reputation(system::RawOrigin::Signed(1).into()));
+                assert_err!(
+                    ReputationModule::update_reputation(
+                        system::RawOrigin::Signed(1).into(),
+                        -5,
+                        ReputationReason::Other,
+                        over_limit
+                    ),
+                    Error::<Test>::DescriptionTooLong
+                );
+                assert_ok!(ReputationModule::update_reputation(
+                    system::RawOrigin::Signed(1).into(),
+                    -5,
+                    ReputationReason::Other,
+                    at_limit
+                ));
+                assert_eq!(ReputationModule::reputations(1).map(|r| r.history.len()), Some(1));
+            });
+        }
+
+        #[test]
+        fn governance_seeds_reputation_for_new_accounts_only() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(ReputationModule::initialize_reputation(system::RawOrigin::Signed(1).into()));
+                let batch = vec![(1, 900), (10, 800), (11, 700), (10, 50)];
+                assert_err!(
+                    ReputationModule::bulk_initialize_reputation(system::RawOrigin::Signed(1).into(), batch.clone()),
+                    sp_runtime::DispatchError::BadOrigin
+                );
+                assert_err!(
+                    ReputationModule::bulk_initialize_reputation(system::RawOrigin::Root.into(), vec![(20, 1); 5]),
+                    Error::<Test>::BatchTooLarge
+                );
+
+                assert_ok!(ReputationModule::bulk_initialize_reputation(system::RawOrigin::Root.into(), batch));
+                let score = |who| ReputationModule::reputations(who).map(|record| record.score);
+                // Le compte 1 garde son score initial, le doublon du compte 10 est ignoré.
+                assert_eq!(score(1), Some(InitialReputation::get()));
+                assert_eq!(score(10), Some(800));
+                assert_eq!(score(11), Some(700));
+                assert!(ReputationModule::reputations(10).map_or(false, |record| record.history.is_empty()));
+                assert_eq!(score(12), None);
+            });
+        }
+    }
 }
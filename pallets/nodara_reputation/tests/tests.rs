@@ -37,6 +37,9 @@ mod tests {
     impl pallet::Config for TestConfig {
         type RuntimeEvent = ();
         type DefaultReputation = sp_runtime::traits::ConstU32<100>;
+        type SlashHandler = ();
+        type EmergencyHalt = ();
+        type MaxBatchSize = sp_runtime::traits::ConstU32<16>;
     }
 
     pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -57,8 +60,8 @@ mod tests {
     fn test_update_reputation_positive() {
         new_test_ext().execute_with(|| {
             assert_ok!(<pallet::Pallet<TestConfig>>::initialize_reputation(RawOrigin::Signed(1).into()));
-            // Update reputation by +20
-            assert_ok!(<pallet::Pallet<TestConfig>>::update_reputation(RawOrigin::Signed(1).into(), 20, b"Positive Contribution".to_vec()));
+            // Update reputation by +20, which only governance may do
+            assert_ok!(<pallet::Pallet<TestConfig>>::adjust_reputation(RawOrigin::Root.into(), 1, 20, pallet::ReputationReason::GoodBehavior, b"Positive Contribution".to_vec()));
             let state = <pallet::ReputationStateStorage<TestConfig>>::get(&1).unwrap();
             assert_eq!(state.score, 120);
         });
@@ -69,10 +72,26 @@ mod tests {
         new_test_ext().execute_with(|| {
             assert_ok!(<pallet::Pallet<TestConfig>>::initialize_reputation(RawOrigin::Signed(1).into()));
             // Update reputation by -30, should not underflow
-            assert_ok!(<pallet::Pallet<TestConfig>>::update_reputation(RawOrigin::Signed(1).into(), -30, b"Negative Feedback".to_vec()));
+            assert_ok!(<pallet::Pallet<TestConfig>>::update_reputation(RawOrigin::Signed(1).into(), -30, pallet::ReputationReason::Penalty, b"Negative Feedback".to_vec()));
             let state = <pallet::ReputationStateStorage<TestConfig>>::get(&1).unwrap();
             // Expected new score is 70 (100 - 30)
             assert_eq!(state.score, 70);
         });
     }
+
+    #[test]
+    fn test_counts_by_reason() {
+        new_test_ext().execute_with(|| {
+            use pallet::ReputationReason::*;
+            assert_ok!(<pallet::Pallet<TestConfig>>::initialize_reputation(RawOrigin::Signed(1).into()));
+            for (delta, code) in [(5, GoodBehavior), (3, GoodBehavior), (-2, Penalty), (1, Other), (4, GoodBehavior)] {
+                assert_ok!(<pallet::Pallet<TestConfig>>::adjust_reputation(RawOrigin::Root.into(), 1, delta, code, b"detail".to_vec()));
+            }
+            assert_eq!(
+                <pallet::Pallet<TestConfig>>::counts_by_reason(&1),
+                vec![(GoodBehavior, 3), (Penalty, 1), (Other, 1)]
+            );
+            assert!(<pallet::Pallet<TestConfig>>::counts_by_reason(&2).is_empty());
+        });
+    }
 }
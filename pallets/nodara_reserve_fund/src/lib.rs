@@ -17,17 +17,25 @@ pub mod pallet {
     use frame_support::{
         dispatch::DispatchResult,
         pallet_prelude::*,
-        traits::{Get, EnsureOrigin},
+        traits::{Currency, Get, EnsureOrigin, Randomness},
     };
     use frame_system::pallet_prelude::*;
+    use nodara_emergency::HaltGuard;
     use pallet_timestamp as timestamp;
     use sp_std::vec::Vec;
-    use sp_runtime::RuntimeDebug;
+    use sp_runtime::{traits::SaturatedConversion, RuntimeDebug};
     use parity_scale_codec::{Encode, Decode};
     use scale_info::TypeInfo;
+    #[cfg(feature = "serde")]
+    use serde::{Deserialize, Serialize};
+
+    /// Longueur maximale (en octets) de la description historisée d'une opération ; une
+    /// description plus longue est tronquée dans l'historique, l'événement la conservant entière.
+    pub type MaxOperationLen = ConstU32<128>;
 
     /// Structure d'un enregistrement d'opération sur le fonds de réserve.
-    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
     pub struct ReserveRecord {
         /// Timestamp de l'opération, obtenu via le pallet_timestamp.
         pub timestamp: u64,
@@ -36,18 +44,54 @@ pub mod pallet {
         /// Nouveau solde après l'opération.
         pub new_balance: u128,
         /// Description ou raison de l'opération.
+        pub operation: BoundedVec<u8, MaxOperationLen>,
+    }
+
+    /// Enregistrement avant la version 1 du stockage, à description non bornée.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct LegacyReserveRecord {
+        pub timestamp: u64,
+        pub previous_balance: u128,
+        pub new_balance: u128,
         pub operation: Vec<u8>,
     }
 
     /// État global du fonds de réserve.
     ///
-    /// On conserve le solde actuel ainsi qu'un historique détaillé des opérations.
-    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, Default, TypeInfo)]
-    pub struct ReserveFundState {
+    /// On conserve le solde actuel ainsi qu'un historique détaillé des opérations, limité aux
+    /// `MaxHistory` plus récentes.
+    #[derive(Encode, Decode, CloneNoBound, PartialEqNoBound, EqNoBound, RuntimeDebugNoBound, DefaultNoBound, TypeInfo, MaxEncodedLen)]
+    #[scale_info(skip_type_params(MaxHistory))]
+    #[codec(mel_bound())]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    #[cfg_attr(feature = "serde", serde(bound(serialize = "", deserialize = "")))]
+    pub struct ReserveFundState<MaxHistory: Get<u32>> {
         /// Solde actuel du fonds de réserve.
         pub balance: u128,
         /// Historique des opérations sur le fonds.
-        pub history: Vec<ReserveRecord>,
+        pub history: BoundedVec<ReserveRecord, MaxHistory>,
+    }
+
+    /// État du fonds de réserve borné par `Config::MaxHistoryLen`.
+    pub type ReserveFundStateOf<T> = ReserveFundState<<T as Config>::MaxHistoryLen>;
+
+    /// Progression d'une redistribution répartie sur plusieurs blocs.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, Default, TypeInfo)]
+    pub struct RedistributionProgress {
+        /// Part créditée à chaque bénéficiaire.
+        pub share: u128,
+        /// Index du prochain bénéficiaire à créditer.
+        pub next_index: u32,
+    }
+
+    /// Mode de redistribution de l'excédent entre les bénéficiaires.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, Default, TypeInfo, MaxEncodedLen)]
+    pub enum RedistributionMode {
+        /// L'excédent est divisé en parts égales, créditées sur plusieurs blocs si nécessaire.
+        #[default]
+        EqualShares,
+        /// L'excédent est entièrement crédité à un bénéficiaire tiré au sort à chaque redistribution.
+        Lottery,
     }
 
     #[pallet::config]
@@ -62,12 +106,33 @@ pub mod pallet {
         /// Seuil minimal de solde à maintenir pour autoriser un retrait (en pourcentage du baseline).
         #[pallet::constant]
         type MinimumReserveRatio: Get<u8>;
+        /// Nombre maximal de bénéficiaires crédités par bloc lors d'une redistribution.
+        #[pallet::constant]
+        type MaxBeneficiariesPerBlock: Get<u32>;
+        /// Source d'aléa utilisée pour le tirage au sort en mode `Lottery`.
+        type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
+        /// Monnaie détenue par le compte du fonds de réserve.
+        type Currency: Currency<Self::AccountId>;
+        /// Compte détenant les fonds réels de la réserve, comparé au solde logique par
+        /// `reconcile_reserve`.
+        type ReserveAccount: Get<Self::AccountId>;
+        /// Nombre maximal d'enregistrements conservés dans l'historique ; au-delà, les plus
+        /// anciens sont évincés.
+        #[pallet::constant]
+        type MaxHistoryLen: Get<u32>;
+        /// Arrêt d'urgence global consulté par chaque appel signé ; `()` le désactive.
+        type EmergencyHalt: nodara_emergency::HaltGuard;
     }
 
     /// Stockage de l'état du fonds de réserve.
     #[pallet::storage]
     #[pallet::getter(fn reserve_state)]
-    pub type ReserveFundStorage<T: Config> = StorageValue<_, ReserveFundState, ValueQuery>;
+    pub type ReserveFundStorage<T: Config> = StorageValue<_, ReserveFundStateOf<T>, ValueQuery>;
+
+    /// Indique si `initialize_reserve` a été exécutée.
+    #[pallet::storage]
+    #[pallet::getter(fn is_initialized)]
+    pub type Initialized<T: Config> = StorageValue<_, bool, ValueQuery>;
 
     /// Paramètre de gouvernance : seuil de redistribution.
     /// Si le solde dépasse ce seuil, l'excédent est redistribué automatiquement.
@@ -75,6 +140,28 @@ pub mod pallet {
     #[pallet::getter(fn redistribution_threshold)]
     pub type RedistributionThreshold<T: Config> = StorageValue<_, u128, ValueQuery>;
 
+    /// Bénéficiaires de la redistribution automatique (définis via DAO).
+    #[pallet::storage]
+    #[pallet::getter(fn beneficiaries)]
+    pub type Beneficiaries<T: Config> = StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+
+    /// Mode de redistribution de l'excédent (défini via DAO).
+    #[pallet::storage]
+    #[pallet::getter(fn redistribution_mode)]
+    pub type RedistributionModeStorage<T: Config> = StorageValue<_, RedistributionMode, ValueQuery>;
+
+    /// Montants redistribués crédités à chaque bénéficiaire.
+    #[pallet::storage]
+    #[pallet::getter(fn beneficiary_credits)]
+    pub type BeneficiaryCredits<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, u128, ValueQuery>;
+
+    /// Redistribution en cours, poursuivie au bloc suivant tant que tous les bénéficiaires
+    /// n'ont pas été crédités.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_redistribution)]
+    pub type PendingRedistribution<T: Config> = StorageValue<_, RedistributionProgress, OptionQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -84,6 +171,17 @@ pub mod pallet {
         RedistributionThresholdUpdated(u128),
         /// Redistribution automatique effectuée (montant redistribué).
         FundsRedistributed(u128),
+        /// Liste des bénéficiaires mise à jour par l'origine DAO (nombre de bénéficiaires).
+        BeneficiariesUpdated(u32),
+        /// Tous les bénéficiaires de la redistribution en cours ont été crédités (part unitaire).
+        RedistributionCompleted(u128),
+        /// Mode de redistribution mis à jour par l'origine DAO.
+        RedistributionModeUpdated(RedistributionMode),
+        /// Bénéficiaire tiré au sort et montant crédité (mode `Lottery`).
+        LotteryWinnerCredited(T::AccountId, u128),
+        /// Rapprochement du solde logique et du solde réel du compte de réserve
+        /// (solde logique avant rapprochement, solde réel).
+        ReserveReconciled(u128, u128),
     }
 
     #[pallet::error]
@@ -92,18 +190,52 @@ pub mod pallet {
         InvalidOperation,
         /// Retrait non autorisé car le solde resterait en dessous du seuil minimal requis.
         InsufficientReserve,
+        /// La liste des bénéficiaires ne peut pas être modifiée pendant une redistribution.
+        RedistributionInProgress,
+        /// Le fonds de réserve n'a pas encore été initialisé.
+        NotInitialized,
+        /// Le module est déjà initialisé ; utiliser la réinitialisation explicite.
+        AlreadyInitialized,
     }
 
+    /// Version du stockage : la version 1 borne l'historique de `ReserveFundState` et la description
+    /// de chaque enregistrement.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
-    /// Hooks permettant l'automatisation (ici, redistribution automatique en fin de bloc).
+    /// Hooks permettant l'automatisation (ici, redistribution automatique en début de bloc).
+    ///
+    /// Au plus `MaxBeneficiariesPerBlock` bénéficiaires sont crédités par bloc ; le reste est
+    /// traité aux blocs suivants. Le poids consommé est retourné au runtime.
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_finalize(_n: BlockNumberFor<T>) {
-            if let Some(amount) = Self::redistribute_funds() {
-                Self::deposit_event(Event::FundsRedistributed(amount));
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            let mut weight = T::DbWeight::get().reads(1);
+            if !PendingRedistribution::<T>::exists() {
+                weight = weight.saturating_add(T::DbWeight::get().reads(4));
+                if let Some(amount) = Self::redistribute_funds() {
+                    weight = weight.saturating_add(T::DbWeight::get().writes(2));
+                    Self::deposit_event(Event::FundsRedistributed(amount));
+                }
             }
+            weight.saturating_add(Self::process_redistribution_batch())
+        }
+
+        fn on_runtime_upgrade() -> Weight {
+            if StorageVersion::get::<Pallet<T>>() >= STORAGE_VERSION {
+                return T::DbWeight::get().reads(1);
+            }
+            Self::migrate_history_to_v1();
+            STORAGE_VERSION.put::<Pallet<T>>();
+            T::DbWeight::get().reads_writes(2, 2)
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), &'static str> {
+            Self::do_try_state()
         }
     }
 
@@ -114,21 +246,16 @@ pub mod pallet {
         #[pallet::weight(10_000)]
         pub fn initialize_reserve(origin: OriginFor<T>) -> DispatchResult {
             ensure_root(origin)?;
-            let now = <timestamp::Pallet<T>>::get();
-            let baseline = T::BaselineReserve::get();
-            let state = ReserveFundState {
-                balance: baseline,
-                history: vec![ReserveRecord {
-                    timestamp: now,
-                    previous_balance: 0,
-                    new_balance: baseline,
-                    operation: b"Initialization".to_vec(),
-                }],
-            };
-            <ReserveFundStorage<T>>::put(state);
-            // Par défaut, on fixe le seuil de redistribution à 150% du baseline.
-            RedistributionThreshold::<T>::put(baseline.saturating_mul(150u128) / 100);
-            Ok(())
+            ensure!(!Initialized::<T>::get(), Error::<T>::AlreadyInitialized);
+            Self::do_initialize_reserve()
+        }
+
+        /// Réinitialise délibérément le fonds de réserve (solde de base, historique et seuil).
+        /// Réservé à l'origine DAO ; `initialize_reserve` refuse d'écraser un état existant.
+        #[pallet::weight(10_000)]
+        pub fn reset_reserve(origin: OriginFor<T>) -> DispatchResult {
+            T::DaoOrigin::ensure_origin(origin)?;
+            Self::do_initialize_reserve()
         }
 
         /// Ajoute une contribution au fonds de réserve.
@@ -137,19 +264,8 @@ pub mod pallet {
         #[pallet::weight(10_000)]
         pub fn contribute(origin: OriginFor<T>, amount: u128, description: Vec<u8>) -> DispatchResult {
             let _sender = ensure_signed(origin)?;
-            let mut state = <ReserveFundStorage<T>>::get();
-            let previous_balance = state.balance;
-            state.balance = state.balance.saturating_add(amount);
-            let now = <timestamp::Pallet<T>>::get();
-            state.history.push(ReserveRecord {
-                timestamp: now,
-                previous_balance,
-                new_balance: state.balance,
-                operation: description.clone(),
-            });
-            <ReserveFundStorage<T>>::put(state);
-            Self::deposit_event(Event::ReserveUpdated(previous_balance, <ReserveFundStorage<T>>::get().balance, description));
-            Ok(())
+            T::EmergencyHalt::ensure_running()?;
+            Self::do_contribute(amount, description)
         }
 
         /// Effectue un retrait du fonds de réserve.
@@ -159,6 +275,8 @@ pub mod pallet {
         #[pallet::weight(10_000)]
         pub fn withdraw(origin: OriginFor<T>, amount: u128, description: Vec<u8>) -> DispatchResult {
             let _sender = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::ensure_initialized()?;
             let mut state = <ReserveFundStorage<T>>::get();
             // Calcul du seuil minimal requis.
             let min_required = T::BaselineReserve::get()
@@ -169,17 +287,49 @@ pub mod pallet {
             let previous_balance = state.balance;
             state.balance = state.balance.saturating_sub(amount);
             let now = <timestamp::Pallet<T>>::get();
-            state.history.push(ReserveRecord {
+            Self::push_record(&mut state.history, ReserveRecord {
                 timestamp: now,
                 previous_balance,
                 new_balance: state.balance,
-                operation: description.clone(),
+                operation: BoundedVec::truncate_from(description.clone()),
             });
             <ReserveFundStorage<T>>::put(state);
             Self::deposit_event(Event::ReserveUpdated(previous_balance, <ReserveFundStorage<T>>::get().balance, description));
             Ok(())
         }
 
+        /// Compare le solde logique du fonds au solde réel de `ReserveAccount`.
+        ///
+        /// Un écart (bug ou transfert externe) est enregistré dans l'historique ; avec `correct`,
+        /// le solde logique est aligné sur le solde réel. `ReserveReconciled` est émis dans tous
+        /// les cas. Réservé à l'origine DAO.
+        #[pallet::weight(10_000)]
+        pub fn reconcile_reserve(origin: OriginFor<T>, correct: bool) -> DispatchResult {
+            T::DaoOrigin::ensure_origin(origin)?;
+            Self::ensure_initialized()?;
+            let actual: u128 = T::Currency::free_balance(&T::ReserveAccount::get()).saturated_into();
+            let mut state = <ReserveFundStorage<T>>::get();
+            let logical = state.balance;
+            if logical != actual {
+                if correct {
+                    state.balance = actual;
+                }
+                Self::push_record(&mut state.history, ReserveRecord {
+                    timestamp: <timestamp::Pallet<T>>::get(),
+                    previous_balance: logical,
+                    new_balance: state.balance,
+                    operation: BoundedVec::truncate_from(if correct {
+                        b"Reconciliation (corrected)".to_vec()
+                    } else {
+                        b"Reconciliation (discrepancy)".to_vec()
+                    }),
+                });
+                <ReserveFundStorage<T>>::put(state);
+            }
+            Self::deposit_event(Event::ReserveReconciled(logical, actual));
+            Ok(())
+        }
+
         /// Permet à une origine DAO de mettre à jour le seuil de redistribution.
         ///
         /// Cette extrinsèque permet de modifier dynamiquement le seuil au-delà duquel l'excédent sera redistribué.
@@ -190,33 +340,227 @@ pub mod pallet {
             Self::deposit_event(Event::RedistributionThresholdUpdated(new_threshold));
             Ok(())
         }
+
+        /// Permet à une origine DAO de définir les bénéficiaires de la redistribution automatique.
+        ///
+        /// Refusé tant qu'une redistribution est en cours, afin que les index déjà traités restent valides.
+        #[pallet::weight(10_000)]
+        pub fn set_beneficiaries(origin: OriginFor<T>, beneficiaries: Vec<T::AccountId>) -> DispatchResult {
+            T::DaoOrigin::ensure_origin(origin)?;
+            ensure!(!PendingRedistribution::<T>::exists(), Error::<T>::RedistributionInProgress);
+            let count = beneficiaries.len() as u32;
+            Beneficiaries::<T>::put(beneficiaries);
+            Self::deposit_event(Event::BeneficiariesUpdated(count));
+            Ok(())
+        }
+
+        /// Permet à une origine DAO de choisir le mode de redistribution de l'excédent.
+        ///
+        /// Refusé tant qu'une redistribution en parts égales est en cours.
+        #[pallet::weight(10_000)]
+        pub fn set_redistribution_mode(origin: OriginFor<T>, mode: RedistributionMode) -> DispatchResult {
+            T::DaoOrigin::ensure_origin(origin)?;
+            ensure!(!PendingRedistribution::<T>::exists(), Error::<T>::RedistributionInProgress);
+            RedistributionModeStorage::<T>::put(mode);
+            Self::deposit_event(Event::RedistributionModeUpdated(mode));
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
+        /// Ajoute `amount` au fonds de réserve et historise l'opération.
+        ///
+        /// Utilisé par `contribute` et par le runtime pour y verser des revenus (frais de transaction, etc.).
+        pub fn do_contribute(amount: u128, description: Vec<u8>) -> DispatchResult {
+            Self::ensure_initialized()?;
+            let mut state = <ReserveFundStorage<T>>::get();
+            let previous_balance = state.balance;
+            state.balance = state.balance.saturating_add(amount);
+            let now = <timestamp::Pallet<T>>::get();
+            Self::push_record(&mut state.history, ReserveRecord {
+                timestamp: now,
+                previous_balance,
+                new_balance: state.balance,
+                operation: BoundedVec::truncate_from(description.clone()),
+            });
+            <ReserveFundStorage<T>>::put(state);
+            Self::deposit_event(Event::ReserveUpdated(previous_balance, <ReserveFundStorage<T>>::get().balance, description));
+            Ok(())
+        }
+
+        /// Vérifie les invariants du fonds de réserve (hook `try_state`) :
+        /// - avant `initialize_reserve`, le solde est nul et l'historique vide ;
+        /// - le solde est égal au `new_balance` du dernier enregistrement de l'historique ;
+        /// - chaque enregistrement part du solde laissé par le précédent ;
+        /// - une redistribution en cours pointe sur un bénéficiaire existant.
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), &'static str> {
+            let state = <ReserveFundStorage<T>>::get();
+            if !Initialized::<T>::get() {
+                ensure!(state.balance == 0 && state.history.is_empty(), "réserve non initialisée mais non vide");
+                return Ok(());
+            }
+            let last = state.history.last().ok_or("historique de la réserve vide")?;
+            ensure!(last.new_balance == state.balance, "solde différent du dernier enregistrement");
+            ensure!(
+                state.history.windows(2).all(|pair| pair[1].previous_balance == pair[0].new_balance),
+                "historique de la réserve discontinu"
+            );
+            if let Some(progress) = PendingRedistribution::<T>::get() {
+                let beneficiaries = Beneficiaries::<T>::decode_len().unwrap_or(0);
+                ensure!((progress.next_index as usize) < beneficiaries, "redistribution en cours hors de la liste des bénéficiaires");
+            }
+            Ok(())
+        }
+
+        /// Historise `record`, en évinçant l'enregistrement le plus ancien si l'historique est plein.
+        fn push_record(history: &mut BoundedVec<ReserveRecord, T::MaxHistoryLen>, record: ReserveRecord) {
+            if let Err(record) = history.try_push(record) {
+                if !history.is_empty() {
+                    history.remove(0);
+                    let _ = history.try_push(record);
+                }
+            }
+        }
+
+        /// Convertit l'état de la version 0, dont l'historique n'était pas borné : le solde est
+        /// conservé, seuls les `MaxHistoryLen` enregistrements les plus récents sont gardés et leurs
+        /// descriptions sont tronquées à `MaxOperationLen` octets.
+        fn migrate_history_to_v1() {
+            let _ = ReserveFundStorage::<T>::translate::<(u128, Vec<LegacyReserveRecord>), _>(|legacy| {
+                legacy.map(|(balance, mut history)| {
+                    let excess = history.len().saturating_sub(T::MaxHistoryLen::get() as usize);
+                    let recent = history.split_off(excess).into_iter().map(|record| ReserveRecord {
+                        timestamp: record.timestamp,
+                        previous_balance: record.previous_balance,
+                        new_balance: record.new_balance,
+                        operation: BoundedVec::truncate_from(record.operation),
+                    });
+                    ReserveFundState { balance, history: BoundedVec::truncate_from(recent.collect()) }
+                })
+            });
+        }
+
+        /// Échoue si `initialize_reserve` n'a pas encore été exécutée.
+        fn ensure_initialized() -> DispatchResult {
+            ensure!(Initialized::<T>::get(), Error::<T>::NotInitialized);
+            Ok(())
+        }
+
+        /// Met en place l'état initial du fonds de réserve, en écrasant l'état courant.
+        fn do_initialize_reserve() -> DispatchResult {
+            let now = <timestamp::Pallet<T>>::get();
+            let baseline = T::BaselineReserve::get();
+            let mut state = ReserveFundStateOf::<T> { balance: baseline, history: BoundedVec::default() };
+            Self::push_record(&mut state.history, ReserveRecord {
+                timestamp: now,
+                previous_balance: 0,
+                new_balance: baseline,
+                operation: BoundedVec::truncate_from(b"Initialization".to_vec()),
+            });
+            <ReserveFundStorage<T>>::put(state);
+            // Par défaut, on fixe le seuil de redistribution à 150% du baseline.
+            RedistributionThreshold::<T>::put(baseline.saturating_mul(150u128) / 100);
+            Initialized::<T>::put(true);
+            Ok(())
+        }
+
         /// Redistribution automatique des fonds.
         ///
         /// Si le solde dépasse le seuil défini, l'excédent est extrait et redistribué.
         /// Cette fonction retourne `Some(montant)` si une redistribution a été effectuée,
         /// ou `None` sinon.
+        ///
+        /// Lorsque des bénéficiaires sont définis, en mode `EqualShares`, l'excédent est divisé en
+        /// parts égales et une redistribution est planifiée ; le reliquat de la division reste dans
+        /// la réserve. En mode `Lottery`, l'excédent est crédité en une fois au bénéficiaire tiré
+        /// au sort par `draw_beneficiary`.
         fn redistribute_funds() -> Option<u128> {
             let mut state = <ReserveFundStorage<T>>::get();
             let threshold = RedistributionThreshold::<T>::get();
             if state.balance > threshold {
-                let excess = state.balance.saturating_sub(threshold);
+                let mut excess = state.balance.saturating_sub(threshold);
+                let beneficiary_count = Beneficiaries::<T>::decode_len().unwrap_or(0) as u128;
+                if beneficiary_count > 0 {
+                    match RedistributionModeStorage::<T>::get() {
+                        RedistributionMode::EqualShares => {
+                            let share = excess / beneficiary_count;
+                            if share == 0 {
+                                return None;
+                            }
+                            excess = share.saturating_mul(beneficiary_count);
+                            PendingRedistribution::<T>::put(RedistributionProgress { share, next_index: 0 });
+                        }
+                        RedistributionMode::Lottery => {
+                            let winner = Self::draw_beneficiary()?;
+                            BeneficiaryCredits::<T>::mutate(&winner, |credit| *credit = credit.saturating_add(excess));
+                            Self::deposit_event(Event::LotteryWinnerCredited(winner, excess));
+                        }
+                    }
+                }
                 let previous_balance = state.balance;
-                state.balance = threshold;
+                state.balance = state.balance.saturating_sub(excess);
                 let now = <timestamp::Pallet<T>>::get();
-                state.history.push(ReserveRecord {
+                Self::push_record(&mut state.history, ReserveRecord {
                     timestamp: now,
                     previous_balance,
                     new_balance: state.balance,
-                    operation: b"Automatic redistribution".to_vec(),
+                    operation: BoundedVec::truncate_from(b"Automatic redistribution".to_vec()),
                 });
                 <ReserveFundStorage<T>>::put(state);
                 return Some(excess);
             }
             None
         }
+
+        /// Tire au sort un bénéficiaire à partir de `T::Randomness` : les 8 premiers octets de
+        /// l'aléa, lus en little-endian, modulo le nombre de bénéficiaires.
+        fn draw_beneficiary() -> Option<T::AccountId> {
+            let beneficiaries = Beneficiaries::<T>::get();
+            if beneficiaries.is_empty() {
+                return None;
+            }
+            let (seed, _) = T::Randomness::random(b"nodara/reserve-lottery");
+            let mut bytes = [0u8; 8];
+            let encoded = seed.encode();
+            let len = encoded.len().min(8);
+            bytes[..len].copy_from_slice(&encoded[..len]);
+            let index = u64::from_le_bytes(bytes) % beneficiaries.len() as u64;
+            beneficiaries.into_iter().nth(index as usize)
+        }
+
+        /// Crédite au plus `MaxBeneficiariesPerBlock` bénéficiaires de la redistribution en cours.
+        ///
+        /// Retourne le poids consommé.
+        fn process_redistribution_batch() -> Weight {
+            let db = T::DbWeight::get();
+            let mut progress = match PendingRedistribution::<T>::get() {
+                Some(progress) => progress,
+                None => return Weight::zero(),
+            };
+            let beneficiaries = Beneficiaries::<T>::get();
+            let start = progress.next_index as usize;
+            let end = start
+                .saturating_add(T::MaxBeneficiariesPerBlock::get() as usize)
+                .min(beneficiaries.len());
+            for account in beneficiaries.get(start..end).unwrap_or_default() {
+                BeneficiaryCredits::<T>::mutate(account, |credit| {
+                    *credit = credit.saturating_add(progress.share)
+                });
+            }
+            let processed = end.saturating_sub(start) as u64;
+            if end >= beneficiaries.len() {
+                PendingRedistribution::<T>::kill();
+                Self::deposit_event(Event::RedistributionCompleted(progress.share));
+            } else {
+                progress.next_index = end as u32;
+                PendingRedistribution::<T>::put(progress);
+            }
+            // Lecture des bénéficiaires, puis lecture/écriture de chaque crédit et de la progression.
+            db.reads(1)
+                .saturating_add(db.reads_writes(processed, processed))
+                .saturating_add(db.writes(1))
+        }
     }
 
     #[pallet::genesis_config]
@@ -250,7 +594,7 @@ pub mod pallet {
         use frame_support::{assert_ok, assert_err, parameter_types};
         use sp_core::H256;
         use sp_runtime::{
-            traits::{BlakeTwo256, IdentityLookup},
+            traits::{BlakeTwo256, Hash, IdentityLookup},
             testing::Header,
         };
         use frame_system as system;
@@ -265,7 +609,8 @@ pub mod pallet {
                 UncheckedExtrinsic = UncheckedExtrinsic,
             {
                 System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-                ReserveFundModule: Pallet,
+                Balances: pallet_balances,
+                ReserveFundModule: crate::pallet::{Pallet, Call, Storage, Event<T>},
                 Timestamp: timestamp::Pallet,
             }
         );
@@ -275,6 +620,20 @@ pub mod pallet {
             pub const BaselineReserve: u128 = 1_000_000;
             pub const MinimumReserveRatio: u8 = 50; // 50% du baseline
             pub const MinimumPeriod: u64 = 1;
+            pub const MaxBeneficiariesPerBlock: u32 = 2;
+            pub static RandomSeed: u64 = 0;
+            pub const ExistentialDeposit: u128 = 1;
+            pub const ReserveAccount: u64 = 99;
+            pub const MaxHistoryLen: u32 = 8;
+        }
+
+        /// Aléa déterministe dérivé de `RandomSeed`, à la manière d'un aléa issu du hash de bloc.
+        pub struct SeededRandomness;
+
+        impl Randomness<H256, u64> for SeededRandomness {
+            fn random(subject: &[u8]) -> (H256, u64) {
+                (BlakeTwo256::hash_of(&(subject, RandomSeed::get())), 0)
+            }
         }
 
         impl system::Config for Test {
@@ -291,11 +650,11 @@ pub mod pallet {
             type AccountId = u64;
             type Lookup = IdentityLookup<Self::AccountId>;
             type Header = Header;
-            type RuntimeEvent = ();
+            type RuntimeEvent = RuntimeEvent;
             type BlockHashCount = BlockHashCount;
             type Version = ();
             type PalletInfo = ();
-            type AccountData = ();
+            type AccountData = pallet_balances::AccountData<u128>;
             type OnNewAccount = ();
             type OnKilledAccount = ();
             type SystemWeightInfo = ();
@@ -304,6 +663,22 @@ pub mod pallet {
             type MaxConsumers = ();
         }
 
+        impl pallet_balances::Config for Test {
+            type RuntimeEvent = RuntimeEvent;
+            type Balance = u128;
+            type DustRemoval = ();
+            type ExistentialDeposit = ExistentialDeposit;
+            type AccountStore = System;
+            type WeightInfo = ();
+            type MaxLocks = ();
+            type MaxReserves = ();
+            type ReserveIdentifier = [u8; 8];
+            type RuntimeHoldReason = ();
+            type RuntimeFreezeReason = ();
+            type FreezeIdentifier = ();
+            type MaxFreezes = ();
+        }
+
         impl timestamp::Config for Test {
             type Moment = u64;
             type OnTimestampSet = ();
@@ -312,10 +687,24 @@ pub mod pallet {
         }
 
         impl Config for Test {
-            type RuntimeEvent = ();
+            type RuntimeEvent = RuntimeEvent;
             type BaselineReserve = BaselineReserve;
             type DaoOrigin = frame_system::EnsureRoot<u64>;
             type MinimumReserveRatio = MinimumReserveRatio;
+            type MaxBeneficiariesPerBlock = MaxBeneficiariesPerBlock;
+            type Randomness = SeededRandomness;
+            type Currency = Balances;
+            type ReserveAccount = ReserveAccount;
+            type MaxHistoryLen = MaxHistoryLen;
+            type EmergencyHalt = ();
+        }
+
+        pub fn new_test_ext() -> sp_io::TestExternalities {
+            let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+            pallet_balances::GenesisConfig::<Test> { balances: vec![(ReserveAccount::get(), BaselineReserve::get())] }
+                .assimilate_storage(&mut storage)
+                .unwrap();
+            storage.into()
         }
 
         #[test]
@@ -358,5 +747,262 @@ pub mod pallet {
                 Error::<Test>::InsufficientReserve
             );
         }
+
+        #[test]
+        fn redistribution_spans_multiple_blocks_when_over_cap() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(ReserveFundModule::initialize_reserve(system::RawOrigin::Root.into()));
+                let beneficiaries: Vec<u64> = vec![10, 11, 12, 13, 14];
+                assert_ok!(ReserveFundModule::set_beneficiaries(system::RawOrigin::Root.into(), beneficiaries.clone()));
+                // Seuil = 1_500_000 ; un excédent de 500_003 donne une part de 100_000 et un reliquat de 3.
+                assert_ok!(ReserveFundModule::contribute(system::RawOrigin::Signed(1).into(), 1_000_003, b"Surplus".to_vec()));
+
+                // Bloc 1 : l'excédent est prélevé et les deux premiers bénéficiaires sont crédités.
+                ReserveFundModule::on_initialize(1);
+                assert_eq!(ReserveFundModule::reserve_state().balance, 1_500_003);
+                assert_eq!(ReserveFundModule::pending_redistribution().map(|p| p.next_index), Some(2));
+                assert_eq!(ReserveFundModule::beneficiary_credits(12), 0);
+
+                // Bloc 2 : deux bénéficiaires de plus.
+                ReserveFundModule::on_initialize(2);
+                assert_eq!(ReserveFundModule::pending_redistribution().map(|p| p.next_index), Some(4));
+
+                // Bloc 3 : le dernier bénéficiaire est crédité et la redistribution se termine.
+                ReserveFundModule::on_initialize(3);
+                assert!(ReserveFundModule::pending_redistribution().is_none());
+                for account in beneficiaries {
+                    assert_eq!(ReserveFundModule::beneficiary_credits(account), 100_000);
+                }
+                // Aucune nouvelle redistribution : le solde est sous le seuil.
+                ReserveFundModule::on_initialize(4);
+                assert_eq!(ReserveFundModule::beneficiary_credits(10), 100_000);
+            });
+        }
+
+        #[test]
+        fn redistribution_weight_grows_with_batch_size() {
+            new_test_ext().execute_with(|| {
+                let idle_weight = ReserveFundModule::on_initialize(1);
+                assert_ok!(ReserveFundModule::initialize_reserve(system::RawOrigin::Root.into()));
+                assert_ok!(ReserveFundModule::set_beneficiaries(system::RawOrigin::Root.into(), vec![10, 11, 12]));
+                assert_ok!(ReserveFundModule::contribute(system::RawOrigin::Signed(1).into(), 600_000, b"Surplus".to_vec()));
+                let busy_weight = ReserveFundModule::on_initialize(2);
+                assert!(busy_weight.all_gt(idle_weight));
+                // La liste ne peut pas changer tant que la redistribution n'est pas terminée.
+                assert_err!(
+                    ReserveFundModule::set_beneficiaries(system::RawOrigin::Root.into(), vec![20]),
+                    Error::<Test>::RedistributionInProgress
+                );
+            });
+        }
+
+        /// Redistribue un excédent de 500_000 en mode `Lottery` et retourne le gagnant.
+        fn lottery_winner(seed: u64) -> u64 {
+            new_test_ext().execute_with(|| {
+                RandomSeed::set(seed);
+                assert_ok!(ReserveFundModule::initialize_reserve(system::RawOrigin::Root.into()));
+                assert_ok!(ReserveFundModule::set_beneficiaries(system::RawOrigin::Root.into(), vec![10, 11, 12, 13, 14]));
+                assert_ok!(ReserveFundModule::set_redistribution_mode(system::RawOrigin::Root.into(), RedistributionMode::Lottery));
+                assert_ok!(ReserveFundModule::contribute(system::RawOrigin::Signed(1).into(), 1_000_000, b"Surplus".to_vec()));
+
+                ReserveFundModule::on_initialize(1);
+                assert_eq!(ReserveFundModule::reserve_state().balance, 1_500_000);
+                assert!(ReserveFundModule::pending_redistribution().is_none());
+                let credited: Vec<u64> = (10..15).filter(|a| ReserveFundModule::beneficiary_credits(a) > 0).collect();
+                assert_eq!(credited.len(), 1);
+                assert_eq!(ReserveFundModule::beneficiary_credits(credited[0]), 500_000);
+                credited[0]
+            })
+        }
+
+        #[test]
+        fn lottery_credits_the_whole_excess_to_a_seeded_winner() {
+            for seed in [0, 1, 7, 42] {
+                let hash = BlakeTwo256::hash_of(&(&b"nodara/reserve-lottery"[..], seed));
+                let mut bytes = [0u8; 8];
+                bytes.copy_from_slice(&hash.as_ref()[..8]);
+                let expected = 10 + u64::from_le_bytes(bytes) % 5;
+                assert_eq!(lottery_winner(seed), expected);
+                // Même graine, même gagnant.
+                assert_eq!(lottery_winner(seed), expected);
+            }
+        }
+
+        #[test]
+        fn redistribution_mode_is_governed() {
+            new_test_ext().execute_with(|| {
+                assert_eq!(ReserveFundModule::redistribution_mode(), RedistributionMode::EqualShares);
+                assert_err!(
+                    ReserveFundModule::set_redistribution_mode(system::RawOrigin::Signed(1).into(), RedistributionMode::Lottery),
+                    sp_runtime::DispatchError::BadOrigin
+                );
+                assert_ok!(ReserveFundModule::initialize_reserve(system::RawOrigin::Root.into()));
+                assert_ok!(ReserveFundModule::set_beneficiaries(system::RawOrigin::Root.into(), vec![10, 11, 12]));
+                assert_ok!(ReserveFundModule::contribute(system::RawOrigin::Signed(1).into(), 600_000, b"Surplus".to_vec()));
+                ReserveFundModule::on_initialize(1);
+                assert_err!(
+                    ReserveFundModule::set_redistribution_mode(system::RawOrigin::Root.into(), RedistributionMode::Lottery),
+                    Error::<Test>::RedistributionInProgress
+                );
+            });
+        }
+
+        #[test]
+        fn operations_require_initialization() {
+            new_test_ext().execute_with(|| {
+                let user = || -> <Test as system::Config>::RuntimeOrigin { system::RawOrigin::Signed(1).into() };
+                assert_err!(ReserveFundModule::contribute(user(), 100, b"Early".to_vec()), Error::<Test>::NotInitialized);
+                assert_err!(ReserveFundModule::withdraw(user(), 100, b"Early".to_vec()), Error::<Test>::NotInitialized);
+                assert_ok!(ReserveFundModule::initialize_reserve(system::RawOrigin::Root.into()));
+                assert_ok!(ReserveFundModule::contribute(user(), 100, b"Late".to_vec()));
+                assert_ok!(ReserveFundModule::withdraw(user(), 100, b"Late".to_vec()));
+            });
+        }
+
+        #[test]
+        fn second_initialize_is_rejected() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(ReserveFundModule::initialize_reserve(system::RawOrigin::Root.into()));
+                assert_ok!(ReserveFundModule::contribute(system::RawOrigin::Signed(1).into(), 500, b"Contribution".to_vec()));
+                let funded = ReserveFundModule::reserve_state();
+                assert_err!(ReserveFundModule::initialize_reserve(system::RawOrigin::Root.into()), Error::<Test>::AlreadyInitialized);
+                assert_eq!(ReserveFundModule::reserve_state(), funded);
+
+                assert_ok!(ReserveFundModule::reset_reserve(system::RawOrigin::Root.into()));
+                let state = ReserveFundModule::reserve_state();
+                assert_eq!(state.balance, BaselineReserve::get());
+                assert_eq!(state.history.len(), 1);
+            });
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn reserve_fund_state_round_trips_through_json() {
+            let state: ReserveFundStateOf<Test> = ReserveFundState {
+                balance: 5_000,
+                history: BoundedVec::truncate_from(vec![ReserveRecord { timestamp: 1_640_000_000, previous_balance: 0, new_balance: 5_000, operation: BoundedVec::truncate_from(b"contribution".to_vec()) }]),
+            };
+            let json = serde_json::to_string(&state).expect("sérialisation JSON");
+            let decoded: ReserveFundStateOf<Test> = serde_json::from_str(&json).expect("désérialisation JSON");
+            assert_eq!(decoded, state);
+        }
+
+        #[test]
+        fn try_state_detects_balance_out_of_sync_with_history() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(ReserveFundModule::do_try_state());
+                assert_ok!(ReserveFundModule::initialize_reserve(system::RawOrigin::Root.into()));
+                assert_ok!(ReserveFundModule::contribute(system::RawOrigin::Signed(1).into(), 500, b"Contribution".to_vec()));
+                assert_ok!(ReserveFundModule::do_try_state());
+
+                // Solde modifié sans enregistrement correspondant dans l'historique.
+                ReserveFundStorage::<Test>::mutate(|state| state.balance += 1);
+                assert_err!(ReserveFundModule::do_try_state(), "solde différent du dernier enregistrement");
+            });
+        }
+
+        #[test]
+        fn reconciliation_reports_and_corrects_external_balance_changes() {
+            new_test_ext().execute_with(|| {
+                let reconciled = || -> Vec<RuntimeEvent> {
+                    System::events()
+                        .into_iter()
+                        .map(|record| record.event)
+                        .filter(|event| matches!(event, RuntimeEvent::ReserveFundModule(Event::ReserveReconciled(..))))
+                        .collect()
+                };
+                System::set_block_number(1);
+                assert_ok!(ReserveFundModule::initialize_reserve(system::RawOrigin::Root.into()));
+                assert_err!(
+                    ReserveFundModule::reconcile_reserve(system::RawOrigin::Signed(1).into(), true),
+                    sp_runtime::DispatchError::BadOrigin
+                );
+
+                // Soldes concordants : rien n'est historisé.
+                assert_ok!(ReserveFundModule::reconcile_reserve(system::RawOrigin::Root.into(), false));
+                assert_eq!(ReserveFundModule::reserve_state().history.len(), 1);
+
+                // Transfert externe vers le compte de réserve.
+                let _ = Balances::deposit_creating(&ReserveAccount::get(), 2_500);
+                assert_ok!(ReserveFundModule::reconcile_reserve(system::RawOrigin::Root.into(), false));
+                let state = ReserveFundModule::reserve_state();
+                assert_eq!(state.balance, 1_000_000);
+                assert_eq!(state.history.len(), 2);
+                assert_eq!(state.history[1].operation.to_vec(), b"Reconciliation (discrepancy)".to_vec());
+
+                assert_ok!(ReserveFundModule::reconcile_reserve(system::RawOrigin::Root.into(), true));
+                let state = ReserveFundModule::reserve_state();
+                assert_eq!(state.balance, 1_002_500);
+                assert_eq!(state.history.last().map(|record| record.previous_balance), Some(1_000_000));
+                assert_eq!(
+                    reconciled(),
+                    vec![
+                        RuntimeEvent::ReserveFundModule(Event::ReserveReconciled(1_000_000, 1_000_000)),
+                        RuntimeEvent::ReserveFundModule(Event::ReserveReconciled(1_000_000, 1_002_500)),
+                        RuntimeEvent::ReserveFundModule(Event::ReserveReconciled(1_000_000, 1_002_500)),
+                    ]
+                );
+                assert_ok!(ReserveFundModule::do_try_state());
+            });
+        }
+
+        #[test]
+        fn history_is_bounded_and_stays_continuous() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(ReserveFundModule::initialize_reserve(system::RawOrigin::Root.into()));
+                for _ in 0..10 {
+                    assert_ok!(ReserveFundModule::contribute(system::RawOrigin::Signed(1).into(), 1_000, b"contribution".to_vec()));
+                }
+                let state = ReserveFundModule::reserve_state();
+                assert_eq!(state.history.len() as u32, MaxHistoryLen::get());
+                // L'initialisation et les deux premières contributions ont été évincées.
+                assert_eq!(state.history.first().map(|record| record.previous_balance), Some(1_002_000));
+                assert_eq!(state.balance, 1_010_000);
+                assert_ok!(ReserveFundModule::do_try_state());
+            });
+        }
+
+        #[test]
+        fn history_migration_keeps_the_balance_and_the_most_recent_records() {
+            use frame_support::traits::Hooks;
+            new_test_ext().execute_with(|| {
+                // Encodage de la version 0 : `history` était un `Vec` non borné, à descriptions non bornées.
+                let record = |new_balance| LegacyReserveRecord {
+                    timestamp: 0,
+                    previous_balance: 0,
+                    new_balance,
+                    operation: vec![b'x'; 200],
+                };
+                let history: Vec<LegacyReserveRecord> = (1..=10).map(record).collect();
+                frame_support::storage::unhashed::put(&ReserveFundStorage::<Test>::hashed_key(), &(5_000u128, history));
+                // Sans migration, le décodage échoue et `ValueQuery` retournerait un solde nul.
+                assert!(ReserveFundStorage::<Test>::try_get().is_err());
+
+                ReserveFundModule::on_runtime_upgrade();
+                let state = ReserveFundModule::reserve_state();
+                assert_eq!(state.balance, 5_000);
+                let balances: Vec<u128> = state.history.iter().map(|record| record.new_balance).collect();
+                assert_eq!(balances, (3..=10).collect::<Vec<u128>>());
+                assert!(state.history.iter().all(|record| record.operation.to_vec() == vec![b'x'; 128]));
+                assert_eq!(StorageVersion::get::<ReserveFundModule>(), STORAGE_VERSION);
+            });
+        }
+
+        #[test]
+        fn history_records_have_a_bounded_encoding() {
+            new_test_ext().execute_with(|| {
+                // Horodatage, deux soldes, puis la description : préfixe compact de 2 octets et 128 octets.
+                assert_eq!(ReserveRecord::max_encoded_len(), 8 + 16 + 16 + 2 + 128);
+                assert_eq!(
+                    ReserveFundStateOf::<Test>::max_encoded_len(),
+                    16 + 1 + MaxHistoryLen::get() as usize * ReserveRecord::max_encoded_len()
+                );
+
+                assert_ok!(ReserveFundModule::initialize_reserve(system::RawOrigin::Root.into()));
+                assert_ok!(ReserveFundModule::contribute(system::RawOrigin::Signed(1).into(), 100, vec![b'x'; 200]));
+                let last = ReserveFundModule::reserve_state().history.last().cloned().expect("contribution historisée");
+                assert_eq!(last.operation.to_vec(), vec![b'x'; 128]);
+            });
+        }
     }
 }
@@ -40,6 +40,7 @@ mod tests {
         type MinReserve = sp_runtime::traits::ConstU32<500>;
         type MaxReserve = sp_runtime::traits::ConstU32<10000>;
         type ReserveSmoothingFactor = sp_runtime::traits::ConstU32<10>;
+        type EmergencyHalt = ();
     }
 
     pub fn new_test_ext() -> sp_io::TestExternalities {
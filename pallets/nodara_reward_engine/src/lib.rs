@@ -11,16 +11,40 @@
 /// - **Dynamic Reward Calculation:** Computes rewards based on configurable parameters.
 /// - **Audit Logging:** Maintains an immutable log of every reward distribution event.
 /// - **DAO Governance Integration:** Allows future proposals to adjust reward parameters.
+/// - **Linear Vesting:** Rewards can optionally be released linearly over a number of blocks.
+/// - **Reputation Multipliers:** Payouts can optionally scale with the recipient's reputation tier.
 /// - **Performance Optimizations:** Optimized arithmetic and memory handling.
 pub use pallet::*;
 
+/// Source of reputation scores consulted by the reward engine to scale payouts.
+///
+/// The `()` implementation knows no scores, which leaves every reward unscaled.
+pub trait ReputationSource<AccountId> {
+    /// Current reputation score of `who`, or `None` if the account has no record.
+    fn reputation_of(who: &AccountId) -> Option<u32>;
+}
+
+impl<AccountId> ReputationSource<AccountId> for () {
+    fn reputation_of(_who: &AccountId) -> Option<u32> {
+        None
+    }
+}
+
 #[frame_support::pallet]
 pub mod pallet {
-    use frame_support::{dispatch::DispatchResult, pallet_prelude::*, traits::Get};
+    use frame_support::{
+        dispatch::DispatchResult,
+        pallet_prelude::*,
+        traits::{Currency, Get},
+    };
     use frame_system::pallet_prelude::*;
+    use nodara_emergency::HaltGuard;
     use pallet_timestamp as timestamp;
     use sp_std::vec::Vec;
-    use sp_runtime::RuntimeDebug;
+    use sp_runtime::{
+        helpers_128bit::multiply_by_rational_with_rounding, traits::{SaturatedConversion, Saturating}, Rounding,
+        RuntimeDebug,
+    };
     use parity_scale_codec::{Encode, Decode};
     use scale_info::TypeInfo;
 
@@ -40,6 +64,18 @@ pub mod pallet {
         pub history: Vec<RewardRecord<AccountId>>,
     }
 
+    /// Linear vesting schedule for a reward.
+    ///
+    /// `total` becomes claimable linearly between `start` and `start + duration`; `released`
+    /// tracks how much has already been paid out through `T::Currency`.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct VestingSchedule<BlockNumber> {
+        pub total: u128,
+        pub released: u128,
+        pub start: BlockNumber,
+        pub duration: BlockNumber,
+    }
+
     #[pallet::pallet]
     #[pallet::generate_store(pub(super) trait Store)]
     pub struct Pallet<T>(_);
@@ -52,6 +88,25 @@ pub mod pallet {
         /// Baseline reward pool for initialization.
         #[pallet::constant]
         type BaselineRewardPool: Get<u128>;
+        /// Maximum size of the reward pool; increases beyond this ceiling are rejected.
+        #[pallet::constant]
+        type MaxRewardPool: Get<u128>;
+        /// Currency used to pay out vested rewards.
+        type Currency: Currency<Self::AccountId>;
+        /// Maximum number of active vesting schedules, bounding the work done in `on_initialize`.
+        #[pallet::constant]
+        type MaxVestingSchedules: Get<u32>;
+        /// Reputation scores used to scale `distribute_reward` payouts; `()` disables scaling.
+        type ReputationSource: crate::ReputationSource<Self::AccountId>;
+        /// Reputation tiers as `(minimum score, multiplier in percent)`, sorted by ascending score.
+        /// The highest tier reached applies; an empty list disables reputation scaling.
+        type ReputationMultipliers: Get<Vec<(u32, u32)>>;
+        /// Global emergency halt checked by every signed call; `()` disables it.
+        type EmergencyHalt: nodara_emergency::HaltGuard;
+        /// Minimum number of blocks between two `update_reward_pool` calls from the same account;
+        /// 0 disables the cooldown. Revenue credited through `increase_reward_pool` is exempt.
+        #[pallet::constant]
+        type UpdateCooldown: Get<BlockNumberFor<Self>>;
     }
 
     /// Storage for the reward engine state.
@@ -60,6 +115,28 @@ pub mod pallet {
     pub type RewardEngineStorage<T: Config> =
         StorageValue<_, RewardEngineState<T::AccountId>, ValueQuery>;
 
+    /// Active vesting schedule per account.
+    #[pallet::storage]
+    #[pallet::getter(fn vesting_schedule)]
+    pub type VestingSchedules<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, VestingSchedule<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Number of entries in `VestingSchedules`.
+    #[pallet::storage]
+    #[pallet::getter(fn vesting_schedule_count)]
+    pub type VestingScheduleCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Rewards accrued by `accrue_reward` and not yet claimed, per account.
+    #[pallet::storage]
+    #[pallet::getter(fn claimable_reward)]
+    pub type ClaimableRewards<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u128, ValueQuery>;
+
+    /// Block of each account's last successful `update_reward_pool` call.
+    #[pallet::storage]
+    #[pallet::getter(fn last_pool_update)]
+    pub type LastPoolUpdate<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>, OptionQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -67,12 +144,50 @@ pub mod pallet {
         RewardDistributed(T::AccountId, u128, Vec<u8>),
         /// Emitted when the reward pool is updated (previous pool, new pool).
         RewardPoolUpdated(u128, u128),
+        /// Emitted when a vested reward is scheduled (account, total amount, vesting blocks).
+        VestingScheduleCreated(T::AccountId, u128, BlockNumberFor<T>),
+        /// Emitted when a vested portion is paid out (account, amount released).
+        VestedRewardReleased(T::AccountId, u128),
+        /// Emitted when a reward is credited to a claimable balance (account, reward amount, details).
+        RewardAccrued(T::AccountId, u128, Vec<u8>),
+        /// Emitted when an account claims its accrued rewards (account, amount paid out).
+        RewardClaimed(T::AccountId, u128),
     }
 
     #[pallet::error]
     pub enum Error<T> {
         /// Insufficient funds in the reward pool.
         InsufficientRewardPool,
+        /// The increase would push the reward pool above `MaxRewardPool`.
+        RewardPoolCeilingExceeded,
+        /// The vesting period must be at least one block.
+        InvalidVestingPeriod,
+        /// The account already has an active vesting schedule.
+        VestingScheduleActive,
+        /// `MaxVestingSchedules` active schedules already exist.
+        TooManyVestingSchedules,
+        /// The account has no active vesting schedule.
+        NoVestingSchedule,
+        /// The account has no accrued reward to claim.
+        NothingToClaim,
+        /// The caller updated the reward pool less than `UpdateCooldown` blocks ago.
+        CooldownNotElapsed,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Releases the vested portion of every active schedule.
+        ///
+        /// The number of schedules is capped by `MaxVestingSchedules`, which bounds this loop.
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let db = T::DbWeight::get();
+            let schedules: Vec<_> = VestingSchedules::<T>::iter().collect();
+            let processed = schedules.len() as u64;
+            for (account, schedule) in schedules {
+                Self::release_vested(&account, schedule, n);
+            }
+            db.reads_writes(1 + processed, 2 * processed)
+        }
     }
 
     #[pallet::call]
@@ -95,7 +210,8 @@ pub mod pallet {
 
         /// Distribute a reward to a given account.
         ///
-        /// The reward is subtracted from the reward pool and logged.
+        /// The reward is first scaled by the recipient's reputation multiplier (see
+        /// `ReputationMultipliers`), then subtracted from the reward pool and logged.
         #[pallet::weight(10_000)]
         pub fn distribute_reward(
             origin: OriginFor<T>,
@@ -104,35 +220,63 @@ pub mod pallet {
             details: Vec<u8>,
         ) -> DispatchResult {
             let _sender = ensure_signed(origin)?;
-            let mut state = <RewardEngineStorage<T>>::get();
-            ensure!(state.reward_pool >= reward, Error::<T>::InsufficientRewardPool);
-            let previous_pool = state.reward_pool;
-            state.reward_pool = state.reward_pool.saturating_sub(reward);
-            let now = <timestamp::Pallet<T>>::get();
-            let record = RewardRecord {
-                timestamp: now,
-                account: account.clone(),
-                reward_amount: reward,
-                details: details.clone(),
-            };
-            state.history.push(record);
-            <RewardEngineStorage<T>>::put(state);
+            T::EmergencyHalt::ensure_running()?;
+            let reward = Self::scaled_reward(&account, reward);
+            let previous_pool = Self::debit_pool(&account, reward, &details)?;
             Self::deposit_event(Event::RewardDistributed(account, reward, details));
             Self::deposit_event(Event::RewardPoolUpdated(previous_pool, previous_pool.saturating_sub(reward)));
             Ok(())
         }
 
+        /// Credit a reward to `account`'s claimable balance instead of paying it out.
+        ///
+        /// The reward is scaled and taken from the pool like `distribute_reward`; the account
+        /// collects the accumulated balance later with `claim_reward`.
+        #[pallet::weight(10_000)]
+        pub fn accrue_reward(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            amount: u128,
+            details: Vec<u8>,
+        ) -> DispatchResult {
+            let _sender = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            let reward = Self::scaled_reward(&account, amount);
+            let previous_pool = Self::debit_pool(&account, reward, &details)?;
+            ClaimableRewards::<T>::mutate(&account, |balance| *balance = balance.saturating_add(reward));
+            Self::deposit_event(Event::RewardAccrued(account, reward, details));
+            Self::deposit_event(Event::RewardPoolUpdated(previous_pool, previous_pool.saturating_sub(reward)));
+            Ok(())
+        }
+
+        /// Pay out the caller's accrued rewards through `T::Currency`.
+        #[pallet::weight(10_000)]
+        pub fn claim_reward(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            let amount = ClaimableRewards::<T>::take(&who);
+            ensure!(amount > 0, Error::<T>::NothingToClaim);
+            let _ = T::Currency::deposit_creating(&who, amount.saturated_into());
+            Self::deposit_event(Event::RewardClaimed(who, amount));
+            Ok(())
+        }
+
         /// Update the reward pool by a given amount.
         ///
         /// If `increase` is true, the amount is added; otherwise, it is subtracted.
+        /// Increases that would exceed `MaxRewardPool` are rejected, as are calls made less than
+        /// `UpdateCooldown` blocks after the caller's previous update.
         #[pallet::weight(10_000)]
         pub fn update_reward_pool(origin: OriginFor<T>, amount: u128, increase: bool) -> DispatchResult {
-            let _sender = ensure_signed(origin)?;
+            let sender = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::note_pool_update(&sender)?;
+            if increase {
+                return Self::increase_reward_pool(amount);
+            }
             let mut state = <RewardEngineStorage<T>>::get();
             let previous_pool = state.reward_pool;
-            if increase {
-                state.reward_pool = state.reward_pool.saturating_add(amount);
-            } else {
+            {
                 ensure!(state.reward_pool >= amount, Error::<T>::InsufficientRewardPool);
                 state.reward_pool = state.reward_pool.saturating_sub(amount);
             }
@@ -154,14 +298,171 @@ pub mod pallet {
             details: Vec<u8>,
         ) -> DispatchResult {
             let _sender = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
             // Calculate dynamic reward based on work and reputation.
             let reward = Self::calculate_dynamic_reward(work, reputation);
             // Reuse distribute_reward logic.
             Self::distribute_reward(origin, account, reward, details)
         }
+
+        /// Distribute a reward that vests linearly over `vesting_blocks` blocks.
+        ///
+        /// The amount is taken from the reward pool immediately and logged like `distribute_reward`;
+        /// it is then paid out through `T::Currency` as it vests, either by `on_initialize` or by
+        /// `claim_vested`.
+        #[pallet::weight(10_000)]
+        pub fn distribute_vested_reward(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            amount: u128,
+            vesting_blocks: BlockNumberFor<T>,
+            details: Vec<u8>,
+        ) -> DispatchResult {
+            let _sender = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            ensure!(vesting_blocks > 0u32.into(), Error::<T>::InvalidVestingPeriod);
+            ensure!(!VestingSchedules::<T>::contains_key(&account), Error::<T>::VestingScheduleActive);
+            let count = VestingScheduleCount::<T>::get();
+            ensure!(count < T::MaxVestingSchedules::get(), Error::<T>::TooManyVestingSchedules);
+            let mut state = <RewardEngineStorage<T>>::get();
+            ensure!(state.reward_pool >= amount, Error::<T>::InsufficientRewardPool);
+            let previous_pool = state.reward_pool;
+            state.reward_pool = state.reward_pool.saturating_sub(amount);
+            let new_pool = state.reward_pool;
+            state.history.push(RewardRecord {
+                timestamp: <timestamp::Pallet<T>>::get(),
+                account: account.clone(),
+                reward_amount: amount,
+                details: details.clone(),
+            });
+            <RewardEngineStorage<T>>::put(state);
+            VestingSchedules::<T>::insert(
+                &account,
+                VestingSchedule {
+                    total: amount,
+                    released: 0,
+                    start: <frame_system::Pallet<T>>::block_number(),
+                    duration: vesting_blocks,
+                },
+            );
+            VestingScheduleCount::<T>::put(count.saturating_add(1));
+            Self::deposit_event(Event::RewardDistributed(account.clone(), amount, details));
+            Self::deposit_event(Event::RewardPoolUpdated(previous_pool, new_pool));
+            Self::deposit_event(Event::VestingScheduleCreated(account, amount, vesting_blocks));
+            Ok(())
+        }
+
+        /// Pay out whatever has vested for the caller so far.
+        #[pallet::weight(10_000)]
+        pub fn claim_vested(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            let schedule = VestingSchedules::<T>::get(&who).ok_or(Error::<T>::NoVestingSchedule)?;
+            Self::release_vested(&who, schedule, <frame_system::Pallet<T>>::block_number());
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
+        /// Reputation multiplier (in percent) applying to `account`.
+        ///
+        /// Returns 100 when scaling is disabled, when the account has no reputation score or when
+        /// its score is below the lowest tier.
+        pub fn reputation_multiplier(account: &T::AccountId) -> u32 {
+            let tiers = T::ReputationMultipliers::get();
+            if tiers.is_empty() {
+                return 100;
+            }
+            <T::ReputationSource as crate::ReputationSource<T::AccountId>>::reputation_of(account)
+                .and_then(|score| tiers.iter().rev().find(|(min_score, _)| score >= *min_score))
+                .map(|(_, multiplier)| *multiplier)
+                .unwrap_or(100)
+        }
+
+        /// `reward` scaled by the reputation multiplier of `account`.
+        pub fn scaled_reward(account: &T::AccountId, reward: u128) -> u128 {
+            let multiplier = Self::reputation_multiplier(account);
+            if multiplier == 100 {
+                return reward;
+            }
+            multiply_by_rational_with_rounding(reward, multiplier as u128, 100, Rounding::Down)
+                .unwrap_or(u128::MAX)
+        }
+
+        /// Amount of `schedule` vested at block `now` (released or not).
+        pub fn vested_amount(schedule: &VestingSchedule<BlockNumberFor<T>>, now: BlockNumberFor<T>) -> u128 {
+            let duration = schedule.duration.saturated_into::<u128>();
+            let elapsed = now.saturating_sub(schedule.start).saturated_into::<u128>().min(duration);
+            if elapsed == duration {
+                return schedule.total;
+            }
+            multiply_by_rational_with_rounding(schedule.total, elapsed, duration, Rounding::Down)
+                .unwrap_or(schedule.total)
+        }
+
+        /// Take `reward` out of the pool and log it for `account`, returning the previous pool.
+        fn debit_pool(account: &T::AccountId, reward: u128, details: &[u8]) -> Result<u128, DispatchError> {
+            let mut state = <RewardEngineStorage<T>>::get();
+            ensure!(state.reward_pool >= reward, Error::<T>::InsufficientRewardPool);
+            let previous_pool = state.reward_pool;
+            state.reward_pool = state.reward_pool.saturating_sub(reward);
+            state.history.push(RewardRecord {
+                timestamp: <timestamp::Pallet<T>>::get(),
+                account: account.clone(),
+                reward_amount: reward,
+                details: details.to_vec(),
+            });
+            <RewardEngineStorage<T>>::put(state);
+            Ok(previous_pool)
+        }
+
+        /// Pay out the releasable part of `schedule` to `account`, removing it once fully vested.
+        fn release_vested(account: &T::AccountId, mut schedule: VestingSchedule<BlockNumberFor<T>>, now: BlockNumberFor<T>) {
+            let releasable = Self::vested_amount(&schedule, now).saturating_sub(schedule.released);
+            if releasable > 0 {
+                let _ = T::Currency::deposit_creating(account, releasable.saturated_into());
+                schedule.released = schedule.released.saturating_add(releasable);
+                Self::deposit_event(Event::VestedRewardReleased(account.clone(), releasable));
+            }
+            if schedule.released >= schedule.total {
+                VestingSchedules::<T>::remove(account);
+                VestingScheduleCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+            } else if releasable > 0 {
+                VestingSchedules::<T>::insert(account, schedule);
+            }
+        }
+
+        /// Increase the reward pool by `amount`, rejecting increases above `MaxRewardPool`.
+        ///
+        /// Used by `update_reward_pool` and by the runtime to credit revenue to the pool.
+        pub fn increase_reward_pool(amount: u128) -> DispatchResult {
+            let mut state = <RewardEngineStorage<T>>::get();
+            let previous_pool = state.reward_pool;
+            state.reward_pool = state
+                .reward_pool
+                .checked_add(amount)
+                .filter(|pool| *pool <= T::MaxRewardPool::get())
+                .ok_or(Error::<T>::RewardPoolCeilingExceeded)?;
+            let new_pool = state.reward_pool;
+            <RewardEngineStorage<T>>::put(state);
+            Self::deposit_event(Event::RewardPoolUpdated(previous_pool, new_pool));
+            Ok(())
+        }
+
+        /// Record a pool update by `who` at the current block, failing with `CooldownNotElapsed`
+        /// if its previous update is more recent than `UpdateCooldown`.
+        fn note_pool_update(who: &T::AccountId) -> DispatchResult {
+            let now = <frame_system::Pallet<T>>::block_number();
+            if let Some(last) = LastPoolUpdate::<T>::get(who) {
+                ensure!(
+                    now >= last.saturating_add(T::UpdateCooldown::get()),
+                    Error::<T>::CooldownNotElapsed
+                );
+            }
+            LastPoolUpdate::<T>::insert(who, now);
+            Ok(())
+        }
+
         /// Calculate dynamic reward based on input factors.
         ///
         /// This is a simple example formula:
@@ -178,7 +479,7 @@ pub mod pallet {
     #[cfg(test)]
     mod tests {
         use super::*;
-        use frame_support::{assert_ok, assert_err, parameter_types};
+        use frame_support::{assert_ok, assert_err, parameter_types, traits::Hooks};
         use sp_core::H256;
         use sp_runtime::{
             traits::{BlakeTwo256, IdentityLookup, Saturating},
@@ -198,13 +499,27 @@ pub mod pallet {
                 System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
                 RewardEngineModule: Pallet,
                 Timestamp: timestamp::Pallet,
+                Balances: pallet_balances,
             }
         );
 
         parameter_types! {
             pub const BlockHashCount: u64 = 250;
             pub const BaselineRewardPool: u128 = 1_000_000;
+            pub const MaxRewardPool: u128 = 3_000_000;
             pub const MinimumPeriod: u64 = 1;
+            pub const ExistentialDeposit: u128 = 1;
+            pub const MaxVestingSchedules: u32 = 2;
+            pub ReputationMultipliers: Vec<(u32, u32)> = vec![(0, 100), (500, 150)];
+            pub static UpdateCooldown: u64 = 0;
+        }
+
+        /// Reputation score of an account in tests: 100 per unit of account id.
+        pub struct AccountIdReputation;
+        impl crate::ReputationSource<u64> for AccountIdReputation {
+            fn reputation_of(who: &u64) -> Option<u32> {
+                Some(*who as u32 * 100)
+            }
         }
 
         impl system::Config for Test {
@@ -225,7 +540,7 @@ pub mod pallet {
             type BlockHashCount = BlockHashCount;
             type Version = ();
             type PalletInfo = ();
-            type AccountData = ();
+            type AccountData = pallet_balances::AccountData<u128>;
             type OnNewAccount = ();
             type OnKilledAccount = ();
             type SystemWeightInfo = ();
@@ -241,9 +556,39 @@ pub mod pallet {
             type WeightInfo = ();
         }
 
+        impl pallet_balances::Config for Test {
+            type RuntimeEvent = ();
+            type Balance = u128;
+            type DustRemoval = ();
+            type ExistentialDeposit = ExistentialDeposit;
+            type AccountStore = System;
+            type WeightInfo = ();
+            type MaxLocks = ();
+            type MaxReserves = ();
+            type ReserveIdentifier = [u8; 8];
+            type RuntimeHoldReason = ();
+            type RuntimeFreezeReason = ();
+            type FreezeIdentifier = ();
+            type MaxFreezes = ();
+        }
+
         impl Config for Test {
             type RuntimeEvent = ();
             type BaselineRewardPool = BaselineRewardPool;
+            type MaxRewardPool = MaxRewardPool;
+            type Currency = Balances;
+            type MaxVestingSchedules = MaxVestingSchedules;
+            type ReputationSource = AccountIdReputation;
+            type ReputationMultipliers = ReputationMultipliers;
+            type EmergencyHalt = ();
+            type UpdateCooldown = UpdateCooldown;
+        }
+
+        pub fn new_test_ext() -> sp_io::TestExternalities {
+            let storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+            let mut ext: sp_io::TestExternalities = storage.into();
+            ext.execute_with(|| System::set_block_number(1));
+            ext
         }
 
         #[test]
@@ -303,5 +648,163 @@ pub mod pallet {
             let final_pool = RewardEngineModule::reward_engine_state().reward_pool;
             assert_eq!(final_pool, current_pool + increase_amount - decrease_amount);
         }
+
+        #[test]
+        fn update_reward_pool_allows_increase_up_to_ceiling() {
+            assert_ok!(RewardEngineModule::initialize_rewards(system::RawOrigin::Root.into()));
+            let headroom = MaxRewardPool::get() - BaselineRewardPool::get();
+            assert_ok!(RewardEngineModule::update_reward_pool(system::RawOrigin::Signed(2).into(), headroom, true));
+            assert_eq!(RewardEngineModule::reward_engine_state().reward_pool, MaxRewardPool::get());
+        }
+
+        #[test]
+        fn update_reward_pool_rejects_increase_over_ceiling() {
+            assert_ok!(RewardEngineModule::initialize_rewards(system::RawOrigin::Root.into()));
+            let headroom = MaxRewardPool::get() - BaselineRewardPool::get();
+            assert_err!(
+                RewardEngineModule::update_reward_pool(system::RawOrigin::Signed(2).into(), headroom + 1, true),
+                Error::<Test>::RewardPoolCeilingExceeded
+            );
+            assert_eq!(RewardEngineModule::reward_engine_state().reward_pool, BaselineRewardPool::get());
+        }
+
+        #[test]
+        fn vested_reward_is_released_linearly_each_block() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(RewardEngineModule::initialize_rewards(system::RawOrigin::Root.into()));
+                assert_ok!(RewardEngineModule::distribute_vested_reward(
+                    system::RawOrigin::Signed(2).into(), 7, 1_000, 10, b"Vested".to_vec()
+                ));
+                assert_eq!(RewardEngineModule::reward_engine_state().reward_pool, BaselineRewardPool::get() - 1_000);
+                assert_eq!(Balances::free_balance(7), 0);
+
+                for block in 2..=11u64 {
+                    System::set_block_number(block);
+                    RewardEngineModule::on_initialize(block);
+                    assert_eq!(Balances::free_balance(7), 100 * (block as u128 - 1));
+                }
+                assert!(RewardEngineModule::vesting_schedule(7).is_none());
+                assert_eq!(RewardEngineModule::vesting_schedule_count(), 0);
+
+                // Nothing more is paid once the schedule has completed.
+                System::set_block_number(12);
+                RewardEngineModule::on_initialize(12);
+                assert_eq!(Balances::free_balance(7), 1_000);
+            });
+        }
+
+        #[test]
+        fn claim_vested_pays_out_the_vested_portion() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(RewardEngineModule::initialize_rewards(system::RawOrigin::Root.into()));
+                assert_err!(
+                    RewardEngineModule::distribute_vested_reward(system::RawOrigin::Signed(2).into(), 7, 1_001, 0, vec![]),
+                    Error::<Test>::InvalidVestingPeriod
+                );
+                assert_ok!(RewardEngineModule::distribute_vested_reward(
+                    system::RawOrigin::Signed(2).into(), 7, 1_001, 3, vec![]
+                ));
+                assert_err!(
+                    RewardEngineModule::claim_vested(system::RawOrigin::Signed(8).into()),
+                    Error::<Test>::NoVestingSchedule
+                );
+
+                System::set_block_number(2);
+                assert_ok!(RewardEngineModule::claim_vested(system::RawOrigin::Signed(7).into()));
+                assert_eq!(Balances::free_balance(7), 333);
+                // Claiming twice in the same block releases nothing more.
+                assert_ok!(RewardEngineModule::claim_vested(system::RawOrigin::Signed(7).into()));
+                assert_eq!(Balances::free_balance(7), 333);
+
+                System::set_block_number(4);
+                assert_ok!(RewardEngineModule::claim_vested(system::RawOrigin::Signed(7).into()));
+                assert_eq!(Balances::free_balance(7), 1_001);
+                assert!(RewardEngineModule::vesting_schedule(7).is_none());
+            });
+        }
+
+        #[test]
+        fn vesting_schedules_are_capped() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(RewardEngineModule::initialize_rewards(system::RawOrigin::Root.into()));
+                let vest = |account| RewardEngineModule::distribute_vested_reward(
+                    system::RawOrigin::Signed(2).into(), account, 100, 5, vec![]
+                );
+                assert_ok!(vest(7));
+                assert_err!(vest(7), Error::<Test>::VestingScheduleActive);
+                assert_ok!(vest(8));
+                assert_err!(vest(9), Error::<Test>::TooManyVestingSchedules);
+            });
+        }
+
+        #[test]
+        fn distribute_reward_scales_with_reputation_tier() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(RewardEngineModule::initialize_rewards(system::RawOrigin::Root.into()));
+                // Account 1 (score 100) stays in the base tier, account 7 (score 700) reaches the 150 % tier.
+                assert_eq!(RewardEngineModule::reputation_multiplier(&1), 100);
+                assert_eq!(RewardEngineModule::reputation_multiplier(&7), 150);
+
+                assert_ok!(RewardEngineModule::distribute_reward(system::RawOrigin::Signed(2).into(), 1, 10_000, vec![]));
+                assert_eq!(RewardEngineModule::reward_engine_state().reward_pool, BaselineRewardPool::get() - 10_000);
+                assert_ok!(RewardEngineModule::distribute_reward(system::RawOrigin::Signed(2).into(), 7, 10_000, vec![]));
+                let state = RewardEngineModule::reward_engine_state();
+                assert_eq!(state.reward_pool, BaselineRewardPool::get() - 25_000);
+                assert_eq!(state.history.last().map(|record| record.reward_amount), Some(15_000));
+            });
+        }
+
+        #[test]
+        fn accrued_rewards_are_claimed_in_one_payout() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(RewardEngineModule::initialize_rewards(system::RawOrigin::Root.into()));
+                for amount in [100, 200, 300] {
+                    assert_ok!(RewardEngineModule::accrue_reward(system::RawOrigin::Signed(2).into(), 1, amount, vec![]));
+                }
+                assert_eq!(RewardEngineModule::claimable_reward(1), 600);
+                let state = RewardEngineModule::reward_engine_state();
+                assert_eq!(state.reward_pool, BaselineRewardPool::get() - 600);
+                assert_eq!(state.history.len(), 3);
+                assert_eq!(Balances::free_balance(1), 0);
+
+                assert_ok!(RewardEngineModule::claim_reward(system::RawOrigin::Signed(1).into()));
+                assert_eq!(Balances::free_balance(1), 600);
+                assert_eq!(RewardEngineModule::claimable_reward(1), 0);
+                assert_err!(
+                    RewardEngineModule::claim_reward(system::RawOrigin::Signed(1).into()),
+                    Error::<Test>::NothingToClaim
+                );
+
+                // The push path still pays nothing into the claimable balance.
+                assert_ok!(RewardEngineModule::distribute_reward(system::RawOrigin::Signed(2).into(), 1, 50, vec![]));
+                assert_eq!(RewardEngineModule::claimable_reward(1), 0);
+            });
+        }
+
+        #[test]
+        fn pool_updates_respect_the_per_caller_cooldown() {
+            new_test_ext().execute_with(|| {
+                UpdateCooldown::set(5);
+                assert_ok!(RewardEngineModule::initialize_rewards(system::RawOrigin::Root.into()));
+                assert_ok!(RewardEngineModule::update_reward_pool(system::RawOrigin::Signed(2).into(), 100, true));
+                assert_eq!(RewardEngineModule::last_pool_update(2), Some(1));
+
+                System::set_block_number(5);
+                assert_err!(
+                    RewardEngineModule::update_reward_pool(system::RawOrigin::Signed(2).into(), 100, false),
+                    Error::<Test>::CooldownNotElapsed
+                );
+                // Another caller and the revenue path are not held back by account 2's cooldown.
+                assert_ok!(RewardEngineModule::update_reward_pool(system::RawOrigin::Signed(3).into(), 100, true));
+                assert_ok!(RewardEngineModule::increase_reward_pool(100));
+                assert_eq!(RewardEngineModule::reward_engine_state().reward_pool, BaselineRewardPool::get() + 300);
+
+                System::set_block_number(6);
+                assert_ok!(RewardEngineModule::update_reward_pool(system::RawOrigin::Signed(2).into(), 100, false));
+                assert_eq!(RewardEngineModule::reward_engine_state().reward_pool, BaselineRewardPool::get() + 200);
+                assert_eq!(RewardEngineModule::last_pool_update(2), Some(6));
+                UpdateCooldown::set(0);
+            });
+        }
     }
 }
@@ -38,6 +38,13 @@ mod tests {
         type RuntimeEvent = ();
         type BaselineRewardPool = sp_runtime::traits::ConstU32<1000>;
         type RewardSmoothingFactor = sp_runtime::traits::ConstU32<10>;
+        type MaxRewardPool = sp_runtime::traits::ConstU128<1_000_000>;
+        type Currency = ();
+        type MaxVestingSchedules = sp_runtime::traits::ConstU32<16>;
+        type ReputationSource = ();
+        type ReputationMultipliers = ();
+        type EmergencyHalt = ();
+        type UpdateCooldown = sp_runtime::traits::ConstU64<0>;
     }
 
     pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -7,21 +7,28 @@
 /// Il utilise une moyenne mobile exponentielle (EMA) avec un mécanisme de dampening pour lisser les fluctuations de volatilité.
 /// Le nouveau paramètre est contraint entre des bornes minimales et maximales, et chaque ajustement est historisé pour une auditabilité complète.
 /// Le module intègre également une extrinsèque DAO permettant de mettre à jour dynamiquement la configuration.
+///
+/// En mode oracle, la volatilité n'est plus acceptée de n'importe quel compte signé : des oracles enregistrés
+/// soumettent leurs mesures par tour, et la médiane des mesures pilote l'EMA dès que le quorum est atteint.
 pub use pallet::*;
 
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{dispatch::DispatchResult, pallet_prelude::*, traits::Get};
     use frame_system::pallet_prelude::*;
+    use nodara_emergency::HaltGuard;
     use pallet_timestamp as timestamp;
     use sp_std::vec::Vec;
     use sp_runtime::RuntimeDebug;
     use parity_scale_codec::{Encode, Decode};
     use scale_info::TypeInfo;
+    #[cfg(feature = "serde")]
+    use serde::{Deserialize, Serialize};
     use sp_runtime::traits::SaturatedConversion;
 
     /// Structure représentant un enregistrement d'ajustement de stabilité.
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
     pub struct StabilityRecord {
         pub timestamp: u64,
         pub old_parameter: u32,
@@ -32,6 +39,7 @@ pub mod pallet {
 
     /// État global du module de stabilité.
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, Default, TypeInfo)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
     pub struct StabilityState {
         pub current_parameter: u32,
         /// Moyenne mobile exponentielle de la volatilité.
@@ -39,6 +47,15 @@ pub mod pallet {
         pub history: Vec<StabilityRecord>,
     }
 
+    /// Borne de `StabilityConfig` atteinte par le paramètre de stabilité.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub enum StabilityBound {
+        /// `min_parameter`.
+        Min,
+        /// `max_parameter`.
+        Max,
+    }
+
     /// Configuration dynamique du module, modifiable par DAO.
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, Default)]
     pub struct StabilityConfig {
@@ -74,6 +91,14 @@ pub mod pallet {
         type MinStabilityParameter: Get<u32>;
         /// Origine autorisée à mettre à jour la configuration DAO.
         type DaoOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+        /// Nombre minimal de mesures d'oracles distinctes pour clore un tour.
+        #[pallet::constant]
+        type MinOracleQuorum: Get<u32>;
+        /// Nombre de tours dont les soumissions sont conservées.
+        #[pallet::constant]
+        type OracleRoundsRetained: Get<u32>;
+        /// Arrêt d'urgence global consulté par chaque appel signé ; `()` le désactive.
+        type EmergencyHalt: nodara_emergency::HaltGuard;
     }
 
     /// Stockage de l'état global du module.
@@ -81,11 +106,38 @@ pub mod pallet {
     #[pallet::getter(fn stability_state)]
     pub type StabilityStorage<T: Config> = StorageValue<_, StabilityState, ValueQuery>;
 
+    /// Indique si `initialize_stability` a été exécutée.
+    #[pallet::storage]
+    #[pallet::getter(fn is_initialized)]
+    pub type Initialized<T: Config> = StorageValue<_, bool, ValueQuery>;
+
     /// Stockage de la configuration dynamique du module.
     #[pallet::storage]
     #[pallet::getter(fn stability_config)]
     pub type StabilityConfigStorage<T: Config> = StorageValue<_, StabilityConfig, ValueQuery>;
 
+    /// Indique si la volatilité provient exclusivement du flux d'oracles.
+    #[pallet::storage]
+    #[pallet::getter(fn oracle_mode)]
+    pub type OracleMode<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// Oracles autorisés à soumettre des mesures de volatilité.
+    #[pallet::storage]
+    #[pallet::getter(fn oracles)]
+    pub type Oracles<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    /// Tour d'oracle en cours.
+    #[pallet::storage]
+    #[pallet::getter(fn current_round)]
+    pub type CurrentRound<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Mesures soumises par tour : (oracle, volatilité). Seuls les `OracleRoundsRetained` derniers tours
+    /// sont conservés.
+    #[pallet::storage]
+    #[pallet::getter(fn round_submissions)]
+    pub type RoundSubmissions<T: Config> =
+        StorageMap<_, Twox64Concat, u32, Vec<(T::AccountId, u32)>, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -93,39 +145,67 @@ pub mod pallet {
         StabilityAdjusted(u32, u32, u32, u32),
         /// Configuration DAO mise à jour : (smoothing_factor, dampening_factor, min_parameter, max_parameter)
         ConfigurationUpdated(u32, u32, u32, u32),
+        /// Historique compacté : (taille avant, taille après)
+        HistoryCompacted(u32, u32),
+        /// Mode oracle activé ou désactivé.
+        OracleModeChanged(bool),
+        /// Oracle ajouté à la liste des oracles autorisés.
+        OracleRegistered(T::AccountId),
+        /// Oracle retiré de la liste des oracles autorisés.
+        OracleRemoved(T::AccountId),
+        /// Mesure d'oracle reçue : (tour, oracle, volatilité)
+        VolatilityReadingSubmitted(u32, T::AccountId, u32),
+        /// Tour clos : (tour, volatilité médiane retenue, nombre de mesures)
+        OracleRoundFinalized(u32, u32, u32),
+        /// Le paramètre vient d'atteindre une borne (saturation) : (borne, valeur). N'est pas réémis
+        /// tant que le paramètre y reste.
+        StabilityBoundHit(StabilityBound, u32),
     }
 
     #[pallet::error]
     pub enum Error<T> {
         /// Erreur lors de l'ajustement (par exemple, calcul erroné ou dépassement de bornes).
         AdjustmentError,
+        /// Le module n'a pas encore été initialisé.
+        NotInitialized,
+        /// Le module est déjà initialisé ; utiliser la réinitialisation explicite.
+        AlreadyInitialized,
+        /// Le facteur de sous-échantillonnage doit être non nul.
+        InvalidCompactionParameters,
+        /// En mode oracle, la volatilité ne peut être soumise que via `submit_volatility_reading`.
+        OracleModeActive,
+        /// Le mode oracle n'est pas activé.
+        OracleModeInactive,
+        /// L'appelant n'est pas un oracle enregistré.
+        NotOracle,
+        /// La mesure ne concerne pas le tour en cours.
+        WrongRound,
+        /// L'oracle a déjà soumis une mesure pour ce tour.
+        AlreadySubmitted,
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Initialise l'état du module avec la valeur de base et la configuration par défaut.
         /// Cette extrinsèque est réservée à une origine Root.
+        ///
+        /// `initial_ema` amorce la moyenne mobile de volatilité. Le premier ajustement vaut
+        /// `smoothing_factor * (volatilité - initial_ema) / 100 / dampening_factor` : avec une EMA
+        /// nulle, la première mesure produit donc un saut proportionnel à toute la volatilité observée,
+        /// alors qu'une EMA proche de la volatilité habituelle du réseau limite ce premier ajustement.
         #[pallet::weight(10_000)]
-        pub fn initialize_stability(origin: OriginFor<T>) -> DispatchResult {
+        pub fn initialize_stability(origin: OriginFor<T>, initial_ema: u32) -> DispatchResult {
             ensure_root(origin)?;
-            let now = <timestamp::Pallet<T>>::get();
-            let baseline = T::BaselineParameter::get();
-            let state = StabilityState {
-                current_parameter: baseline,
-                volatility_ema: 0,
-                history: Vec::new(),
-            };
-            <StabilityStorage<T>>::put(state);
-            // Initialisation de la configuration DAO à partir des constantes.
-            let config = StabilityConfig {
-                smoothing_factor: T::SmoothingFactor::get(),
-                dampening_factor: T::DampeningFactor::get(),
-                min_parameter: T::MinStabilityParameter::get(),
-                max_parameter: T::MaxStabilityParameter::get(),
-            };
-            <StabilityConfigStorage<T>>::put(config);
-            Self::deposit_event(Event::StabilityAdjusted(baseline, baseline, 0, 0));
-            Ok(())
+            ensure!(!Initialized::<T>::get(), Error::<T>::AlreadyInitialized);
+            Self::do_initialize_stability(initial_ema)
+        }
+
+        /// Réinitialise délibérément l'état et la configuration du module (voir `initialize_stability`).
+        /// Réservé à l'origine DAO ; `initialize_stability` refuse d'écraser un état existant.
+        #[pallet::weight(10_000)]
+        pub fn reset_stability(origin: OriginFor<T>, initial_ema: u32) -> DispatchResult {
+            T::DaoOrigin::ensure_origin(origin)?;
+            Self::do_initialize_stability(initial_ema)
         }
 
         /// Met à jour la volatilité observée et ajuste le paramètre de stabilité.
@@ -134,6 +214,134 @@ pub mod pallet {
         #[pallet::weight(10_000)]
         pub fn update_volatility(origin: OriginFor<T>, volatility: u32) -> DispatchResult {
             let _ = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::ensure_initialized()?;
+            ensure!(!OracleMode::<T>::get(), Error::<T>::OracleModeActive);
+            Self::apply_volatility(volatility)
+        }
+
+        /// Active ou désactive le mode oracle. Réservé à l'origine DAO.
+        #[pallet::weight(10_000)]
+        pub fn set_oracle_mode(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            T::DaoOrigin::ensure_origin(origin)?;
+            OracleMode::<T>::put(enabled);
+            Self::deposit_event(Event::OracleModeChanged(enabled));
+            Ok(())
+        }
+
+        /// Ajoute un oracle autorisé. Réservé à l'origine DAO.
+        #[pallet::weight(10_000)]
+        pub fn register_oracle(origin: OriginFor<T>, oracle: T::AccountId) -> DispatchResult {
+            T::DaoOrigin::ensure_origin(origin)?;
+            Oracles::<T>::insert(&oracle, ());
+            Self::deposit_event(Event::OracleRegistered(oracle));
+            Ok(())
+        }
+
+        /// Retire un oracle autorisé. Réservé à l'origine DAO.
+        #[pallet::weight(10_000)]
+        pub fn remove_oracle(origin: OriginFor<T>, oracle: T::AccountId) -> DispatchResult {
+            T::DaoOrigin::ensure_origin(origin)?;
+            Oracles::<T>::remove(&oracle);
+            Self::deposit_event(Event::OracleRemoved(oracle));
+            Ok(())
+        }
+
+        /// Soumet la mesure de volatilité d'un oracle enregistré pour le tour `round`.
+        ///
+        /// Dès que `MinOracleQuorum` oracles distincts ont soumis une mesure, le tour est clos : la
+        /// médiane des mesures ajuste le paramètre de stabilité comme `update_volatility`, ce qui
+        /// neutralise une valeur aberrante isolée.
+        #[pallet::weight(10_000)]
+        pub fn submit_volatility_reading(origin: OriginFor<T>, round: u32, volatility: u32) -> DispatchResult {
+            let oracle = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::ensure_initialized()?;
+            ensure!(OracleMode::<T>::get(), Error::<T>::OracleModeInactive);
+            ensure!(Oracles::<T>::contains_key(&oracle), Error::<T>::NotOracle);
+            ensure!(round == CurrentRound::<T>::get(), Error::<T>::WrongRound);
+            let submissions = RoundSubmissions::<T>::try_mutate(round, |submissions| -> Result<Vec<u32>, DispatchError> {
+                ensure!(!submissions.iter().any(|(who, _)| *who == oracle), Error::<T>::AlreadySubmitted);
+                submissions.push((oracle.clone(), volatility));
+                Ok(submissions.iter().map(|(_, reading)| *reading).collect())
+            })?;
+            Self::deposit_event(Event::VolatilityReadingSubmitted(round, oracle, volatility));
+
+            if submissions.len() as u32 >= T::MinOracleQuorum::get().max(1) {
+                let count = submissions.len() as u32;
+                let median = Self::median(submissions);
+                Self::apply_volatility(median)?;
+                CurrentRound::<T>::put(round.saturating_add(1));
+                if let Some(expired) = round.checked_sub(T::OracleRoundsRetained::get()) {
+                    RoundSubmissions::<T>::remove(expired);
+                }
+                Self::deposit_event(Event::OracleRoundFinalized(round, median, count));
+            }
+            Ok(())
+        }
+
+        /// Permet à une origine DAO de mettre à jour la configuration du module.
+        ///
+        /// Les paramètres mis à jour sont le facteur de lissage, le facteur de dampening,
+        /// la borne minimale et la borne maximale pour le paramètre de stabilité.
+        #[pallet::weight(10_000)]
+        pub fn update_configuration(
+            origin: OriginFor<T>,
+            new_smoothing: u32,
+            new_dampening: u32,
+            new_min: u32,
+            new_max: u32,
+        ) -> DispatchResult {
+            T::DaoOrigin::ensure_origin(origin)?;
+            Self::ensure_initialized()?;
+            let config = StabilityConfig {
+                smoothing_factor: new_smoothing,
+                dampening_factor: new_dampening,
+                min_parameter: new_min,
+                max_parameter: new_max,
+            };
+            <StabilityConfigStorage<T>>::put(config.clone());
+            Self::deposit_event(Event::ConfigurationUpdated(new_smoothing, new_dampening, new_min, new_max));
+            Ok(())
+        }
+
+        /// Compacte l'historique au lieu de le tronquer.
+        ///
+        /// Les `keep_recent` entrées les plus récentes sont conservées telles quelles ; parmi les
+        /// entrées plus anciennes, seule une sur `downsample_factor` est gardée (la plus ancienne de
+        /// chaque groupe), ce qui borne la taille de l'historique tout en préservant la tendance longue.
+        #[pallet::weight(10_000)]
+        pub fn compact_history(origin: OriginFor<T>, keep_recent: u32, downsample_factor: u32) -> DispatchResult {
+            T::DaoOrigin::ensure_origin(origin)?;
+            ensure!(downsample_factor > 0, Error::<T>::InvalidCompactionParameters);
+            let (before, after) = <StabilityStorage<T>>::mutate(|state| {
+                let before = state.history.len() as u32;
+                Self::downsample_history(&mut state.history, keep_recent as usize, downsample_factor as usize);
+                (before, state.history.len() as u32)
+            });
+            Self::deposit_event(Event::HistoryCompacted(before, after));
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Médiane des mesures ; pour un nombre pair de mesures, moyenne (arrondie par défaut) des
+        /// deux valeurs centrales. Retourne 0 pour une liste vide.
+        pub fn median(mut readings: Vec<u32>) -> u32 {
+            if readings.is_empty() {
+                return 0;
+            }
+            readings.sort_unstable();
+            let mid = readings.len() / 2;
+            if readings.len() % 2 == 1 {
+                readings[mid]
+            } else {
+                ((readings[mid - 1] as u64 + readings[mid] as u64) / 2) as u32
+            }
+        }
+
+        /// Intègre une mesure de volatilité dans l'EMA et ajuste le paramètre de stabilité.
+        fn apply_volatility(volatility: u32) -> DispatchResult {
             // Récupérer l'état et la configuration courants.
             let mut state = <StabilityStorage<T>>::get();
             let config = <StabilityConfigStorage<T>>::get();
@@ -168,42 +376,73 @@ pub mod pallet {
             };
 
             // Mise à jour de l'état.
+            let old_parameter = state.current_parameter;
             state.current_parameter = new_parameter;
             state.volatility_ema = new_ema;
             state.history.push(record);
 
             <StabilityStorage<T>>::put(state);
-            Self::deposit_event(Event::StabilityAdjusted(state.current_parameter, new_parameter, volatility, new_ema));
+            Self::deposit_event(Event::StabilityAdjusted(old_parameter, new_parameter, volatility, new_ema));
+            if let Some(bound) = Self::bound_reached(&config, old_parameter, new_parameter) {
+                Self::deposit_event(Event::StabilityBoundHit(bound, new_parameter));
+            }
             Ok(())
         }
 
-        /// Permet à une origine DAO de mettre à jour la configuration du module.
-        ///
-        /// Les paramètres mis à jour sont le facteur de lissage, le facteur de dampening,
-        /// la borne minimale et la borne maximale pour le paramètre de stabilité.
-        #[pallet::weight(10_000)]
-        pub fn update_configuration(
-            origin: OriginFor<T>,
-            new_smoothing: u32,
-            new_dampening: u32,
-            new_min: u32,
-            new_max: u32,
-        ) -> DispatchResult {
-            T::DaoOrigin::ensure_origin(origin)?;
+        /// Borne atteinte par `new_parameter` alors que `old_parameter` n'y était pas.
+        fn bound_reached(config: &StabilityConfig, old_parameter: u32, new_parameter: u32) -> Option<StabilityBound> {
+            if old_parameter == new_parameter {
+                None
+            } else if new_parameter == config.max_parameter {
+                Some(StabilityBound::Max)
+            } else if new_parameter == config.min_parameter {
+                Some(StabilityBound::Min)
+            } else {
+                None
+            }
+        }
+
+        /// Échoue si `initialize_stability` n'a pas encore été exécutée.
+        fn ensure_initialized() -> DispatchResult {
+            ensure!(Initialized::<T>::get(), Error::<T>::NotInitialized);
+            Ok(())
+        }
+
+        /// Met en place l'état et la configuration initiaux, en écrasant l'état courant.
+        fn do_initialize_stability(initial_ema: u32) -> DispatchResult {
+            let now = <timestamp::Pallet<T>>::get();
+            let baseline = T::BaselineParameter::get();
+            let state = StabilityState {
+                current_parameter: baseline,
+                volatility_ema: initial_ema,
+                history: Vec::new(),
+            };
+            <StabilityStorage<T>>::put(state);
+            // Initialisation de la configuration DAO à partir des constantes.
             let config = StabilityConfig {
-                smoothing_factor: new_smoothing,
-                dampening_factor: new_dampening,
-                min_parameter: new_min,
-                max_parameter: new_max,
+                smoothing_factor: T::SmoothingFactor::get(),
+                dampening_factor: T::DampeningFactor::get(),
+                min_parameter: T::MinStabilityParameter::get(),
+                max_parameter: T::MaxStabilityParameter::get(),
             };
-            <StabilityConfigStorage<T>>::put(config.clone());
-            Self::deposit_event(Event::ConfigurationUpdated(new_smoothing, new_dampening, new_min, new_max));
+            <StabilityConfigStorage<T>>::put(config);
+            Initialized::<T>::put(true);
+            Self::deposit_event(Event::StabilityAdjusted(baseline, baseline, 0, initial_ema));
             Ok(())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        // Des fonctions utilitaires supplémentaires peuvent être ajoutées ici si besoin.
+        /// Sous-échantillonne `history` : conserve les `keep_recent` dernières entrées et une entrée
+        /// sur `factor` parmi les précédentes. `factor` doit être non nul.
+        fn downsample_history<E>(history: &mut Vec<E>, keep_recent: usize, factor: usize) {
+            let recent = history.split_off(history.len().saturating_sub(keep_recent));
+            let mut index = 0usize;
+            history.retain(|_| {
+                let keep = index % factor == 0;
+                index += 1;
+                keep
+            });
+            history.extend(recent);
+        }
     }
 
     #[cfg(feature = "std")]
@@ -216,7 +455,7 @@ pub mod pallet {
     #[cfg(test)]
     mod tests {
         use super::*;
-        use frame_support::{assert_ok, parameter_types};
+        use frame_support::{assert_err, assert_ok, parameter_types};
         use sp_core::H256;
         use sp_runtime::{
             traits::{BlakeTwo256, IdentityLookup},
@@ -234,7 +473,7 @@ pub mod pallet {
                 UncheckedExtrinsic = UncheckedExtrinsic,
             {
                 System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-                StabilityGuardModule: Pallet,
+                StabilityGuardModule: pallet::{Pallet, Call, Storage, Event<T>},
                 Timestamp: timestamp::Pallet,
             }
         );
@@ -247,6 +486,8 @@ pub mod pallet {
             pub const MaxStabilityParameter: u32 = 200;
             pub const MinStabilityParameter: u32 = 50;
             pub const MinimumPeriod: u64 = 1;
+            pub const MinOracleQuorum: u32 = 3;
+            pub const OracleRoundsRetained: u32 = 2;
         }
 
         impl system::Config for Test {
@@ -263,7 +504,7 @@ pub mod pallet {
             type AccountId = u64;
             type Lookup = IdentityLookup<Self::AccountId>;
             type Header = Header;
-            type RuntimeEvent = ();
+            type RuntimeEvent = RuntimeEvent;
             type BlockHashCount = BlockHashCount;
             type Version = ();
             type PalletInfo = ();
@@ -284,18 +525,21 @@ pub mod pallet {
         }
 
         impl Config for Test {
-            type RuntimeEvent = ();
+            type RuntimeEvent = RuntimeEvent;
             type BaselineParameter = BaselineParameter;
             type SmoothingFactor = SmoothingFactor;
             type DampeningFactor = DampeningFactor;
             type MaxStabilityParameter = MaxStabilityParameter;
             type MinStabilityParameter = MinStabilityParameter;
             type DaoOrigin = frame_system::EnsureRoot<u64>;
+            type MinOracleQuorum = MinOracleQuorum;
+            type OracleRoundsRetained = OracleRoundsRetained;
+            type EmergencyHalt = ();
         }
 
         #[test]
         fn initialize_stability_works() {
-            assert_ok!(StabilityGuardModule::initialize_stability(system::RawOrigin::Root.into()));
+            assert_ok!(StabilityGuardModule::initialize_stability(system::RawOrigin::Root.into(), 0));
             let state = StabilityGuardModule::stability_state();
             assert_eq!(state.current_parameter, BaselineParameter::get());
             assert_eq!(state.volatility_ema, 0);
@@ -304,7 +548,7 @@ pub mod pallet {
 
         #[test]
         fn update_volatility_adjusts_parameter() {
-            assert_ok!(StabilityGuardModule::initialize_stability(system::RawOrigin::Root.into()));
+            assert_ok!(StabilityGuardModule::initialize_stability(system::RawOrigin::Root.into(), 0));
             // Première mise à jour avec volatilité = 80.
             assert_ok!(StabilityGuardModule::update_volatility(system::RawOrigin::Signed(1).into(), 80));
             let state = StabilityGuardModule::stability_state();
@@ -323,9 +567,30 @@ pub mod pallet {
             assert_eq!(state.history.len(), 2);
         }
 
+        pub fn new_test_ext() -> sp_io::TestExternalities {
+            let storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+            storage.into()
+        }
+
+        #[test]
+        fn seeded_ema_dampens_first_adjustment() {
+            let first_delta = |initial_ema: u32| {
+                new_test_ext().execute_with(|| {
+                    assert_ok!(StabilityGuardModule::initialize_stability(system::RawOrigin::Root.into(), initial_ema));
+                    assert_eq!(StabilityGuardModule::stability_state().volatility_ema, initial_ema);
+                    assert_ok!(StabilityGuardModule::update_volatility(system::RawOrigin::Signed(1).into(), 90));
+                    StabilityGuardModule::stability_state().current_parameter as i32 - BaselineParameter::get() as i32
+                })
+            };
+            // EMA nulle : (30*90)/100 = 27, delta = 27/2 = 13.
+            assert_eq!(first_delta(0), 13);
+            // EMA amorcée à 80 : (30*90 + 70*80)/100 = 83, delta = (83-80)/2 = 1.
+            assert_eq!(first_delta(80), 1);
+        }
+
         #[test]
         fn update_configuration_works() {
-            assert_ok!(StabilityGuardModule::initialize_stability(system::RawOrigin::Root.into()));
+            assert_ok!(StabilityGuardModule::initialize_stability(system::RawOrigin::Root.into(), 0));
             // Mise à jour de la configuration DAO.
             assert_ok!(StabilityGuardModule::update_configuration(
                 system::RawOrigin::Root.into(),
@@ -340,6 +605,161 @@ pub mod pallet {
             assert_eq!(config.min_parameter, 60);
             assert_eq!(config.max_parameter, 180);
         }
+
+        #[test]
+        fn operations_require_initialization() {
+            new_test_ext().execute_with(|| {
+                assert_err!(
+                    StabilityGuardModule::update_volatility(system::RawOrigin::Signed(1).into(), 80),
+                    Error::<Test>::NotInitialized
+                );
+                assert_err!(
+                    StabilityGuardModule::update_configuration(system::RawOrigin::Root.into(), 40, 3, 60, 180),
+                    Error::<Test>::NotInitialized
+                );
+                assert_ok!(StabilityGuardModule::initialize_stability(system::RawOrigin::Root.into(), 0));
+                assert_ok!(StabilityGuardModule::update_volatility(system::RawOrigin::Signed(1).into(), 80));
+                assert_ok!(StabilityGuardModule::update_configuration(system::RawOrigin::Root.into(), 40, 3, 60, 180));
+            });
+        }
+
+        #[test]
+        fn second_initialize_is_rejected() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(StabilityGuardModule::initialize_stability(system::RawOrigin::Root.into(), 0));
+                assert_ok!(StabilityGuardModule::update_volatility(system::RawOrigin::Signed(1).into(), 80));
+                let adjusted = StabilityGuardModule::stability_state();
+                assert_err!(
+                    StabilityGuardModule::initialize_stability(system::RawOrigin::Root.into(), 0),
+                    Error::<Test>::AlreadyInitialized
+                );
+                assert_eq!(StabilityGuardModule::stability_state(), adjusted);
+
+                assert_ok!(StabilityGuardModule::reset_stability(system::RawOrigin::Root.into(), 0));
+                let state = StabilityGuardModule::stability_state();
+                assert_eq!(state.current_parameter, BaselineParameter::get());
+                assert!(state.history.is_empty());
+            });
+        }
+
+        #[test]
+        fn compact_history_keeps_recent_entries_verbatim() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(StabilityGuardModule::initialize_stability(system::RawOrigin::Root.into(), 0));
+                for volatility in 1..=10u32 {
+                    assert_ok!(StabilityGuardModule::update_volatility(system::RawOrigin::Signed(1).into(), volatility * 10));
+                }
+                let history = StabilityGuardModule::stability_state().history;
+                assert_eq!(history.len(), 10);
+
+                assert_ok!(StabilityGuardModule::compact_history(system::RawOrigin::Root.into(), 4, 3));
+
+                let compacted = StabilityGuardModule::stability_state().history;
+                // 6 entrées anciennes -> 2 (volatilités 10 et 40), puis les 4 récentes.
+                assert_eq!(compacted.len(), 6);
+                assert_eq!(compacted[0], history[0]);
+                assert_eq!(compacted[1], history[3]);
+                assert_eq!(&compacted[2..], &history[6..]);
+                // Un historique plus court que la fenêtre n'est pas modifié.
+                assert_ok!(StabilityGuardModule::compact_history(system::RawOrigin::Root.into(), 10, 3));
+                assert_eq!(StabilityGuardModule::stability_state().history, compacted);
+            });
+        }
+
+        #[test]
+        fn oracle_median_drives_adjustment() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(StabilityGuardModule::initialize_stability(system::RawOrigin::Root.into(), 0));
+                for oracle in 1..=4u64 {
+                    assert_ok!(StabilityGuardModule::register_oracle(system::RawOrigin::Root.into(), oracle));
+                }
+                assert_err!(
+                    StabilityGuardModule::submit_volatility_reading(system::RawOrigin::Signed(1).into(), 0, 80),
+                    Error::<Test>::OracleModeInactive
+                );
+                assert_ok!(StabilityGuardModule::set_oracle_mode(system::RawOrigin::Root.into(), true));
+                assert_err!(
+                    StabilityGuardModule::update_volatility(system::RawOrigin::Signed(1).into(), 80),
+                    Error::<Test>::OracleModeActive
+                );
+                assert_err!(
+                    StabilityGuardModule::submit_volatility_reading(system::RawOrigin::Signed(9).into(), 0, 80),
+                    Error::<Test>::NotOracle
+                );
+
+                let submit = |oracle: u64, volatility: u32| {
+                    StabilityGuardModule::submit_volatility_reading(system::RawOrigin::Signed(oracle).into(), 0, volatility)
+                };
+                assert_ok!(submit(1, 80));
+                assert_ok!(submit(2, 1_000)); // valeur aberrante
+                assert_err!(submit(1, 85), Error::<Test>::AlreadySubmitted);
+                // Quorum non atteint : aucun ajustement.
+                assert_eq!(StabilityGuardModule::stability_state().current_parameter, BaselineParameter::get());
+
+                assert_ok!(submit(3, 90));
+                // Médiane de [80, 90, 1000] = 90 : EMA = 30*90/100 = 27, delta = 27/2 = 13.
+                // (La moyenne, 390, aurait donné EMA = 117 et un paramètre de 158.)
+                let state = StabilityGuardModule::stability_state();
+                assert_eq!(state.volatility_ema, 27);
+                assert_eq!(state.current_parameter, 113);
+                assert_eq!(state.history.last().map(|record| record.volatility), Some(90));
+                assert_eq!(StabilityGuardModule::current_round(), 1);
+                assert_eq!(StabilityGuardModule::round_submissions(0), vec![(1, 80), (2, 1_000), (3, 90)]);
+                assert_err!(submit(4, 70), Error::<Test>::WrongRound);
+            });
+        }
+
+        #[test]
+        fn bound_hit_is_signalled_once_per_saturation() {
+            new_test_ext().execute_with(|| {
+                System::set_block_number(1);
+                assert_ok!(StabilityGuardModule::initialize_stability(system::RawOrigin::Root.into(), 0));
+                let update = |volatility| StabilityGuardModule::update_volatility(system::RawOrigin::Signed(1).into(), volatility);
+                let bound_hits = || -> Vec<(StabilityBound, u32)> {
+                    System::events()
+                        .into_iter()
+                        .filter_map(|record| match record.event {
+                            RuntimeEvent::StabilityGuardModule(Event::StabilityBoundHit(bound, value)) => Some((bound, value)),
+                            _ => None,
+                        })
+                        .collect()
+                };
+
+                // EMA 0 -> 300 : 100 + 150 plafonné à 200.
+                assert_ok!(update(1_000));
+                assert_eq!(StabilityGuardModule::stability_state().current_parameter, MaxStabilityParameter::get());
+                assert_eq!(bound_hits(), vec![(StabilityBound::Max, 200)]);
+                // Toujours plafonné : pas de nouvel événement.
+                assert_ok!(update(1_000));
+                assert_eq!(StabilityGuardModule::stability_state().current_parameter, MaxStabilityParameter::get());
+                assert_eq!(bound_hits().len(), 1);
+
+                // Retour sous la borne, puis nouvelle saturation.
+                assert_ok!(update(0));
+                assert!(StabilityGuardModule::stability_state().current_parameter < MaxStabilityParameter::get());
+                assert_ok!(update(5_000));
+                assert_eq!(bound_hits(), vec![(StabilityBound::Max, 200), (StabilityBound::Max, 200)]);
+            });
+        }
+
+        #[test]
+        fn median_of_even_count_averages_middle_readings() {
+            assert_eq!(StabilityGuardModule::median(vec![40, 10, 30, 20]), 25);
+            assert_eq!(StabilityGuardModule::median(vec![u32::MAX, u32::MAX]), u32::MAX);
+            assert_eq!(StabilityGuardModule::median(Vec::new()), 0);
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn stability_state_round_trips_through_json() {
+            let state = StabilityState {
+                current_parameter: 120,
+                volatility_ema: 15,
+                history: vec![StabilityRecord { timestamp: 1_640_000_000, old_parameter: 100, new_parameter: 120, volatility: 20, new_ema: 15 }],
+            };
+            let json = serde_json::to_string(&state).expect("sérialisation JSON");
+            let decoded: StabilityState = serde_json::from_str(&json).expect("désérialisation JSON");
+            assert_eq!(decoded, state);
+        }
     }
 }
-
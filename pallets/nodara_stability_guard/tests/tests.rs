@@ -38,6 +38,7 @@ mod tests {
         type RuntimeEvent = ();
         type BaselineStability = sp_runtime::traits::ConstU32<100>;
         type StabilitySmoothingFactor = sp_runtime::traits::ConstU32<10>;
+        type EmergencyHalt = ();
     }
 
     // Create a new test externalities environment.
@@ -19,11 +19,36 @@ pub mod pallet {
         traits::{Get, UnixTime},
     };
     use frame_system::pallet_prelude::*;
+    use nodara_emergency::HaltGuard;
     use parity_scale_codec::{Encode, Decode};
     use scale_info::TypeInfo;
     use sp_std::vec::Vec;
     use sp_runtime::RuntimeDebug;
 
+    /// Algorithme de hachage appliqué aux paramètres d'un standard lors de la vérification de
+    /// conformité.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum HashAlgo {
+        /// Blake2 sur 128 bits.
+        #[default]
+        Blake2_128,
+        /// Blake2 sur 256 bits.
+        Blake2_256,
+        /// Keccak sur 256 bits.
+        Keccak256,
+    }
+
+    impl HashAlgo {
+        /// Calcule le hash de `data` avec l'algorithme sélectionné.
+        pub fn hash(&self, data: &[u8]) -> Vec<u8> {
+            match self {
+                HashAlgo::Blake2_128 => sp_io::hashing::blake2_128(data).to_vec(),
+                HashAlgo::Blake2_256 => sp_io::hashing::blake2_256(data).to_vec(),
+                HashAlgo::Keccak256 => sp_io::hashing::keccak_256(data).to_vec(),
+            }
+        }
+    }
+
     /// Structure représentant la définition d'un standard.
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     pub struct Standard {
@@ -33,6 +58,8 @@ pub mod pallet {
         pub description: Vec<u8>,
         /// Règles ou paramètres associés au standard (format JSON recommandé).
         pub parameters: Vec<u8>,
+        /// Algorithme de hachage des paramètres utilisé par `verify_compliance`.
+        pub hash_algo: HashAlgo,
     }
 
     /// Structure représentant une entrée dans l'historique de vérification de conformité.
@@ -55,6 +82,8 @@ pub mod pallet {
         type MaxStandardLength: Get<u32>;
         /// Fournisseur de temps pour obtenir un timestamp réel.
         type TimeProvider: UnixTime;
+        /// Arrêt d'urgence global consulté par chaque appel signé ; `()` le désactive.
+        type EmergencyHalt: nodara_emergency::HaltGuard;
     }
 
     /// Stockage des standards définis.
@@ -97,6 +126,7 @@ pub mod pallet {
     impl<T: Config> Pallet<T> {
         /// Définit un nouveau standard.
         ///
+        /// `hash_algo` sélectionne l'algorithme de hachage des paramètres (Blake2-128 par défaut).
         /// Seul Root peut appeler cette fonction.
         #[pallet::weight(10_000)]
         pub fn define_standard(
@@ -104,6 +134,7 @@ pub mod pallet {
             id: Vec<u8>,
             description: Vec<u8>,
             parameters: Vec<u8>,
+            hash_algo: Option<HashAlgo>,
         ) -> DispatchResult {
             ensure_root(origin)?;
             ensure!(
@@ -111,7 +142,12 @@ pub mod pallet {
                 Error::<T>::StandardTooLong
             );
             ensure!(!Standards::<T>::contains_key(&id), Error::<T>::StandardAlreadyExists);
-            let standard = Standard { id: id.clone(), description, parameters };
+            let standard = Standard {
+                id: id.clone(),
+                description,
+                parameters,
+                hash_algo: hash_algo.unwrap_or_default(),
+            };
             Standards::<T>::insert(&id, standard);
             Self::deposit_event(Event::StandardDefined(id));
             Ok(())
@@ -142,7 +178,7 @@ pub mod pallet {
 
         /// Vérifie la conformité d'une opération par rapport à un standard défini.
         ///
-        /// La vérification avancée calcule le hash Blake2-128 des paramètres du standard et le recherche dans les données de l'opération.
+        /// La vérification avancée calcule le hash des paramètres du standard, avec son `hash_algo`, et le recherche dans les données de l'opération.
         #[pallet::weight(10_000)]
         pub fn verify_compliance(
             origin: OriginFor<T>,
@@ -150,22 +186,31 @@ pub mod pallet {
             operation_data: Vec<u8>,
         ) -> DispatchResult {
             let _ = ensure_signed(origin)?;
-            let standard = Standards::<T>::get(&standard_id).ok_or(Error::<T>::StandardNotFound)?;
-            let standard_hash = sp_io::hashing::blake2_128(&standard.parameters);
+            T::EmergencyHalt::ensure_running()?;
+            Self::check_compliance(&standard_id, operation_data)
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Vérifie `operation_data` par rapport au standard `standard_id`, journalise le résultat
+        /// dans `ComplianceHistory` et échoue avec `ComplianceCheckFailed` si l'opération n'est pas
+        /// conforme. Utilisée par `verify_compliance` et par les autres modules (voir
+        /// `pallet_bridge::AssetCompliance`).
+        pub fn check_compliance(standard_id: &[u8], operation_data: Vec<u8>) -> DispatchResult {
+            let standard = Standards::<T>::get(standard_id).ok_or(Error::<T>::StandardNotFound)?;
+            let standard_hash = standard.hash_algo.hash(&standard.parameters);
             let outcome = operation_data.windows(standard_hash.len())
-                .any(|window| window == standard_hash);
+                .any(|window| window == &standard_hash[..]);
             let log = ComplianceLog {
                 timestamp: T::TimeProvider::now().as_secs(),
                 operation_details: operation_data,
                 outcome,
             };
             ComplianceHistory::<T>::mutate(|history| history.push(log));
-            Self::deposit_event(Event::ComplianceChecked(standard_id.clone(), outcome));
+            Self::deposit_event(Event::ComplianceChecked(standard_id.to_vec(), outcome));
             if outcome { Ok(()) } else { Err(Error::<T>::ComplianceCheckFailed.into()) }
         }
-    }
 
-    impl<T: Config> Pallet<T> {
         /// Fonction de rotation de l'historique pour limiter la taille du journal.
         pub fn rotate_history(max_entries: usize) {
             ComplianceHistory::<T>::mutate(|history| {
@@ -176,3 +221,138 @@ pub mod pallet {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use frame_support::{assert_err, assert_ok, parameter_types, traits::UnixTime};
+    use sp_core::H256;
+    use sp_runtime::{
+        traits::{BlakeTwo256, IdentityLookup},
+        testing::Header,
+    };
+    use frame_system as system;
+
+    type UncheckedExtrinsic = system::mocking::MockUncheckedExtrinsic<Test>;
+    type Block = system::mocking::MockBlock<Test>;
+
+    frame_support::construct_runtime!(
+        pub enum Test where
+            Block = Block,
+            NodeBlock = Block,
+            UncheckedExtrinsic = UncheckedExtrinsic,
+        {
+            System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+            StandardsModule: pallet::{Pallet, Call, Storage, Event<T>},
+        }
+    );
+
+    parameter_types! {
+        pub const BlockHashCount: u64 = 250;
+        pub const MaxStandardLength: u32 = 256;
+    }
+
+    impl system::Config for Test {
+        type BaseCallFilter = frame_support::traits::Everything;
+        type BlockWeights = ();
+        type BlockLength = ();
+        type DbWeight = ();
+        type RuntimeOrigin = system::mocking::Origin;
+        type RuntimeCall = Call;
+        type Index = u64;
+        type BlockNumber = u64;
+        type Hash = H256;
+        type Hashing = BlakeTwo256;
+        type AccountId = u64;
+        type Lookup = IdentityLookup<Self::AccountId>;
+        type Header = Header;
+        type RuntimeEvent = ();
+        type BlockHashCount = BlockHashCount;
+        type Version = ();
+        type PalletInfo = ();
+        type AccountData = ();
+        type OnNewAccount = ();
+        type OnKilledAccount = ();
+        type SystemWeightInfo = ();
+        type SS58Prefix = ();
+        type OnSetCode = ();
+        type MaxConsumers = ();
+    }
+
+    pub struct MockTime;
+    impl UnixTime for MockTime {
+        fn now() -> core::time::Duration {
+            core::time::Duration::from_secs(1_000)
+        }
+    }
+
+    impl pallet::Config for Test {
+        type RuntimeEvent = ();
+        type MaxStandardLength = MaxStandardLength;
+        type TimeProvider = MockTime;
+        type EmergencyHalt = ();
+    }
+
+    pub fn new_test_ext() -> sp_io::TestExternalities {
+        let storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+        storage.into()
+    }
+
+    fn operation_with(hash: &[u8]) -> Vec<u8> {
+        let mut data = b"operation:".to_vec();
+        data.extend_from_slice(hash);
+        data
+    }
+
+    #[test]
+    fn standard_defaults_to_blake2_128() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(StandardsModule::define_standard(
+                system::RawOrigin::Root.into(),
+                b"STD".to_vec(),
+                b"desc".to_vec(),
+                b"params".to_vec(),
+                None
+            ));
+            assert_eq!(StandardsModule::standards(b"STD".to_vec()).map(|s| s.hash_algo), Some(HashAlgo::Blake2_128));
+            let data = operation_with(&sp_io::hashing::blake2_128(b"params"));
+            assert_ok!(StandardsModule::verify_compliance(system::RawOrigin::Signed(1).into(), b"STD".to_vec(), data));
+        });
+    }
+
+    #[test]
+    fn compliance_matches_the_hash_of_the_selected_algorithm() {
+        new_test_ext().execute_with(|| {
+            let cases = [
+                (b"B128".to_vec(), HashAlgo::Blake2_128, sp_io::hashing::blake2_128(b"params").to_vec()),
+                (b"B256".to_vec(), HashAlgo::Blake2_256, sp_io::hashing::blake2_256(b"params").to_vec()),
+                (b"K256".to_vec(), HashAlgo::Keccak256, sp_io::hashing::keccak_256(b"params").to_vec()),
+            ];
+            for (id, algo, _) in cases.iter() {
+                assert_ok!(StandardsModule::define_standard(
+                    system::RawOrigin::Root.into(),
+                    id.clone(),
+                    b"desc".to_vec(),
+                    b"params".to_vec(),
+                    Some(*algo)
+                ));
+            }
+            for (id, _, expected) in cases.iter() {
+                assert_ok!(StandardsModule::verify_compliance(
+                    system::RawOrigin::Signed(1).into(),
+                    id.clone(),
+                    operation_with(expected)
+                ));
+            }
+            // Le hash Blake2-128 ne satisfait pas un standard en Keccak-256.
+            assert_err!(
+                StandardsModule::verify_compliance(
+                    system::RawOrigin::Signed(1).into(),
+                    b"K256".to_vec(),
+                    operation_with(&cases[0].2)
+                ),
+                Error::<Test>::ComplianceCheckFailed
+            );
+        });
+    }
+}
@@ -37,6 +37,7 @@ mod tests {
     impl pallet::Config for TestConfig {
         type RuntimeEvent = ();
         type MaxStandardLength = sp_runtime::traits::ConstU32<256>;
+        type EmergencyHalt = ();
     }
 
     pub fn new_test_ext() -> sp_io::TestExternalities {
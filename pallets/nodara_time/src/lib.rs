@@ -0,0 +1,61 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! # Nodara Time
+//!
+//! Sources d'horodatage (en secondes Unix) communes aux modules Nodara, compatibles `no_std`.
+//!
+//! Chaque module utilisateur déclare dans sa configuration un `type TimeSource: nodara_time::TimeSource`,
+//! choisi par le runtime :
+//!
+//! - `TimestampSource<Runtime>` (feature `timestamp`) lit `pallet_timestamp` ;
+//! - `BlockTimeSource<Runtime, ExpectedBlockTime, GenesisTime>` estime l'heure à partir du numéro
+//!   de bloc, pour les runtimes minimaux sans `pallet_timestamp` ;
+//! - `()` retourne l'horodatage fixe historique, pour les tests.
+
+use core::marker::PhantomData;
+use frame_support::traits::Get;
+use sp_runtime::traits::SaturatedConversion;
+
+/// Source de l'heure courante, en secondes Unix.
+pub trait TimeSource {
+    /// Heure courante en secondes Unix.
+    fn now() -> u64;
+}
+
+/// Horodatage fixe, sans rapport avec l'heure réelle.
+impl TimeSource for () {
+    fn now() -> u64 {
+        1_640_000_000
+    }
+}
+
+/// Heure approximative dérivée du bloc courant :
+/// `GenesisTime + numéro de bloc * ExpectedBlockTime / 1000`.
+///
+/// `ExpectedBlockTime` est la durée cible d'un bloc en millisecondes, `GenesisTime` l'heure du
+/// bloc de genèse en secondes Unix. L'estimation dérive si la production de blocs s'écarte de la
+/// cible, mais reste croissante avec le numéro de bloc.
+pub struct BlockTimeSource<T, ExpectedBlockTime, GenesisTime>(PhantomData<(T, ExpectedBlockTime, GenesisTime)>);
+
+impl<T, ExpectedBlockTime, GenesisTime> TimeSource for BlockTimeSource<T, ExpectedBlockTime, GenesisTime>
+where
+    T: frame_system::Config,
+    ExpectedBlockTime: Get<u64>,
+    GenesisTime: Get<u64>,
+{
+    fn now() -> u64 {
+        let block = frame_system::Pallet::<T>::block_number().saturated_into::<u64>();
+        GenesisTime::get().saturating_add(block.saturating_mul(ExpectedBlockTime::get()) / 1_000)
+    }
+}
+
+/// Heure du bloc courant lue dans `pallet_timestamp` (millisecondes), convertie en secondes.
+#[cfg(feature = "timestamp")]
+pub struct TimestampSource<T>(PhantomData<T>);
+
+#[cfg(feature = "timestamp")]
+impl<T: pallet_timestamp::Config> TimeSource for TimestampSource<T> {
+    fn now() -> u64 {
+        pallet_timestamp::Pallet::<T>::get().saturated_into::<u64>() / 1_000
+    }
+}
@@ -12,19 +12,27 @@
 
 pub use pallet::*;
 
+/// Cible des messages `nodara_log!` de ce module.
+pub const LOG_TARGET: &str = "runtime::risk_management";
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::{
         dispatch::DispatchResult, pallet_prelude::*, traits::{Get, UnixTime},
     };
     use frame_system::pallet_prelude::*;
+    use nodara_emergency::HaltGuard;
+    use nodara_log::nodara_log;
     use sp_std::vec::Vec;
-    use sp_runtime::RuntimeDebug;
+    use sp_runtime::{traits::Saturating, RuntimeDebug};
     use parity_scale_codec::{Encode, Decode};
     use scale_info::TypeInfo;
+    #[cfg(feature = "serde")]
+    use serde::{Deserialize, Serialize};
 
     /// Structure représentant un événement de risque.
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
     pub struct RiskEvent {
         /// Horodatage de l'événement (en secondes Unix).
         pub timestamp: u64,
@@ -36,6 +44,7 @@ pub mod pallet {
 
     /// État global du module de gestion des risques.
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, Default, TypeInfo)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
     pub struct RiskState {
         /// Score de risque actuel (>= 0).
         pub current_risk: i32,
@@ -47,6 +56,29 @@ pub mod pallet {
         pub history: Vec<RiskEvent>,
     }
 
+    /// Catégorie d'un événement de risque, suivie avec son propre score et son propre seuil.
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub enum RiskCategory {
+        /// Congestion, partitions ou pannes de nœuds.
+        Network,
+        /// Volatilité, liquidité ou déséquilibres économiques.
+        Economic,
+        /// Attaques, comportements malveillants ou vulnérabilités.
+        Security,
+        /// Incidents d'exploitation (mises à jour, configuration).
+        Operational,
+    }
+
+    /// Fenêtre de limitation des alertes ouverte par un `RiskAlert`.
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct AlertWindow<BlockNumber> {
+        /// Bloc à partir duquel la fenêtre se referme.
+        pub until: BlockNumber,
+        /// Nombre d'alertes supprimées depuis l'ouverture de la fenêtre.
+        pub suppressed: u32,
+    }
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
         /// Type d'événement du runtime.
@@ -57,11 +89,20 @@ pub mod pallet {
         /// Seuil critique de risque (en u32, converti en i32).
         #[pallet::constant]
         type RiskThreshold: Get<u32>;
-        /// Facteur de lissage pour le calcul de l'EMA (doit être > 0).
+        /// Facteur de lissage initial pour le calcul de l'EMA (doit être >= 2), modifiable ensuite
+        /// via `update_smoothing_factor`.
+        ///
+        /// Chaque nouvel événement pèse `1 / smoothing` dans l'EMA et l'historique `(smoothing - 1) / smoothing` :
+        /// 0 provoquerait une division par zéro et 1 ferait ignorer l'historique.
         #[pallet::constant]
         type RiskSmoothingFactor: Get<u32>;
         /// Fournisseur de temps pour obtenir un timestamp réel.
         type TimeProvider: UnixTime;
+        /// Durée (en blocs) pendant laquelle les alertes répétées sont supprimées après un `RiskAlert`.
+        #[pallet::constant]
+        type AlertCooldown: Get<BlockNumberFor<Self>>;
+        /// Arrêt d'urgence global consulté par chaque appel signé ; `()` le désactive.
+        type EmergencyHalt: nodara_emergency::HaltGuard;
     }
 
     /// Stockage de l'état de gestion des risques.
@@ -69,6 +110,37 @@ pub mod pallet {
     #[pallet::getter(fn risk_state)]
     pub type RiskStateStorage<T: Config> = StorageValue<_, RiskState, ValueQuery>;
 
+    /// Indique si `initialize_risk` a été exécutée.
+    #[pallet::storage]
+    #[pallet::getter(fn is_initialized)]
+    pub type Initialized<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    #[pallet::type_value]
+    pub fn DefaultSmoothingFactor<T: Config>() -> u32 {
+        T::RiskSmoothingFactor::get()
+    }
+
+    /// Facteur de lissage courant de l'EMA, initialisé depuis `RiskSmoothingFactor`.
+    #[pallet::storage]
+    #[pallet::getter(fn smoothing_factor)]
+    pub type SmoothingFactor<T: Config> = StorageValue<_, u32, ValueQuery, DefaultSmoothingFactor<T>>;
+
+    /// Fenêtre de limitation des alertes en cours, le cas échéant.
+    #[pallet::storage]
+    #[pallet::getter(fn alert_window)]
+    pub type AlertThrottle<T: Config> = StorageValue<_, AlertWindow<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Score de risque par catégorie (>= 0), alimenté par `submit_categorized_risk_event`.
+    #[pallet::storage]
+    #[pallet::getter(fn category_risk)]
+    pub type CategoryRisk<T: Config> = StorageMap<_, Blake2_128Concat, RiskCategory, i32, ValueQuery>;
+
+    /// Seuil d'alerte propre à chaque catégorie, défini par Root. Une catégorie sans seuil
+    /// n'alerte pas indépendamment du score agrégé.
+    #[pallet::storage]
+    #[pallet::getter(fn category_threshold)]
+    pub type CategoryThresholds<T: Config> = StorageMap<_, Blake2_128Concat, RiskCategory, i32, OptionQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -79,6 +151,16 @@ pub mod pallet {
         RiskThresholdUpdated(i32, i32),
         /// Alerte déclenchée si le risque dépasse le seuil (compte, nouveau score de risque).
         RiskAlert(T::AccountId, i32),
+        /// Facteur de lissage de l'EMA mis à jour (ancien facteur, nouveau facteur).
+        SmoothingFactorUpdated(u32, u32),
+        /// Synthèse émise à la fin d'une fenêtre de limitation : nombre d'alertes supprimées
+        /// pendant que le risque restait au-dessus du seuil.
+        RiskStillElevated(u32),
+        /// Le score d'une catégorie vient de dépasser son seuil (catégorie, nouveau score),
+        /// indépendamment du score agrégé.
+        CategoryRiskAlert(RiskCategory, i32),
+        /// Seuil d'une catégorie défini ou retiré (catégorie, nouveau seuil).
+        CategoryThresholdUpdated(RiskCategory, Option<i32>),
     }
 
     #[pallet::error]
@@ -87,11 +169,32 @@ pub mod pallet {
         InvalidRiskFactor,
         /// Erreur dans la mise à jour du seuil de risque.
         InvalidThreshold,
+        /// Le facteur de lissage de l'EMA doit être supérieur ou égal à 2.
+        InvalidSmoothingFactor,
+        /// Le module n'a pas encore été initialisé.
+        NotInitialized,
+        /// Le module est déjà initialisé ; utiliser la réinitialisation explicite.
+        AlreadyInitialized,
     }
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Referme la fenêtre de limitation arrivée à échéance et émet sa synthèse.
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let db = T::DbWeight::get();
+            match AlertThrottle::<T>::get() {
+                Some(window) if n >= window.until => {
+                    Self::close_alert_window();
+                    db.reads_writes(1, 1)
+                }
+                _ => db.reads(1),
+            }
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Initialise l'état de risque avec le score de base et le seuil défini.
@@ -99,21 +202,16 @@ pub mod pallet {
         #[pallet::weight(10_000)]
         pub fn initialize_risk(origin: OriginFor<T>) -> DispatchResult {
             ensure_root(origin)?;
-            let now = T::TimeProvider::now().as_secs();
-            let baseline = T::BaselineRisk::get() as i32;
-            let threshold = T::RiskThreshold::get() as i32;
-            let state = RiskState {
-                current_risk: baseline,
-                risk_ema: baseline,
-                threshold,
-                history: vec![RiskEvent {
-                    timestamp: now,
-                    risk_factor: 0,
-                    description: b"Initialisation".to_vec(),
-                }],
-            };
-            RiskStateStorage::<T>::put(state);
-            Ok(())
+            ensure!(!Initialized::<T>::get(), Error::<T>::AlreadyInitialized);
+            Self::do_initialize_risk()
+        }
+
+        /// Réinitialise délibérément l'état de risque (score, EMA, seuil et historique).
+        /// Cette fonction est réservée à Root ; `initialize_risk` refuse d'écraser un état existant.
+        #[pallet::weight(10_000)]
+        pub fn reset_risk(origin: OriginFor<T>) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::do_initialize_risk()
         }
 
         /// Soumet un événement de risque.
@@ -130,11 +228,85 @@ pub mod pallet {
             description: Vec<u8>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::do_submit_risk_event(who, risk_factor, description)
+        }
+
+        /// Soumet un événement de risque rattaché à une catégorie.
+        ///
+        /// L'événement est comptabilisé comme avec `submit_risk_event` ; le facteur est en outre
+        /// ajouté au score de la catégorie (clamped à 0). Si ce score franchit le seuil de la
+        /// catégorie, `CategoryRiskAlert` est émis, même si le score agrégé reste sous le seuil global.
+        #[pallet::weight(10_000)]
+        pub fn submit_categorized_risk_event(
+            origin: OriginFor<T>,
+            category: RiskCategory,
+            risk_factor: i32,
+            description: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            T::EmergencyHalt::ensure_running()?;
+            Self::do_submit_risk_event(who, risk_factor, description)?;
+            Self::update_category_risk(category, risk_factor);
+            Ok(())
+        }
+
+        /// Définit (ou retire, avec `None`) le seuil d'alerte d'une catégorie.
+        /// Seul Root peut appeler cette fonction.
+        #[pallet::weight(10_000)]
+        pub fn set_category_threshold(
+            origin: OriginFor<T>,
+            category: RiskCategory,
+            threshold: Option<u32>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            let threshold = threshold.map(|threshold| threshold as i32);
+            CategoryThresholds::<T>::set(category, threshold);
+            Self::deposit_event(Event::CategoryThresholdUpdated(category, threshold));
+            Ok(())
+        }
+
+        /// Met à jour le seuil de risque.
+        /// Seul Root peut appeler cette fonction.
+        #[pallet::weight(10_000)]
+        pub fn update_threshold(origin: OriginFor<T>, new_threshold: u32) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::ensure_initialized()?;
+            let old_threshold = RiskStateStorage::<T>::get().threshold;
+            let new_threshold_i32 = new_threshold as i32;
+            RiskStateStorage::<T>::mutate(|state| {
+                state.threshold = new_threshold_i32;
+            });
+            Self::deposit_event(Event::RiskThresholdUpdated(old_threshold, new_threshold_i32));
+            Ok(())
+        }
+
+        /// Met à jour le facteur de lissage utilisé par les prochains calculs d'EMA.
+        /// Seul Root peut appeler cette fonction ; le facteur doit être >= 2.
+        #[pallet::weight(10_000)]
+        pub fn update_smoothing_factor(origin: OriginFor<T>, new_factor: u32) -> DispatchResult {
+            ensure_root(origin)?;
+            Self::ensure_initialized()?;
+            Self::ensure_valid_smoothing(new_factor)?;
+            let old_factor = SmoothingFactor::<T>::get();
+            SmoothingFactor::<T>::put(new_factor);
+            Self::deposit_event(Event::SmoothingFactorUpdated(old_factor, new_factor));
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Comptabilise un événement de risque soumis par `who` dans l'EMA, le score agrégé et
+        /// l'historique, et déclenche l'alerte globale si le seuil est dépassé.
+        fn do_submit_risk_event(who: T::AccountId, risk_factor: i32, description: Vec<u8>) -> DispatchResult {
+            Self::ensure_initialized()?;
             ensure!(risk_factor != 0, Error::<T>::InvalidRiskFactor);
+            let smoothing = SmoothingFactor::<T>::get();
+            Self::ensure_valid_smoothing(smoothing)?;
+            let smoothing = smoothing as i32;
             let now = T::TimeProvider::now().as_secs();
             RiskStateStorage::<T>::mutate(|state| {
                 let old_ema = state.risk_ema;
-                let smoothing = T::RiskSmoothingFactor::get() as i32;
                 let new_ema = if old_ema == 0 { risk_factor } else { (risk_factor + (smoothing - 1) * old_ema) / smoothing };
                 state.risk_ema = new_ema;
                 // Mise à jour du score de risque, en s'assurant qu'il reste >= 0.
@@ -145,31 +317,94 @@ pub mod pallet {
                     risk_factor,
                     description: description.clone(),
                 });
-                // Déclenchement d'une alerte si le risque dépasse le seuil.
+                // Déclenchement d'une alerte si le risque dépasse le seuil, limitée par `AlertCooldown`.
                 if new_risk > state.threshold {
-                    Self::deposit_event(Event::RiskAlert(who.clone(), new_risk));
+                    Self::raise_alert(&who, new_risk, state.threshold);
+                } else {
+                    Self::close_alert_window();
                 }
                 Self::deposit_event(Event::RiskEventSubmitted(who, risk_factor, new_ema, new_risk));
             });
             Ok(())
         }
 
-        /// Met à jour le seuil de risque.
-        /// Seul Root peut appeler cette fonction.
-        #[pallet::weight(10_000)]
-        pub fn update_threshold(origin: OriginFor<T>, new_threshold: u32) -> DispatchResult {
-            ensure_root(origin)?;
-            let old_threshold = RiskStateStorage::<T>::get().threshold;
-            let new_threshold_i32 = new_threshold as i32;
-            RiskStateStorage::<T>::mutate(|state| {
-                state.threshold = new_threshold_i32;
-            });
-            Self::deposit_event(Event::RiskThresholdUpdated(old_threshold, new_threshold_i32));
+        /// Ajoute `risk_factor` au score de `category` (clamped à 0) et émet `CategoryRiskAlert`
+        /// si ce score franchit le seuil de la catégorie.
+        fn update_category_risk(category: RiskCategory, risk_factor: i32) {
+            let old_risk = CategoryRisk::<T>::get(category);
+            let new_risk = old_risk.saturating_add(risk_factor).max(0);
+            CategoryRisk::<T>::insert(category, new_risk);
+            if let Some(threshold) = CategoryThresholds::<T>::get(category) {
+                if old_risk <= threshold && new_risk > threshold {
+                    nodara_log!(warn, "alerte de risque {:?} : score {} au-dessus du seuil {}", category, new_risk, threshold);
+                    Self::deposit_event(Event::CategoryRiskAlert(category, new_risk));
+                }
+            }
+        }
+
+        /// Échoue si `initialize_risk` n'a pas encore été exécutée.
+        fn ensure_initialized() -> DispatchResult {
+            ensure!(Initialized::<T>::get(), Error::<T>::NotInitialized);
+            Ok(())
+        }
+
+        /// Met en place l'état de risque initial, en écrasant l'état courant.
+        fn do_initialize_risk() -> DispatchResult {
+            let smoothing = T::RiskSmoothingFactor::get();
+            Self::ensure_valid_smoothing(smoothing)?;
+            SmoothingFactor::<T>::put(smoothing);
+            let now = T::TimeProvider::now().as_secs();
+            let baseline = T::BaselineRisk::get() as i32;
+            let threshold = T::RiskThreshold::get() as i32;
+            let state = RiskState {
+                current_risk: baseline,
+                risk_ema: baseline,
+                threshold,
+                history: vec![RiskEvent {
+                    timestamp: now,
+                    risk_factor: 0,
+                    description: b"Initialisation".to_vec(),
+                }],
+            };
+            RiskStateStorage::<T>::put(state);
+            let _ = CategoryRisk::<T>::clear(u32::MAX, None);
+            Initialized::<T>::put(true);
+            Ok(())
+        }
+
+        /// Émet un `RiskAlert`, sauf si une fenêtre de limitation est en cours : l'alerte est alors
+        /// seulement comptabilisée, pour la synthèse `RiskStillElevated`.
+        fn raise_alert(who: &T::AccountId, risk: i32, threshold: i32) {
+            let now = <frame_system::Pallet<T>>::block_number();
+            if let Some(mut window) = AlertThrottle::<T>::get() {
+                if now < window.until {
+                    window.suppressed = window.suppressed.saturating_add(1);
+                    AlertThrottle::<T>::put(window);
+                    return;
+                }
+                Self::close_alert_window();
+            }
+            nodara_log!(warn, "alerte de risque : score {} au-dessus du seuil {}", risk, threshold);
+            AlertThrottle::<T>::put(AlertWindow { until: now.saturating_add(T::AlertCooldown::get()), suppressed: 0 });
+            Self::deposit_event(Event::RiskAlert(who.clone(), risk));
+        }
+
+        /// Referme la fenêtre de limitation en cours, en émettant `RiskStillElevated` si des alertes
+        /// ont été supprimées.
+        fn close_alert_window() {
+            if let Some(window) = AlertThrottle::<T>::take() {
+                if window.suppressed > 0 {
+                    Self::deposit_event(Event::RiskStillElevated(window.suppressed));
+                }
+            }
+        }
+
+        /// Vérifie qu'un facteur de lissage est utilisable par le calcul de l'EMA (>= 2).
+        pub fn ensure_valid_smoothing(smoothing: u32) -> DispatchResult {
+            ensure!(smoothing >= 2, Error::<T>::InvalidSmoothingFactor);
             Ok(())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
         /// Fonction utilitaire retournant le timestamp actuel.
         /// En production, remplacez par un fournisseur de temps fiable (ex. `pallet_timestamp`).
         pub fn current_timestamp() -> u64 {
@@ -180,7 +415,7 @@ pub mod pallet {
     #[cfg(test)]
     mod tests {
         use super::*;
-        use frame_support::{assert_ok, assert_err, parameter_types};
+        use frame_support::{assert_ok, assert_err, parameter_types, traits::Hooks};
         use sp_core::H256;
         use sp_runtime::{
             traits::{BlakeTwo256, IdentityLookup},
@@ -214,7 +449,8 @@ pub mod pallet {
             pub const BlockHashCount: u64 = 250;
             pub const BaselineRisk: u32 = 50;
             pub const RiskThreshold: u32 = 100;
-            pub const RiskSmoothingFactor: u32 = 10;
+            pub static RiskSmoothingFactor: u32 = 10;
+            pub const AlertCooldown: u64 = 5;
         }
 
         impl system::Config for Test {
@@ -231,7 +467,7 @@ pub mod pallet {
             type AccountId = u64;
             type Lookup = IdentityLookup<Self::AccountId>;
             type Header = Header;
-            type RuntimeEvent = ();
+            type RuntimeEvent = RuntimeEvent;
             type BlockHashCount = BlockHashCount;
             type Version = ();
             type PalletInfo = ();
@@ -245,11 +481,13 @@ pub mod pallet {
         }
 
         impl Config for Test {
-            type RuntimeEvent = ();
+            type RuntimeEvent = RuntimeEvent;
             type BaselineRisk = BaselineRisk;
             type RiskThreshold = RiskThreshold;
             type RiskSmoothingFactor = RiskSmoothingFactor;
             type TimeProvider = TestTimeProvider;
+            type AlertCooldown = AlertCooldown;
+            type EmergencyHalt = ();
         }
 
         #[test]
@@ -293,4 +531,169 @@ pub mod pallet {
             let new_threshold = 200;
             assert_ok!(RiskModule::update_threshold(system::RawOrigin::Root.into(), new_threshold));
             let state = RiskModule::risk_state();
-            assert_eq!(state.threshold, new_threshold as i
+            assert_eq!(state.threshold, new_threshold as i32);
+        }
+
+        pub fn new_test_ext() -> sp_io::TestExternalities {
+            let storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+            storage.into()
+        }
+
+        #[test]
+        fn initialize_risk_rejects_invalid_smoothing_factor() {
+            new_test_ext().execute_with(|| {
+                for invalid in [0u32, 1] {
+                    RiskSmoothingFactor::set(&invalid);
+                    assert_err!(
+                        RiskModule::initialize_risk(system::RawOrigin::Root.into()),
+                        Error::<Test>::InvalidSmoothingFactor
+                    );
+                }
+                RiskSmoothingFactor::set(&2);
+                assert_ok!(RiskModule::initialize_risk(system::RawOrigin::Root.into()));
+                RiskSmoothingFactor::set(&10);
+            });
+        }
+
+        #[test]
+        fn updated_smoothing_factor_applies_to_next_event() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(RiskModule::initialize_risk(system::RawOrigin::Root.into()));
+                assert_eq!(RiskModule::smoothing_factor(), RiskSmoothingFactor::get());
+                assert_err!(
+                    RiskModule::update_smoothing_factor(system::RawOrigin::Root.into(), 1),
+                    Error::<Test>::InvalidSmoothingFactor
+                );
+                assert_ok!(RiskModule::update_smoothing_factor(system::RawOrigin::Root.into(), 2));
+                assert_ok!(RiskModule::submit_risk_event(system::RawOrigin::Signed(1).into(), 30, b"Spike".to_vec()));
+                // EMA = (30 + (2 - 1) * 50) / 2 = 40, contre (30 + 9 * 50) / 10 = 48 avec le facteur initial.
+                assert_eq!(RiskModule::risk_state().risk_ema, 40);
+            });
+        }
+
+        #[test]
+        fn operations_require_initialization() {
+            new_test_ext().execute_with(|| {
+                assert_err!(
+                    RiskModule::submit_risk_event(system::RawOrigin::Signed(1).into(), 10, b"Early".to_vec()),
+                    Error::<Test>::NotInitialized
+                );
+                assert_err!(RiskModule::update_threshold(system::RawOrigin::Root.into(), 200), Error::<Test>::NotInitialized);
+                assert_err!(RiskModule::update_smoothing_factor(system::RawOrigin::Root.into(), 5), Error::<Test>::NotInitialized);
+                assert_ok!(RiskModule::initialize_risk(system::RawOrigin::Root.into()));
+                assert_ok!(RiskModule::submit_risk_event(system::RawOrigin::Signed(1).into(), 10, b"Late".to_vec()));
+                assert_ok!(RiskModule::update_threshold(system::RawOrigin::Root.into(), 200));
+                assert_ok!(RiskModule::update_smoothing_factor(system::RawOrigin::Root.into(), 5));
+            });
+        }
+
+        #[test]
+        fn second_initialize_is_rejected() {
+            new_test_ext().execute_with(|| {
+                assert_ok!(RiskModule::initialize_risk(system::RawOrigin::Root.into()));
+                assert_ok!(RiskModule::submit_risk_event(system::RawOrigin::Signed(1).into(), 30, b"Spike".to_vec()));
+                let observed = RiskModule::risk_state();
+                assert_err!(RiskModule::initialize_risk(system::RawOrigin::Root.into()), Error::<Test>::AlreadyInitialized);
+                assert_eq!(RiskModule::risk_state(), observed);
+
+                assert_ok!(RiskModule::reset_risk(system::RawOrigin::Root.into()));
+                assert_eq!(RiskModule::risk_state().current_risk, BaselineRisk::get() as i32);
+                assert_eq!(RiskModule::risk_state().history.len(), 1);
+            });
+        }
+
+        #[test]
+        fn repeated_alerts_are_suppressed_during_cooldown() {
+            new_test_ext().execute_with(|| {
+                let alerts = || -> Vec<RuntimeEvent> {
+                    System::events()
+                        .into_iter()
+                        .map(|record| record.event)
+                        .filter(|event| matches!(
+                            event,
+                            RuntimeEvent::RiskModule(Event::RiskAlert(..)) | RuntimeEvent::RiskModule(Event::RiskStillElevated(_))
+                        ))
+                        .collect()
+                };
+                System::set_block_number(1);
+                assert_ok!(RiskModule::initialize_risk(system::RawOrigin::Root.into()));
+
+                // 50 + 60 = 110 > 100 : première alerte, puis sept alertes supprimées pendant la fenêtre.
+                assert_ok!(RiskModule::submit_risk_event(system::RawOrigin::Signed(1).into(), 60, vec![]));
+                for block in 1..=5u64 {
+                    System::set_block_number(block);
+                    RiskModule::on_initialize(block);
+                    assert_ok!(RiskModule::submit_risk_event(system::RawOrigin::Signed(1).into(), 1, vec![]));
+                    if block == 3 {
+                        assert_ok!(RiskModule::submit_risk_event(system::RawOrigin::Signed(1).into(), 1, vec![]));
+                        assert_ok!(RiskModule::submit_risk_event(system::RawOrigin::Signed(1).into(), 1, vec![]));
+                    }
+                }
+                assert_eq!(alerts(), vec![RuntimeEvent::RiskModule(Event::RiskAlert(1, 110))]);
+                assert_eq!(RiskModule::alert_window().map(|window| window.suppressed), Some(7));
+
+                // Fin de la fenêtre : une seule synthèse.
+                System::set_block_number(6);
+                RiskModule::on_initialize(6);
+                assert_eq!(
+                    alerts(),
+                    vec![RuntimeEvent::RiskModule(Event::RiskAlert(1, 110)), RuntimeEvent::RiskModule(Event::RiskStillElevated(7))]
+                );
+                assert!(RiskModule::alert_window().is_none());
+
+                // Le risque reste élevé : une nouvelle alerte ouvre une nouvelle fenêtre.
+                assert_ok!(RiskModule::submit_risk_event(system::RawOrigin::Signed(1).into(), 1, vec![]));
+                assert_eq!(alerts().last(), Some(&RuntimeEvent::RiskModule(Event::RiskAlert(1, 118))));
+            });
+        }
+
+        #[test]
+        fn category_alerts_independently_of_aggregate_threshold() {
+            new_test_ext().execute_with(|| {
+                let category_alerts = || -> Vec<RuntimeEvent> {
+                    System::events()
+                        .into_iter()
+                        .map(|record| record.event)
+                        .filter(|event| matches!(event, RuntimeEvent::RiskModule(Event::CategoryRiskAlert(..))))
+                        .collect()
+                };
+                System::set_block_number(1);
+                assert_ok!(RiskModule::initialize_risk(system::RawOrigin::Root.into()));
+                assert_err!(
+                    RiskModule::set_category_threshold(system::RawOrigin::Signed(1).into(), RiskCategory::Security, Some(20)),
+                    sp_runtime::DispatchError::BadOrigin
+                );
+                assert_ok!(RiskModule::set_category_threshold(system::RawOrigin::Root.into(), RiskCategory::Security, Some(20)));
+
+                // 15 puis 10 en sécurité : 25 > 20 pour la catégorie, 50 + 25 = 75 <= 100 pour l'agrégat.
+                assert_ok!(RiskModule::submit_categorized_risk_event(system::RawOrigin::Signed(1).into(), RiskCategory::Security, 15, vec![]));
+                assert!(category_alerts().is_empty());
+                assert_ok!(RiskModule::submit_categorized_risk_event(system::RawOrigin::Signed(1).into(), RiskCategory::Security, 10, vec![]));
+                assert_eq!(RiskModule::risk_state().current_risk, 75);
+                assert_eq!(category_alerts(), vec![RuntimeEvent::RiskModule(Event::CategoryRiskAlert(RiskCategory::Security, 25))]);
+                assert!(!System::events().iter().any(|record| matches!(record.event, RuntimeEvent::RiskModule(Event::RiskAlert(..)))));
+
+                // Déjà au-dessus du seuil : pas de nouvelle alerte ; les autres catégories ne sont pas concernées.
+                assert_ok!(RiskModule::submit_categorized_risk_event(system::RawOrigin::Signed(1).into(), RiskCategory::Security, 5, vec![]));
+                assert_ok!(RiskModule::submit_categorized_risk_event(system::RawOrigin::Signed(1).into(), RiskCategory::Network, 20, vec![]));
+                assert_eq!(category_alerts().len(), 1);
+                assert_eq!(RiskModule::category_risk(RiskCategory::Security), 30);
+                assert_eq!(RiskModule::category_risk(RiskCategory::Network), 20);
+            });
+        }
+
+        #[cfg(feature = "serde")]
+        #[test]
+        fn risk_state_round_trips_through_json() {
+            let state = RiskState {
+                current_risk: 42,
+                risk_ema: 30,
+                threshold: 100,
+                history: vec![RiskEvent { timestamp: 1_640_000_000, risk_factor: -5, description: b"recul".to_vec() }],
+            };
+            let json = serde_json::to_string(&state).expect("sérialisation JSON");
+            let decoded: RiskState = serde_json::from_str(&json).expect("désérialisation JSON");
+            assert_eq!(decoded, state);
+        }
+    }
+}
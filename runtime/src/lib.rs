@@ -8,18 +8,21 @@
 //! Ce runtime intègre tous les modules du réseau Nodara : System, Timestamp, Aura, Grandpa, Session,
 //! ainsi que tous les modules personnalisés : Bridge, Biosphere, Growth, Identity, Interop, IoTBridge,
 //! LiquidityFlow, RewardEngine, StabilityGuard, Standards, Pow, PredictiveGuard, Reputation, ReserveFund,
-//! Marketplace.
+//! Marketplace, RiskManagement, ainsi que l'arrêt d'urgence global (Emergency) qui bloque d'un seul appel
+//! les appels signés de tous ces modules.
 //!
 //! Le runtime expose également une API complète (NodeRuntimeApi) pour interroger l'état des différents modules.
 
 use sp_core::OpaqueMetadata;
 use sp_runtime::{
     generic,
-    traits::{BlakeTwo256, Block as BlockT, IdentifyAccount, Verify},
+    traits::{AccountIdConversion, BlakeTwo256, Block as BlockT, IdentifyAccount, Verify},
     MultiSignature,
 };
 use sp_version::RuntimeVersion;
 use parity_scale_codec::{Encode, Decode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
 
 #[macro_use]
 extern crate sp_api;
@@ -103,6 +106,8 @@ pub mod nodara_predictive_guard;
 pub mod nodara_reputation;
 pub mod nodara_reserve_fund;
 pub mod nodara_marketplace;
+pub mod risk_management;
+pub mod nodara_emergency;
 
 // ---------------------------------------------------------------------
 // Construct Runtime!
@@ -120,6 +125,7 @@ frame_support::construct_runtime!(
         Aura: pallet_aura::{Pallet, Call, Storage, Inherent, ValidateUnsigned},
         Grandpa: pallet_grandpa::{Pallet, Call, Storage, Config, Event},
         Session: pallet_session::{Pallet, Call, Storage, Event, Config as SessionConfig},
+        Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 
         // Vos modules personnalisés.
         Bridge: pallet_bridge::{Pallet, Call, Storage, Event<T>},
@@ -137,6 +143,8 @@ frame_support::construct_runtime!(
         Reputation: nodara_reputation::{Pallet, Call, Storage, Event<T>},
         ReserveFund: nodara_reserve_fund::{Pallet, Call, Storage, Event<T>},
         Marketplace: nodara_marketplace::{Pallet, Call, Storage, Event<T>},
+        RiskManagement: risk_management::{Pallet, Call, Storage, Event<T>},
+        Emergency: nodara_emergency::{Pallet, Call, Storage, Event<T>},
     }
 );
 
@@ -175,17 +183,605 @@ impl pallet_session::Config for Runtime {
     type WeightInfo = ();
 }
 
+frame_support::parameter_types! {
+    /// Solde minimal d'un compte existant.
+    pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = Balance;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type MaxLocks = frame_support::traits::ConstU32<50>;
+    type MaxReserves = frame_support::traits::ConstU32<50>;
+    type ReserveIdentifier = [u8; 8];
+    type RuntimeHoldReason = ();
+    type RuntimeFreezeReason = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+}
+
+// ---------------------------------------------------------------------
+// Revenue Routing
+// ---------------------------------------------------------------------
+
+frame_support::parameter_types! {
+    /// Part des frais collectés versée au fonds de réserve ; le reste alimente le pool de récompenses.
+    pub const ReserveFeeShare: sp_runtime::Percent = sp_runtime::Percent::from_percent(60);
+    /// Compte détenant les fonds réels du fonds de réserve.
+    pub ReserveFundAccount: AccountId = frame_support::PalletId(*b"nd/rsrve").into_account_truncating();
+    /// Compte de la réserve de récompenses.
+    pub RewardPoolAccount: AccountId = frame_support::PalletId(*b"nd/rwrds").into_account_truncating();
+    /// Paliers de réputation `(score minimal, multiplicateur en %)` appliqués aux récompenses,
+    /// consultés via `nodara_reputation`. Une liste vide désactive la modulation.
+    pub RewardReputationMultipliers: sp_std::vec::Vec<(u32, u32)> = sp_std::vec![(0, 100), (500, 125), (800, 150)];
+    /// Paliers de réputation `(score minimal, remise en %)` appliqués aux frais de la marketplace
+    /// payés par le preneur. Une liste vide désactive les remises.
+    pub MarketplaceFeeDiscounts: sp_std::vec::Vec<(u32, u32)> = sp_std::vec![(500, 20), (800, 40)];
+    /// Frais minimal d'une transaction remisée de la marketplace.
+    pub const MarketplaceMinTradeFee: u32 = 1;
+    /// Réputation minimale pour placer un ordre sur la marketplace (`None` désactive le contrôle).
+    pub const MarketplaceMinReputationToTrade: Option<u32> = Some(300);
+}
+
+/// Répartit les frais prélevés par la marketplace entre le fonds de réserve et le pool de
+/// récompenses selon `ReserveFeeShare`. La part du fonds de réserve est créditée sur
+/// `ReserveFundAccount` en plus de son solde comptable ; celle du pool de récompenses reste
+/// comptable, `nodara_reward_engine` créditant les bénéficiaires au versement.
+pub struct RevenueSplitter;
+
+impl nodara_marketplace::RevenueRouter for RevenueSplitter {
+    fn route_revenue(amount: u128) -> frame_support::dispatch::DispatchResult {
+        use frame_support::traits::Currency;
+        let reserve_share = ReserveFeeShare::get().mul_floor(amount);
+        let reward_share = amount.saturating_sub(reserve_share);
+        nodara_reserve_fund::Pallet::<Runtime>::do_contribute(reserve_share, b"trade fees".to_vec())?;
+        let _ = Balances::deposit_creating(&ReserveFundAccount::get(), reserve_share);
+        if reward_share > 0 {
+            nodara_reward_engine::Pallet::<Runtime>::increase_reward_pool(reward_share)?;
+        }
+        Ok(())
+    }
+}
+
+/// Expose le score de `nodara_reputation` au moteur de récompenses pour moduler les versements.
+pub struct RewardReputation;
+
+impl nodara_reward_engine::ReputationSource<AccountId> for RewardReputation {
+    fn reputation_of(who: &AccountId) -> Option<u32> {
+        nodara_reputation::Reputations::<Runtime>::get(who).map(|record| record.score)
+    }
+}
+
+/// Expose le score de `nodara_reputation` à la marketplace pour la remise sur les frais du preneur.
+pub struct MarketplaceReputation;
+
+impl nodara_marketplace::ReputationSource<AccountId> for MarketplaceReputation {
+    fn reputation_of(who: &AccountId) -> Option<u32> {
+        nodara_reputation::Reputations::<Runtime>::get(who).map(|record| record.score)
+    }
+}
+
+/// Crédite les dépôts de proposition prélevés par `nodara_reputation` au solde du fonds de
+/// réserve ou au pool de récompenses, selon leur destination.
+pub struct ReputationSlashAccounting;
+
+impl nodara_reputation::SlashedDepositHandler for ReputationSlashAccounting {
+    fn on_deposit_slashed(
+        destination: nodara_reputation::SlashDestination,
+        amount: u128,
+    ) -> frame_support::dispatch::DispatchResult {
+        match destination {
+            nodara_reputation::SlashDestination::ReserveFund => {
+                nodara_reserve_fund::Pallet::<Runtime>::do_contribute(amount, b"slashed deposit".to_vec())
+            },
+            nodara_reputation::SlashDestination::RewardPool => {
+                nodara_reward_engine::Pallet::<Runtime>::increase_reward_pool(amount)
+            },
+            nodara_reputation::SlashDestination::Burn => Ok(()),
+        }
+    }
+}
+
+// ---------------------------------------------------------------------
+// Asset Definitions
+// ---------------------------------------------------------------------
+
+/// Expose les actifs supportés par le bridge comme registre de référence de la marketplace :
+/// un actif enregistré sur la marketplace sous un symbole connu du bridge doit déclarer les
+/// décimales de sa représentation sur Nodara.
+pub struct BridgeAssetDefinitions;
+
+impl nodara_marketplace::AssetDefinitionSource for BridgeAssetDefinitions {
+    fn decimals_of(symbol: &[u8]) -> Option<u8> {
+        pallet_bridge::SupportedAssets::<Runtime>::iter_values()
+            .find(|asset| asset.symbol.as_slice() == symbol)
+            .map(|asset| asset.nodara_decimals)
+    }
+}
+
+frame_support::parameter_types! {
+    /// Standard de `nodara_standards` que doivent satisfaire les métadonnées des actifs du bridge
+    /// (`None` désactive le contrôle).
+    pub BridgeAssetStandard: Option<sp_std::vec::Vec<u8>> = None;
+}
+
+/// Vérifie les métadonnées encodées des actifs du bridge auprès de `nodara_standards` pour le
+/// standard fourni par `StandardId`. Un standard inconnu rend toute définition non conforme.
+pub struct StandardsAssetCompliance<StandardId>(sp_std::marker::PhantomData<StandardId>);
+
+impl<StandardId: frame_support::traits::Get<Option<sp_std::vec::Vec<u8>>>> pallet_bridge::AssetCompliance
+    for StandardsAssetCompliance<StandardId>
+{
+    fn is_compliant(_asset: &[u8], encoded_metadata: &[u8]) -> bool {
+        match StandardId::get() {
+            Some(standard_id) => nodara_standards::Pallet::<Runtime>::check_compliance(
+                &standard_id,
+                encoded_metadata.to_vec(),
+            )
+            .is_ok(),
+            None => true,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------
+// Bridge Notifications
+// ---------------------------------------------------------------------
+
+/// Annonce chaque transfert finalisé du bridge à la chaîne homologue par un message de module
+/// interop (`ModuleMessages`, hors de portée des utilisateurs), identifié par l'identifiant du
+/// transfert et dont le payload est la demande encodée en SCALE. Une notification retardée par
+/// une suspension ou par la limite de messages du bloc est mise en attente et renvoyée par
+/// `nodara_interop` ; seuls les rejets définitifs sont journalisés.
+pub struct InteropTransferNotifier;
+
+impl pallet_bridge::OnTransferFinalized<AccountId> for InteropTransferNotifier {
+    fn on_transfer_finalized(request: &pallet_bridge::TransferRequest<AccountId>) {
+        if let Err(e) = nodara_interop::Pallet::<Runtime>::enqueue_outgoing(request.id, request.encode()) {
+            frame_support::log::warn!(
+                target: "runtime::bridge",
+                "notification interop du transfert {} impossible : {:?}",
+                request.id,
+                e
+            );
+        }
+    }
+}
+
+/// Soldes des tokens représentatifs émis par le bridge, par actif et par compte. Tenus par le
+/// runtime tant qu'aucun module multi-actifs n'est intégré.
+#[frame_support::storage_alias]
+pub type BridgedBalances = StorageDoubleMap<
+    BridgeLedger,
+    frame_support::Blake2_128Concat,
+    Vec<u8>,
+    frame_support::Blake2_128Concat,
+    AccountId,
+    u128,
+    frame_support::pallet_prelude::ValueQuery,
+>;
+
+/// Gestionnaire par défaut des tokens représentatifs du bridge : crédite et débite
+/// `BridgedBalances`, sans jamais toucher à la monnaie native.
+pub struct BridgedAssetLedger;
+
+impl pallet_bridge::BridgeAssetManager<AccountId> for BridgedAssetLedger {
+    fn mint(asset: Vec<u8>, to: &AccountId, amount: u128) -> frame_support::dispatch::DispatchResult {
+        BridgedBalances::mutate(asset, to, |balance| *balance = balance.saturating_add(amount));
+        Ok(())
+    }
+
+    fn burn(asset: Vec<u8>, from: &AccountId, amount: u128) -> frame_support::dispatch::DispatchResult {
+        BridgedBalances::try_mutate(asset, from, |balance| {
+            *balance = balance
+                .checked_sub(amount)
+                .ok_or(sp_runtime::DispatchError::Other("solde représentatif insuffisant"))?;
+            Ok(())
+        })
+    }
+}
+
+// ---------------------------------------------------------------------
+// Growth Metrics
+// ---------------------------------------------------------------------
+
+frame_support::parameter_types! {
+    /// Signal de croissance produit par chaque extrinsèque du bloc, appliqué par `nodara_growth`
+    /// en fin de bloc.
+    pub const GrowthSignalPerExtrinsic: u32 = 10;
+}
+
+/// Dérive le signal de croissance automatique du nombre d'extrinsèques du bloc courant. Le compte
+/// est arrêté par `frame_system` avant les `on_finalize` des modules.
+pub struct ExtrinsicCountMetrics;
+
+impl nodara_growth::NetworkMetricsSource for ExtrinsicCountMetrics {
+    fn growth_signal() -> u32 {
+        frame_system::Pallet::<Runtime>::extrinsic_count().saturating_mul(GrowthSignalPerExtrinsic::get())
+    }
+}
+
+// ---------------------------------------------------------------------
+// Configuration for Custom Pallets
+// ---------------------------------------------------------------------
+
+frame_support::parameter_types! {
+    /// Taille maximale des métadonnées d'un actif de la marketplace.
+    pub const MarketplaceMaxAssetMetadataLength: u32 = 256;
+    /// Frais de base d'une transaction de la marketplace, prélevés sur le preneur avant remise.
+    pub const MarketplaceBaseTradeFee: u32 = 10;
+    /// Nombre maximal d'ordres expirés retirés de la marketplace par bloc.
+    pub const MarketplaceMaxExpiriesPerBlock: u32 = 50;
+}
+
+impl nodara_marketplace::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxAssetMetadataLength = MarketplaceMaxAssetMetadataLength;
+    type BaseTradeFee = MarketplaceBaseTradeFee;
+    type RevenueRouter = RevenueSplitter;
+    type GovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxExpiriesPerBlock = MarketplaceMaxExpiriesPerBlock;
+    type Currency = Balances;
+    type ReserveFundAccount = ReserveFundAccount;
+    type AssetDefinitions = BridgeAssetDefinitions;
+    type ReputationSource = MarketplaceReputation;
+    type FeeDiscountTiers = MarketplaceFeeDiscounts;
+    type MinTradeFee = MarketplaceMinTradeFee;
+    type MinReputationToTrade = MarketplaceMinReputationToTrade;
+    type OrderAmountLimits = ();
+    type EmergencyHalt = Emergency;
+}
+
+frame_support::parameter_types! {
+    /// Pool de récompenses initial du moteur de récompenses.
+    pub const BaselineRewardPool: u128 = 1_000_000;
+    /// Plafond du pool de récompenses.
+    pub const MaxRewardPool: u128 = 1_000_000_000;
+    /// Nombre maximal de calendriers de vesting actifs.
+    pub const MaxVestingSchedules: u32 = 100;
+    /// Nombre minimal de blocs entre deux `update_reward_pool` d'un même compte.
+    pub const RewardUpdateCooldown: BlockNumber = 10;
+}
+
+impl nodara_reward_engine::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type BaselineRewardPool = BaselineRewardPool;
+    type MaxRewardPool = MaxRewardPool;
+    type Currency = Balances;
+    type MaxVestingSchedules = MaxVestingSchedules;
+    type ReputationSource = RewardReputation;
+    type ReputationMultipliers = RewardReputationMultipliers;
+    type EmergencyHalt = Emergency;
+    type UpdateCooldown = RewardUpdateCooldown;
+}
+
+frame_support::parameter_types! {
+    /// Nombre de confirmations requis pour finaliser un transfert du bridge.
+    pub const BridgeRequiredConfirmations: u32 = 3;
+    /// Nombre maximal de confirmations stockées par transfert.
+    pub const BridgeMaxConfirmations: u32 = 10;
+    /// Nombre maximal d'actifs supportés par le bridge.
+    pub const BridgeMaxSupportedAssets: u32 = 64;
+    /// Durée de vie (en blocs) d'une demande de transfert non finalisée, soit 24 h à 6 s par bloc.
+    pub const BridgeTransferTtl: u64 = 14_400;
+    /// Délai (en blocs) laissé à l'initiateur avant que tout compte puisse retirer une demande expirée.
+    pub const BridgeReapGrace: u64 = 600;
+    /// Nombre minimal de blocs entre deux confirmations d'un même transfert.
+    pub const BridgeMinConfirmationInterval: u64 = 1;
+    /// Part du stake total requise pour le quorum pondéré des confirmations.
+    pub const BridgeStakeQuorum: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(67);
+}
+
+impl pallet_bridge::Config for Runtime {
+    type Event = RuntimeEvent;
+    type Currency = Balances;
+    type RequiredConfirmations = BridgeRequiredConfirmations;
+    type MaxConfirmations = BridgeMaxConfirmations;
+    type MaxSupportedAssets = BridgeMaxSupportedAssets;
+    type TransferTtl = BridgeTransferTtl;
+    type ReapGrace = BridgeReapGrace;
+    type MinConfirmationInterval = BridgeMinConfirmationInterval;
+    type AssetManager = BridgedAssetLedger;
+    type AssetManagers = ();
+    type GovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+    type OnTransferFinalized = InteropTransferNotifier;
+    type EmergencyHalt = Emergency;
+    type StakeSource = ();
+    type StakeQuorum = BridgeStakeQuorum;
+    type AssetCompliance = StandardsAssetCompliance<BridgeAssetStandard>;
+    type TransferAmountLimits = ();
+}
+
+frame_support::parameter_types! {
+    /// Score attribué par `initialize_reputation`, en deçà du seuil de la marketplace.
+    pub const InitialReputation: u32 = 100;
+    /// Nombre de votes requis pour adopter une proposition de réputation.
+    pub const ReputationProposalThreshold: u32 = 10;
+    /// Dépôt réservé à la création d'une proposition de réputation.
+    pub const ReputationProposalDeposit: Balance = 100;
+    /// Période de vote (en blocs) d'une proposition de réputation, soit 24 h à 6 s par bloc.
+    pub const ReputationVotingPeriod: u64 = 14_400;
+    /// Longueur maximale d'une description de proposition ou d'un motif d'ajustement.
+    pub const MaxProposalDescriptionLen: u32 = 256;
+    /// Les dépôts des propositions expirées alimentent le fonds de réserve.
+    pub const ReputationSlashDestination: nodara_reputation::SlashDestination =
+        nodara_reputation::SlashDestination::ReserveFund;
+    /// Nombre maximal de comptes initialisés par `bulk_initialize_reputation`.
+    pub const ReputationMaxBatchSize: u32 = 100;
+}
+
+impl nodara_reputation::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type InitialReputation = InitialReputation;
+    type GovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+    type ProposalThreshold = ReputationProposalThreshold;
+    type Currency = Balances;
+    type ProposalDeposit = ReputationProposalDeposit;
+    type VotingPeriod = ReputationVotingPeriod;
+    type MaxProposalDescriptionLen = MaxProposalDescriptionLen;
+    type SlashDestination = ReputationSlashDestination;
+    type ReserveFundAccount = ReserveFundAccount;
+    type RewardPoolAccount = RewardPoolAccount;
+    type SlashHandler = ReputationSlashAccounting;
+    type EmergencyHalt = Emergency;
+    type MaxBatchSize = ReputationMaxBatchSize;
+}
+
+frame_support::parameter_types! {
+    /// Multiplicateur de croissance à l'initialisation.
+    pub const GrowthBaselineMultiplier: u32 = 100;
+    /// Facteur de lissage appliqué aux signaux de croissance.
+    pub const GrowthSmoothingFactor: u32 = 10;
+}
+
+impl nodara_growth::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type BaselineMultiplier = GrowthBaselineMultiplier;
+    type SmoothingFactor = GrowthSmoothingFactor;
+    type RequiredConfirmations = BridgeRequiredConfirmations;
+    type AssetManager = BridgedAssetLedger;
+    type NetworkMetrics = ExtrinsicCountMetrics;
+    type EmergencyHalt = Emergency;
+}
+
+/// Horloge commune des historiques des modules : secondes Unix lues dans `pallet_timestamp`, la
+/// même base que `nodara_standards` et que `nodara_reserve_fund` une fois ses millisecondes
+/// converties, ce qui rend leurs horodatages comparables dans `audit_export`.
+pub type HistoryTimeSource = nodara_time::TimestampSource<Runtime>;
+
+frame_support::parameter_types! {
+    /// Délai d'expiration par défaut d'un message interop.
+    pub const InteropBaseTimeout: u64 = 300;
+    /// Taille maximale par défaut d'un payload interop.
+    pub const InteropMaxPayloadLength: u32 = 65_536;
+    /// Taille maximale d'une signature interop.
+    pub const InteropMaxSignatureLength: u32 = 64;
+    /// Nombre maximal de messages interop traités par bloc.
+    pub const InteropMaxMessagesPerBlock: u32 = 100;
+    /// Délai d'expiration par défaut d'une soumission IoT.
+    pub const IotBaseTimeout: u64 = 60;
+    /// Taille maximale par défaut d'un payload IoT.
+    pub const IotMaxPayloadLength: u32 = 1_024;
+    /// Nombre maximal de dispositifs IoT enregistrés.
+    pub const IotMaxDevices: u32 = 1_000;
+    /// Nombre maximal d'enregistrements IoT conservés.
+    pub const IotMaxStoredRecords: u32 = 1_000;
+    /// Nombre maximal d'entrées conservées dans les historiques interop et IoT.
+    pub const MaxModuleHistoryLen: u32 = 1_000;
+    /// Longueur maximale des détails KYC d'une identité.
+    pub const MaxKycLength: u32 = 256;
+    /// Longueur maximale (description et paramètres) d'un standard.
+    pub const MaxStandardLength: u32 = 256;
+}
+
+impl nodara_interop::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type BaseTimeout = InteropBaseTimeout;
+    type MaxPayloadLength = InteropMaxPayloadLength;
+    type MaxSignatureLength = InteropMaxSignatureLength;
+    type MaxMessagesPerBlock = InteropMaxMessagesPerBlock;
+    type GovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+    type TimeSource = HistoryTimeSource;
+    type MaxHistoryLen = MaxModuleHistoryLen;
+    type EmergencyHalt = Emergency;
+}
+
+impl nodara_iot::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxPayloadLength = IotMaxPayloadLength;
+    type BaseTimeout = IotBaseTimeout;
+    type TimeSource = HistoryTimeSource;
+    type ConfigOrigin = frame_system::EnsureRoot<AccountId>;
+    type MaxDevices = IotMaxDevices;
+    type MaxStoredRecords = IotMaxStoredRecords;
+    type MaxHistoryLen = MaxModuleHistoryLen;
+    type EmergencyHalt = Emergency;
+}
+
+impl nodara_id::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type DefaultVerification = frame_support::traits::ConstBool<false>;
+    type MaxKycLength = MaxKycLength;
+    type KycValidator = ();
+    type TimeSource = HistoryTimeSource;
+    type EmergencyHalt = Emergency;
+}
+
+impl nodara_standards::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxStandardLength = MaxStandardLength;
+    type TimeProvider = Timestamp;
+    type EmergencyHalt = Emergency;
+}
+
 // ---------------------------------------------------------------------
 // Runtime API
 // ---------------------------------------------------------------------
 
+frame_support::parameter_types! {
+    /// Nombre maximal d'entrées retournées par une page d'historique de l'API runtime, quelle que
+    /// soit la taille demandée.
+    pub const MaxHistoryPageSize: u32 = 100;
+    /// Durée cible d'un bloc en millisecondes, utilisée pour convertir une plage de blocs en
+    /// fenêtre d'horodatages dans `audit_export`.
+    pub const ExpectedBlockTime: u64 = 6_000;
+    /// Heure du bloc de genèse en secondes Unix.
+    pub const GenesisTime: u64 = 1_640_000_000;
+}
+
+/// Synthèse de l'état du réseau pour les tableaux de bord de supervision.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct HealthSummary {
+    /// Phase courante de la biosphère.
+    pub bio_phase: nodara_biosphere::BioPhase,
+    /// Niveau d'énergie de la biosphère.
+    pub energy_level: u32,
+    /// Paramètre de stabilité courant.
+    pub stability_parameter: u32,
+    /// Score de risque courant.
+    pub risk_score: i32,
+    /// Solde du fonds de réserve.
+    pub reserve_balance: u128,
+    /// Pool de récompenses disponible.
+    pub reward_pool: u128,
+}
+
+/// Assemble la synthèse de santé à partir d'une lecture de stockage par module.
+fn node_health_summary() -> HealthSummary {
+    let bio = nodara_biosphere::Pallet::<Runtime>::bio_state();
+    HealthSummary {
+        bio_phase: bio.current_phase,
+        energy_level: bio.energy_level,
+        stability_parameter: nodara_stability_guard::Pallet::<Runtime>::stability_state().current_parameter,
+        risk_score: risk_management::Pallet::<Runtime>::risk_state().current_risk,
+        reserve_balance: nodara_reserve_fund::Pallet::<Runtime>::reserve_state().balance,
+        reward_pool: nodara_reward_engine::Pallet::<Runtime>::reward_engine_state().reward_pool,
+    }
+}
+
+/// Position de lecture de chaque section d'un export d'audit : nombre d'entrées de la plage déjà
+/// retournées pour le module.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+pub struct AuditCursor {
+    pub interop: u32,
+    pub iot: u32,
+    pub reserve: u32,
+    pub identity: u32,
+    pub compliance: u32,
+}
+
+/// Export d'audit : entrées d'historique des modules comprises dans une plage de blocs.
+///
+/// Tous les horodatages sont en secondes Unix ; ceux de `nodara_reserve_fund`, enregistrés en
+/// millisecondes, sont convertis. Chaque section avance selon son propre curseur.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AuditBundle {
+    /// Historique du module Interop.
+    pub interop: Vec<nodara_interop::InteropHistoryEntry<AccountId>>,
+    /// Journal d'audit du module IoT Bridge.
+    pub iot: Vec<nodara_iot::IotHistoryEntry>,
+    /// Opérations sur le fonds de réserve.
+    pub reserve: Vec<nodara_reserve_fund::ReserveRecord>,
+    /// Mises à jour d'identité : (timestamp, compte, ancien statut, nouveau statut, détails KYC).
+    pub identity: Vec<(u64, AccountId, bool, bool, Vec<u8>)>,
+    /// Vérifications de conformité du module Standards.
+    pub compliance: Vec<nodara_standards::ComplianceLog>,
+    /// Curseur de la page suivante, ou `None` si toutes les sections sont épuisées.
+    pub next: Option<AuditCursor>,
+}
+
+/// Heure estimée (secondes Unix) du début du bloc `block`, selon `GenesisTime` et `ExpectedBlockTime`.
+fn block_start_time(block: u32) -> u64 {
+    GenesisTime::get().saturating_add(u64::from(block).saturating_mul(ExpectedBlockTime::get()) / 1_000)
+}
+
+/// Au plus `len` entrées de `entries` à partir de `cursor`, qui est avancé d'autant ; lève
+/// `has_more` s'il reste des entrées après la page.
+fn audit_page<E>(entries: Vec<E>, cursor: &mut u32, len: u32, has_more: &mut bool) -> Vec<E> {
+    let (start, len) = (*cursor as usize, len as usize);
+    *has_more |= entries.len() > start.saturating_add(len);
+    let page: Vec<E> = entries.into_iter().skip(start).take(len).collect();
+    *cursor = cursor.saturating_add(page.len() as u32);
+    page
+}
+
+/// Rassemble les entrées d'historique horodatées entre le début de `from_block` et la fin de
+/// `to_block`, en retournant pour chaque module au plus `len` entrées retenues à partir de sa
+/// position dans `cursor`. `len` est plafonné à `MaxHistoryPageSize`.
+fn audit_export(from_block: u32, to_block: u32, cursor: AuditCursor, len: u32) -> AuditBundle {
+    let (from_time, to_time) = (block_start_time(from_block), block_start_time(to_block.saturating_add(1)));
+    let in_range = |timestamp: u64| timestamp >= from_time && timestamp < to_time;
+    let len = len.min(MaxHistoryPageSize::get());
+    let mut next = cursor;
+    let mut has_more = false;
+
+    let interop = audit_page(
+        nodara_interop::Pallet::<Runtime>::interop_history()
+            .into_iter()
+            .filter(|entry| in_range(entry.0))
+            .collect(),
+        &mut next.interop,
+        len,
+        &mut has_more,
+    );
+    let iot = audit_page(
+        nodara_iot::Pallet::<Runtime>::iot_history()
+            .into_iter()
+            .filter(|entry| in_range(entry.0))
+            .collect(),
+        &mut next.iot,
+        len,
+        &mut has_more,
+    );
+    let reserve = audit_page(
+        nodara_reserve_fund::Pallet::<Runtime>::reserve_state()
+            .history
+            .into_iter()
+            .map(|record| nodara_reserve_fund::ReserveRecord { timestamp: record.timestamp / 1_000, ..record })
+            .filter(|record| in_range(record.timestamp))
+            .collect(),
+        &mut next.reserve,
+        len,
+        &mut has_more,
+    );
+    let identity = audit_page(
+        nodara_id::Pallet::<Runtime>::identity_history()
+            .into_iter()
+            .filter(|entry| in_range(entry.0))
+            .collect(),
+        &mut next.identity,
+        len,
+        &mut has_more,
+    );
+    let compliance = audit_page(
+        nodara_standards::Pallet::<Runtime>::compliance_history()
+            .into_iter()
+            .filter(|log| in_range(log.timestamp))
+            .collect(),
+        &mut next.compliance,
+        len,
+        &mut has_more,
+    );
+    AuditBundle { interop, iot, reserve, identity, compliance, next: has_more.then_some(next) }
+}
+
 sp_api::decl_runtime_apis! {
     pub trait NodeRuntimeApi {
         /// Returns asset metadata (as bytes) for a given asset ID from the Marketplace module.
         fn marketplace_get_asset(asset_id: u64) -> Option<Vec<u8>>;
 
+        /// Returns the value of a `key=value;` metadata field for an asset from the Marketplace module.
+        fn marketplace_metadata_field(asset_id: u64, key: Vec<u8>) -> Option<Vec<u8>>;
+
+        /// Returns the total trade fees collected by the Marketplace module.
+        fn marketplace_fees_collected() -> u128;
+
         /// Returns the global state of the Biosphere module.
-        fn biosphere_get_state() -> nodara_biosphere::BioState;
+        fn biosphere_get_state() -> nodara_biosphere::BioStateOf<Runtime>;
 
         /// Returns the current growth state from the Growth module.
         fn growth_get_state() -> nodara_growth::GrowthState;
@@ -193,12 +789,21 @@ sp_api::decl_runtime_apis! {
         /// Returns the identity data for a given account from the Identity module.
         fn identity_get(account: u64) -> Option<nodara_id::IdentityData>;
 
+        /// Returns whether the account holds a verified identity, without the KYC payload.
+        fn identity_is_verified(account: u64) -> bool;
+
         /// Returns the interop history from the Interop module.
-        fn interop_get_history() -> Vec<(u64, u64, Vec<u8>, Vec<u8>)>;
+        fn interop_get_history() -> Vec<(u64, u64, Vec<u8>, Vec<u8>, Option<AccountId>)>;
+
+        /// Returns the ids of the interop messages sent or received by `account`.
+        fn interop_messages_for(account: AccountId) -> Vec<u64>;
 
         /// Returns the IoT record for a given message ID from the IoT Bridge module.
         fn iot_get_record(message_id: u64) -> Option<nodara_iot::IotRecord>;
 
+        /// Returns the `emit_events` flag of a registered IoT device, or `None` if it is not registered.
+        fn iot_device_emits_events(device_id: Vec<u8>) -> Option<bool>;
+
         /// Returns the liquidity state from the Liquidity Flow module.
         fn liquidity_get_state() -> nodara_liquidity_flow::LiquidityState;
 
@@ -214,14 +819,52 @@ sp_api::decl_runtime_apis! {
         /// Returns the PoW state from the Pow module.
         fn pow_get_state() -> nodara_pow::PowState;
 
+        /// Returns the number of difficulty adjustments recorded by the Pow module.
+        fn pow_history_len() -> u32;
+
+        /// Returns up to `len` difficulty adjustments starting at `start`
+        /// as (timestamp, old difficulty, new difficulty, signal).
+        /// `len` is clamped to `MaxHistoryPageSize`.
+        fn pow_history_page(start: u32, len: u32) -> Vec<(u64, u32, u32, i32)>;
+
+        /// Returns the `n` miners with the most accepted work as (account, work),
+        /// highest first. `n` is clamped to `MaxHistoryPageSize`.
+        fn pow_top_miners(n: u32) -> Vec<(AccountId, u32)>;
+
         /// Returns the current predictive value from the Predictive Guard module.
         fn predictive_get_value() -> u32;
 
         /// Returns the reputation record for a given account from the Reputation module.
         fn reputation_get(account: u64) -> Option<nodara_reputation::ReputationRecord>;
 
+        /// Returns the number of reputation adjustments per reason code for an account.
+        fn reputation_counts_by_reason(account: u64) -> Vec<(nodara_reputation::ReputationReason, u32)>;
+
+        /// Returns the score change `update_reputation` would apply for a raw delta,
+        /// using the current penalty factor of the Reputation module.
+        fn reputation_preview_delta(raw: i32) -> i32;
+
         /// Returns the reserve fund state from the Reserve Fund module.
-        fn reserve_get_state() -> nodara_reserve_fund::ReserveFundState;
+        fn reserve_get_state() -> nodara_reserve_fund::ReserveFundStateOf<Runtime>;
+
+        /// Returns the (current, required) progress of a pending transfer from the Bridge module:
+        /// confirmation counts, or confirmed stake against the stake quorum when confirmations are
+        /// stake-weighted.
+        fn bridge_confirmation_status(transfer_id: u64) -> Option<(u128, u128)>;
+
+        /// Returns the cumulative (to Nodara, from Nodara) finalized volume of an asset from the Bridge module.
+        fn bridge_volume(asset: Vec<u8>) -> Option<(u128, u128)>;
+
+        /// Returns the identifiers of all assets registered in the Bridge module.
+        fn bridge_list_assets() -> Vec<Vec<u8>>;
+
+        /// Returns a summary of the main module states in a single call.
+        fn node_health_summary() -> HealthSummary;
+
+        /// Returns the interop, IoT, reserve, identity and compliance history entries recorded
+        /// between the start of `from_block` and the end of `to_block`, paginated per module
+        /// from its position in `cursor`. `len` is clamped to `MaxHistoryPageSize`.
+        fn audit_export(from_block: u32, to_block: u32, cursor: AuditCursor, len: u32) -> AuditBundle;
 
         /// Dummy function for testing.
         fn dummy() -> u32;
@@ -233,7 +876,15 @@ impl NodeRuntimeApi for Runtime {
         nodara_marketplace::Pallet::<Runtime>::assets(asset_id).map(|asset| asset.metadata)
     }
 
-    fn biosphere_get_state() -> nodara_biosphere::BioState {
+    fn marketplace_metadata_field(asset_id: u64, key: Vec<u8>) -> Option<Vec<u8>> {
+        nodara_marketplace::Pallet::<Runtime>::metadata_field(asset_id, &key)
+    }
+
+    fn marketplace_fees_collected() -> u128 {
+        nodara_marketplace::Pallet::<Runtime>::fees_accrued()
+    }
+
+    fn biosphere_get_state() -> nodara_biosphere::BioStateOf<Runtime> {
         nodara_biosphere::Pallet::<Runtime>::bio_state()
     }
 
@@ -245,14 +896,26 @@ impl NodeRuntimeApi for Runtime {
         nodara_id::Pallet::<Runtime>::identities(account)
     }
 
-    fn interop_get_history() -> Vec<(u64, u64, Vec<u8>, Vec<u8>)> {
-        nodara_interop::Pallet::<Runtime>::interop_history()
+    fn identity_is_verified(account: u64) -> bool {
+        nodara_id::Pallet::<Runtime>::is_verified(&account)
+    }
+
+    fn interop_get_history() -> Vec<(u64, u64, Vec<u8>, Vec<u8>, Option<AccountId>)> {
+        nodara_interop::Pallet::<Runtime>::interop_history().into_inner()
+    }
+
+    fn interop_messages_for(account: AccountId) -> Vec<u64> {
+        nodara_interop::Pallet::<Runtime>::messages_for(&account)
     }
 
     fn iot_get_record(message_id: u64) -> Option<nodara_iot::IotRecord> {
         nodara_iot::Pallet::<Runtime>::iot_data(message_id)
     }
 
+    fn iot_device_emits_events(device_id: Vec<u8>) -> Option<bool> {
+        nodara_iot::Pallet::<Runtime>::device_emits_events(&device_id)
+    }
+
     fn liquidity_get_state() -> nodara_liquidity_flow::LiquidityState {
         nodara_liquidity_flow::Pallet::<Runtime>::liquidity_state()
     }
@@ -273,6 +936,18 @@ impl NodeRuntimeApi for Runtime {
         nodara_pow::Pallet::<Runtime>::pow_state()
     }
 
+    fn pow_history_len() -> u32 {
+        nodara_pow::Pallet::<Runtime>::pow_history_len()
+    }
+
+    fn pow_history_page(start: u32, len: u32) -> Vec<(u64, u32, u32, i32)> {
+        nodara_pow::Pallet::<Runtime>::pow_history_page(start, len.min(MaxHistoryPageSize::get()))
+    }
+
+    fn pow_top_miners(n: u32) -> Vec<(AccountId, u32)> {
+        nodara_pow::Pallet::<Runtime>::top_miners(n.min(MaxHistoryPageSize::get()))
+    }
+
     fn predictive_get_value() -> u32 {
         nodara_predictive_guard::Pallet::<Runtime>::predictive_value()
     }
@@ -281,10 +956,38 @@ impl NodeRuntimeApi for Runtime {
         nodara_reputation::Pallet::<Runtime>::reputations(account)
     }
 
-    fn reserve_get_state() -> nodara_reserve_fund::ReserveFundState {
+    fn reputation_counts_by_reason(account: u64) -> Vec<(nodara_reputation::ReputationReason, u32)> {
+        nodara_reputation::Pallet::<Runtime>::counts_by_reason(&account)
+    }
+
+    fn reputation_preview_delta(raw: i32) -> i32 {
+        nodara_reputation::Pallet::<Runtime>::preview_delta(raw)
+    }
+
+    fn reserve_get_state() -> nodara_reserve_fund::ReserveFundStateOf<Runtime> {
         nodara_reserve_fund::Pallet::<Runtime>::reserve_fund_state()
     }
 
+    fn bridge_confirmation_status(transfer_id: u64) -> Option<(u128, u128)> {
+        pallet_bridge::Pallet::<Runtime>::confirmation_status(transfer_id)
+    }
+
+    fn bridge_volume(asset: Vec<u8>) -> Option<(u128, u128)> {
+        pallet_bridge::Pallet::<Runtime>::volume(&asset)
+    }
+
+    fn bridge_list_assets() -> Vec<Vec<u8>> {
+        pallet_bridge::Pallet::<Runtime>::list_assets()
+    }
+
+    fn node_health_summary() -> HealthSummary {
+        node_health_summary()
+    }
+
+    fn audit_export(from_block: u32, to_block: u32, cursor: AuditCursor, len: u32) -> AuditBundle {
+        audit_export(from_block, to_block, cursor, len)
+    }
+
     fn dummy() -> u32 {
         42
     }
@@ -296,3 +999,551 @@ impl NodeRuntimeApi for Runtime {
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Runtime;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn health_summary_reflects_module_states() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            nodara_biosphere::BioStateStorage::<Runtime>::put(nodara_biosphere::BioState {
+                current_phase: nodara_biosphere::BioPhase::Defense,
+                energy_level: 60,
+                quantum_flux: 5,
+                last_updated: 0,
+                history: Default::default(),
+            });
+            nodara_stability_guard::StabilityStorage::<Runtime>::mutate(|s| s.current_parameter = 112);
+            risk_management::RiskStateStorage::<Runtime>::mutate(|s| s.current_risk = 80);
+            nodara_reserve_fund::ReserveFundStorage::<Runtime>::mutate(|s| s.balance = 1_500_000);
+            nodara_reward_engine::RewardEngineStorage::<Runtime>::mutate(|s| s.reward_pool = 900_000);
+
+            assert_eq!(
+                node_health_summary(),
+                HealthSummary {
+                    bio_phase: nodara_biosphere::BioPhase::Defense,
+                    energy_level: 60,
+                    stability_parameter: 112,
+                    risk_score: 80,
+                    reserve_balance: 1_500_000,
+                    reward_pool: 900_000,
+                }
+            );
+        });
+    }
+
+    #[test]
+    fn collected_fee_is_split_per_configured_share() {
+        use nodara_marketplace::RevenueRouter;
+        sp_io::TestExternalities::default().execute_with(|| {
+            nodara_reserve_fund::Initialized::<Runtime>::put(true);
+
+            frame_support::assert_ok!(RevenueSplitter::route_revenue(1_000));
+
+            // 60 % vers le fonds de réserve, 40 % vers le pool de récompenses.
+            assert_eq!(nodara_reserve_fund::Pallet::<Runtime>::reserve_state().balance, 600);
+            assert_eq!(Balances::free_balance(ReserveFundAccount::get()), 600);
+            assert_eq!(nodara_reward_engine::Pallet::<Runtime>::reward_engine_state().reward_pool, 400);
+        });
+    }
+
+    #[test]
+    fn trade_fee_is_charged_to_the_taker_and_split() {
+        use frame_support::traits::{Currency, Get};
+        sp_io::TestExternalities::default().execute_with(|| {
+            nodara_reserve_fund::Initialized::<Runtime>::put(true);
+            let maker = AccountId::new([1u8; 32]);
+            let taker = AccountId::new([2u8; 32]);
+            for account in [&maker, &taker] {
+                nodara_reputation::Reputations::<Runtime>::insert(
+                    account,
+                    nodara_reputation::ReputationRecord { score: 300, history: Vec::new() },
+                );
+            }
+            Balances::make_free_balance_be(&taker, 1_000);
+            let order = |id, order_type, execution| nodara_marketplace::Order {
+                id,
+                asset_id: 1,
+                order_type,
+                price: 100,
+                quantity: 1,
+                account: id,
+                timestamp: 0,
+                expiry_block: None,
+                execution,
+                max_slippage_bps: None,
+            };
+
+            frame_support::assert_ok!(nodara_marketplace::Pallet::<Runtime>::place_order(
+                RuntimeOrigin::signed(maker.clone()),
+                order(1, nodara_marketplace::OrderType::Sell, nodara_marketplace::ExecutionPolicy::GTC)
+            ));
+            frame_support::assert_ok!(nodara_marketplace::Pallet::<Runtime>::place_order(
+                RuntimeOrigin::signed(taker.clone()),
+                order(2, nodara_marketplace::OrderType::Buy, nodara_marketplace::ExecutionPolicy::IOC)
+            ));
+
+            // Le preneur paie le prix au vendeur et les frais, répartis à 60/40.
+            let fee = u128::from(MarketplaceBaseTradeFee::get());
+            assert_eq!(Balances::free_balance(&taker), 1_000 - 100 - fee);
+            assert_eq!(Balances::free_balance(&maker), 100);
+            assert_eq!(nodara_reserve_fund::Pallet::<Runtime>::reserve_state().balance, 6);
+            assert_eq!(Balances::free_balance(ReserveFundAccount::get()), 6);
+            assert_eq!(nodara_reward_engine::Pallet::<Runtime>::reward_engine_state().reward_pool, 4);
+        });
+    }
+
+    #[test]
+    fn slashed_deposits_are_credited_to_their_destination() {
+        use nodara_reputation::{SlashDestination, SlashedDepositHandler};
+        sp_io::TestExternalities::default().execute_with(|| {
+            nodara_reserve_fund::Initialized::<Runtime>::put(true);
+
+            frame_support::assert_ok!(ReputationSlashAccounting::on_deposit_slashed(SlashDestination::ReserveFund, 300));
+            frame_support::assert_ok!(ReputationSlashAccounting::on_deposit_slashed(SlashDestination::RewardPool, 200));
+            frame_support::assert_ok!(ReputationSlashAccounting::on_deposit_slashed(SlashDestination::Burn, 100));
+
+            assert_eq!(nodara_reserve_fund::Pallet::<Runtime>::reserve_state().balance, 300);
+            assert_eq!(nodara_reward_engine::Pallet::<Runtime>::reward_engine_state().reward_pool, 200);
+        });
+    }
+
+    #[test]
+    fn expired_proposal_deposit_is_credited_to_the_reserve_fund() {
+        use frame_support::traits::{Currency, Hooks};
+        sp_io::TestExternalities::default().execute_with(|| {
+            nodara_reserve_fund::Initialized::<Runtime>::put(true);
+            frame_system::Pallet::<Runtime>::set_block_number(1);
+            let proposer = AccountId::new([1u8; 32]);
+            Balances::make_free_balance_be(&proposer, 1_000);
+
+            frame_support::assert_ok!(nodara_reputation::Pallet::<Runtime>::propose_parameter_update(
+                RuntimeOrigin::signed(proposer.clone()),
+                5,
+                b"penalite".to_vec()
+            ));
+            // La proposition expire sans vote : son dépôt rejoint le fonds de réserve.
+            nodara_reputation::Pallet::<Runtime>::on_initialize(1 + ReputationVotingPeriod::get() as BlockNumber);
+
+            let deposit = ReputationProposalDeposit::get();
+            assert_eq!(Balances::total_balance(&proposer), 1_000 - deposit);
+            assert_eq!(Balances::free_balance(ReserveFundAccount::get()), deposit);
+            assert_eq!(nodara_reserve_fund::Pallet::<Runtime>::reserve_state().balance, deposit);
+        });
+    }
+
+    #[test]
+    fn block_activity_drives_the_growth_multiplier() {
+        use frame_support::traits::{Get, Hooks};
+        sp_io::TestExternalities::default().execute_with(|| {
+            frame_system::Pallet::<Runtime>::set_block_number(1);
+            frame_support::assert_ok!(nodara_growth::Pallet::<Runtime>::initialize_state(RuntimeOrigin::root()));
+            let baseline = nodara_growth::Pallet::<Runtime>::growth_state().current_multiplier;
+
+            // Bloc sans activité : aucun signal, le multiplicateur ne bouge pas.
+            frame_system::Pallet::<Runtime>::note_finished_extrinsics();
+            nodara_growth::Pallet::<Runtime>::on_finalize(1);
+            assert_eq!(nodara_growth::Pallet::<Runtime>::growth_state().current_multiplier, baseline);
+
+            // Vingt extrinsèques appliquées : signal de 20 * GrowthSignalPerExtrinsic.
+            frame_system::Pallet::<Runtime>::set_block_number(2);
+            for _ in 0..20 {
+                frame_system::Pallet::<Runtime>::note_applied_extrinsic(&Ok(().into()), Default::default());
+            }
+            frame_system::Pallet::<Runtime>::note_finished_extrinsics();
+            nodara_growth::Pallet::<Runtime>::on_finalize(2);
+
+            let signal = 20 * GrowthSignalPerExtrinsic::get();
+            let smoothing = <Runtime as nodara_growth::Config>::SmoothingFactor::get();
+            let state = nodara_growth::Pallet::<Runtime>::growth_state();
+            assert!(state.current_multiplier > baseline);
+            assert_eq!(state.current_multiplier, baseline + signal / smoothing);
+            assert_eq!(state.history.last().map(|entry| entry.signal), Some(signal));
+        });
+    }
+
+    #[test]
+    fn high_reputation_account_receives_larger_reward() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            let trusted = AccountId::new([1u8; 32]);
+            let newcomer = AccountId::new([2u8; 32]);
+            for (account, score) in [(&trusted, 900), (&newcomer, 100)] {
+                nodara_reputation::Reputations::<Runtime>::insert(
+                    account,
+                    nodara_reputation::ReputationRecord { score, history: Vec::new() },
+                );
+            }
+            nodara_reward_engine::RewardEngineStorage::<Runtime>::mutate(|s| s.reward_pool = 100_000);
+
+            let distributor = || RuntimeOrigin::signed(AccountId::new([9u8; 32]));
+            frame_support::assert_ok!(nodara_reward_engine::Pallet::<Runtime>::distribute_reward(
+                distributor(), newcomer, 10_000, Vec::new()
+            ));
+            frame_support::assert_ok!(nodara_reward_engine::Pallet::<Runtime>::distribute_reward(
+                distributor(), trusted, 10_000, Vec::new()
+            ));
+
+            // Même récompense de base : 10 000 pour le nouveau venu, 15 000 (palier 800) pour le compte réputé.
+            let paid: Vec<u128> = nodara_reward_engine::Pallet::<Runtime>::reward_engine_state()
+                .history
+                .iter()
+                .map(|record| record.reward_amount)
+                .collect();
+            assert_eq!(paid, vec![10_000, 15_000]);
+        });
+    }
+
+    #[test]
+    fn high_reputation_taker_pays_lower_trade_fee() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            nodara_reserve_fund::Initialized::<Runtime>::put(true);
+            let trusted = AccountId::new([1u8; 32]);
+            let newcomer = AccountId::new([2u8; 32]);
+            let market_maker = AccountId::new([9u8; 32]);
+            // Le nouveau venu et le teneur de marché ont juste la réputation requise pour placer des ordres.
+            for (account, score) in [(&trusted, 900), (&newcomer, 300), (&market_maker, 300)] {
+                nodara_reputation::Reputations::<Runtime>::insert(
+                    account,
+                    nodara_reputation::ReputationRecord { score, history: Vec::new() },
+                );
+            }
+            // Les preneurs paient le vendeur pour chaque exécution.
+            for taker in [&trusted, &newcomer] {
+                <<Runtime as nodara_marketplace::Config>::Currency as frame_support::traits::Currency<AccountId>>::make_free_balance_be(
+                    taker, 1_000,
+                );
+            }
+            let seller = || RuntimeOrigin::signed(market_maker.clone());
+            let order = |id, order_type, execution| nodara_marketplace::Order {
+                id,
+                asset_id: 1,
+                order_type,
+                price: 10,
+                quantity: 1,
+                account: id,
+                timestamp: 0,
+                expiry_block: None,
+                execution,
+                max_slippage_bps: None,
+            };
+            let fee_paid_by = |taker: &AccountId, buy_id, sell_id| {
+                frame_support::assert_ok!(nodara_marketplace::Pallet::<Runtime>::place_order(
+                    seller(), order(sell_id, nodara_marketplace::OrderType::Sell, nodara_marketplace::ExecutionPolicy::GTC)
+                ));
+                let before = nodara_marketplace::Pallet::<Runtime>::fees_accrued();
+                frame_support::assert_ok!(nodara_marketplace::Pallet::<Runtime>::place_order(
+                    RuntimeOrigin::signed(taker.clone()),
+                    order(buy_id, nodara_marketplace::OrderType::Buy, nodara_marketplace::ExecutionPolicy::IOC)
+                ));
+                nodara_marketplace::Pallet::<Runtime>::fees_accrued() - before
+            };
+
+            // Même transaction : le preneur réputé (palier 800) paie moins que le nouveau venu.
+            let newcomer_fee = fee_paid_by(&newcomer, 1, 2);
+            let trusted_fee = fee_paid_by(&trusted, 3, 4);
+            assert!(trusted_fee < newcomer_fee);
+        });
+    }
+
+    #[test]
+    fn emergency_halt_blocks_signed_calls_across_pallets_until_resumed() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            let user = || RuntimeOrigin::signed(AccountId::new([1u8; 32]));
+            frame_support::assert_ok!(nodara_emergency::Pallet::<Runtime>::halt_all(RuntimeOrigin::root()));
+
+            frame_support::assert_noop!(
+                nodara_marketplace::Pallet::<Runtime>::register_asset(user(), 1, b"name=Nodara".to_vec()),
+                nodara_emergency::Error::<Runtime>::Halted
+            );
+            frame_support::assert_noop!(
+                nodara_reputation::Pallet::<Runtime>::initialize_reputation(user()),
+                nodara_emergency::Error::<Runtime>::Halted
+            );
+            frame_support::assert_noop!(
+                nodara_predictive_guard::Pallet::<Runtime>::initialize_predictive(user()),
+                nodara_emergency::Error::<Runtime>::Halted
+            );
+
+            frame_support::assert_ok!(nodara_emergency::Pallet::<Runtime>::resume_all(RuntimeOrigin::root()));
+
+            frame_support::assert_ok!(nodara_marketplace::Pallet::<Runtime>::register_asset(
+                user(), 1, b"name=Nodara".to_vec()
+            ));
+            frame_support::assert_ok!(nodara_reputation::Pallet::<Runtime>::initialize_reputation(user()));
+            frame_support::assert_ok!(nodara_predictive_guard::Pallet::<Runtime>::initialize_predictive(user()));
+        });
+    }
+
+    #[test]
+    fn placing_orders_requires_minimum_reputation() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            let trusted = AccountId::new([1u8; 32]);
+            let newcomer = AccountId::new([2u8; 32]);
+            let threshold = MarketplaceMinReputationToTrade::get().unwrap();
+            for (account, score) in [(&trusted, threshold), (&newcomer, threshold - 1)] {
+                nodara_reputation::Reputations::<Runtime>::insert(
+                    account,
+                    nodara_reputation::ReputationRecord { score, history: Vec::new() },
+                );
+            }
+            let sell = |id| nodara_marketplace::Order {
+                id,
+                asset_id: 1,
+                order_type: nodara_marketplace::OrderType::Sell,
+                price: 10,
+                quantity: 1,
+                account: id,
+                timestamp: 0,
+                expiry_block: None,
+                execution: nodara_marketplace::ExecutionPolicy::GTC,
+                max_slippage_bps: None,
+            };
+
+            frame_support::assert_noop!(
+                nodara_marketplace::Pallet::<Runtime>::place_order(RuntimeOrigin::signed(newcomer.clone()), sell(1)),
+                nodara_marketplace::Error::<Runtime>::ReputationTooLow
+            );
+            // Le compte ne peut pas relever lui-même son score jusqu'au seuil.
+            frame_support::assert_noop!(
+                nodara_reputation::Pallet::<Runtime>::update_reputation(
+                    RuntimeOrigin::signed(newcomer.clone()),
+                    1,
+                    nodara_reputation::ReputationReason::GoodBehavior,
+                    Vec::new()
+                ),
+                nodara_reputation::Error::<Runtime>::PositiveSelfAdjustment
+            );
+            frame_support::assert_noop!(
+                nodara_marketplace::Pallet::<Runtime>::place_order(RuntimeOrigin::signed(newcomer), sell(1)),
+                nodara_marketplace::Error::<Runtime>::ReputationTooLow
+            );
+            // Un compte sans score est refusé lui aussi.
+            frame_support::assert_noop!(
+                nodara_marketplace::Pallet::<Runtime>::place_order(RuntimeOrigin::signed(AccountId::new([3u8; 32])), sell(2)),
+                nodara_marketplace::Error::<Runtime>::ReputationTooLow
+            );
+            frame_support::assert_ok!(nodara_marketplace::Pallet::<Runtime>::place_order(
+                RuntimeOrigin::signed(trusted), sell(3)
+            ));
+            assert!(nodara_marketplace::Pallet::<Runtime>::sell_orders(3).is_some());
+        });
+    }
+
+    #[test]
+    fn marketplace_registration_is_checked_against_bridge_assets() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            pallet_bridge::SupportedAssets::<Runtime>::insert(
+                b"ETH".to_vec(),
+                pallet_bridge::AssetMetadata {
+                    name: b"Ether".to_vec(),
+                    symbol: b"ETH".to_vec(),
+                    decimals: 18,
+                    nodara_decimals: 12,
+                    source_chain: b"ETH".to_vec(),
+                },
+            );
+            let owner = || RuntimeOrigin::signed(AccountId::new([1u8; 32]));
+
+            // Décimales cohérentes avec la représentation Nodara du bridge.
+            frame_support::assert_ok!(nodara_marketplace::Pallet::<Runtime>::register_asset(
+                owner(), 1, b"name=Wrapped Ether;symbol=ETH;decimals=12".to_vec()
+            ));
+            // Décimales de la chaîne source : conflit avec la définition du bridge.
+            frame_support::assert_err!(
+                nodara_marketplace::Pallet::<Runtime>::register_asset(
+                    owner(), 2, b"name=Ether;symbol=ETH;decimals=18".to_vec()
+                ),
+                nodara_marketplace::Error::<Runtime>::AssetDefinitionConflict
+            );
+            assert!(nodara_marketplace::Pallet::<Runtime>::assets(2).is_none());
+        });
+    }
+
+    #[test]
+    fn bridge_asset_metadata_is_checked_against_the_configured_standard() {
+        use pallet_bridge::AssetCompliance;
+        frame_support::parameter_types! {
+            pub AssetStandard: Option<Vec<u8>> = Some(b"BRIDGE-ASSET".to_vec());
+        }
+        sp_io::TestExternalities::default().execute_with(|| {
+            frame_support::assert_ok!(nodara_standards::Pallet::<Runtime>::define_standard(
+                RuntimeOrigin::root(),
+                b"BRIDGE-ASSET".to_vec(),
+                b"Definition d'actif auditee".to_vec(),
+                b"audited=true".to_vec(),
+                None
+            ));
+            let metadata = |name: Vec<u8>| pallet_bridge::AssetMetadata {
+                name,
+                symbol: b"ETH".to_vec(),
+                decimals: 18,
+                nodara_decimals: 12,
+                source_chain: b"ETH".to_vec(),
+            };
+            // Le nom de l'actif conforme porte l'empreinte des paramètres du standard.
+            let mut audited_name = b"Ether ".to_vec();
+            audited_name.extend_from_slice(&sp_io::hashing::blake2_128(b"audited=true"));
+
+            type Compliance = StandardsAssetCompliance<AssetStandard>;
+            assert!(Compliance::is_compliant(b"ETH", &metadata(audited_name).encode()));
+            assert!(!Compliance::is_compliant(b"ETH", &metadata(b"Ether".to_vec()).encode()));
+            let outcomes: Vec<bool> = nodara_standards::Pallet::<Runtime>::compliance_history()
+                .iter()
+                .map(|log| log.outcome)
+                .collect();
+            assert_eq!(outcomes, vec![true, false]);
+
+            // Contrôle désactivé par défaut dans le runtime.
+            assert!(StandardsAssetCompliance::<BridgeAssetStandard>::is_compliant(
+                b"ETH",
+                &metadata(b"Ether".to_vec()).encode()
+            ));
+        });
+    }
+
+    #[test]
+    fn history_page_is_clamped_to_max_page_size() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            let cap = MaxHistoryPageSize::get();
+            nodara_pow::PowStateStorage::<Runtime>::mutate(|state| {
+                state.history = (0..u64::from(cap) + 50)
+                    .map(|block| nodara_pow::PowAdjustment { timestamp: block, old_difficulty: 1, new_difficulty: 1, signal: 0 })
+                    .collect();
+            });
+
+            let page = <Runtime as NodeRuntimeApi>::pow_history_page(10, u32::MAX);
+            assert_eq!(page.len() as u32, cap);
+            assert_eq!(page.first().map(|entry| entry.0), Some(10));
+            // Une page plus petite que le plafond n'est pas affectée.
+            assert_eq!(<Runtime as NodeRuntimeApi>::pow_history_page(0, 5).len(), 5);
+        });
+    }
+
+    #[test]
+    fn audit_export_gathers_each_module_history_within_block_range() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            nodara_reserve_fund::Initialized::<Runtime>::put(true);
+            frame_support::assert_ok!(nodara_interop::Pallet::<Runtime>::update_config_params(
+                RuntimeOrigin::root(), 300, 1_024
+            ));
+            frame_support::assert_ok!(nodara_iot::Pallet::<Runtime>::update_config_params(RuntimeOrigin::root(), 60, 1_024));
+            frame_support::assert_ok!(nodara_standards::Pallet::<Runtime>::define_standard(
+                RuntimeOrigin::root(),
+                b"AUDIT".to_vec(),
+                b"Operation auditee".to_vec(),
+                b"audited=true".to_vec(),
+                None
+            ));
+            let mut audited = b"operation ".to_vec();
+            audited.extend_from_slice(&sp_io::hashing::blake2_128(b"audited=true"));
+            let payload = b"mesure".to_vec();
+            let signature = sp_io::hashing::blake2_128(&payload).to_vec();
+
+            // Une opération par module aux blocs 0 et 15, horodatées par `pallet_timestamp` (ms).
+            for (n, block) in [0u32, 15].into_iter().enumerate() {
+                frame_system::Pallet::<Runtime>::set_block_number(block);
+                Timestamp::set_timestamp(block_start_time(block) * 1_000);
+                let user = || RuntimeOrigin::signed(AccountId::new([1 + n as u8; 32]));
+                frame_support::assert_ok!(nodara_interop::Pallet::<Runtime>::send_message(
+                    user(), n as u64, payload.clone(), signature.clone()
+                ));
+                frame_support::assert_ok!(nodara_iot::Pallet::<Runtime>::submit_iot_data(
+                    user(), n as u64, payload.clone(), b"sensor".to_vec(), signature.clone()
+                ));
+                frame_support::assert_ok!(nodara_reserve_fund::Pallet::<Runtime>::contribute(
+                    user(), 100, b"contribution".to_vec()
+                ));
+                frame_support::assert_ok!(nodara_id::Pallet::<Runtime>::register_identity(user(), b"kyc".to_vec()));
+                frame_support::assert_ok!(nodara_standards::Pallet::<Runtime>::verify_compliance(
+                    user(), b"AUDIT".to_vec(), audited.clone()
+                ));
+            }
+
+            // Blocs 10 à 19 : seules les opérations du bloc 15 sont retenues, toutes en secondes.
+            let inside = block_start_time(15);
+            let bundle = <Runtime as NodeRuntimeApi>::audit_export(10, 19, AuditCursor::default(), u32::MAX);
+            assert_eq!(bundle.interop.iter().map(|entry| entry.0).collect::<Vec<_>>(), vec![inside]);
+            assert_eq!(bundle.iot.iter().map(|entry| entry.0).collect::<Vec<_>>(), vec![inside]);
+            assert_eq!(bundle.reserve.iter().map(|record| record.timestamp).collect::<Vec<_>>(), vec![inside]);
+            assert_eq!(bundle.identity.iter().map(|entry| entry.0).collect::<Vec<_>>(), vec![inside]);
+            assert_eq!(bundle.compliance.iter().map(|log| log.timestamp).collect::<Vec<_>>(), vec![inside]);
+            assert_eq!(bundle.next, None);
+
+            // Chaque section avance selon son propre curseur : une section déjà lue reste vide
+            // tandis que les autres continuent.
+            let first_page = <Runtime as NodeRuntimeApi>::audit_export(0, 19, AuditCursor::default(), 1);
+            assert_eq!(first_page.interop.len(), 1);
+            let cursor = first_page.next.expect("entrées restantes");
+            assert_eq!(cursor, AuditCursor { interop: 1, iot: 1, reserve: 1, identity: 1, compliance: 1 });
+            let second_page = <Runtime as NodeRuntimeApi>::audit_export(0, 19, AuditCursor { reserve: 2, ..cursor }, 1);
+            assert!(second_page.reserve.is_empty());
+            assert_eq!(second_page.compliance.iter().map(|log| log.timestamp).collect::<Vec<_>>(), vec![inside]);
+            assert_eq!(second_page.next, None);
+        });
+    }
+
+    #[test]
+    fn finalized_transfer_is_announced_through_interop() {
+        use frame_support::traits::Get;
+        sp_io::TestExternalities::default().execute_with(|| {
+            frame_support::assert_ok!(nodara_interop::Pallet::<Runtime>::update_config_params(
+                RuntimeOrigin::root(), 300, 1_024
+            ));
+            pallet_bridge::SupportedAssets::<Runtime>::insert(
+                b"ETH".to_vec(),
+                pallet_bridge::AssetMetadata {
+                    name: b"Ether".to_vec(),
+                    symbol: b"ETH".to_vec(),
+                    decimals: 18,
+                    nodara_decimals: 18,
+                    source_chain: b"ETH".to_vec(),
+                },
+            );
+            let required = <Runtime as pallet_bridge::Config>::RequiredConfirmations::get();
+            let request = pallet_bridge::TransferRequest {
+                id: 7,
+                from: AccountId::new([1u8; 32]),
+                asset: b"ETH".to_vec(),
+                amount: 1_000,
+                destination: AccountId::new([2u8; 32]),
+                confirmations: (0..required).map(|i| AccountId::new([10 + i as u8; 32])).collect(),
+                to_nodara: true,
+                initiated_at: 0,
+                last_confirmed_at: None,
+            };
+            pallet_bridge::PendingTransfers::<Runtime>::insert(7, request.clone());
+            // Un message utilisateur portant le même identifiant ne masque pas la notification.
+            frame_support::assert_ok!(nodara_interop::Pallet::<Runtime>::send_message(
+                RuntimeOrigin::signed(AccountId::new([3u8; 32])),
+                7,
+                b"forged".to_vec(),
+                sp_io::hashing::blake2_128(b"forged").to_vec()
+            ));
+
+            frame_support::assert_ok!(pallet_bridge::Pallet::<Runtime>::finalize_transfer(
+                RuntimeOrigin::signed(AccountId::new([1u8; 32])), 7
+            ));
+
+            // Le destinataire est crédité de la représentation de l'actif, puis la chaîne homologue est notifiée.
+            assert_eq!(BridgedBalances::get(b"ETH".to_vec(), AccountId::new([2u8; 32])), 1_000);
+            let message = nodara_interop::Pallet::<Runtime>::module_messages(7).expect("message interop sortant");
+            assert_eq!(message.payload, request.encode());
+            assert_eq!(message.signature, sp_io::hashing::blake2_128(&request.encode()).to_vec());
+        });
+    }
+
+    #[test]
+    fn interop_and_iot_payload_limits_are_independent() {
+        sp_io::TestExternalities::default().execute_with(|| {
+            frame_support::assert_ok!(nodara_iot::Pallet::<Runtime>::update_config_params(RuntimeOrigin::root(), 60, 128));
+            frame_support::assert_ok!(nodara_interop::Pallet::<Runtime>::update_config_params(RuntimeOrigin::root(), 300, 65_536));
+            assert_eq!(nodara_iot::Pallet::<Runtime>::iot_config().max_payload_length, 128);
+            assert_eq!(nodara_interop::Pallet::<Runtime>::interop_config().max_payload_length, 65_536);
+
+            frame_support::assert_ok!(nodara_iot::Pallet::<Runtime>::update_config_params(RuntimeOrigin::root(), 60, 64));
+            assert_eq!(nodara_interop::Pallet::<Runtime>::interop_config().max_payload_length, 65_536);
+            assert_eq!(nodara_iot::Pallet::<Runtime>::iot_config().max_payload_length, 64);
+        });
+    }
+}
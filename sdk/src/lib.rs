@@ -18,26 +18,72 @@ use std::vec::Vec;
 
 pub mod error {
     use core::fmt;
+    #[cfg(not(feature = "std"))]
+    use alloc::string::String;
+
+    /// Résultat des opérations du SDK.
+    pub type Result<T> = core::result::Result<T, SdkError>;
 
-    #[derive(Debug, PartialEq, Eq)]
+    #[derive(Clone, Debug, PartialEq, Eq)]
     pub enum SdkError {
+        /// Échec de la couche de transport (connexion perdue, requête non aboutie).
+        Transport,
+        /// Échec d'encodage ou de décodage SCALE d'une donnée.
+        Codec,
+        /// Erreur retournée par le nœud RPC.
+        Rpc { code: i32, message: String },
+        /// Échec de production d'une signature.
+        Signature,
+        /// Délai de réponse dépassé.
+        Timeout,
+        /// La donnée demandée n'existe pas sur la chaîne (`what` la décrit).
+        NotFound { what: &'static str },
         ConnectionFailed,
         TransactionSubmissionFailed,
         BalanceQueryFailed,
         SignatureVerificationFailed,
         EncodingError,
         DecodingError,
+        InvalidNonce,
+        InvalidAmount,
+        AmountOverflow,
+    }
+
+    impl SdkError {
+        /// Indique si l'erreur est transitoire et justifie une nouvelle tentative.
+        ///
+        /// Les erreurs de connexion, de transport, de soumission ou les délais dépassés peuvent
+        /// disparaître d'elles-mêmes ; une erreur RPC, une signature
+        /// invalide, un nonce invalide ou une erreur d'encodage échoueront à nouveau à l'identique.
+        pub fn is_transient(&self) -> bool {
+            matches!(
+                self,
+                SdkError::ConnectionFailed
+                    | SdkError::TransactionSubmissionFailed
+                    | SdkError::Transport
+                    | SdkError::Timeout
+            )
+        }
     }
 
     impl fmt::Display for SdkError {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
             match self {
+                SdkError::Transport => write!(f, "Transport error"),
+                SdkError::Codec => write!(f, "SCALE codec error"),
+                SdkError::Rpc { code, message } => write!(f, "RPC error {}: {}", code, message),
+                SdkError::Signature => write!(f, "Signing failed"),
+                SdkError::Timeout => write!(f, "Request timed out"),
+                SdkError::NotFound { what } => write!(f, "{} not found", what),
                 SdkError::ConnectionFailed => write!(f, "Connection to Nodara network failed"),
                 SdkError::TransactionSubmissionFailed => write!(f, "Transaction submission failed"),
                 SdkError::BalanceQueryFailed => write!(f, "Balance query failed"),
                 SdkError::SignatureVerificationFailed => write!(f, "Signature verification failed"),
                 SdkError::EncodingError => write!(f, "Data encoding error"),
                 SdkError::DecodingError => write!(f, "Data decoding error"),
+                SdkError::InvalidNonce => write!(f, "Invalid transaction nonce"),
+                SdkError::InvalidAmount => write!(f, "Invalid decimal amount"),
+                SdkError::AmountOverflow => write!(f, "Amount exceeds u128 range"),
             }
         }
     }
@@ -105,7 +151,7 @@ pub mod transaction {
 }
 
 pub mod client {
-    use crate::error::SdkError;
+    use crate::error::{Result, SdkError};
     use crate::transaction::Transaction;
 
     /// Dummy client for interacting with the Nodara network.
@@ -120,13 +166,13 @@ pub mod client {
         }
 
         /// Simule la connexion au réseau Nodara.
-        pub fn connect(&mut self) -> Result<(), SdkError> {
+        pub fn connect(&mut self) -> Result<()> {
             self.connected = true;
             Ok(())
         }
 
         /// Simule la soumission d'une transaction.
-        pub fn submit_transaction(&self, tx: Transaction) -> Result<(), SdkError> {
+        pub fn submit_transaction(&self, tx: Transaction) -> Result<()> {
             if !self.connected {
                 return Err(SdkError::ConnectionFailed);
             }
@@ -135,7 +181,7 @@ pub mod client {
         }
 
         /// Simule une requête de solde pour un compte donné.
-        pub fn query_balance(&self, _account: u64) -> Result<u128, SdkError> {
+        pub fn query_balance(&self, _account: u64) -> Result<u128> {
             if !self.connected {
                 return Err(SdkError::ConnectionFailed);
             }
@@ -145,7 +191,507 @@ pub mod client {
     }
 }
 
+pub mod transport {
+    use crate::error::Result;
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+
+    /// Transport RPC générique vers un nœud Nodara.
+    ///
+    /// Les paramètres et la réponse sont encodés en SCALE ; chaque méthode documente ses types.
+    pub trait Transport {
+        /// Envoie une requête RPC et retourne la réponse brute.
+        fn request(&self, method: &str, params: &[u8]) -> Result<Vec<u8>>;
+    }
+}
+
+pub mod nonce {
+    use crate::error::{Result, SdkError};
+    use crate::transport::Transport;
+    use parity_scale_codec::{Decode, Encode};
+    #[cfg(not(feature = "std"))]
+    use alloc::collections::BTreeMap;
+    #[cfg(feature = "std")]
+    use std::collections::BTreeMap;
+
+    /// Méthode RPC retournant le prochain nonce d'un compte. Paramètres : compte (`u64`). Réponse : `u64`.
+    pub const ACCOUNT_NONCE_METHOD: &str = "system_accountNextIndex";
+
+    /// Suivi local et optimiste des nonces par compte.
+    ///
+    /// Le nonce n'est lu sur la chaîne qu'au premier usage d'un compte ou après une erreur
+    /// `InvalidNonce` ; entre-temps il est incrémenté localement à chaque soumission acceptée,
+    /// ce qui permet d'enchaîner plusieurs transactions sans attendre leur inclusion.
+    #[derive(Clone, Debug, Default)]
+    pub struct NonceManager {
+        next: BTreeMap<u64, u64>,
+    }
+
+    impl NonceManager {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Retourne le nonce à utiliser pour la prochaine transaction de `account`.
+        pub fn next_nonce<C: Transport>(&mut self, client: &C, account: u64) -> Result<u64> {
+            match self.next.get(&account) {
+                Some(nonce) => Ok(*nonce),
+                None => self.resync(client, account),
+            }
+        }
+
+        /// Enregistre l'acceptation d'une transaction utilisant `nonce`.
+        pub fn confirm(&mut self, account: u64, nonce: u64) {
+            self.next.insert(account, nonce.saturating_add(1));
+        }
+
+        /// Relit le nonce de `account` sur la chaîne et remplace la valeur locale.
+        pub fn resync<C: Transport>(&mut self, client: &C, account: u64) -> Result<u64> {
+            let raw = client.request(ACCOUNT_NONCE_METHOD, &account.encode())?;
+            let nonce = u64::decode(&mut &raw[..]).map_err(|_| SdkError::DecodingError)?;
+            self.next.insert(account, nonce);
+            Ok(nonce)
+        }
+    }
+}
+
+pub mod tx {
+    use crate::crypto::KeyPair;
+    use crate::error::{Result, SdkError};
+    use crate::nonce::NonceManager;
+    use crate::transaction::Transaction;
+    use crate::transport::Transport;
+    use core::time::Duration;
+    use parity_scale_codec::Encode;
+    #[cfg(not(feature = "std"))]
+    use alloc::vec::Vec;
+
+    /// Méthode RPC de soumission. Paramètres : `(Transaction, nonce, signature)`. Réponse : brute.
+    pub const SUBMIT_METHOD: &str = "author_submitExtrinsic";
+
+    /// Politique de nouvelle tentative avec backoff exponentiel.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct RetryPolicy {
+        /// Nombre total de tentatives, la première comprise.
+        pub max_attempts: u32,
+        /// Délai avant la deuxième tentative, doublé à chaque échec.
+        pub base_delay: Duration,
+        /// Plafond du délai entre deux tentatives.
+        pub max_delay: Duration,
+    }
+
+    impl Default for RetryPolicy {
+        fn default() -> Self {
+            Self {
+                max_attempts: 5,
+                base_delay: Duration::from_millis(200),
+                max_delay: Duration::from_secs(5),
+            }
+        }
+    }
+
+    impl RetryPolicy {
+        /// Délai à attendre après l'échec de la tentative `attempt` (à partir de 1).
+        ///
+        /// Le délai exponentiel plafonné est réparti pour moitié en partie fixe et pour moitié en
+        /// gigue, tirée de `seed` afin que des clients concurrents ne se resynchronisent pas.
+        pub fn backoff_delay(&self, attempt: u32, seed: u64) -> Duration {
+            let factor = 1u32.checked_shl(attempt.saturating_sub(1)).unwrap_or(u32::MAX);
+            let capped = self.base_delay.saturating_mul(factor).min(self.max_delay);
+            let half = capped / 2;
+            let jitter_range = half.as_nanos() as u64;
+            let jitter = if jitter_range == 0 { 0 } else { xorshift(seed ^ u64::from(attempt)) % (jitter_range + 1) };
+            half + Duration::from_nanos(jitter)
+        }
+    }
+
+    fn xorshift(mut x: u64) -> u64 {
+        x ^= 0x9E37_79B9_7F4A_7C15;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        x
+    }
+
+    /// Signe et soumet une transaction avec un nonce explicite, en une seule tentative.
+    pub fn submit<C: Transport>(client: &C, call: &Transaction, nonce: u64, signer: &KeyPair) -> Result<Vec<u8>> {
+        let signature = signer.sign(&(call, nonce).encode());
+        client.request(SUBMIT_METHOD, &(call, nonce, signature).encode())
+    }
+
+    /// Soumet une transaction avec le nonce suivi par `nonces` pour le compte émetteur.
+    ///
+    /// En cas d'erreur `InvalidNonce`, le nonce est resynchronisé depuis la chaîne et la
+    /// soumission est retentée une fois.
+    pub fn submit_with_nonce<C: Transport>(
+        client: &C,
+        nonces: &mut NonceManager,
+        call: &Transaction,
+        signer: &KeyPair,
+    ) -> Result<Vec<u8>> {
+        let nonce = nonces.next_nonce(client, call.from)?;
+        let result = match submit(client, call, nonce, signer) {
+            Err(SdkError::InvalidNonce) => {
+                let nonce = nonces.resync(client, call.from)?;
+                submit(client, call, nonce, signer).map(|response| (response, nonce))
+            }
+            other => other.map(|response| (response, nonce)),
+        };
+        let (response, used) = result?;
+        nonces.confirm(call.from, used);
+        Ok(response)
+    }
+
+    /// Soumet une transaction en réessayant sur les erreurs transitoires, en utilisant `sleep`
+    /// pour attendre entre deux tentatives.
+    ///
+    /// Les erreurs permanentes (voir `SdkError::is_transient`) sont retournées immédiatement ;
+    /// après `max_attempts` échecs, la dernière erreur est retournée.
+    pub fn submit_with_retry_using<C, S>(
+        client: &C,
+        nonces: &mut NonceManager,
+        call: &Transaction,
+        signer: &KeyPair,
+        policy: &RetryPolicy,
+        mut sleep: S,
+    ) -> Result<Vec<u8>>
+    where
+        C: Transport,
+        S: FnMut(Duration),
+    {
+        let seed = call.encode().iter().fold(0u64, |acc, b| acc.rotate_left(5) ^ u64::from(*b));
+        let mut attempt = 1;
+        loop {
+            match submit_with_nonce(client, nonces, call, signer) {
+                Ok(response) => return Ok(response),
+                Err(err) if err.is_transient() && attempt < policy.max_attempts => {
+                    sleep(policy.backoff_delay(attempt, seed));
+                    attempt += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Soumet une transaction en réessayant sur les erreurs transitoires (voir `submit_with_retry_using`).
+    #[cfg(feature = "std")]
+    pub fn submit_with_retry<C: Transport>(
+        client: &C,
+        nonces: &mut NonceManager,
+        call: &Transaction,
+        signer: &KeyPair,
+        policy: RetryPolicy,
+    ) -> Result<Vec<u8>> {
+        submit_with_retry_using(client, nonces, call, signer, &policy, std::thread::sleep)
+    }
+}
+
+pub mod events {
+    use crate::error::{Result, SdkError};
+    use crate::transport::Transport;
+    use parity_scale_codec::{Decode, Encode};
+    #[cfg(not(feature = "std"))]
+    use alloc::{string::String, vec::Vec};
+
+    /// Méthode RPC retournant `System::Events` d'un bloc.
+    /// Paramètres : numéro de bloc (`u64`). Réponse : `Vec<EventRecord>`.
+    pub const SYSTEM_EVENTS_METHOD: &str = "system_events";
+
+    /// Événement tel qu'encodé dans `System::Events`.
+    #[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+    pub struct EventRecord {
+        pub pallet: Vec<u8>,
+        pub variant: Vec<u8>,
+        pub data: Vec<u8>,
+    }
+
+    /// Événement Nodara décodé, commun à la récupération historique et à l'abonnement.
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct DecodedEvent {
+        /// Bloc dans lequel l'événement a été émis.
+        pub block: u64,
+        /// Nom du pallet émetteur (ex. `"Bridge"`).
+        pub pallet: String,
+        /// Nom de la variante d'événement (ex. `"TransferFinalized"`).
+        pub variant: String,
+        /// Champs de l'événement, encodés en SCALE.
+        pub data: Vec<u8>,
+    }
+
+    impl DecodedEvent {
+        /// Construit un événement décodé à partir d'un enregistrement brut.
+        pub fn from_record(block: u64, record: EventRecord) -> Result<Self> {
+            Ok(Self {
+                block,
+                pallet: String::from_utf8(record.pallet).map_err(|_| SdkError::DecodingError)?,
+                variant: String::from_utf8(record.variant).map_err(|_| SdkError::DecodingError)?,
+                data: record.data,
+            })
+        }
+    }
+
+    /// Récupère les événements des blocs `from_block..=to_block`, filtrés par pallet si demandé.
+    pub fn fetch<T: Transport>(
+        client: &T,
+        from_block: u64,
+        to_block: u64,
+        pallet: Option<&str>,
+    ) -> Result<Vec<DecodedEvent>> {
+        let mut events = Vec::new();
+        for block in from_block..=to_block {
+            let raw = client.request(SYSTEM_EVENTS_METHOD, &block.encode())?;
+            let records = Vec::<EventRecord>::decode(&mut &raw[..]).map_err(|_| SdkError::DecodingError)?;
+            for record in records {
+                let event = DecodedEvent::from_record(block, record)?;
+                if pallet.map_or(true, |name| event.pallet == name) {
+                    events.push(event);
+                }
+            }
+        }
+        Ok(events)
+    }
+}
+
+pub mod format {
+    use crate::error::{Result, SdkError};
+    #[cfg(not(feature = "std"))]
+    use alloc::string::{String, ToString};
+
+    /// Formate un montant brut avec exactement `decimals` chiffres après le point.
+    ///
+    /// Exemple : `format_amount(150_000_000, 8)` donne `"1.50000000"` ; sans décimales, le
+    /// montant est retourné tel quel.
+    pub fn format_amount(amount: u128, decimals: u8) -> String {
+        let digits = amount.to_string();
+        let decimals = decimals as usize;
+        if decimals == 0 {
+            return digits;
+        }
+        let padded = if digits.len() <= decimals {
+            "0".repeat(decimals + 1 - digits.len()) + &digits
+        } else {
+            digits
+        };
+        let (integer, fraction) = padded.split_at(padded.len() - decimals);
+        let mut out = String::with_capacity(padded.len() + 1);
+        out.push_str(integer);
+        out.push('.');
+        out.push_str(fraction);
+        out
+    }
+
+    /// Convertit une chaîne décimale en montant brut avec `decimals` décimales (inverse de `format_amount`).
+    ///
+    /// La partie fractionnaire peut être plus courte que `decimals` (`"1.5"`) ; les chiffres au-delà
+    /// de `decimals` ne sont acceptés que s'ils sont nuls, pour ne jamais tronquer silencieusement.
+    /// Retourne `InvalidAmount` pour une chaîne mal formée et `AmountOverflow` si le montant dépasse `u128`.
+    pub fn parse_amount(s: &str, decimals: u8) -> Result<u128> {
+        let (integer, fraction) = match s.split_once('.') {
+            Some((integer, fraction)) if !fraction.is_empty() => (integer, fraction),
+            Some(_) => return Err(SdkError::InvalidAmount),
+            None => (s, ""),
+        };
+        if integer.is_empty() || !integer.bytes().chain(fraction.bytes()).all(|b| b.is_ascii_digit()) {
+            return Err(SdkError::InvalidAmount);
+        }
+        let decimals = decimals as usize;
+        let (kept, dropped) = fraction.split_at(fraction.len().min(decimals));
+        if dropped.bytes().any(|b| b != b'0') {
+            return Err(SdkError::InvalidAmount);
+        }
+        let padding = core::iter::repeat(b'0').take(decimals - kept.len());
+        integer.bytes().chain(kept.bytes()).chain(padding).try_fold(0u128, |value, digit| {
+            value
+                .checked_mul(10)
+                .and_then(|value| value.checked_add(u128::from(digit - b'0')))
+                .ok_or(SdkError::AmountOverflow)
+        })
+    }
+}
+
+pub mod api {
+    //! Appels typés aux méthodes de `NodeRuntimeApi`.
+    //!
+    //! Chaque appel passe par `state_call` avec le nom `NodeRuntimeApi_<méthode>` et des paramètres
+    //! encodés en SCALE ; la réponse est décodée et un `None` devient `SdkError::NotFound`. Le SDK ne
+    //! dépend pas des crates du runtime : pour les méthodes retournant un type de pallet, l'appelant
+    //! fournit le type de décodage `R`.
+    use crate::error::{Result, SdkError};
+    use crate::transport::Transport;
+    use alloc::vec::Vec;
+    use parity_scale_codec::{Decode, Encode};
+
+    /// Méthode RPC exécutant une méthode de runtime API.
+    /// Paramètres : `(nom de la méthode, paramètres encodés)`. Réponse : valeur de retour encodée.
+    pub const STATE_CALL_METHOD: &str = "state_call";
+
+    /// Préfixe des méthodes de `NodeRuntimeApi`.
+    pub const API_PREFIX: &str = "NodeRuntimeApi_";
+
+    /// Appelle la méthode `name` de `NodeRuntimeApi` et décode sa valeur de retour.
+    pub fn call<C: Transport, R: Decode>(client: &C, name: &str, params: &[u8]) -> Result<R> {
+        let mut method = Vec::with_capacity(API_PREFIX.len() + name.len());
+        method.extend_from_slice(API_PREFIX.as_bytes());
+        method.extend_from_slice(name.as_bytes());
+        let raw = client.request(STATE_CALL_METHOD, &(method, params.to_vec()).encode())?;
+        R::decode(&mut &raw[..]).map_err(|_| SdkError::Codec)
+    }
+
+    /// Comme `call` pour une méthode retournant `Option<R>` ; `None` devient `NotFound { what }`.
+    pub fn call_found<C: Transport, R: Decode>(
+        client: &C,
+        name: &str,
+        params: &[u8],
+        what: &'static str,
+    ) -> Result<R> {
+        call::<C, Option<R>>(client, name, params)?.ok_or(SdkError::NotFound { what })
+    }
+
+    /// Métadonnées brutes d'un actif de la marketplace.
+    pub fn marketplace_get_asset<C: Transport>(client: &C, asset_id: u64) -> Result<Vec<u8>> {
+        call_found(client, "marketplace_get_asset", &asset_id.encode(), "marketplace asset")
+    }
+
+    /// Valeur du champ `key` des métadonnées d'un actif de la marketplace.
+    pub fn marketplace_metadata_field<C: Transport>(client: &C, asset_id: u64, key: Vec<u8>) -> Result<Vec<u8>> {
+        call_found(client, "marketplace_metadata_field", &(asset_id, key).encode(), "marketplace metadata field")
+    }
+
+    /// Frais cumulés collectés par la marketplace.
+    pub fn marketplace_fees_collected<C: Transport>(client: &C) -> Result<u128> {
+        call(client, "marketplace_fees_collected", &[])
+    }
+
+    /// État global de la biosphère (`nodara_biosphere::BioState`).
+    pub fn biosphere_get_state<C: Transport, R: Decode>(client: &C) -> Result<R> {
+        call(client, "biosphere_get_state", &[])
+    }
+
+    /// État de croissance (`nodara_growth::GrowthState`).
+    pub fn growth_get_state<C: Transport, R: Decode>(client: &C) -> Result<R> {
+        call(client, "growth_get_state", &[])
+    }
+
+    /// Identité d'un compte (`nodara_id::IdentityData`).
+    pub fn identity_get<C: Transport, R: Decode>(client: &C, account: u64) -> Result<R> {
+        call_found(client, "identity_get", &account.encode(), "identity")
+    }
+
+    /// Indique si le compte détient une identité vérifiée.
+    pub fn identity_is_verified<C: Transport>(client: &C, account: u64) -> Result<bool> {
+        call(client, "identity_is_verified", &account.encode())
+    }
+
+    /// Historique interop : `(timestamp, id, opération, détails, signataire)`.
+    pub fn interop_get_history<C: Transport, A: Decode>(client: &C) -> Result<Vec<(u64, u64, Vec<u8>, Vec<u8>, Option<A>)>> {
+        call(client, "interop_get_history", &[])
+    }
+
+    /// Identifiants des messages interop envoyés ou reçus par `account`.
+    pub fn interop_messages_for<C: Transport, A: Encode>(client: &C, account: &A) -> Result<Vec<u64>> {
+        call(client, "interop_messages_for", &account.encode())
+    }
+
+    /// Enregistrement IoT d'un message (`nodara_iot::IotRecord`).
+    pub fn iot_get_record<C: Transport, R: Decode>(client: &C, message_id: u64) -> Result<R> {
+        call_found(client, "iot_get_record", &message_id.encode(), "IoT record")
+    }
+
+    /// Drapeau `emit_events` d'un dispositif IoT enregistré.
+    pub fn iot_device_emits_events<C: Transport>(client: &C, device_id: Vec<u8>) -> Result<bool> {
+        call_found(client, "iot_device_emits_events", &device_id.encode(), "IoT device")
+    }
+
+    /// État du module de liquidité (`nodara_liquidity_flow::LiquidityState`).
+    pub fn liquidity_get_state<C: Transport, R: Decode>(client: &C) -> Result<R> {
+        call(client, "liquidity_get_state", &[])
+    }
+
+    /// État du moteur de récompenses (`nodara_reward_engine::RewardEngineState<u64>`).
+    pub fn reward_get_state<C: Transport, R: Decode>(client: &C) -> Result<R> {
+        call(client, "reward_get_state", &[])
+    }
+
+    /// État du module de stabilité (`nodara_stability_guard::StabilityState`).
+    pub fn stability_get_state<C: Transport, R: Decode>(client: &C) -> Result<R> {
+        call(client, "stability_get_state", &[])
+    }
+
+    /// Standard enregistré (`nodara_standards::Standard`).
+    pub fn standards_get_standard<C: Transport, R: Decode>(client: &C, standard_id: Vec<u8>) -> Result<R> {
+        call_found(client, "standards_get_standard", &standard_id.encode(), "standard")
+    }
+
+    /// État du module PoW (`nodara_pow::PowState`).
+    pub fn pow_get_state<C: Transport, R: Decode>(client: &C) -> Result<R> {
+        call(client, "pow_get_state", &[])
+    }
+
+    /// Nombre d'entrées de l'historique PoW.
+    pub fn pow_history_len<C: Transport>(client: &C) -> Result<u32> {
+        call(client, "pow_history_len", &[])
+    }
+
+    /// Page de l'historique PoW, bornée côté runtime par `MaxHistoryPageSize`.
+    pub fn pow_history_page<C: Transport>(client: &C, start: u32, len: u32) -> Result<Vec<(u64, u32, u32, i32)>> {
+        call(client, "pow_history_page", &(start, len).encode())
+    }
+
+    /// Mineurs ayant soumis le plus de travail : `(compte, travail)`, bornés côté runtime par
+    /// `MaxHistoryPageSize`.
+    pub fn pow_top_miners<C: Transport, A: Decode>(client: &C, n: u32) -> Result<Vec<(A, u32)>> {
+        call(client, "pow_top_miners", &n.encode())
+    }
+
+    /// Valeur prédictive courante.
+    pub fn predictive_get_value<C: Transport>(client: &C) -> Result<u32> {
+        call(client, "predictive_get_value", &[])
+    }
+
+    /// Réputation d'un compte (`nodara_reputation::ReputationRecord`).
+    pub fn reputation_get<C: Transport, R: Decode>(client: &C, account: u64) -> Result<R> {
+        call_found(client, "reputation_get", &account.encode(), "reputation record")
+    }
+
+    /// Nombre de mises à jour de réputation par motif (`nodara_reputation::ReputationReason`).
+    pub fn reputation_counts_by_reason<C: Transport, R: Decode>(client: &C, account: u64) -> Result<Vec<(R, u32)>> {
+        call(client, "reputation_counts_by_reason", &account.encode())
+    }
+
+    /// Variation de réputation effectivement appliquée pour une variation brute.
+    pub fn reputation_preview_delta<C: Transport>(client: &C, raw: i32) -> Result<i32> {
+        call(client, "reputation_preview_delta", &raw.encode())
+    }
+
+    /// État du fonds de réserve (`nodara_reserve_fund::ReserveFundState`).
+    pub fn reserve_get_state<C: Transport, R: Decode>(client: &C) -> Result<R> {
+        call(client, "reserve_get_state", &[])
+    }
+
+    /// Progression `(actuelle, requise)` d'un transfert en attente : nombre de confirmations, ou
+    /// stake confirmé par rapport au quorum lorsque les confirmations sont pondérées par le stake.
+    pub fn bridge_confirmation_status<C: Transport>(client: &C, transfer_id: u64) -> Result<(u128, u128)> {
+        call_found(client, "bridge_confirmation_status", &transfer_id.encode(), "pending transfer")
+    }
+
+    /// Volume cumulé `(vers Nodara, depuis Nodara)` d'un actif du bridge.
+    pub fn bridge_volume<C: Transport>(client: &C, asset: Vec<u8>) -> Result<(u128, u128)> {
+        call_found(client, "bridge_volume", &asset.encode(), "bridge asset")
+    }
+
+    /// Identifiants des actifs supportés par le bridge.
+    pub fn bridge_list_assets<C: Transport>(client: &C) -> Result<Vec<Vec<u8>>> {
+        call(client, "bridge_list_assets", &[])
+    }
+
+    /// Résumé de santé du nœud (`HealthSummary` du runtime).
+    pub fn node_health_summary<C: Transport, R: Decode>(client: &C) -> Result<R> {
+        call(client, "node_health_summary", &[])
+    }
+}
+
 pub mod utils {
+    use crate::error::{Result, SdkError};
     use parity_scale_codec::{Decode, Encode};
 
     /// Encode les données en utilisant SCALE.
@@ -154,15 +700,113 @@ pub mod utils {
     }
 
     /// Décode les données en utilisant SCALE.
-    pub fn decode_data<T: Decode>(data: &[u8]) -> Result<T, parity_scale_codec::Error> {
-        T::decode(&mut &data[..])
+    pub fn decode_data<T: Decode>(data: &[u8]) -> Result<T> {
+        T::decode(&mut &data[..]).map_err(|_| SdkError::Codec)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::collections::BTreeMap;
     use alloc::string::String;
+    use parity_scale_codec::{Decode, Encode};
+
+    /// Transport simulé retournant des événements préenregistrés par bloc.
+    struct MockEventsTransport {
+        blocks: BTreeMap<u64, Vec<events::EventRecord>>,
+    }
+
+    impl transport::Transport for MockEventsTransport {
+        fn request(&self, method: &str, params: &[u8]) -> Result<Vec<u8>, error::SdkError> {
+            assert_eq!(method, events::SYSTEM_EVENTS_METHOD);
+            let block = u64::decode(&mut &params[..]).map_err(|_| error::SdkError::DecodingError)?;
+            Ok(self.blocks.get(&block).cloned().unwrap_or_default().encode())
+        }
+    }
+
+    /// Transport simulé échouant `failures` fois avec `error` avant de réussir.
+    struct FlakyTransport {
+        failures: u32,
+        error: error::SdkError,
+        attempts: core::cell::Cell<u32>,
+    }
+
+    impl transport::Transport for FlakyTransport {
+        fn request(&self, method: &str, _params: &[u8]) -> Result<Vec<u8>, error::SdkError> {
+            if method == nonce::ACCOUNT_NONCE_METHOD {
+                return Ok(0u64.encode());
+            }
+            assert_eq!(method, tx::SUBMIT_METHOD);
+            let attempt = self.attempts.get() + 1;
+            self.attempts.set(attempt);
+            if attempt <= self.failures {
+                Err(self.error.clone())
+            } else {
+                Ok(vec![0x01])
+            }
+        }
+    }
+
+    fn retry_policy() -> tx::RetryPolicy {
+        tx::RetryPolicy {
+            max_attempts: 4,
+            base_delay: core::time::Duration::from_millis(100),
+            max_delay: core::time::Duration::from_millis(250),
+        }
+    }
+
+    /// Runtime API simulée : réponses encodées par nom de méthode ; une méthode absente échoue.
+    struct MockRuntimeApi {
+        responses: BTreeMap<Vec<u8>, Vec<u8>>,
+    }
+
+    impl MockRuntimeApi {
+        fn with(entries: &[(&str, Vec<u8>)]) -> Self {
+            let responses = entries
+                .iter()
+                .map(|(name, raw)| ([api::API_PREFIX.as_bytes(), name.as_bytes()].concat(), raw.clone()))
+                .collect();
+            Self { responses }
+        }
+    }
+
+    impl transport::Transport for MockRuntimeApi {
+        fn request(&self, method: &str, params: &[u8]) -> Result<Vec<u8>, error::SdkError> {
+            assert_eq!(method, api::STATE_CALL_METHOD);
+            let (name, _) = <(Vec<u8>, Vec<u8>)>::decode(&mut &params[..]).map_err(|_| error::SdkError::Codec)?;
+            self.responses.get(&name).cloned().ok_or(error::SdkError::Transport)
+        }
+    }
+
+    /// Chaîne simulée n'acceptant que le nonce attendu pour chaque soumission.
+    #[derive(Default)]
+    struct MockChain {
+        chain_nonce: core::cell::Cell<u64>,
+        nonce_queries: core::cell::Cell<u32>,
+        accepted: core::cell::RefCell<Vec<u64>>,
+    }
+
+    impl transport::Transport for MockChain {
+        fn request(&self, method: &str, params: &[u8]) -> Result<Vec<u8>, error::SdkError> {
+            if method == nonce::ACCOUNT_NONCE_METHOD {
+                self.nonce_queries.set(self.nonce_queries.get() + 1);
+                return Ok(self.chain_nonce.get().encode());
+            }
+            let (_, nonce, _) = <(transaction::Transaction, u64, Vec<u8>)>::decode(&mut &params[..])
+                .map_err(|_| error::SdkError::DecodingError)?;
+            if nonce != self.chain_nonce.get() {
+                return Err(error::SdkError::InvalidNonce);
+            }
+            self.chain_nonce.set(nonce + 1);
+            self.accepted.borrow_mut().push(nonce);
+            Ok(vec![])
+        }
+    }
+
+    fn record(pallet: &str, variant: &str) -> events::EventRecord {
+        events::EventRecord { pallet: pallet.as_bytes().to_vec(), variant: variant.as_bytes().to_vec(), data: vec![] }
+    }
 
     #[test]
     fn dummy_sdk_functionality() {
@@ -186,4 +830,193 @@ mod tests {
         let decoded: String = utils::decode_data(&encoded).unwrap();
         assert_eq!(decoded, "Hello Nodara".to_string());
     }
+
+    #[test]
+    fn fetch_events_over_block_range_with_pallet_filter() {
+        let mut blocks = BTreeMap::new();
+        blocks.insert(1, vec![record("Bridge", "TransferInitiated"), record("System", "ExtrinsicSuccess")]);
+        blocks.insert(3, vec![record("Bridge", "TransferFinalized")]);
+        blocks.insert(5, vec![record("Bridge", "TransferConfirmed")]);
+        let client = MockEventsTransport { blocks };
+
+        let all = events::fetch(&client, 1, 3, None).unwrap();
+        assert_eq!(all.len(), 3);
+
+        let bridge = events::fetch(&client, 1, 3, Some("Bridge")).unwrap();
+        let summary: Vec<(u64, String)> = bridge.into_iter().map(|e| (e.block, e.variant)).collect();
+        assert_eq!(summary, vec![(1, "TransferInitiated".to_string()), (3, "TransferFinalized".to_string())]);
+
+        assert!(events::fetch(&client, 1, 5, Some("Identity")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn submit_with_retry_recovers_from_transient_failures() {
+        let client = FlakyTransport { failures: 2, error: error::SdkError::ConnectionFailed, attempts: Default::default() };
+        let tx = transaction::Transaction::new(1, 2, 1000, vec![]);
+        let mut delays = Vec::new();
+        let result = tx::submit_with_retry_using(&client, &mut nonce::NonceManager::new(), &tx, &crypto::KeyPair::generate(), &retry_policy(), |d| delays.push(d));
+        assert_eq!(result, Ok(vec![0x01]));
+        assert_eq!(client.attempts.get(), 3);
+        assert_eq!(delays.len(), 2);
+        // Premier délai dans [50ms, 100ms], second dans [100ms, 200ms].
+        assert!(delays[0] >= core::time::Duration::from_millis(50) && delays[0] <= core::time::Duration::from_millis(100));
+        assert!(delays[1] >= core::time::Duration::from_millis(100) && delays[1] <= core::time::Duration::from_millis(200));
+    }
+
+    #[test]
+    fn submit_with_retry_gives_up_after_max_attempts() {
+        let client = FlakyTransport { failures: 10, error: error::SdkError::TransactionSubmissionFailed, attempts: Default::default() };
+        let tx = transaction::Transaction::new(1, 2, 1000, vec![]);
+        let result = tx::submit_with_retry_using(&client, &mut nonce::NonceManager::new(), &tx, &crypto::KeyPair::generate(), &retry_policy(), |_| {});
+        assert_eq!(result, Err(error::SdkError::TransactionSubmissionFailed));
+        assert_eq!(client.attempts.get(), retry_policy().max_attempts);
+    }
+
+    #[test]
+    fn submit_with_retry_bails_on_permanent_error() {
+        let client = FlakyTransport { failures: 1, error: error::SdkError::SignatureVerificationFailed, attempts: Default::default() };
+        let tx = transaction::Transaction::new(1, 2, 1000, vec![]);
+        let result = tx::submit_with_retry_using(&client, &mut nonce::NonceManager::new(), &tx, &crypto::KeyPair::generate(), &retry_policy(), |_| {});
+        assert_eq!(result, Err(error::SdkError::SignatureVerificationFailed));
+        assert_eq!(client.attempts.get(), 1);
+    }
+
+    #[test]
+    fn backoff_delay_is_capped() {
+        let policy = retry_policy();
+        for attempt in 1..10 {
+            assert!(policy.backoff_delay(attempt, 42) <= policy.max_delay);
+        }
+    }
+
+    #[test]
+    fn back_to_back_submissions_use_sequential_nonces() {
+        let chain = MockChain::default();
+        let mut nonces = nonce::NonceManager::new();
+        let keypair = crypto::KeyPair::generate();
+        for amount in 1..=3 {
+            let tx = transaction::Transaction::new(1, 2, amount, vec![]);
+            assert!(tx::submit_with_nonce(&chain, &mut nonces, &tx, &keypair).is_ok());
+        }
+        assert_eq!(*chain.accepted.borrow(), vec![0, 1, 2]);
+        assert_eq!(chain.nonce_queries.get(), 1);
+    }
+
+    #[test]
+    fn nonce_resyncs_after_out_of_sync_error() {
+        let chain = MockChain::default();
+        let mut nonces = nonce::NonceManager::new();
+        let keypair = crypto::KeyPair::generate();
+        let tx = transaction::Transaction::new(1, 2, 10, vec![]);
+        assert!(tx::submit_with_nonce(&chain, &mut nonces, &tx, &keypair).is_ok());
+        // Des transactions soumises par un autre client font avancer le nonce on-chain.
+        chain.chain_nonce.set(5);
+        assert!(tx::submit_with_nonce(&chain, &mut nonces, &tx, &keypair).is_ok());
+        assert_eq!(*chain.accepted.borrow(), vec![0, 5]);
+        assert_eq!(chain.nonce_queries.get(), 2);
+        assert_eq!(nonces.next_nonce(&chain, 1), Ok(6));
+    }
+
+    #[test]
+    fn format_amount_pads_to_asset_decimals() {
+        assert_eq!(format::format_amount(150_000_000, 8), "1.50000000");
+        assert_eq!(format::format_amount(1, 8), "0.00000001");
+        assert_eq!(format::format_amount(0, 8), "0.00000000");
+        assert_eq!(format::format_amount(1_000_000, 6), "1.000000");
+        assert_eq!(format::format_amount(42, 0), "42");
+        assert_eq!(format::format_amount(0, 0), "0");
+        assert_eq!(format::format_amount(u128::MAX, 18), "340282366920938463463.374607431768211455");
+        // Plus de décimales que de chiffres représentables dans un u128.
+        assert_eq!(format::format_amount(5, 40), format!("0.{}5", "0".repeat(39)));
+    }
+
+    #[test]
+    fn parse_amount_inverts_format_amount() {
+        for (amount, decimals) in [(150_000_000u128, 8u8), (0, 8), (1, 18), (42, 0), (u128::MAX, 18), (u128::MAX, 0), (7, 40)] {
+            assert_eq!(format::parse_amount(&format::format_amount(amount, decimals), decimals), Ok(amount));
+        }
+        // Zéros de fin omis ou superflus.
+        assert_eq!(format::parse_amount("1.5", 8), Ok(150_000_000));
+        assert_eq!(format::parse_amount("1", 8), Ok(100_000_000));
+        assert_eq!(format::parse_amount("1.500000000000", 8), Ok(150_000_000));
+        assert_eq!(format::parse_amount("3.000", 0), Ok(3));
+        assert_eq!(format::parse_amount("0", 255), Ok(0));
+    }
+
+    #[test]
+    fn parse_amount_rejects_malformed_and_overflowing_input() {
+        for input in ["", ".5", "1.", "1.2.3", "-1", "+1", "1,5", " 1", "1e8", "١"] {
+            assert_eq!(format::parse_amount(input, 8), Err(error::SdkError::InvalidAmount), "{input:?}");
+        }
+        // Précision supérieure à celle de l'actif.
+        assert_eq!(format::parse_amount("1.000000001", 8), Err(error::SdkError::InvalidAmount));
+        assert_eq!(format::parse_amount("340282366920938463463374607431768211456", 0), Err(error::SdkError::AmountOverflow));
+        assert_eq!(format::parse_amount("340282366920938463463.374607431768211456", 18), Err(error::SdkError::AmountOverflow));
+        assert_eq!(format::parse_amount("1", 39), Err(error::SdkError::AmountOverflow));
+    }
+
+    #[test]
+    fn sdk_error_variants_are_constructible_and_matchable() {
+        let errors = vec![
+            error::SdkError::Transport,
+            error::SdkError::Codec,
+            error::SdkError::Rpc { code: -32601, message: String::from("Method not found") },
+            error::SdkError::Signature,
+            error::SdkError::Timeout,
+        ];
+        let labels: Vec<&str> = errors
+            .iter()
+            .map(|err| match err {
+                error::SdkError::Transport => "transport",
+                error::SdkError::Codec => "codec",
+                error::SdkError::Rpc { code: -32601, .. } => "rpc",
+                error::SdkError::Signature => "signature",
+                error::SdkError::Timeout => "timeout",
+                _ => "other",
+            })
+            .collect();
+        assert_eq!(labels, vec!["transport", "codec", "rpc", "signature", "timeout"]);
+
+        let transient: Vec<bool> = errors.iter().map(error::SdkError::is_transient).collect();
+        assert_eq!(transient, vec![true, false, false, false, true]);
+        assert_eq!(errors[2].to_string(), "RPC error -32601: Method not found");
+    }
+
+    #[test]
+    fn decode_data_reports_codec_error() {
+        let result: error::Result<u64> = utils::decode_data(&[0x01, 0x02]);
+        assert_eq!(result, Err(error::SdkError::Codec));
+    }
+
+    #[test]
+    fn runtime_api_wrappers_decode_present_values() {
+        let client = MockRuntimeApi::with(&[
+            ("marketplace_get_asset", Some(b"asset".to_vec()).encode()),
+            ("bridge_confirmation_status", Some((2u128, 3u128)).encode()),
+            ("marketplace_fees_collected", 42u128.encode()),
+            ("bridge_list_assets", vec![b"ETH".to_vec()].encode()),
+        ]);
+        assert_eq!(api::marketplace_get_asset(&client, 7), Ok(b"asset".to_vec()));
+        assert_eq!(api::bridge_confirmation_status(&client, 1), Ok((2, 3)));
+        assert_eq!(api::marketplace_fees_collected(&client), Ok(42));
+        assert_eq!(api::bridge_list_assets(&client), Ok(vec![b"ETH".to_vec()]));
+    }
+
+    #[test]
+    fn runtime_api_wrappers_map_absent_and_invalid_values_to_errors() {
+        let client = MockRuntimeApi::with(&[
+            ("marketplace_get_asset", Option::<Vec<u8>>::None.encode()),
+            ("bridge_volume", Option::<(u128, u128)>::None.encode()),
+            ("pow_history_len", vec![0x01]),
+        ]);
+        let missing = api::marketplace_get_asset(&client, 7);
+        assert_eq!(missing, Err(error::SdkError::NotFound { what: "marketplace asset" }));
+        assert_eq!(missing.unwrap_err().to_string(), "marketplace asset not found");
+        assert_eq!(
+            api::bridge_volume(&client, b"ETH".to_vec()),
+            Err(error::SdkError::NotFound { what: "bridge asset" })
+        );
+        assert_eq!(api::pow_history_len(&client), Err(error::SdkError::Codec));
+        assert_eq!(api::predictive_get_value(&client), Err(error::SdkError::Transport));
+    }
 }